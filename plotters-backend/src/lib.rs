@@ -67,7 +67,8 @@ pub mod rasterizer;
 mod style;
 mod text;
 
-pub use style::{BackendColor, BackendStyle};
+pub use rasterizer::BitmapInterpolation;
+pub use style::{BackendColor, BackendStyle, DashPattern, LineCap, LineJoin};
 pub use text::{text_anchor, BackendTextStyle, FontFamily, FontStyle, FontTransform};
 
 use text_anchor::{HPos, VPos};
@@ -157,6 +158,23 @@ pub trait DrawingBackend: Sized {
         rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    /// Draw a rectangle with rounded corners on the drawing backend
+    /// - `upper_left`: The coordinate of the upper-left corner of the rect
+    /// - `bottom_right`: The coordinate of the bottom-right corner of the rect
+    /// - `radius`: The radius of the corners, which is limited to half of the shorter side
+    /// - `style`: The style
+    /// - `fill`: If the rectangle should be filled
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        rasterizer::draw_rounded_rect(self, upper_left, bottom_right, radius, style, fill)
+    }
+
     /// Draw a path on the drawing backend
     /// - `path`: The iterator of key points of the path
     /// - `style`: The style of the path
@@ -169,6 +187,14 @@ pub trait DrawingBackend: Sized {
             return Ok(());
         }
 
+        if let Some(pattern) = style.dash_pattern() {
+            let solid = style::SolidStyle::of(style);
+            for dash in rasterizer::dash_path(path, pattern) {
+                self.draw_path(dash, &solid)?;
+            }
+            return Ok(());
+        }
+
         if style.stroke_width() == 1 {
             let mut begin: Option<BackendCoord> = None;
             for end in path.into_iter() {
@@ -183,7 +209,16 @@ pub trait DrawingBackend: Sized {
             }
         } else {
             let p: Vec<_> = path.into_iter().collect();
-            let v = rasterizer::polygonize(&p[..], style.stroke_width());
+            let v = if p.len() > 2 && p.first() == p.last() {
+                rasterizer::polygonize_closed_with(&p[..], style.stroke_width(), style.line_join())
+            } else {
+                rasterizer::polygonize_with(
+                    &p[..],
+                    style.stroke_width(),
+                    style.line_join(),
+                    style.line_cap(),
+                )
+            };
             return self.fill_polygon(v, &style.color());
         }
         Ok(())
@@ -247,6 +282,49 @@ pub trait DrawingBackend: Sized {
         };
         let trans = style.transform();
         let (w, h) = self.get_size();
+
+        if let Some((halo_color, halo_width)) = style.halo() {
+            // The glyphs are collected first, since the halo is drawn underneath them
+            let mut glyph = vec![];
+            style
+                .draw(text, (0, 0), |x, y, color| {
+                    let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
+                    glyph.push((pos.0 + x, pos.1 + y, color));
+                    Ok::<(), DrawingErrorKind<Self::ErrorType>>(())
+                })
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))??;
+
+            // The coverage can't be recovered from a fully transparent text, which has no halo
+            let coverage: Vec<_> = glyph
+                .iter()
+                .map(|(x, y, c)| {
+                    let alpha = if color.alpha > 0.0 {
+                        c.alpha / color.alpha
+                    } else {
+                        0.0
+                    };
+                    (*x, *y, alpha)
+                })
+                .collect();
+            let halo = text::halo_pixels(&coverage, halo_width);
+            let pixels = halo
+                .into_iter()
+                .map(|(x, y, alpha)| {
+                    let color = BackendColor {
+                        alpha: halo_color.alpha * alpha,
+                        rgb: halo_color.rgb,
+                    };
+                    (x, y, color)
+                })
+                .chain(glyph);
+            for (x, y, color) in pixels {
+                if x >= 0 && x < w as i32 && y >= 0 && y < h as i32 {
+                    self.draw_pixel((x, y), color)?;
+                }
+            }
+            return Ok(());
+        }
+
         let drawing_result = style.draw(text, (0, 0), |x, y, color| {
             let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
             let (x, y) = (pos.0 + x, pos.1 + y);
@@ -262,6 +340,22 @@ pub trait DrawingBackend: Sized {
         }
     }
 
+    /// Draw a batch of texts on the drawing backend, such as the tick labels of an axis
+    ///
+    /// - `texts`: The texts to draw, each with its anchor point and style
+    ///
+    /// The default implementation simply calls [draw_text](trait.DrawingBackend.html#method.draw_text)
+    /// for each text, backends may override it to share the font setup among the texts.
+    fn draw_texts<TStyle: BackendTextStyle>(
+        &mut self,
+        texts: &[(&str, BackendCoord, &TStyle)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (text, pos, style) in texts {
+            self.draw_text(text, *style, *pos)?;
+        }
+        Ok(())
+    }
+
     /// Estimate the size of the horizontal text if rendered on this backend.
     /// This is important because some of the backend may not have font ability.
     /// Thus this allows those backend reports proper value rather than ask the
@@ -326,4 +420,89 @@ pub trait DrawingBackend: Sized {
 
         Ok(())
     }
+
+    /// Blit a bitmap on to the backend, scaling it so that it fills the given rectangle.
+    ///
+    /// - `upper_left`: The upper-left corner of the target rectangle
+    /// - `bottom_right`: The bottom-right corner of the target rectangle
+    /// - `src_size`: The size of the source bitmap
+    /// - `src`: The source of the image
+    /// - `interpolation`: How the pixels should be interpolated when the bitmap is scaled
+    ///
+    /// The default implementation resamples the visible part of the bitmap with the built-in
+    /// rasterizer and then calls [blit_bitmap](trait.DrawingBackend.html#method.blit_bitmap),
+    /// thus it has the same assumption on the pixel format (RGB).
+    fn blit_bitmap_scaled(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        src_size: (u32, u32),
+        src: &[u8],
+        interpolation: BitmapInterpolation,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        rasterizer::blit_scaled_bitmap(
+            self,
+            upper_left,
+            bottom_right,
+            src_size,
+            src,
+            3,
+            interpolation,
+        )
+    }
+
+    /// Clip the following drawing operations to a polygon, until
+    /// [end_clip](trait.DrawingBackend.html#method.end_clip) is called.
+    ///
+    /// - `vert`: The vertices of the clipping polygon
+    /// - **returns**: If the backend supports clipping natively. If it doesn't, which is the
+    ///   default, the backend is unchanged and the caller is responsible for clipping, for example
+    ///   with the [PolygonClip](rasterizer/struct.PolygonClip.html) scanline mask.
+    fn begin_clip_polygon(
+        &mut self,
+        _vert: &[BackendCoord],
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
+
+    /// Clip the following drawing operations to a rectangle, until
+    /// [end_clip](trait.DrawingBackend.html#method.end_clip) is called.
+    ///
+    /// - `upper_left`, `bottom_right`: The corners of the rectangle, both are inclusive
+    /// - **returns**: If the backend supports clipping natively. The default implementation clips
+    ///   to the polygon of the rectangle with
+    ///   [begin_clip_polygon](trait.DrawingBackend.html#method.begin_clip_polygon), the backends
+    ///   may override it with a cheaper rectangular clipping, for example with
+    ///   [clip_line](rasterizer/fn.clip_line.html).
+    fn begin_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let (x0, y0) = upper_left;
+        let (x1, y1) = (bottom_right.0 + 1, bottom_right.1 + 1);
+        self.begin_clip_polygon(&[(x0, y0), (x1, y0), (x1, y1), (x0, y1)])
+    }
+
+    /// Stop clipping started by a successful
+    /// [begin_clip_polygon](trait.DrawingBackend.html#method.begin_clip_polygon) or
+    /// [begin_clip_rect](trait.DrawingBackend.html#method.begin_clip_rect)
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Embed a piece of metadata, such as the information needed to reproduce the chart, into
+    /// the output of the backend
+    ///
+    /// - `key`: The name of the metadata
+    /// - `value`: The value of the metadata
+    /// - **returns**: If the backend is able to embed the metadata. The default implementation
+    ///   simply discards it and returns false.
+    fn add_metadata(
+        &mut self,
+        _key: &str,
+        _value: &str,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
 }
@@ -0,0 +1,86 @@
+use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+
+/// Approximate a cubic Bezier curve with a polyline.
+///
+/// The curve is split into segments of about 2 pixels long, estimated by the length of the
+/// control polygon, which is never shorter than the curve itself.
+///
+/// - `from`: The start point of the curve
+/// - `ctrl0`: The control point of the start point
+/// - `ctrl1`: The control point of the end point
+/// - `to`: The end point of the curve
+/// - **returns**: The vertices of the polyline, including both end points
+pub fn flatten_cubic_bezier(
+    from: (f64, f64),
+    ctrl0: (f64, f64),
+    ctrl1: (f64, f64),
+    to: (f64, f64),
+) -> Vec<BackendCoord> {
+    let dist = |a: (f64, f64), b: (f64, f64)| (b.0 - a.0).hypot(b.1 - a.1);
+    let len = dist(from, ctrl0) + dist(ctrl0, ctrl1) + dist(ctrl1, to);
+    let steps = if len.is_finite() {
+        ((len / 2.0).ceil() as usize).clamp(1, 1024)
+    } else {
+        1
+    };
+
+    let mut ret: Vec<BackendCoord> = Vec::with_capacity(steps + 1);
+    for i in 0..=steps {
+        let t = i as f64 / steps as f64;
+        let s = 1.0 - t;
+        let (a, b, c, d) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+        let point = (
+            (a * from.0 + b * ctrl0.0 + c * ctrl1.0 + d * to.0).round() as i32,
+            (a * from.1 + b * ctrl0.1 + c * ctrl1.1 + d * to.1).round() as i32,
+        );
+        if ret.last() != Some(&point) {
+            ret.push(point);
+        }
+    }
+    ret
+}
+
+/// Draw a cubic Bezier curve, which is approximated by a path.
+///
+/// - `back`: The drawing backend
+/// - `points`: The start point, the two control points and the end point of the curve
+/// - `style`: The style of the curve
+pub fn draw_cubic_bezier<DB: DrawingBackend, S: BackendStyle>(
+    back: &mut DB,
+    points: [BackendCoord; 4],
+    style: &S,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let [from, ctrl0, ctrl1, to] = points;
+    let to_f = |(x, y): BackendCoord| (f64::from(x), f64::from(y));
+    let path = flatten_cubic_bezier(to_f(from), to_f(ctrl0), to_f(ctrl1), to_f(to));
+    if path.len() < 2 {
+        return Ok(());
+    }
+    back.draw_path(path, style)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flatten_cubic_bezier() {
+        // A straight line stays on the line
+        let path = flatten_cubic_bezier((0.0, 0.0), (10.0, 0.0), (20.0, 0.0), (30.0, 0.0));
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(30, 0)));
+        assert!(path.len() > 10);
+        assert!(path.iter().all(|&(_, y)| y == 0));
+        assert!(path.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // A symmetric arch reaches 3/4 of the height of its control points in the middle
+        let path = flatten_cubic_bezier((0.0, 0.0), (0.0, 40.0), (40.0, 40.0), (40.0, 0.0));
+        assert_eq!(path.iter().map(|p| p.1).max(), Some(30));
+        assert!(path.contains(&(20, 30)));
+
+        assert_eq!(
+            flatten_cubic_bezier((1.0, 1.0), (1.0, 1.0), (1.0, 1.0), (1.0, 1.0)),
+            [(1, 1)]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The interpolation method used when a bitmap is scaled during blitting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitmapInterpolation {
+    /// Use the value of the closest source pixel
+    Nearest,
+    /// Linearly interpolate the four surrounding source pixels
+    Bilinear,
+}
+
+/// Resample a bitmap buffer to a new size.
+///
+/// Only the part of the scaled image covered by `window` is produced, which allows the caller to
+/// skip the pixels that will be clipped anyway. The window is given in the pixel coordinate of the
+/// scaled image and the returned buffer is laid out row by row with the window's size.
+///
+/// - `src`: The source buffer
+/// - `src_size`: The size of the source bitmap in pixels
+/// - `pixel_size`: The number of bytes used by each pixel, each byte is interpolated independently
+/// - `dst_size`: The size of the scaled image
+/// - `window`: The upper-left and bottom-right (exclusive) corners of the part to produce
+/// - `interpolation`: The interpolation method
+/// - **returns**: The resampled buffer
+pub fn scale_bitmap(
+    src: &[u8],
+    (sw, sh): (u32, u32),
+    pixel_size: usize,
+    (dw, dh): (u32, u32),
+    ((x0, y0), (x1, y1)): (BackendCoord, BackendCoord),
+    interpolation: BitmapInterpolation,
+) -> Vec<u8> {
+    let (ww, wh) = ((x1 - x0).max(0) as usize, (y1 - y0).max(0) as usize);
+    let mut ret = vec![0; ww * wh * pixel_size];
+
+    if sw == 0 || sh == 0 || dw == 0 || dh == 0 {
+        return ret;
+    }
+
+    let x_scale = f64::from(sw) / f64::from(dw);
+    let y_scale = f64::from(sh) / f64::from(dh);
+
+    let pixel_at = |x: u32, y: u32| {
+        let offset = (y as usize * sw as usize + x as usize) * pixel_size;
+        &src[offset..offset + pixel_size]
+    };
+
+    let mut dst = ret.chunks_exact_mut(pixel_size);
+
+    for y in y0..y1 {
+        // Sample at the center of the destination pixel
+        let sy = ((f64::from(y) + 0.5) * y_scale - 0.5).clamp(0.0, f64::from(sh - 1));
+        for x in x0..x1 {
+            let sx = ((f64::from(x) + 0.5) * x_scale - 0.5).clamp(0.0, f64::from(sw - 1));
+            let out = dst.next().unwrap();
+            match interpolation {
+                BitmapInterpolation::Nearest => {
+                    out.copy_from_slice(pixel_at(sx.round() as u32, sy.round() as u32));
+                }
+                BitmapInterpolation::Bilinear => {
+                    let (left, top) = (sx.floor() as u32, sy.floor() as u32);
+                    let (right, bottom) = ((left + 1).min(sw - 1), (top + 1).min(sh - 1));
+                    let (fx, fy) = (sx - f64::from(left), sy - f64::from(top));
+                    let (tl, tr) = (pixel_at(left, top), pixel_at(right, top));
+                    let (bl, br) = (pixel_at(left, bottom), pixel_at(right, bottom));
+                    for (idx, value) in out.iter_mut().enumerate() {
+                        let t = f64::from(tl[idx]) * (1.0 - fx) + f64::from(tr[idx]) * fx;
+                        let b = f64::from(bl[idx]) * (1.0 - fx) + f64::from(br[idx]) * fx;
+                        *value = (t * (1.0 - fy) + b * fy).round() as u8;
+                    }
+                }
+            }
+        }
+    }
+
+    ret
+}
+
+/// Blit a bitmap on to the backend, scaling it so that it fills the given rectangle.
+///
+/// The visible part of the target rectangle is resampled with [scale_bitmap] and then copied with
+/// `DrawingBackend::blit_bitmap`, so the pixels outside of the backend are never computed.
+///
+/// - `back`: The backend to draw on
+/// - `upper_left`, `bottom_right`: The target rectangle
+/// - `src_size`: The size of the source bitmap
+/// - `src`: The source buffer
+/// - `pixel_size`: The number of bytes used by each pixel in `src`
+/// - `interpolation`: The interpolation method
+pub fn blit_scaled_bitmap<DB: DrawingBackend>(
+    back: &mut DB,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    src_size: (u32, u32),
+    src: &[u8],
+    pixel_size: usize,
+    interpolation: BitmapInterpolation,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let (x0, x1) = (
+        upper_left.0.min(bottom_right.0),
+        upper_left.0.max(bottom_right.0),
+    );
+    let (y0, y1) = (
+        upper_left.1.min(bottom_right.1),
+        upper_left.1.max(bottom_right.1),
+    );
+    let (w, h) = back.get_size();
+
+    // The visible part, in the coordinate of the scaled image
+    let window = (
+        ((-x0).max(0), (-y0).max(0)),
+        (x1.min(w as i32) - x0, y1.min(h as i32) - y0),
+    );
+    let window_size = ((window.1).0 - (window.0).0, (window.1).1 - (window.0).1);
+
+    if window_size.0 <= 0 || window_size.1 <= 0 {
+        return Ok(());
+    }
+
+    let buf = scale_bitmap(
+        src,
+        src_size,
+        pixel_size,
+        ((x1 - x0) as u32, (y1 - y0) as u32),
+        window,
+        interpolation,
+    );
+
+    back.blit_bitmap(
+        (x0 + (window.0).0, y0 + (window.0).1),
+        (window_size.0 as u32, window_size.1 as u32),
+        &buf,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scale_nearest() {
+        let src = [0, 10, 20, 30];
+        let ret = scale_bitmap(
+            &src,
+            (2, 2),
+            1,
+            (4, 4),
+            ((0, 0), (4, 4)),
+            BitmapInterpolation::Nearest,
+        );
+        assert_eq!(
+            ret,
+            vec![0, 0, 10, 10, 0, 0, 10, 10, 20, 20, 30, 30, 20, 20, 30, 30]
+        );
+    }
+
+    #[test]
+    fn test_scale_bilinear_window() {
+        let src = [0, 100];
+        let ret = scale_bitmap(
+            &src,
+            (2, 1),
+            1,
+            (4, 1),
+            ((1, 0), (3, 1)),
+            BitmapInterpolation::Bilinear,
+        );
+        assert_eq!(ret, vec![25, 75]);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::BackendCoord;
+use std::convert::TryFrom;
+
+/// The scanline mask of a polygon, which is used to clip the drawing to an arbitrary polygon on
+/// the backends without native clipping support.
+///
+/// For each row, the mask holds the spans of pixels inside the polygon, using the same even-odd
+/// rule as [point_in_polygon](fn.point_in_polygon.html).
+#[derive(Clone, Debug)]
+pub struct PolygonClip {
+    top: i32,
+    rows: Vec<Vec<(i32, i32)>>,
+}
+
+impl PolygonClip {
+    /// Build the scanline mask of a polygon
+    ///
+    /// - `vertices`: The vertices of the polygon, the polygon is implicitly closed
+    /// - `size`: The size of the backend, only the rows of the polygon on the backend are kept,
+    ///   thus a huge polygon doesn't cost more than the backend has rows
+    /// - **returns**: The scanline mask
+    pub fn new(vertices: &[BackendCoord], size: (u32, u32)) -> Self {
+        let height = i32::try_from(size.1).unwrap_or(i32::MAX);
+        let top = vertices.iter().map(|p| p.1).min().unwrap_or(0).max(0);
+        let bottom = vertices
+            .iter()
+            .map(|p| p.1)
+            .max()
+            .unwrap_or(-1)
+            .min(height - 1);
+
+        let rows = (top..=bottom)
+            .map(|y| {
+                let py = f64::from(y);
+                let mut crossings: Vec<f64> = vertices
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, a)| {
+                        let b = &vertices[(idx + 1) % vertices.len()];
+                        let (ax, ay) = (f64::from(a.0), f64::from(a.1));
+                        let (bx, by) = (f64::from(b.0), f64::from(b.1));
+                        if (ay > py) != (by > py) {
+                            Some(ax + (py - ay) * (bx - ax) / (by - ay))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                crossings
+                    .chunks(2)
+                    .filter(|span| span.len() == 2)
+                    .map(|span| (span[0].ceil() as i32, span[1].ceil() as i32))
+                    .filter(|(start, end)| start < end)
+                    .collect()
+            })
+            .collect();
+
+        Self { top, rows }
+    }
+
+    /// Check if the pixel is inside the clipping polygon
+    ///
+    /// - `point`: The pixel to check
+    /// - **returns**: If the pixel should be drawn
+    pub fn contains(&self, (x, y): BackendCoord) -> bool {
+        if y < self.top {
+            return false;
+        }
+        self.rows
+            .get((y - self.top) as usize)
+            .into_iter()
+            .flatten()
+            .any(|&(start, end)| start <= x && x < end)
+    }
+}
+
+/// Clip a line segment to a rectangle with the Cohen-Sutherland algorithm, which is used by the
+/// backends to skip the part of a line outside of the clipping rectangle before rasterizing it.
+///
+/// - `from`, `to`: The end points of the segment
+/// - `upper_left`, `bottom_right`: The corners of the rectangle, both are inclusive
+/// - **returns**: The visible part of the segment, or `None` if the segment is completely outside
+///   of the rectangle
+pub fn clip_line(
+    from: BackendCoord,
+    to: BackendCoord,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+) -> Option<(BackendCoord, BackendCoord)> {
+    const LEFT: u8 = 1;
+    const RIGHT: u8 = 2;
+    const TOP: u8 = 4;
+    const BOTTOM: u8 = 8;
+
+    let (x0, y0) = (f64::from(upper_left.0), f64::from(upper_left.1));
+    let (x1, y1) = (f64::from(bottom_right.0), f64::from(bottom_right.1));
+    let outcode = |(x, y): (f64, f64)| {
+        let horizontal = if x < x0 {
+            LEFT
+        } else if x > x1 {
+            RIGHT
+        } else {
+            0
+        };
+        let vertical = if y < y0 {
+            TOP
+        } else if y > y1 {
+            BOTTOM
+        } else {
+            0
+        };
+        horizontal | vertical
+    };
+
+    let (mut a, mut b) = (
+        (f64::from(from.0), f64::from(from.1)),
+        (f64::from(to.0), f64::from(to.1)),
+    );
+    let (mut code_a, mut code_b) = (outcode(a), outcode(b));
+    loop {
+        if code_a | code_b == 0 {
+            let round = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+            return Some((round(a), round(b)));
+        }
+        if code_a & code_b != 0 {
+            return None;
+        }
+
+        // Move the end point outside of the rectangle to the edge it is beyond
+        let code = if code_a != 0 { code_a } else { code_b };
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let point = if code & TOP != 0 {
+            (a.0 + dx * (y0 - a.1) / dy, y0)
+        } else if code & BOTTOM != 0 {
+            (a.0 + dx * (y1 - a.1) / dy, y1)
+        } else if code & LEFT != 0 {
+            (x0, a.1 + dy * (x0 - a.0) / dx)
+        } else {
+            (x1, a.1 + dy * (x1 - a.0) / dx)
+        };
+        if code == code_a {
+            a = point;
+            code_a = outcode(a);
+        } else {
+            b = point;
+            code_b = outcode(b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rasterizer::point_in_polygon;
+
+    #[test]
+    fn test_polygon_clip() {
+        let triangle = [(0, 0), (20, 10), (0, 20)];
+        let clip = PolygonClip::new(&triangle, (25, 25));
+        for x in -5..25 {
+            for y in -5..25 {
+                assert_eq!(
+                    clip.contains((x, y)),
+                    point_in_polygon((x, y), &triangle),
+                    "{:?}",
+                    (x, y)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_clip_taller_than_backend() {
+        let tall = [
+            (0, -20_000_000),
+            (100, -20_000_000),
+            (100, 20_000_000),
+            (0, 20_000_000),
+        ];
+        let clip = PolygonClip::new(&tall, (100, 50));
+        assert_eq!(clip.rows.len(), 50);
+        assert!(clip.contains((10, 0)) && clip.contains((10, 49)));
+        assert!(!clip.contains((10, -1)) && !clip.contains((10, 50)));
+    }
+
+    #[test]
+    fn test_clip_line() {
+        let clip = |from, to| clip_line(from, to, (0, 0), (100, 50));
+        assert_eq!(clip((10, 10), (90, 40)), Some(((10, 10), (90, 40))));
+        assert_eq!(clip((-50, 25), (150, 25)), Some(((0, 25), (100, 25))));
+        assert_eq!(clip((50, -50), (50, 100)), Some(((50, 0), (50, 50))));
+        assert_eq!(clip((-10, -10), (60, 60)), Some(((0, 0), (50, 50))));
+        assert_eq!(clip((150, 100), (50, 0)), Some(((100, 50), (50, 0))));
+        // Completely outside, including the segments passing by the corners
+        assert_eq!(clip((-10, -10), (-5, 60)), None);
+        assert_eq!(clip((200, 60), (50, -90)), None);
+        assert_eq!(clip((90, -20), (130, 20)), None);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{BackendCoord, DashPattern};
+
+/// Split a path into the dashes of the given dash pattern.
+///
+/// The pattern runs continuously through the vertices of the path, thus a dash may turn a corner,
+/// in which case it contains the corner as a vertex.
+///
+/// - `path`: The key points of the path
+/// - `pattern`: The dash pattern
+/// - **returns**: The dashes, each of which is a path with at least 2 points
+pub fn dash_path<I: IntoIterator<Item = BackendCoord>>(
+    path: I,
+    pattern: DashPattern,
+) -> Vec<Vec<BackendCoord>> {
+    let to_i = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+
+    let mut path = path.into_iter();
+    let mut start = match path.next() {
+        Some(p) => (f64::from(p.0), f64::from(p.1)),
+        None => return vec![],
+    };
+
+    if pattern.off == 0 {
+        let dash: Vec<_> = std::iter::once(to_i(start)).chain(path).collect();
+        return if dash.len() > 1 { vec![dash] } else { vec![] };
+    }
+    if pattern.on == 0 {
+        return vec![];
+    }
+
+    let (on, off) = (f64::from(pattern.on), f64::from(pattern.off));
+    let phase = f64::from(pattern.offset % (pattern.on + pattern.off));
+    let (mut is_solid, mut dist) = if phase < on {
+        (true, phase)
+    } else {
+        (false, phase - on)
+    };
+
+    let mut ret = vec![];
+    let mut dash = vec![];
+    if is_solid {
+        dash.push(to_i(start));
+    }
+
+    for p in path {
+        let end = (f64::from(p.0), f64::from(p.1));
+        while start != end {
+            let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+            let d = dx.hypot(dy);
+            let size = if is_solid { on } else { off };
+            let left = size - dist;
+            if left < d {
+                let t = left / d;
+                start = (start.0 + dx * t, start.1 + dy * t);
+                dist = size;
+            } else {
+                start = end;
+                dist += d;
+            }
+
+            if is_solid {
+                dash.push(to_i(start));
+            }
+
+            if size <= dist {
+                if is_solid {
+                    ret.push(std::mem::take(&mut dash));
+                } else {
+                    dash.push(to_i(start));
+                }
+                dist = 0.0;
+                is_solid = !is_solid;
+            }
+        }
+    }
+
+    if dash.len() > 1 {
+        ret.push(dash);
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dash_path() {
+        let path = vec![(0, 0), (20, 0), (20, 10)];
+        let dashes = dash_path(path.clone(), DashPattern::new(6, 4));
+        assert_eq!(
+            dashes,
+            [
+                vec![(0, 0), (6, 0)],
+                vec![(10, 0), (16, 0)],
+                vec![(20, 0), (20, 6)],
+            ]
+        );
+
+        // The offset shifts the pattern, and a dash may turn the corner
+        let dashes = dash_path(path.clone(), DashPattern::new(6, 4).offset(4));
+        assert_eq!(
+            dashes,
+            [
+                vec![(0, 0), (2, 0)],
+                vec![(6, 0), (12, 0)],
+                vec![(16, 0), (20, 0), (20, 2)],
+                vec![(20, 6), (20, 10)],
+            ]
+        );
+
+        assert_eq!(dash_path(path.clone(), DashPattern::new(6, 0)), [path]);
+        assert!(dash_path(vec![(0, 0), (20, 0)], DashPattern::new(0, 4)).is_empty());
+    }
+}
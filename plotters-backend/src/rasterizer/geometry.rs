@@ -0,0 +1,89 @@
+use crate::BackendCoord;
+
+/// Check if a point is inside a polygon, using the even-odd rule which is also used by the
+/// built-in polygon rasterizer.
+///
+/// - `point`: The point to test
+/// - `vertices`: The vertices of the polygon, the polygon is implicitly closed
+/// - **returns**: If the point is inside the polygon. Points exactly on the edges may be reported
+///   either way.
+pub fn point_in_polygon(point: BackendCoord, vertices: &[BackendCoord]) -> bool {
+    let (px, py) = (f64::from(point.0), f64::from(point.1));
+    let mut inside = false;
+
+    for (idx, a) in vertices.iter().enumerate() {
+        let b = &vertices[(idx + 1) % vertices.len()];
+        let (ax, ay) = (f64::from(a.0), f64::from(a.1));
+        let (bx, by) = (f64::from(b.0), f64::from(b.1));
+
+        if (ay > py) != (by > py) && px < ax + (py - ay) * (bx - ax) / (by - ay) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Compute the intersection point of two line segments.
+///
+/// - `a`, `b`: The two segments, each of them is given by its two end points
+/// - **returns**: The intersection point, or `None` if the segments don't intersect or they are
+///   parallel
+pub fn segment_intersection(
+    a: (BackendCoord, BackendCoord),
+    b: (BackendCoord, BackendCoord),
+) -> Option<(f64, f64)> {
+    let (a0, a1) = (to_f64(a.0), to_f64(a.1));
+    let (b0, b1) = (to_f64(b.0), to_f64(b.1));
+
+    let da = (a1.0 - a0.0, a1.1 - a0.1);
+    let db = (b1.0 - b0.0, b1.1 - b0.1);
+
+    let denom = da.0 * db.1 - da.1 * db.0;
+    if denom.abs() <= f64::EPSILON {
+        return None;
+    }
+
+    let d0 = (b0.0 - a0.0, b0.1 - a0.1);
+    let u = (d0.0 * db.1 - d0.1 * db.0) / denom;
+    let v = (d0.0 * da.1 - d0.1 * da.0) / denom;
+
+    if !(0.0..=1.0).contains(&u) || !(0.0..=1.0).contains(&v) {
+        return None;
+    }
+
+    Some((a0.0 + u * da.0, a0.1 + u * da.1))
+}
+
+fn to_f64(p: BackendCoord) -> (f64, f64) {
+    (f64::from(p.0), f64::from(p.1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_point_in_polygon() {
+        let square = [(0, 0), (10, 0), (10, 10), (0, 10)];
+        assert!(point_in_polygon((5, 5), &square));
+        assert!(!point_in_polygon((15, 5), &square));
+        assert!(!point_in_polygon((5, -1), &square));
+    }
+
+    #[test]
+    fn test_segment_intersection() {
+        assert_eq!(
+            segment_intersection(((0, 0), (10, 10)), ((0, 10), (10, 0))),
+            Some((5.0, 5.0))
+        );
+        assert_eq!(
+            segment_intersection(((0, 0), (10, 0)), ((0, 1), (10, 1))),
+            None
+        );
+        assert_eq!(
+            segment_intersection(((0, 0), (4, 4)), ((0, 10), (10, 0))),
+            None
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::style::SolidStyle;
 use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 
 pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
@@ -10,6 +11,14 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         return Ok(());
     }
 
+    if let Some(pattern) = style.dash_pattern() {
+        let solid = SolidStyle::of(style);
+        for dash in super::dash_path([from, to].iter().cloned(), pattern) {
+            back.draw_path(dash, &solid)?;
+        }
+        return Ok(());
+    }
+
     if style.stroke_width() != 1 {
         // If the line is wider than 1px, then we need to make it a polygon
         let v = (i64::from(to.0 - from.0), i64::from(to.1 - from.1));
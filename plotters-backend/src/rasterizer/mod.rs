@@ -4,6 +4,10 @@
   backend. And this is the rasterizer that utilize this minimal ability to build a
   fully functioning backend.
 
+  The geometry helpers used by the rasterizers, such as [polygonize], [point_in_polygon] and
+  [segment_intersection], are also exported, so that custom elements and backends can build
+  shapes consistent with the built-in ones.
+
 */
 
 // TODO: We need to revisit this. It has been a long time since last time we figured out
@@ -29,7 +33,7 @@ mod line;
 pub use line::draw_line;
 
 mod rect;
-pub use rect::draw_rect;
+pub use rect::{draw_rect, draw_rounded_rect};
 
 mod circle;
 pub use circle::draw_circle;
@@ -38,4 +42,19 @@ mod polygon;
 pub use polygon::fill_polygon;
 
 mod path;
-pub use path::polygonize;
+pub use path::{polygonize, polygonize_closed, polygonize_closed_with, polygonize_with};
+
+mod bezier;
+pub use bezier::{draw_cubic_bezier, flatten_cubic_bezier};
+
+mod dash;
+pub use dash::dash_path;
+
+mod geometry;
+pub use geometry::{point_in_polygon, segment_intersection};
+
+mod clip;
+pub use clip::{clip_line, PolygonClip};
+
+mod blit;
+pub use blit::{blit_scaled_bitmap, scale_bitmap, BitmapInterpolation};
@@ -1,4 +1,4 @@
-use crate::BackendCoord;
+use crate::{BackendCoord, LineCap, LineJoin};
 
 // Compute the tanginal and normal vectors of the given straight line.
 fn get_dir_vector(from: BackendCoord, to: BackendCoord, flag: bool) -> ((f64, f64), (f64, f64)) {
@@ -14,10 +14,38 @@ fn get_dir_vector(from: BackendCoord, to: BackendCoord, flag: bool) -> ((f64, f6
     }
 }
 
+// Emit the points of an arc around the center, from the offset vector `from` to the offset vector
+// `to` in the shorter direction. The starting point is not emitted.
+fn push_arc(center: BackendCoord, from: (f64, f64), to: (f64, f64), buf: &mut Vec<BackendCoord>) {
+    let r = (from.0 * from.0 + from.1 * from.1).sqrt();
+    let begin = from.1.atan2(from.0);
+    let mut delta = to.1.atan2(to.0) - begin;
+    if delta > std::f64::consts::PI {
+        delta -= 2.0 * std::f64::consts::PI;
+    } else if delta < -std::f64::consts::PI {
+        delta += 2.0 * std::f64::consts::PI;
+    }
+
+    // Keep the chords around 2 pixels long
+    let steps = ((delta.abs() * r / 2.0).ceil() as usize).max(1);
+    for i in 1..=steps {
+        let angle = begin + delta * i as f64 / steps as f64;
+        buf.push((
+            (f64::from(center.0) + r * angle.cos()).round() as i32,
+            (f64::from(center.1) + r * angle.sin()).round() as i32,
+        ));
+    }
+}
+
 // Compute the polygonized vertex of the given angle
 // d is the distance between the polygon edge and the actual line.
 // d can be negative, this will emit a vertex on the other side of the line.
-fn compute_polygon_vertex(triple: &[BackendCoord; 3], d: f64, buf: &mut Vec<BackendCoord>) {
+fn compute_polygon_vertex(
+    triple: &[BackendCoord; 3],
+    d: f64,
+    join: LineJoin,
+    buf: &mut Vec<BackendCoord>,
+) {
     buf.clear();
 
     // Compute the tanginal and normal vectors of the given straight line.
@@ -62,28 +90,66 @@ fn compute_polygon_vertex(triple: &[BackendCoord; 3], d: f64, buf: &mut Vec<Back
     let c1 = b_p.1 - a_p.1;
 
     // Since the points are not collinear, the determinant is not 0, and we can get a intersection point.
-    let u = (c0 * b1 - c1 * b0) / (a0 * b1 - a1 * b0);
+    let det = a0 * b1 - a1 * b0;
+    let u = (c0 * b1 - c1 * b0) / det;
+    let v = (a0 * c1 - a1 * c0) / det;
     let x = a_p.0 + u * a_t.0;
     let y = a_p.1 + u * a_t.1;
 
     let cross_product = a_t.0 * b_t.1 - a_t.1 * b_t.0;
     if (cross_product < 0.0 && d < 0.0) || (cross_product > 0.0 && d > 0.0) {
-        // Then we are at the outer side of the angle, so we need to consider a cap.
-        let dist_square = (x - triple[1].0 as f64).powi(2) + (y - triple[1].1 as f64).powi(2);
-        // If the point is too far away from the line, we need to cap it.
-        if dist_square > d * d * 16.0 {
-            buf.push((a_p.0.round() as i32, a_p.1.round() as i32));
-            buf.push((b_p.0.round() as i32, b_p.1.round() as i32));
-            return;
+        // Then we are at the outer side of the angle, so we need to consider a join.
+        let a_p = (a_p.0.round() as i32, a_p.1.round() as i32);
+        let b_p = (b_p.0.round() as i32, b_p.1.round() as i32);
+        match join {
+            LineJoin::Miter => {
+                let dist_square =
+                    (x - triple[1].0 as f64).powi(2) + (y - triple[1].1 as f64).powi(2);
+                // If the point is too far away from the line, we need to cap it.
+                if dist_square > d * d * 16.0 {
+                    buf.push(a_p);
+                    buf.push(b_p);
+                } else {
+                    buf.push((x.round() as i32, y.round() as i32));
+                }
+            }
+            LineJoin::Bevel => {
+                buf.push(a_p);
+                buf.push(b_p);
+            }
+            LineJoin::Round => {
+                buf.push(a_p);
+                push_arc(
+                    triple[1],
+                    (d * a_n.0, d * a_n.1),
+                    (d * b_n.0, d * b_n.1),
+                    buf,
+                );
+            }
         }
+        return;
     }
 
+    // At the inner side of the angle, the intersection point runs away from the vertex as the
+    // angle gets sharper. Once it goes beyond either of the segments, the polygon folds over
+    // itself, so we pull the point back towards the vertex along the bisector.
+    let len = |p: BackendCoord, q: BackendCoord| {
+        (f64::from(p.0 - q.0).powi(2) + f64::from(p.1 - q.1).powi(2)).sqrt()
+    };
+    let scale = (len(triple[0], triple[1]) / u.abs())
+        .min(len(triple[2], triple[1]) / v.abs())
+        .min(1.0);
+    let x = f64::from(triple[1].0) + (x - f64::from(triple[1].0)) * scale;
+    let y = f64::from(triple[1].1) + (y - f64::from(triple[1].1)) * scale;
+
     buf.push((x.round() as i32, y.round() as i32));
 }
 
 fn traverse_vertices<'a>(
     mut vertices: impl Iterator<Item = &'a BackendCoord>,
     width: u32,
+    join: LineJoin,
+    cap: LineCap,
     mut op: impl FnMut(BackendCoord),
 ) {
     let mut a = vertices.next().unwrap();
@@ -115,31 +181,155 @@ fn traverse_vertices<'a>(
         recent.swap(0, 1);
         recent.swap(1, 2);
         recent[2] = *p;
-        compute_polygon_vertex(&recent, f64::from(width) / 2.0, &mut vertex_buf);
+        compute_polygon_vertex(&recent, f64::from(width) / 2.0, join, &mut vertex_buf);
         vertex_buf.iter().cloned().for_each(&mut op);
     }
 
     let b = recent[1];
     let a = recent[2];
 
-    let (_, n) = get_dir_vector(a, b, true);
+    let (t, n) = get_dir_vector(a, b, true);
+    let d = f64::from(width) / 2.0;
 
     op((
-        (f64::from(a.0) + n.0 * f64::from(width) / 2.0).round() as i32,
-        (f64::from(a.1) + n.1 * f64::from(width) / 2.0).round() as i32,
+        (f64::from(a.0) + n.0 * d).round() as i32,
+        (f64::from(a.1) + n.1 * d).round() as i32,
     ));
+
+    // The cap is emitted when we reach the end of the path, it goes around the end point to the
+    // other side of the stroke, where the traversal in the opposite direction begins.
+    let n = (n.0 * d, n.1 * d);
+    let t = (-t.0 * d, -t.1 * d);
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Square => {
+            op((
+                (f64::from(a.0) + n.0 + t.0).round() as i32,
+                (f64::from(a.1) + n.1 + t.1).round() as i32,
+            ));
+            op((
+                (f64::from(a.0) - n.0 + t.0).round() as i32,
+                (f64::from(a.1) - n.1 + t.1).round() as i32,
+            ));
+        }
+        LineCap::Round => {
+            vertex_buf.clear();
+            push_arc(a, n, t, &mut vertex_buf);
+            push_arc(a, t, (-n.0, -n.1), &mut vertex_buf);
+            vertex_buf.iter().cloned().for_each(&mut op);
+        }
+    }
 }
 
-/// Covert a path with >1px stroke width into polygon.
+/// Convert a path with >1px stroke width into a polygon that covers the stroke.
+///
+/// The returned polygon walks along one side of the path and comes back along the other side.
+/// The ends of the path are flat (butt caps). At each inner vertex the two sides are joined with
+/// a miter join, unless the miter is more than four times longer than half of the stroke width, in
+/// which case a bevel join is used instead. See [polygonize_with] for other joins and caps.
+///
+/// This is the same routine used by the default `DrawingBackend::draw_path` implementation, thus
+/// it can be used by custom elements to build outlines which are consistent with the built-in ones.
+///
+/// - `vertices`: The key points of the path
+/// - `stroke_width`: The width of the stroke in pixels
+/// - **returns**: The vertices of the polygon, empty if the path has less than 2 points
 pub fn polygonize(vertices: &[BackendCoord], stroke_width: u32) -> Vec<BackendCoord> {
+    polygonize_with(vertices, stroke_width, LineJoin::Miter, LineCap::Butt)
+}
+
+/// Convert a path with >1px stroke width into a polygon that covers the stroke, with the given
+/// joins at the inner vertices and caps at the ends of the path.
+///
+/// Round joins and caps are approximated by chords of about 2 pixels long.
+///
+/// - `vertices`: The key points of the path
+/// - `stroke_width`: The width of the stroke in pixels
+/// - `join`: How the segments are joined at the inner vertices
+/// - `cap`: How the ends of the path are drawn
+/// - **returns**: The vertices of the polygon, empty if the path has less than 2 points
+pub fn polygonize_with(
+    vertices: &[BackendCoord],
+    stroke_width: u32,
+    join: LineJoin,
+    cap: LineCap,
+) -> Vec<BackendCoord> {
     if vertices.len() < 2 {
         return vec![];
     }
 
     let mut ret = vec![];
 
-    traverse_vertices(vertices.iter(), stroke_width, |v| ret.push(v));
-    traverse_vertices(vertices.iter().rev(), stroke_width, |v| ret.push(v));
+    traverse_vertices(vertices.iter(), stroke_width, join, cap, |v| ret.push(v));
+    traverse_vertices(vertices.iter().rev(), stroke_width, join, cap, |v| {
+        ret.push(v)
+    });
+
+    ret
+}
+
+/// Convert a closed path with >1px stroke width into a polygon that covers the stroke.
+///
+/// Unlike [polygonize], the last point is connected back to the first one and every vertex,
+/// including the first one, is joined the same way as the inner vertices of an open path.
+/// The result contains the outer ring and the inner ring of the stroke connected by a
+/// zero-width bridge, the two rings are in opposite directions so that the enclosed region is a
+/// hole under both the even-odd and the non-zero fill rules.
+///
+/// - `vertices`: The key points of the path, the closing point may be omitted
+/// - `stroke_width`: The width of the stroke in pixels
+/// - **returns**: The vertices of the polygon
+pub fn polygonize_closed(vertices: &[BackendCoord], stroke_width: u32) -> Vec<BackendCoord> {
+    polygonize_closed_with(vertices, stroke_width, LineJoin::Miter)
+}
+
+/// Convert a closed path with >1px stroke width into a polygon that covers the stroke, with the
+/// given joins at the vertices. See [polygonize_closed] for the details.
+///
+/// - `vertices`: The key points of the path, the closing point may be omitted
+/// - `stroke_width`: The width of the stroke in pixels
+/// - `join`: How the segments are joined at the vertices
+/// - **returns**: The vertices of the polygon
+pub fn polygonize_closed_with(
+    vertices: &[BackendCoord],
+    stroke_width: u32,
+    join: LineJoin,
+) -> Vec<BackendCoord> {
+    let mut points: Vec<BackendCoord> = Vec::with_capacity(vertices.len());
+    for p in vertices {
+        if points.last() != Some(p) {
+            points.push(*p);
+        }
+    }
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    if points.len() < 3 {
+        return polygonize_with(&points, stroke_width, join, LineCap::Butt);
+    }
+
+    let d = f64::from(stroke_width) / 2.0;
+    let n = points.len();
+    let mut vertex_buf = Vec::with_capacity(2);
+
+    let mut ring = |d: f64, ret: &mut Vec<BackendCoord>| {
+        let begin = ret.len();
+        for i in 0..n {
+            let triple = [points[(i + n - 1) % n], points[i], points[(i + 1) % n]];
+            compute_polygon_vertex(&triple, d, join, &mut vertex_buf);
+            ret.extend_from_slice(&vertex_buf);
+        }
+        // Close the ring explicitly, so that the bridge between the rings cancels out
+        let first = ret[begin];
+        ret.push(first);
+    };
+
+    let mut ret = vec![];
+    ring(d, &mut ret);
+    let outer_len = ret.len();
+    ring(-d, &mut ret);
+    ret[outer_len..].reverse();
 
     ret
 }
@@ -153,7 +343,7 @@ mod test {
     fn test_no_inf_in_compute_polygon_vertex() {
         let path = [(335, 386), (338, 326), (340, 286)];
         let mut buf = Vec::new();
-        compute_polygon_vertex(&path, 2.0, buf.as_mut());
+        compute_polygon_vertex(&path, 2.0, LineJoin::Miter, buf.as_mut());
         assert!(!buf.is_empty());
         let nani32 = f64::INFINITY as i32;
         assert!(!buf.iter().any(|&v| v.0 == nani32 || v.1 == nani32));
@@ -164,9 +354,65 @@ mod test {
     fn standard_corner() {
         let path = [(10, 10), (20, 10), (20, 20)];
         let mut buf = Vec::new();
-        compute_polygon_vertex(&path, 2.0, buf.as_mut());
+        compute_polygon_vertex(&path, 2.0, LineJoin::Miter, buf.as_mut());
         assert!(!buf.is_empty());
         let buf2 = vec![(18, 12)];
         assert_eq!(buf, buf2);
     }
+
+    #[test]
+    fn test_polygonize_closed() {
+        use crate::rasterizer::point_in_polygon;
+
+        let square = [(10, 10), (30, 10), (30, 30), (10, 30), (10, 10)];
+        let poly = polygonize_closed(&square, 4);
+
+        // The stroke covers the edges, including the closing one, but not the interior
+        assert!(point_in_polygon((20, 10), &poly));
+        assert!(point_in_polygon((10, 20), &poly));
+        assert!(point_in_polygon((11, 11), &poly));
+        assert!(!point_in_polygon((20, 20), &poly));
+        assert!(!point_in_polygon((20, 5), &poly));
+    }
+
+    #[test]
+    fn test_polygonize_joins_and_caps() {
+        use crate::rasterizer::point_in_polygon;
+
+        let path = [(10, 30), (30, 30), (30, 10)];
+
+        // The miter join covers the outer corner, while round and bevel joins cut it off
+        let miter = polygonize_with(&path, 10, LineJoin::Miter, LineCap::Butt);
+        let round = polygonize_with(&path, 10, LineJoin::Round, LineCap::Butt);
+        let bevel = polygonize_with(&path, 10, LineJoin::Bevel, LineCap::Butt);
+        assert!(point_in_polygon((34, 34), &miter));
+        assert!(!point_in_polygon((34, 34), &round));
+        assert!(!point_in_polygon((34, 34), &bevel));
+        assert!(point_in_polygon((33, 33), &round));
+        assert!(!point_in_polygon((33, 33), &bevel));
+        assert!(point_in_polygon((26, 26), &round));
+
+        // Butt caps end at the end points, the others go beyond by half of the stroke width
+        assert!(!point_in_polygon((8, 30), &miter));
+        assert!(!point_in_polygon((30, 8), &miter));
+        let square = polygonize_with(&path, 10, LineJoin::Miter, LineCap::Square);
+        assert!(point_in_polygon((8, 30), &square));
+        assert!(point_in_polygon((6, 34), &square));
+        assert!(point_in_polygon((30, 6), &square));
+        let round = polygonize_with(&path, 10, LineJoin::Miter, LineCap::Round);
+        assert!(point_in_polygon((6, 30), &round));
+        assert!(point_in_polygon((30, 6), &round));
+        assert!(!point_in_polygon((5, 35), &round));
+    }
+
+    /// A sharp turn after a short segment used to fold the inner side of the stroke
+    #[test]
+    fn test_sharp_inner_join() {
+        let path = [(10, 10), (50, 12), (12, 14)];
+        let mut buf = Vec::new();
+        compute_polygon_vertex(&path, 10.0, LineJoin::Miter, &mut buf);
+        assert_eq!(buf.len(), 1);
+        // The inner point stays within the segments instead of running far behind the start
+        assert!(buf[0].0 >= 10 && buf[0].0 <= 50);
+    }
 }
@@ -31,6 +31,18 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
                 check_result!(b.draw_line((upper_left.0, y), (bottom_right.0, y), style));
             }
         }
+    } else if style.dash_pattern().is_some() {
+        // Dash the outline as a whole, so that the pattern runs around the corners
+        b.draw_path(
+            vec![
+                upper_left,
+                (bottom_right.0, upper_left.1),
+                bottom_right,
+                (upper_left.0, bottom_right.1),
+                upper_left,
+            ],
+            style,
+        )?;
     } else {
         b.draw_line(
             (upper_left.0, upper_left.1),
@@ -55,3 +67,57 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
     }
     Ok(())
 }
+
+pub fn draw_rounded_rect<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    radius: u32,
+    style: &S,
+    fill: bool,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    if style.color().alpha == 0.0 {
+        return Ok(());
+    }
+    let (x0, y0) = (
+        upper_left.0.min(bottom_right.0),
+        upper_left.1.min(bottom_right.1),
+    );
+    let (x1, y1) = (
+        upper_left.0.max(bottom_right.0),
+        upper_left.1.max(bottom_right.1),
+    );
+
+    let r = f64::from(radius)
+        .min(f64::from(x1 - x0) / 2.0)
+        .min(f64::from(y1 - y0) / 2.0);
+    if r < 1.0 {
+        return b.draw_rect((x0, y0), (x1, y1), style, fill);
+    }
+
+    // The corners are approximated by chords of about 2 pixels long
+    let steps = ((r * std::f64::consts::PI / 4.0).ceil() as usize).max(2);
+    let corners = [
+        (f64::from(x1) - r, f64::from(y1) - r),
+        (f64::from(x0) + r, f64::from(y1) - r),
+        (f64::from(x0) + r, f64::from(y0) + r),
+        (f64::from(x1) - r, f64::from(y0) + r),
+    ];
+    let mut vertices = Vec::with_capacity(4 * (steps + 1) + 1);
+    for (idx, (cx, cy)) in corners.iter().enumerate() {
+        for i in 0..=steps {
+            let angle = (idx as f64 + i as f64 / steps as f64) * std::f64::consts::FRAC_PI_2;
+            vertices.push((
+                (cx + r * angle.cos()).round() as i32,
+                (cy + r * angle.sin()).round() as i32,
+            ));
+        }
+    }
+
+    if fill {
+        b.fill_polygon(vertices, style)
+    } else {
+        vertices.push(vertices[0]);
+        b.draw_path(vertices, style)
+    }
+}
@@ -15,6 +15,77 @@ impl BackendColor {
     }
 }
 
+/// Describes how two segments of a thick stroke are joined at a corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet, falling back to a bevel join at very
+    /// sharp corners
+    Miter,
+    /// The corner is rounded with the radius of half of the stroke width
+    Round,
+    /// The corner is cut off by a straight line
+    Bevel,
+}
+
+impl LineJoin {
+    /// Convert the line join into a CSS compatible string which can be used in `stroke-linejoin` attribute.
+    pub fn as_str(&self) -> &str {
+        match self {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        }
+    }
+}
+
+/// Describes how the ends of a thick stroke are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end point
+    Butt,
+    /// The stroke ends with a half circle around the end point
+    Round,
+    /// The stroke is extended by half of the stroke width beyond the end point
+    Square,
+}
+
+impl LineCap {
+    /// Convert the line cap into a CSS compatible string which can be used in `stroke-linecap` attribute.
+    pub fn as_str(&self) -> &str {
+        match self {
+            LineCap::Butt => "butt",
+            LineCap::Round => "round",
+            LineCap::Square => "square",
+        }
+    }
+}
+
+/// Describes the dash pattern of a stroke: the stroke is drawn for `on` pixels and then skipped
+/// for `off` pixels repeatedly, starting `offset` pixels into the pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DashPattern {
+    /// The length of the dashes
+    pub on: u32,
+    /// The length of the gaps between the dashes
+    pub off: u32,
+    /// How far into the pattern the stroke starts
+    pub offset: u32,
+}
+
+impl DashPattern {
+    /// Create a new dash pattern which starts with a full dash
+    /// - `on`: The length of the dashes
+    /// - `off`: The length of the gaps between the dashes
+    pub fn new(on: u32, off: u32) -> Self {
+        Self { on, off, offset: 0 }
+    }
+
+    /// Start the stroke the given number of pixels into the pattern
+    pub fn offset(self, offset: u32) -> Self {
+        Self { offset, ..self }
+    }
+}
+
 /// The style data for the backend drawing API
 pub trait BackendStyle {
     /// Get the color of current style
@@ -24,6 +95,58 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// Get how the segments of a thick stroke are joined
+    fn line_join(&self) -> LineJoin {
+        LineJoin::Miter
+    }
+
+    /// Get how the ends of a thick stroke are drawn
+    fn line_cap(&self) -> LineCap {
+        LineCap::Butt
+    }
+
+    /// Get the dash pattern of the stroke, the stroke is solid if there's none
+    fn dash_pattern(&self) -> Option<DashPattern> {
+        None
+    }
+}
+
+/// The style that draws the dashes split from a dashed stroke as solid strokes
+pub(crate) struct SolidStyle {
+    color: BackendColor,
+    stroke_width: u32,
+    line_join: LineJoin,
+    line_cap: LineCap,
+}
+
+impl SolidStyle {
+    pub(crate) fn of<S: BackendStyle>(style: &S) -> Self {
+        Self {
+            color: style.color(),
+            stroke_width: style.stroke_width(),
+            line_join: style.line_join(),
+            line_cap: style.line_cap(),
+        }
+    }
+}
+
+impl BackendStyle for SolidStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+
+    fn line_join(&self) -> LineJoin {
+        self.line_join
+    }
+
+    fn line_cap(&self) -> LineCap {
+        self.line_cap
+    }
 }
 
 impl BackendStyle for BackendColor {
@@ -219,6 +219,12 @@ pub trait BackendTextStyle {
 
     fn family(&self) -> FontFamily;
 
+    /// The halo drawn around the glyphs to keep the text readable over busy backgrounds, as the
+    /// color of the halo and its width in pixels
+    fn halo(&self) -> Option<(BackendColor, u32)> {
+        None
+    }
+
     #[allow(clippy::type_complexity)]
     fn layout_box(&self, text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError>;
 
@@ -229,3 +235,42 @@ pub trait BackendTextStyle {
         draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::FontError>;
 }
+
+/// Compute the halo around the glyph pixels, by dilating the glyph coverage with a disc.
+///
+/// - `glyph`: The pixels of the glyphs, with the coverage of each pixel
+/// - `radius`: The width of the halo in pixels
+/// - **returns**: The pixels of the halo, with the coverage of each pixel
+pub(crate) fn halo_pixels(glyph: &[(i32, i32, f64)], radius: u32) -> Vec<(i32, i32, f64)> {
+    let r = radius as i32;
+    let (mut x0, mut y0, mut x1, mut y1) = (i32::MAX, i32::MAX, i32::MIN, i32::MIN);
+    for &(x, y, _) in glyph {
+        x0 = x0.min(x - r);
+        y0 = y0.min(y - r);
+        x1 = x1.max(x + r);
+        y1 = y1.max(y + r);
+    }
+    if x0 > x1 {
+        return vec![];
+    }
+
+    let w = (x1 - x0 + 1) as usize;
+    let mut coverage = vec![0.0f64; w * (y1 - y0 + 1) as usize];
+    for &(x, y, alpha) in glyph {
+        for dy in -r..=r {
+            for dx in -r..=r {
+                if dx * dx + dy * dy <= r * r {
+                    let idx = (y + dy - y0) as usize * w + (x + dx - x0) as usize;
+                    coverage[idx] = coverage[idx].max(alpha);
+                }
+            }
+        }
+    }
+
+    coverage
+        .into_iter()
+        .enumerate()
+        .filter(|(_, alpha)| *alpha > 0.0)
+        .map(|(idx, alpha)| (x0 + (idx % w) as i32, y0 + (idx / w) as i32, alpha))
+        .collect()
+}
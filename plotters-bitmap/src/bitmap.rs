@@ -1,20 +1,27 @@
 use plotters_backend::{
-    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+    rasterizer, BackendColor, BackendCoord, BackendStyle, BitmapInterpolation, DrawingBackend,
+    DrawingErrorKind,
 };
 use std::marker::PhantomData;
 
-use crate::bitmap_pixel::{PixelFormat, RGBPixel};
+use crate::bitmap_pixel::{PixelFormat, RGBAPixel, RGBPixel};
 use crate::error::BitMapBackendError;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 mod image_encoding_support {
-    pub(super) use image::{ImageBuffer, Rgb};
+    pub(super) use image::{ImageBuffer, Rgb, RgbImage, RgbaImage};
     pub(super) use std::path::Path;
     pub(super) type BorrowedImage<'a> = ImageBuffer<Rgb<u8>, &'a mut [u8]>;
 }
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 use image_encoding_support::*;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "image"),
+    feature = "png_encoder_mini"
+))]
+use std::path::Path;
 
 mod target;
 
@@ -40,6 +47,8 @@ pub struct BitMapBackend<'a, P: PixelFormat = RGBPixel> {
     buffer: Buffer<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// The rectangle that the drawing is clipped to, the corners are inclusive
+    clip: Option<(BackendCoord, BackendCoord)>,
     _pantomdata: PhantomData<P>,
 }
 
@@ -50,13 +59,21 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
 
 impl<'a> BitMapBackend<'a, RGBPixel> {
     /// Create a new bitmap backend
-    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    ///
+    /// Without the `image_encoder` feature, the image is saved as PNG by the built-in encoder of
+    /// the `png_encoder_mini` feature, and presenting the image to a path without the `png`
+    /// extension fails with an IO error.
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "image", feature = "png_encoder_mini")
+    ))]
     pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, (w, h): (u32, u32)) -> Self {
         Self {
             target: Target::File(path.as_ref()),
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            clip: None,
             _pantomdata: PhantomData,
         }
     }
@@ -85,6 +102,7 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            clip: None,
             _pantomdata: PhantomData,
         })
     }
@@ -104,6 +122,34 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
     pub fn with_buffer(buf: &'a mut [u8], (w, h): (u32, u32)) -> Self {
         Self::with_buffer_and_format(buf, (w, h)).expect("Wrong buffer size")
     }
+
+    /// Create a new bitmap backend which draws directly on an existing `image::RgbImage`,
+    /// without copying the pixels
+    ///
+    /// - `image`: The image to draw on
+    /// - **returns**: The newly created bitmap backend, with the size of the image
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn from_rgb_image(image: &'a mut RgbImage) -> Self {
+        let size = image.dimensions();
+        Self::with_buffer_and_format(image, size).expect("Wrong buffer size")
+    }
+}
+
+impl<'a> BitMapBackend<'a, RGBAPixel> {
+    /// Create a new bitmap backend which draws directly on an existing `image::RgbaImage`,
+    /// without copying the pixels.
+    ///
+    /// The alpha channel of the image is honored: drawing on a transparent pixel produces a
+    /// translucent pixel, and the pixels that are not drawn keep their alpha values. For a
+    /// `DynamicImage`, use `as_mut_rgba8` to get the underlying `RgbaImage`.
+    ///
+    /// - `image`: The image to draw on
+    /// - **returns**: The newly created bitmap backend, with the size of the image
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn from_image(image: &'a mut RgbaImage) -> Self {
+        let size = image.dimensions();
+        Self::with_buffer_and_format(image, size).expect("Wrong buffer size")
+    }
 }
 
 impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
@@ -128,6 +174,7 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             size: (w, h),
             buffer: Buffer::Borrowed(buf),
             saved: false,
+            clip: None,
             _pantomdata: PhantomData,
         })
     }
@@ -137,6 +184,16 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
         self.buffer.borrow_buffer()
     }
 
+    /// The inclusive bounds where the pixels can be drawn, which is the intersection of the image
+    /// and the clipping rectangle
+    fn bounds(&self) -> (BackendCoord, BackendCoord) {
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        match self.clip {
+            Some(((x0, y0), (x1, y1))) => ((x0.max(0), y0.max(0)), (x1.min(w - 1), y1.min(h - 1))),
+            None => ((0, 0), (w - 1, h - 1)),
+        }
+    }
+
     /// Split a bitmap backend vertically into several sub drawing area which allows
     /// multi-threading rendering.
     ///
@@ -185,11 +242,52 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         Ok(())
     }
 
-    #[cfg(any(target_arch = "wasm32", not(feature = "image")))]
+    #[cfg(any(
+        target_arch = "wasm32",
+        not(any(feature = "image", feature = "png_encoder_mini"))
+    ))]
     fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
         Ok(())
     }
 
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        not(feature = "image"),
+        feature = "png_encoder_mini"
+    ))]
+    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if !P::can_be_saved() {
+            return Ok(());
+        }
+        let size = self.get_size();
+        match &mut self.target {
+            Target::File(path) => {
+                let is_png = path
+                    .extension()
+                    .map_or(false, |ext| ext.eq_ignore_ascii_case("png"));
+                if !is_png {
+                    return Err(DrawingErrorKind::DrawingError(BitMapBackendError::IOError(
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            "The built-in encoder only saves the image as PNG",
+                        ),
+                    )));
+                }
+                let file = std::fs::File::create(path)
+                    .map_err(|e| DrawingErrorKind::DrawingError(BitMapBackendError::IOError(e)))?;
+                crate::png_mini::write_rgb_png(
+                    std::io::BufWriter::new(file),
+                    size,
+                    self.buffer.borrow_buffer(),
+                )
+                .map_err(|e| DrawingErrorKind::DrawingError(BitMapBackendError::IOError(e)))?;
+                self.saved = true;
+                Ok(())
+            }
+            Target::Buffer(_) => Ok(()),
+        }
+    }
+
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
         if !P::can_be_saved() {
@@ -228,11 +326,8 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         point: BackendCoord,
         color: BackendColor,
     ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
-        if point.0 < 0
-            || point.1 < 0
-            || point.0 as u32 >= self.size.0
-            || point.1 as u32 >= self.size.1
-        {
+        let ((x0, y0), (x1, y1)) = self.bounds();
+        if point.0 < x0 || point.1 < y0 || point.0 > x1 || point.1 > y1 {
             return Ok(());
         }
 
@@ -250,9 +345,32 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         to: (i32, i32),
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.dash_pattern().is_some() {
+            // The dashes are drawn one by one, each of which comes back as a solid line
+            return rasterizer::draw_line(self, from, to, style);
+        }
+
         let alpha = style.color().alpha;
         let (r, g, b) = style.color().rgb;
 
+        let (from, to) = if self.clip.is_some() {
+            // The wide lines are clipped by the pixels, only the parts far away are cut off here
+            let ((x0, y0), (x1, y1)) = self.bounds();
+            let extra = style.stroke_width() as i32;
+            let extra = if extra > 1 { extra } else { 0 };
+            match rasterizer::clip_line(
+                from,
+                to,
+                (x0 - extra, y0 - extra),
+                (x1 + extra, y1 + extra),
+            ) {
+                Some(line) => line,
+                None => return Ok(()),
+            }
+        } else {
+            (from, to)
+        };
+
         if (from.0 == to.0 || from.1 == to.1) && style.stroke_width() == 1 {
             if alpha >= 1.0 {
                 if from.1 == to.1 {
@@ -266,7 +384,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
             return Ok(());
         }
 
-        plotters_backend::rasterizer::draw_line(self, from, to, style)
+        rasterizer::draw_line(self, from, to, style)
     }
 
     fn draw_rect<S: BackendStyle>(
@@ -279,6 +397,28 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         let alpha = style.color().alpha;
         let (r, g, b) = style.color().rgb;
         if fill {
+            let (upper_left, bottom_right) = match self.clip {
+                Some(_) => {
+                    let ((x0, y0), (x1, y1)) = self.bounds();
+                    let clipped_upper_left = (
+                        upper_left.0.min(bottom_right.0).max(x0),
+                        upper_left.1.min(bottom_right.1).max(y0),
+                    );
+                    let clipped_bottom_right = (
+                        upper_left.0.max(bottom_right.0).min(x1 + 1),
+                        upper_left.1.max(bottom_right.1).min(y1 + 1),
+                    );
+                    // The rect is entirely outside of the clip, otherwise the clamped corners
+                    // would be swapped back into a sliver on the edge of the clip
+                    if clipped_upper_left.0 >= clipped_bottom_right.0
+                        || clipped_upper_left.1 >= clipped_bottom_right.1
+                    {
+                        return Ok(());
+                    }
+                    (clipped_upper_left, clipped_bottom_right)
+                }
+                None => (upper_left, bottom_right),
+            };
             if alpha >= 1.0 {
                 P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
             } else {
@@ -286,7 +426,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
             }
             return Ok(());
         }
-        plotters_backend::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
+        rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
     fn blit_bitmap(
@@ -295,14 +435,15 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         (sw, sh): (u32, u32),
         src: &[u8],
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let (dw, dh) = self.get_size();
+        let (dw, _) = self.get_size();
+        let ((bx0, by0), (bx1, by1)) = self.bounds();
 
         let (x0, y0) = pos;
         let (x1, y1) = (x0 + sw as i32, y0 + sh as i32);
 
-        let (x0, y0, x1, y1) = (x0.max(0), y0.max(0), x1.min(dw as i32), y1.min(dh as i32));
+        let (x0, y0, x1, y1) = (x0.max(bx0), y0.max(by0), x1.min(bx1 + 1), y1.min(by1 + 1));
 
-        if x0 == x1 || y0 == y1 {
+        if x0 >= x1 || y0 >= y1 {
             return Ok(());
         }
 
@@ -315,8 +456,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
         let mut dst = &mut self.get_raw_pixel_buffer()[dst_start..];
 
-        let src_start =
-            Self::PIXEL_SIZE * ((sh as i32 + y0 - y1) * sw as i32 + (sw as i32 + x0 - x1)) as usize;
+        let src_start = Self::PIXEL_SIZE * ((y0 - pos.1) * sw as i32 + (x0 - pos.0)) as usize;
         let mut src = &src[src_start..];
 
         if src_gap == 0 && dst_gap == 0 {
@@ -334,6 +474,39 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
         Ok(())
     }
+
+    fn blit_bitmap_scaled(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        src_size: (u32, u32),
+        src: &[u8],
+        interpolation: BitmapInterpolation,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        rasterizer::blit_scaled_bitmap(
+            self,
+            upper_left,
+            bottom_right,
+            src_size,
+            src,
+            Self::PIXEL_SIZE,
+            interpolation,
+        )
+    }
+
+    fn begin_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.clip = Some((upper_left, bottom_right));
+        Ok(true)
+    }
+
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clip = None;
+        Ok(())
+    }
 }
 
 impl<P: PixelFormat> Drop for BitMapBackend<'_, P> {
@@ -1,11 +1,17 @@
 #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
 use crate::gif_support;
 use std::marker::PhantomData;
-#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "image", feature = "png_encoder_mini")
+))]
 use std::path::Path;
 
 pub(super) enum Target<'a> {
-    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "image", feature = "png_encoder_mini")
+    ))]
     File(&'a Path),
     Buffer(PhantomData<&'a u32>),
     #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
@@ -13,7 +19,10 @@ pub(super) enum Target<'a> {
 }
 
 pub(super) enum Buffer<'a> {
-    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "image", feature = "png_encoder_mini")
+    ))]
     Owned(Vec<u8>),
     Borrowed(&'a mut [u8]),
 }
@@ -22,7 +31,10 @@ impl<'a> Buffer<'a> {
     #[inline(always)]
     pub(super) fn borrow_buffer(&mut self) -> &mut [u8] {
         match self {
-            #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+            #[cfg(all(
+                not(target_arch = "wasm32"),
+                any(feature = "image", feature = "png_encoder_mini")
+            ))]
             Buffer::Owned(buf) => &mut buf[..],
             Buffer::Borrowed(buf) => buf,
         }
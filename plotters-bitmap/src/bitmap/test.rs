@@ -330,6 +330,38 @@ fn test_bitmap_bgrx_pixel_format() {
         }
     }
 }
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "image"))]
+#[test]
+fn test_bitmap_from_image() {
+    use crate::BitMapBackend;
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+    use plotters::prelude::{Color, BLUE, RED};
+
+    let mut image = RgbaImage::from_pixel(10, 10, Rgba([0, 0, 0, 0]));
+    {
+        let mut back = BitMapBackend::from_image(&mut image);
+        back.draw_rect((0, 0), (5, 10), &RED, true).unwrap();
+        back.draw_rect((5, 0), (10, 10), &BLUE.mix(0.5), true)
+            .unwrap();
+        back.draw_pixel((0, 0), BLUE.mix(0.5).to_backend_color())
+            .unwrap();
+    }
+
+    // Opaque colors replace the pixels
+    assert_eq!(image.get_pixel(1, 1), &Rgba([255, 0, 0, 255]));
+    // Translucent colors over a transparent pixel keep their own color
+    assert_eq!(image.get_pixel(7, 7), &Rgba([0, 0, 255, 128]));
+    // Translucent colors over an opaque pixel are blended
+    assert_eq!(image.get_pixel(0, 0), &Rgba([128, 0, 128, 255]));
+
+    let mut image = RgbImage::new(4, 4);
+    {
+        let mut back = BitMapBackend::from_rgb_image(&mut image);
+        back.draw_rect((0, 0), (4, 4), &RED, true).unwrap();
+    }
+    assert_eq!(image.get_pixel(3, 3), &Rgb([255, 0, 0]));
+}
+
 #[cfg(test)]
 #[test]
 fn test_draw_simple_lines() {
@@ -387,6 +419,140 @@ fn test_bitmap_blit() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_bitmap_blit_clipped() {
+    let src_bitmap: Vec<u8> = (0..10 * 10 * 3).map(|x| (x / 3) as u8).collect();
+
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        back.blit_bitmap((15, 15), (10, 10), &src_bitmap[..])
+            .unwrap();
+    }
+
+    for y in 15..20 {
+        for x in 15..20 {
+            let expected = ((y - 15) * 10 + (x - 15)) as u8;
+            assert_eq!(buffer[(y * 20 + x) * 3], expected);
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_clip_rect_outside() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        assert!(back.begin_clip_rect((5, 5), (14, 14)).unwrap());
+        // The filled rects on each side of the clip, which are entirely clipped
+        for &(upper_left, bottom_right) in [
+            ((0, 0), (3, 19)),
+            ((16, 0), (19, 19)),
+            ((0, 0), (19, 3)),
+            ((0, 16), (19, 19)),
+        ]
+        .iter()
+        {
+            back.draw_rect(upper_left, bottom_right, &WHITE.to_rgba(), true)
+                .unwrap();
+            back.draw_rect(upper_left, bottom_right, &WHITE.mix(0.5), true)
+                .unwrap();
+        }
+        back.end_clip().unwrap();
+    }
+
+    assert!(buffer.iter().all(|x| *x == 0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_clip_rect() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        assert!(back.begin_clip_rect((5, 5), (14, 14)).unwrap());
+        back.draw_line((-100, 7), (100, 7), &WHITE.to_rgba())
+            .unwrap();
+        back.draw_line(
+            (0, 0),
+            (19, 19),
+            &Into::<ShapeStyle>::into(WHITE).stroke_width(3),
+        )
+        .unwrap();
+        back.draw_rect((10, 0), (20, 20), &WHITE.to_rgba(), true)
+            .unwrap();
+        back.draw_circle((5, 14), 4, &WHITE.to_rgba(), true)
+            .unwrap();
+        back.blit_bitmap((0, 0), (6, 6), &[255; 6 * 6 * 3]).unwrap();
+        back.end_clip().unwrap();
+        back.draw_pixel((0, 0), WHITE.to_backend_color()).unwrap();
+    }
+
+    let inside = |x: usize, y: usize| (5..15).contains(&x) && (5..15).contains(&y);
+    for y in 0..20 {
+        for x in 0..20 {
+            if !inside(x, y) && (x, y) != (0, 0) {
+                assert_eq!(buffer[(y * 20 + x) * 3], 0, "{:?}", (x, y));
+            }
+        }
+    }
+    for &(x, y) in [
+        (0, 0),
+        (5, 7),
+        (14, 7),
+        (9, 9),
+        (14, 5),
+        (14, 14),
+        (5, 5),
+        (6, 13),
+    ]
+    .iter()
+    {
+        assert_eq!(buffer[(y * 20 + x) * 3], 255, "{:?}", (x, y));
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_blit_scaled() {
+    use plotters::prelude::*;
+    use plotters_backend::BitmapInterpolation;
+
+    let src_bitmap: Vec<u8> = vec![10, 10, 10, 20, 20, 20, 30, 30, 30, 40, 40, 40];
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        back.blit_bitmap_scaled(
+            (-5, 10),
+            (15, 30),
+            (2, 2),
+            &src_bitmap[..],
+            BitmapInterpolation::Nearest,
+        )
+        .unwrap();
+    }
+
+    for y in 0..20 {
+        for x in 0..20 {
+            let expected = match (x, y) {
+                (0..=4, 10..=19) => 10,
+                (5..=14, 10..=19) => 20,
+                _ => 0,
+            };
+            assert_eq!(buffer[(y * 20 + x) * 3], expected, "at ({}, {})", x, y);
+        }
+    }
+}
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 #[cfg(test)]
 mod test {
@@ -532,6 +698,44 @@ mod test {
         checked_save_file("test_text_clipping", &buffer, width as u32, height as u32);
     }
 
+    #[test]
+    fn test_text_halo() {
+        let (width, height) = (200, 60);
+        let draw = |halo: bool| {
+            let mut buffer = vec![0; (width * height * 3) as usize];
+            {
+                let root =
+                    BitMapBackend::with_buffer(&mut buffer, (width, height)).into_drawing_area();
+                root.fill(&WHITE).unwrap();
+                let style = TextStyle::from(("sans-serif", 30).into_font()).color(&WHITE);
+                let style = if halo { style.halo(&BLACK, 2) } else { style };
+                root.draw_text("Halo", &style, (20, 15)).unwrap();
+            }
+            buffer
+        };
+
+        // The white text is only visible through its halo
+        assert!(draw(false).iter().all(|x| *x == 255));
+        let buffer = draw(true);
+        assert!(buffer.contains(&0));
+        checked_save_file("test_text_halo", &buffer, width, height);
+    }
+
+    #[test]
+    fn test_transparent_text_halo() {
+        let (width, height) = (200, 60);
+        let mut buffer = vec![0; (width * height * 3) as usize];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, (width, height)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            let style = TextStyle::from(("sans-serif", 30).into_font())
+                .color(&TRANSPARENT)
+                .halo(&BLACK, 2);
+            root.draw_text("Halo", &style, (20, 15)).unwrap();
+        }
+        assert!(buffer.iter().all(|x| *x == 255));
+    }
+
     #[test]
     fn test_series_labels() {
         let (width, height) = (500, 500);
@@ -612,3 +816,94 @@ mod test {
         );
     }
 }
+
+#[test]
+fn test_bitmap_dash_pattern() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        let style = Into::<ShapeStyle>::into(WHITE).dash_pattern(DashPattern::new(4, 2));
+        back.draw_line((0, 2), (19, 2), &style).unwrap();
+        back.draw_rect((2, 6), (17, 17), &style, false).unwrap();
+    }
+
+    let lit = |x: usize, y: usize| buffer[(y * 20 + x) * 3] != 0;
+    // The dashes are at 0..=4, 6..=10, 12..=16 and 18..=19
+    let row: Vec<_> = (0..20).map(|x| lit(x, 2)).collect();
+    for x in [0, 3, 4, 6, 10, 12, 16, 18, 19].iter() {
+        assert!(row[*x], "{}", x);
+    }
+    for x in [5, 11, 17].iter() {
+        assert!(!row[*x], "{}", x);
+    }
+    // The pattern runs around the corners of the rectangle
+    assert!(lit(2, 6) && lit(6, 6) && !lit(7, 6));
+    assert!(lit(17, 6) && lit(17, 7) && !lit(17, 8) && lit(17, 9));
+    assert!(!lit(9, 9));
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "image"),
+    feature = "png_encoder_mini"
+))]
+#[test]
+fn test_bitmap_backend_png_mini() {
+    use plotters::prelude::*;
+    use std::convert::TryInto;
+
+    let path = std::env::temp_dir().join("plotters-bitmap-test-png-mini.png");
+    {
+        let back = BitMapBackend::new(&path, (30, 20));
+        let area = back.into_drawing_area();
+        area.fill(&WHITE).unwrap();
+        area.draw(&Rectangle::new([(5, 5), (15, 10)], RED.filled()))
+            .unwrap();
+        area.present().unwrap();
+    }
+    let png = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        png[..8],
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+    );
+    let mut chunks = vec![];
+    let mut rest = &png[8..];
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        chunks.push((rest[4..8].to_vec(), rest[8..8 + len].to_vec()));
+        // The length, the type, the data and the CRC
+        rest = &rest[12 + len..];
+    }
+    let kinds: Vec<_> = chunks.iter().map(|(kind, _)| kind.as_slice()).collect();
+    assert_eq!(kinds, [&b"IHDR"[..], &b"IDAT"[..], &b"IEND"[..]]);
+    assert_eq!(chunks[0].1, [0, 0, 0, 30, 0, 0, 0, 20, 8, 2, 0, 0, 0]);
+    // The zlib header of the deflate stream
+    assert_eq!(chunks[1].1[0] & 0x0f, 8);
+    assert!(chunks[2].1.is_empty());
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "image"),
+    feature = "png_encoder_mini"
+))]
+#[test]
+fn test_bitmap_backend_png_mini_other_format() {
+    use plotters_backend::{DrawingBackend, DrawingErrorKind};
+
+    // The built-in encoder doesn't write PNG data to a file of another format
+    let path = std::env::temp_dir().join("plotters-bitmap-test-png-mini.jpg");
+    let mut back = BitMapBackend::new(&path, (30, 20));
+    match back.present() {
+        Err(DrawingErrorKind::DrawingError(BitMapBackendError::IOError(e))) => {
+            assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput)
+        }
+        _ => panic!("Expected an IO error"),
+    }
+    drop(back);
+    assert!(!path.exists());
+}
@@ -1,7 +1,9 @@
 mod bgrx;
 mod pixel_format;
 mod rgb;
+mod rgba;
 
 pub use bgrx::BGRXPixel;
 pub use pixel_format::PixelFormat;
 pub use rgb::RGBPixel;
+pub use rgba::RGBAPixel;
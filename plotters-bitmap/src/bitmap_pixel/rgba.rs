@@ -0,0 +1,137 @@
+use super::PixelFormat;
+use crate::BitMapBackend;
+use plotters_backend::DrawingBackend;
+
+/// The marker type that indicates we are currently using a RGBA8888 pixel format, the alpha
+/// channel is not premultiplied, which is the layout of `image::RgbaImage`
+pub struct RGBAPixel;
+
+impl RGBAPixel {
+    /// Composite the color over the pixel, the alpha channel of the pixel is updated as well, thus
+    /// drawing on a transparent image produces the correct translucent pixels
+    #[inline(always)]
+    fn blend_over(pixel: &mut [u8], r: u8, g: u8, b: u8, a: f64) {
+        let dst_a = f64::from(pixel[3]) / 255.0;
+        let out_a = a + dst_a * (1.0 - a);
+        if out_a <= 0.0 {
+            return;
+        }
+        for (idx, src) in [r, g, b].iter().enumerate() {
+            let value = (f64::from(*src) * a + f64::from(pixel[idx]) * dst_a * (1.0 - a)) / out_a;
+            pixel[idx] = value.round().clamp(0.0, 255.0) as u8;
+        }
+        pixel[3] = (out_a * 255.0).round().clamp(0.0, 255.0) as u8;
+    }
+
+    /// Get the clipped pixel range of the rectangle, or `None` if it's entirely out of range
+    fn clip_rect(
+        size: (u32, u32),
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+    ) -> Option<(i32, i32, i32, i32)> {
+        let (w, h) = size;
+        let (x0, y0) = (
+            upper_left.0.min(bottom_right.0).max(0),
+            upper_left.1.min(bottom_right.1).max(0),
+        );
+        let (x1, y1) = (
+            upper_left.0.max(bottom_right.0).min(w as i32),
+            upper_left.1.max(bottom_right.1).min(h as i32),
+        );
+        if x0 >= x1 || y0 >= y1 {
+            return None;
+        }
+        Some((x0, y0, x1, y1))
+    }
+}
+
+impl PixelFormat for RGBAPixel {
+    const PIXEL_SIZE: usize = 4;
+    const EFFECTIVE_PIXEL_SIZE: usize = 4;
+
+    #[inline(always)]
+    fn byte_at(r: u8, g: u8, b: u8, _a: u64, idx: usize) -> u8 {
+        match idx {
+            0 => r,
+            1 => g,
+            2 => b,
+            _ => 0xff,
+        }
+    }
+
+    #[inline(always)]
+    fn decode_pixel(data: &[u8]) -> (u8, u8, u8, u64) {
+        (data[0], data[1], data[2], u64::from(data[3]))
+    }
+
+    fn blend_rect_fast(
+        target: &mut BitMapBackend<'_, Self>,
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+        r: u8,
+        g: u8,
+        b: u8,
+        a: f64,
+    ) {
+        let a = a.clamp(0.0, 1.0);
+        if a == 0.0 {
+            return;
+        }
+        let w = target.get_size().0 as i32;
+        if let Some((x0, y0, x1, y1)) = Self::clip_rect(target.get_size(), upper_left, bottom_right)
+        {
+            let dst = target.get_raw_pixel_buffer();
+            for y in y0..y1 {
+                let start = (y * w + x0) as usize * Self::PIXEL_SIZE;
+                let end = (y * w + x1) as usize * Self::PIXEL_SIZE;
+                for pixel in dst[start..end].chunks_mut(Self::PIXEL_SIZE) {
+                    Self::blend_over(pixel, r, g, b, a);
+                }
+            }
+        }
+    }
+
+    fn fill_rect_fast(
+        target: &mut BitMapBackend<'_, Self>,
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
+        let w = target.get_size().0 as i32;
+        if let Some((x0, y0, x1, y1)) = Self::clip_rect(target.get_size(), upper_left, bottom_right)
+        {
+            let dst = target.get_raw_pixel_buffer();
+            for y in y0..y1 {
+                let start = (y * w + x0) as usize * Self::PIXEL_SIZE;
+                let end = (y * w + x1) as usize * Self::PIXEL_SIZE;
+                for pixel in dst[start..end].chunks_mut(Self::PIXEL_SIZE) {
+                    pixel.copy_from_slice(&[r, g, b, 0xff]);
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn draw_pixel(
+        target: &mut BitMapBackend<'_, Self>,
+        point: (i32, i32),
+        (r, g, b): (u8, u8, u8),
+        alpha: f64,
+    ) {
+        let (x, y) = (point.0 as usize, point.1 as usize);
+        let w = target.get_size().0 as usize;
+        let buf = target.get_raw_pixel_buffer();
+        let base = (y * w + x) * Self::PIXEL_SIZE;
+
+        if base + Self::PIXEL_SIZE <= buf.len() {
+            let pixel = &mut buf[base..base + Self::PIXEL_SIZE];
+            if alpha >= 1.0 - 1.0 / 256.0 {
+                pixel.copy_from_slice(&[r, g, b, 0xff]);
+            } else if alpha > 0.0 {
+                Self::blend_over(pixel, r, g, b, alpha);
+            }
+        }
+    }
+}
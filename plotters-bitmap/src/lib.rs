@@ -12,6 +12,14 @@ mod gif_support;
 
 pub mod bitmap_pixel;
 mod error;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(
+        all(feature = "png_encoder_mini", not(feature = "image")),
+        all(test, feature = "image")
+    )
+))]
+mod png_mini;
 
 mod bitmap;
 pub use bitmap::BitMapBackend;
@@ -0,0 +1,284 @@
+/*!
+   A minimal PNG encoder, which is used to save the RGB bitmap when the `image` crate isn't
+   enabled.
+
+   The image data is filtered row by row and compressed with the fixed Huffman codes of DEFLATE,
+   which is far less sophisticated than a full PNG encoder, but the charts, which are mostly made
+   of flat colors, still compress well.
+*/
+use std::io::{self, Write};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+const WINDOW_SIZE: usize = 32768;
+const HASH_BITS: u32 = 15;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Encode an 8-bit RGB buffer as a PNG image
+///
+/// - `out`: The writer of the PNG file
+/// - `size`: The width and height of the image
+/// - `rgb`: The pixels, 3 bytes each, row by row
+/// - **returns**: The IO error if it fails to write
+pub(crate) fn write_rgb_png<W: Write>(
+    mut out: W,
+    (w, h): (u32, u32),
+    rgb: &[u8],
+) -> io::Result<()> {
+    let stride = w as usize * 3;
+    if rgb.len() != stride * h as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "The buffer doesn't match the image size",
+        ));
+    }
+
+    out.write_all(&SIGNATURE)?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&w.to_be_bytes());
+    header.extend_from_slice(&h.to_be_bytes());
+    // 8-bit RGB, deflate compression, adaptive filtering, no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_chunk(&mut out, b"IHDR", &header)?;
+
+    write_chunk(&mut out, b"IDAT", &zlib_compress(&filter_rows(rgb, stride)))?;
+    write_chunk(&mut out, b"IEND", &[])?;
+    out.flush()
+}
+
+fn write_chunk<W: Write>(out: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(crc32(!0, kind), data);
+    out.write_all(&(!crc).to_be_bytes())
+}
+
+fn crc32(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    // The largest block that doesn't overflow before taking the modulo
+    for block in data.chunks(5552) {
+        for &byte in block {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+// Prefix each row with the filter type and apply the filter, choosing between `Sub` and `Up`
+// by the sum of the absolute residuals, which is the heuristic suggested by the PNG spec
+fn filter_rows(rgb: &[u8], stride: usize) -> Vec<u8> {
+    let mut filtered = Vec::with_capacity(rgb.len() + rgb.len() / stride.max(1));
+    let mut sub = vec![0; stride];
+    let mut up = vec![0; stride];
+    for (idx, row) in rgb.chunks(stride.max(1)).enumerate() {
+        for i in 0..row.len() {
+            let left = if i >= 3 { row[i - 3] } else { 0 };
+            let above = if idx > 0 {
+                rgb[(idx - 1) * stride + i]
+            } else {
+                0
+            };
+            sub[i] = row[i].wrapping_sub(left);
+            up[i] = row[i].wrapping_sub(above);
+        }
+        let cost = |data: &[u8]| {
+            data.iter()
+                .map(|&x| (x as i8).unsigned_abs() as u64)
+                .sum::<u64>()
+        };
+        if idx > 0 && cost(&up) < cost(&sub) {
+            filtered.push(2);
+            filtered.extend_from_slice(&up[..row.len()]);
+        } else {
+            filtered.push(1);
+            filtered.extend_from_slice(&sub[..row.len()]);
+        }
+    }
+    filtered
+}
+
+struct BitWriter {
+    data: Vec<u8>,
+    acc: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        self.acc |= value << self.bits;
+        self.bits += bits;
+        while self.bits >= 8 {
+            self.data.push(self.acc as u8);
+            self.acc >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    // The Huffman codes are stored from the most significant bit
+    fn write_code(&mut self, code: u32, bits: u32) {
+        self.write(code.reverse_bits() >> (32 - bits), bits);
+    }
+
+    fn write_literal(&mut self, symbol: u16) {
+        let symbol = u32::from(symbol);
+        match symbol {
+            0..=143 => self.write_code(0x30 + symbol, 8),
+            144..=255 => self.write_code(0x190 + symbol - 144, 9),
+            256..=279 => self.write_code(symbol - 256, 7),
+            _ => self.write_code(0xc0 + symbol - 280, 8),
+        }
+    }
+
+    fn write_match(&mut self, length: usize, distance: usize) {
+        let code = LENGTH_BASE
+            .iter()
+            .rposition(|&b| b as usize <= length)
+            .unwrap();
+        self.write_literal(257 + code as u16);
+        self.write(
+            (length - LENGTH_BASE[code] as usize) as u32,
+            LENGTH_EXTRA[code].into(),
+        );
+
+        let code = DISTANCE_BASE
+            .iter()
+            .rposition(|&b| b as usize <= distance)
+            .unwrap();
+        self.write_code(code as u32, 5);
+        self.write(
+            (distance - DISTANCE_BASE[code] as usize) as u32,
+            DISTANCE_EXTRA[code].into(),
+        );
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.data.push(self.acc as u8);
+        }
+        self.data
+    }
+}
+
+fn hash(data: &[u8]) -> usize {
+    let key = u32::from(data[0]) | u32::from(data[1]) << 8 | u32::from(data[2]) << 16;
+    (key.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+}
+
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter {
+        // The zlib header: deflate with 32K window, the fastest compression level
+        data: vec![0x78, 0x01],
+        acc: 0,
+        bits: 0,
+    };
+    // A single final block with the fixed Huffman codes
+    writer.write(0b011, 3);
+
+    // The latest position of each hash of 3 bytes, off by one so that zero means empty
+    let mut table = vec![0usize; 1 << HASH_BITS];
+    let mut pos = 0;
+    while pos < data.len() {
+        let mut best = 0;
+        if pos + MIN_MATCH <= data.len() {
+            let slot = &mut table[hash(&data[pos..])];
+            let candidate = slot.wrapping_sub(1);
+            *slot = pos + 1;
+            if candidate < pos && pos - candidate <= WINDOW_SIZE {
+                let limit = (data.len() - pos).min(MAX_MATCH);
+                best = (0..limit)
+                    .position(|i| data[candidate + i] != data[pos + i])
+                    .unwrap_or(limit);
+                if best >= MIN_MATCH {
+                    writer.write_match(best, pos - candidate);
+                }
+            }
+        }
+
+        if best >= MIN_MATCH {
+            for p in pos + 1..(pos + best).min(data.len() + 1 - MIN_MATCH) {
+                table[hash(&data[p..])] = p + 1;
+            }
+            pos += best;
+        } else {
+            writer.write_literal(data[pos].into());
+            pos += 1;
+        }
+    }
+    writer.write_literal(256);
+
+    let mut compressed = writer.finish();
+    compressed.extend_from_slice(&adler32(data).to_be_bytes());
+    compressed
+}
+
+#[cfg(all(test, feature = "image"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_png_round_trip() {
+        let (w, h) = (67u32, 41u32);
+        let mut rgb = vec![];
+        for y in 0..h {
+            for x in 0..w {
+                // Flat regions, gradients and noise
+                let noise = (x * 7919 + y * 104_729) % 251;
+                rgb.extend_from_slice(&[(x * 3) as u8, if y < 20 { 200 } else { noise as u8 }, 17]);
+            }
+        }
+
+        let mut png = vec![];
+        write_rgb_png(&mut png, (w, h), &rgb).unwrap();
+        let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+            .unwrap()
+            .to_rgb8();
+        assert_eq!(decoded.dimensions(), (w, h));
+        assert_eq!(decoded.into_raw(), rgb);
+
+        // A blank image is compressed to a tiny fraction of its size
+        let blank = vec![255; 300 * 200 * 3];
+        png.clear();
+        write_rgb_png(&mut png, (300, 200), &blank).unwrap();
+        assert!(png.len() < blank.len() / 50);
+        let decoded = image::load_from_memory(&png).unwrap().to_rgb8();
+        assert_eq!(decoded.into_raw(), blank);
+
+        assert!(write_rgb_png(&mut vec![], (2, 2), &[0; 3]).is_err());
+    }
+}
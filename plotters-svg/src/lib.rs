@@ -7,4 +7,4 @@
 */
 mod svg;
 
-pub use svg::SVGBackend;
+pub use svg::{SVGBackend, SVGUnit};
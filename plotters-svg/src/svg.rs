@@ -4,15 +4,17 @@ The SVG image drawing backend
 
 use plotters_backend::{
     text_anchor::{HPos, VPos},
-    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
-    FontStyle, FontTransform,
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DashPattern, DrawingBackend,
+    DrawingErrorKind, FontStyle, FontTransform, LineCap, LineJoin,
 };
 
+use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::fs::File;
 #[allow(unused_imports)]
 use std::io::Cursor;
-use std::io::{BufWriter, Error, Write};
+use std::io::{BufWriter, Error, Sink, Write};
+use std::ops::Range;
 use std::path::Path;
 
 struct Rgb(u8, u8, u8);
@@ -20,16 +22,18 @@ fn make_svg_color(color: BackendColor) -> Rgb {
     Rgb(color.rgb.0, color.rgb.1, color.rgb.2)
 }
 
-enum Target<'a> {
+enum Target<'a, W> {
     File(String, &'a Path),
     Buffer(&'a mut String),
+    Writer(String, Option<W>),
 }
 
-impl Target<'_> {
+impl<W> Target<'_, W> {
     fn get_mut(&mut self) -> &mut String {
         match self {
             Target::File(ref mut buf, _) => buf,
             Target::Buffer(buf) => buf,
+            Target::Writer(ref mut buf, _) => buf,
         }
     }
 }
@@ -43,6 +47,9 @@ enum SVGTag {
     Polyline,
     Rectangle,
     Text,
+    ClipPath,
+    Group,
+    Metadata,
     #[allow(dead_code)]
     Image,
 }
@@ -58,16 +65,76 @@ impl SVGTag {
             SVGTag::Text => "text",
             SVGTag::Image => "image",
             SVGTag::Polygon => "polygon",
+            SVGTag::ClipPath => "clipPath",
+            SVGTag::Group => "g",
+            SVGTag::Metadata => "metadata",
         }
     }
 }
 
+/// The physical unit of the size of an SVG document, see
+/// [SVGBackend::with_physical_size](struct.SVGBackend.html#method.with_physical_size)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SVGUnit {
+    /// Millimeters, `mm`
+    Millimeter,
+    /// Centimeters, `cm`
+    Centimeter,
+    /// Inches, `in`
+    Inch,
+    /// Points, 1/72 of an inch, `pt`
+    Point,
+}
+
+impl SVGUnit {
+    fn suffix(&self) -> &'static str {
+        match self {
+            SVGUnit::Millimeter => "mm",
+            SVGUnit::Centimeter => "cm",
+            SVGUnit::Inch => "in",
+            SVGUnit::Point => "pt",
+        }
+    }
+
+    fn per_inch(&self) -> f64 {
+        match self {
+            SVGUnit::Millimeter => 25.4,
+            SVGUnit::Centimeter => 2.54,
+            SVGUnit::Inch => 1.0,
+            SVGUnit::Point => 72.0,
+        }
+    }
+}
+
+// Round the number to the given decimal places, so that it's written with at most that many
+fn round_to(value: f64, precision: Option<u32>) -> f64 {
+    match precision {
+        Some(decimals) => {
+            let scale = 10f64.powi(decimals.min(15) as i32);
+            (value * scale).round() / scale
+        }
+        None => value,
+    }
+}
+
 /// The SVG image drawing backend
-pub struct SVGBackend<'a> {
-    target: Target<'a>,
+///
+/// The document can be written to a file, a `String` buffer or any `std::io::Write`. The type
+/// parameter `W` is the type of the writer, which is only used by
+/// [with_writer](#method.with_writer).
+pub struct SVGBackend<'a, W: Write = Sink> {
+    target: Target<'a, W>,
     size: (u32, u32),
     tag_stack: Vec<SVGTag>,
     saved: bool,
+    // The ids of the clip paths written so far, by their polygons, thus the elements clipped to
+    // the same polygon, such as a clipped series, share a single clip path
+    clip_paths: HashMap<Vec<BackendCoord>, usize>,
+    precision: Option<u32>,
+    physical_size: Option<(SVGUnit, f64)>,
+    view_box: Option<(f64, f64, f64, f64)>,
+    // The position of the `<svg>` tag in the buffer, which is rewritten when the options change
+    header: Range<usize>,
 }
 
 trait FormatEscaped {
@@ -164,6 +231,7 @@ struct AttrWriter<'a, State> {
     buf: &'a mut String,
     tag: SVGTag,
     tag_stack: &'a mut Vec<SVGTag>,
+    precision: Option<u32>,
     state: std::marker::PhantomData<State>,
 }
 
@@ -174,13 +242,19 @@ struct AttrWriter<'a, State> {
 /// or [finish_without_closing](AttrWriter::finish_without_closing) (to schedule writing
 //  writing the closing tag for later).
 impl<'a> AttrWriter<'a, Init> {
-    fn open_tag(buf: &'a mut String, tag: SVGTag, tag_stack: &'a mut Vec<SVGTag>) -> Self {
+    fn open_tag(
+        buf: &'a mut String,
+        tag: SVGTag,
+        tag_stack: &'a mut Vec<SVGTag>,
+        precision: Option<u32>,
+    ) -> Self {
         buf.push('<');
         buf.push_str(tag.to_tag_name());
         AttrWriter {
             buf,
             tag,
             tag_stack,
+            precision,
             state: Default::default(),
         }
     }
@@ -192,6 +266,7 @@ impl<'a> AttrWriter<'a, Init> {
             buf: self.buf,
             tag: self.tag.clone(),
             tag_stack: self.tag_stack,
+            precision: self.precision,
             state: Default::default(),
         }
     }
@@ -204,6 +279,17 @@ impl<'a> AttrWriter<'a, Init> {
         self.tag_stack.push(self.tag);
         self.buf.push_str(">\n");
     }
+
+    fn write_dash_pattern(&mut self, pattern: Option<DashPattern>) {
+        if let Some(pattern) = pattern {
+            self.write_key("stroke-dasharray")
+                .write_value(format!("{} {}", pattern.on, pattern.off).as_str());
+            if pattern.offset != 0 {
+                self.write_key("stroke-dashoffset")
+                    .write_value(pattern.offset);
+            }
+        }
+    }
 }
 
 impl<'a> AttrWriter<'a, Value> {
@@ -212,9 +298,15 @@ impl<'a> AttrWriter<'a, Value> {
         FormatEscaped::format_escaped(self.buf, value);
         self.buf.push('"');
     }
+
+    /// Write a fractional number, which is rounded to the precision of the document
+    fn write_float(self, value: f64) {
+        let precision = self.precision;
+        self.write_value(round_to(value, precision));
+    }
 }
 
-impl<'a> SVGBackend<'a> {
+impl<'a, W: Write> SVGBackend<'a, W> {
     fn escape_and_push(buf: &mut String, value: &str) {
         value
             .chars()
@@ -234,51 +326,355 @@ impl<'a> SVGBackend<'a> {
 
     /// Opens a tag and provides facilities for writing attrs and closing the tag
     fn open_tag(&mut self, tag: SVGTag) -> AttrWriter<'_, Init> {
-        AttrWriter::open_tag(self.target.get_mut(), tag, &mut self.tag_stack)
+        let precision = self.precision;
+        AttrWriter::open_tag(self.target.get_mut(), tag, &mut self.tag_stack, precision)
     }
 
-    fn init_svg_file(&mut self, size: (u32, u32)) {
-        let mut attrwriter = self.open_tag(SVGTag::Svg);
-        attrwriter.write_key("width").write_value(size.0);
-        attrwriter.write_key("height").write_value(size.1);
+    /// Write the font attributes of the text style to the tag
+    fn write_font_attrs<S: BackendTextStyle>(attrwriter: &mut AttrWriter<'_, Init>, style: &S) {
+        let color = style.color();
+        attrwriter
+            .write_key("font-family")
+            .write_value(style.family().as_str());
         attrwriter
-            .write_key("viewBox")
-            .write_value(("0 0 ", size.0, ' ', size.1));
+            .write_key("font-size")
+            .write_float(style.size() / 1.24);
+        attrwriter.write_key("opacity").write_float(color.alpha);
         attrwriter
-            .write_key("xmlns")
-            .write_value("http://www.w3.org/2000/svg");
+            .write_key("fill")
+            .write_value(make_svg_color(color));
+
+        // The halo is the stroke of the glyphs painted underneath the fill
+        if let Some((halo, width)) = style.halo() {
+            attrwriter
+                .write_key("stroke")
+                .write_value(make_svg_color(halo));
+            attrwriter
+                .write_key("stroke-opacity")
+                .write_float(halo.alpha);
+            attrwriter.write_key("stroke-width").write_value(width * 2);
+            attrwriter.write_key("stroke-linejoin").write_value("round");
+            attrwriter.write_key("paint-order").write_value("stroke");
+        }
+
+        match style.style() {
+            FontStyle::Normal => {}
+            FontStyle::Bold => {
+                attrwriter.write_key("font-weight").write_value("bold");
+            }
+            other_style => {
+                attrwriter
+                    .write_key("font-style")
+                    .write_value(other_style.as_str());
+            }
+        };
+    }
+
+    /// Write a text tag, the font attributes are omitted if `with_font` is false, in which case
+    /// they are inherited from the enclosing group
+    fn write_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+        with_font: bool,
+    ) {
+        let (x0, y0) = pos;
+        let text_anchor = match style.anchor().h_pos {
+            HPos::Left => "start",
+            HPos::Right => "end",
+            HPos::Center => "middle",
+        };
+
+        let dy = match style.anchor().v_pos {
+            VPos::Top => "0.76em",
+            VPos::Center => "0.5ex",
+            VPos::Bottom => "-0.5ex",
+        };
+
+        let mut attrwriter = self.open_tag(SVGTag::Text);
+        attrwriter.write_key("x").write_value(x0);
+        attrwriter.write_key("y").write_value(y0);
+        attrwriter.write_key("dy").write_value(dy);
+        attrwriter.write_key("text-anchor").write_value(text_anchor);
+        if with_font {
+            Self::write_font_attrs(&mut attrwriter, style);
+        }
+
+        let trans = style.transform();
+        match trans {
+            FontTransform::Rotate90 => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(90, ", x0, ", ", y0, ')'));
+            }
+            FontTransform::Rotate180 => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(180, ", x0, ", ", y0, ')'));
+            }
+            FontTransform::Rotate270 => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(270, ", x0, ", ", y0, ')'));
+            }
+            _ => {}
+        }
         attrwriter.finish_without_closing();
+
+        Self::escape_and_push(self.target.get_mut(), text);
+        self.target.get_mut().push('\n');
+
+        self.close_tag();
     }
 
-    /// Create a new SVG drawing backend
-    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+    fn write_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) {
+        if style.color().alpha == 0.0 {
+            return;
+        }
+        let color = make_svg_color(style.color());
+        let dash_pattern = if fill { None } else { style.dash_pattern() };
+        let (fill, stroke) = if !fill {
+            (None, Some(color))
+        } else {
+            (Some(color), None)
+        };
+
+        let mut attrwriter = self.open_tag(SVGTag::Rectangle);
+        attrwriter.write_key("x").write_value(upper_left.0);
+        attrwriter.write_key("y").write_value(upper_left.1);
+        attrwriter
+            .write_key("width")
+            .write_value(bottom_right.0 - upper_left.0);
+        attrwriter
+            .write_key("height")
+            .write_value(bottom_right.1 - upper_left.1);
+        if radius > 0 {
+            attrwriter.write_key("rx").write_value(radius);
+            attrwriter.write_key("ry").write_value(radius);
+        }
+        attrwriter
+            .write_key("opacity")
+            .write_float(style.color().alpha);
+        attrwriter.write_key("fill").write_value(fill);
+        attrwriter.write_key("stroke").write_value(stroke);
+        attrwriter.write_dash_pattern(dash_pattern);
+        attrwriter.close();
+    }
+
+    fn create(target: Target<'a, W>, size: (u32, u32)) -> Self {
         let mut ret = Self {
-            target: Target::File(String::default(), path.as_ref()),
+            target,
             size,
-            tag_stack: vec![],
+            tag_stack: vec![SVGTag::Svg],
             saved: false,
+            clip_paths: HashMap::new(),
+            precision: None,
+            physical_size: None,
+            view_box: None,
+            header: 0..0,
         };
 
-        ret.init_svg_file(size);
+        let start = ret.target.get_mut().len();
+        ret.header = start..start;
+        ret.write_header();
         ret
     }
 
-    /// Create a new SVG drawing backend and store the document into a String buffer
-    pub fn with_string(buf: &'a mut String, size: (u32, u32)) -> Self {
-        let mut ret = Self {
-            target: Target::Buffer(buf),
-            size,
-            tag_stack: vec![],
-            saved: false,
+    /// Write the `<svg>` tag, or replace the one that is already written
+    fn write_header(&mut self) {
+        let (w, h) = self.size;
+        let precision = self.precision;
+        let mut header = String::new();
+        let mut tag_stack = vec![];
+        let mut attrwriter =
+            AttrWriter::open_tag(&mut header, SVGTag::Svg, &mut tag_stack, precision);
+        match self.physical_size {
+            Some((unit, dpi)) => {
+                let scale = unit.per_inch() / dpi;
+                attrwriter
+                    .write_key("width")
+                    .write_value((round_to(f64::from(w) * scale, precision), unit.suffix()));
+                attrwriter
+                    .write_key("height")
+                    .write_value((round_to(f64::from(h) * scale, precision), unit.suffix()));
+            }
+            None => {
+                attrwriter.write_key("width").write_value(w);
+                attrwriter.write_key("height").write_value(h);
+            }
+        }
+        match self.view_box {
+            Some((x, y, vw, vh)) => {
+                attrwriter.write_key("viewBox").write_value((
+                    round_to(x, precision),
+                    ' ',
+                    round_to(y, precision),
+                    ' ',
+                    round_to(vw, precision),
+                    ' ',
+                    round_to(vh, precision),
+                ));
+            }
+            None => {
+                attrwriter
+                    .write_key("viewBox")
+                    .write_value(("0 0 ", w, ' ', h));
+            }
+        }
+        attrwriter
+            .write_key("xmlns")
+            .write_value("http://www.w3.org/2000/svg");
+        attrwriter.finish_without_closing();
+
+        let buf = self.target.get_mut();
+        buf.replace_range(self.header.clone(), &header);
+        self.header.end = self.header.start + header.len();
+    }
+
+    /// Set the number of the decimal places of the fractional numbers in the document, such as
+    /// the opacities, the font sizes and the physical size. By default, the numbers are written
+    /// with the full precision.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// use plotters_svg::SVGBackend;
+    ///
+    /// let mut svg = String::new();
+    /// {
+    ///     let mut backend = SVGBackend::with_string(&mut svg, (100, 100)).with_precision(2);
+    ///     backend.draw_circle((50, 50), 20, &RED.mix(1.0 / 3.0), true).unwrap();
+    /// }
+    /// assert!(svg.contains("opacity=\"0.33\""));
+    /// ```
+    ///
+    /// - `decimals`: The maximum number of the decimal places
+    /// - **returns**: The backend with the precision set
+    pub fn with_precision(mut self, decimals: u32) -> Self {
+        self.precision = Some(decimals);
+        self.write_header();
+        self
+    }
+
+    /// Set the physical size of the document, which is computed from the size in pixels and the
+    /// resolution, for the figures that should be printed at an exact size. The drawing itself
+    /// is still in pixels, and it's scaled by the viewer to fit the physical size.
+    ///
+    /// ```rust
+    /// use plotters_svg::{SVGBackend, SVGUnit};
+    ///
+    /// let mut svg = String::new();
+    /// // 85mm wide at 300 DPI, which is a single column of many journals
+    /// let (w, h) = ((85.0 / 25.4 * 300.0) as u32, (60.0 / 25.4 * 300.0) as u32);
+    /// SVGBackend::with_string(&mut svg, (w, h))
+    ///     .with_precision(1)
+    ///     .with_physical_size(SVGUnit::Millimeter, 300.0);
+    /// assert!(svg.starts_with("<svg width=\"84.9mm\" height=\"59.9mm\""));
+    /// ```
+    ///
+    /// - `unit`: The unit of the physical size
+    /// - `dpi`: The number of pixels per inch
+    /// - **returns**: The backend with the physical size set
+    pub fn with_physical_size(mut self, unit: SVGUnit, dpi: f64) -> Self {
+        self.physical_size = Some((unit, dpi));
+        self.write_header();
+        self
+    }
+
+    /// Set the `viewBox` of the document, which is the rectangle of the drawing, in pixels, that
+    /// is shown. By default it's the whole drawing, a different rectangle can be used to crop or
+    /// pad the figure.
+    ///
+    /// - `view_box`: The left, top, width and height of the rectangle
+    /// - **returns**: The backend with the view box set
+    pub fn with_view_box(mut self, view_box: (f64, f64, f64, f64)) -> Self {
+        self.view_box = Some(view_box);
+        self.write_header();
+        self
+    }
+
+    /// Create a new SVG drawing backend which writes the document to the writer when it's
+    /// presented. This allows the document to be streamed to anything that implements
+    /// `std::io::Write`, for example the response body of a web handler.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut backend = SVGBackend::with_writer(Vec::new(), (100, 100));
+    /// backend.draw_circle((50, 50), 20, &RED, true).unwrap();
+    /// let bytes = backend.present_to_writer().unwrap();
+    ///
+    /// let svg = backend.into_inner().unwrap().unwrap();
+    /// assert_eq!(svg.len(), bytes);
+    /// ```
+    pub fn with_writer(writer: W, size: (u32, u32)) -> Self {
+        Self::create(Target::Writer(String::default(), Some(writer)), size)
+    }
+
+    /// Finish the document and write it to the target, this is what `present()` does, but the
+    /// size of the document is returned. Once the document is presented, the following calls
+    /// don't write anything and return 0.
+    /// - **returns**: The number of bytes written
+    pub fn present_to_writer(&mut self) -> Result<usize, DrawingErrorKind<Error>> {
+        if self.saved {
+            return Ok(0);
+        }
+        while self.close_tag() {}
+        let size = match self.target {
+            Target::File(ref buf, path) => {
+                let outfile = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
+                let mut outfile = BufWriter::new(outfile);
+                outfile
+                    .write_all(buf.as_ref())
+                    .map_err(DrawingErrorKind::DrawingError)?;
+                buf.len()
+            }
+            Target::Buffer(ref buf) => buf.len(),
+            Target::Writer(ref buf, Some(ref mut writer)) => {
+                writer
+                    .write_all(buf.as_ref())
+                    .and_then(|_| writer.flush())
+                    .map_err(DrawingErrorKind::DrawingError)?;
+                buf.len()
+            }
+            Target::Writer(_, None) => 0,
         };
+        self.saved = true;
+        Ok(size)
+    }
 
-        ret.init_svg_file(size);
+    /// Present the document and get the writer back. See [with_writer](#method.with_writer) for
+    /// an example.
+    /// - **returns**: The writer, or `None` if the backend doesn't use a writer
+    pub fn into_inner(mut self) -> Result<Option<W>, DrawingErrorKind<Error>> {
+        self.present_to_writer()?;
+        Ok(match self.target {
+            Target::Writer(_, ref mut writer) => writer.take(),
+            _ => None,
+        })
+    }
+}
 
-        ret
+impl<'a> SVGBackend<'a> {
+    /// Create a new SVG drawing backend
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::create(Target::File(String::default(), path.as_ref()), size)
+    }
+
+    /// Create a new SVG drawing backend and store the document into a String buffer
+    pub fn with_string(buf: &'a mut String, size: (u32, u32)) -> Self {
+        Self::create(Target::Buffer(buf), size)
     }
 }
 
-impl<'a> DrawingBackend for SVGBackend<'a> {
+impl<'a, W: Write> DrawingBackend for SVGBackend<'a, W> {
     type ErrorType = Error;
 
     fn get_size(&self) -> (u32, u32) {
@@ -290,21 +686,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
     }
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
-        if !self.saved {
-            while self.close_tag() {}
-            match self.target {
-                Target::File(ref buf, path) => {
-                    let outfile = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
-                    let mut outfile = BufWriter::new(outfile);
-                    outfile
-                        .write_all(buf.as_ref())
-                        .map_err(DrawingErrorKind::DrawingError)?;
-                }
-                Target::Buffer(_) => {}
-            }
-            self.saved = true;
-        }
-        Ok(())
+        self.present_to_writer().map(|_| ())
     }
 
     fn draw_pixel(
@@ -321,7 +703,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         attrwriter.write_key("width").write_value("1");
         attrwriter.write_key("height").write_value("1");
         attrwriter.write_key("stroke").write_value("none");
-        attrwriter.write_key("opacity").write_value(color.alpha);
+        attrwriter.write_key("opacity").write_float(color.alpha);
         attrwriter
             .write_key("fill")
             .write_value(make_svg_color(color));
@@ -329,6 +711,61 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         Ok(())
     }
 
+    fn begin_clip_polygon(
+        &mut self,
+        vert: &[BackendCoord],
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let id = match self.clip_paths.get(vert) {
+            Some(id) => format!("plotters-clip-{}", id),
+            None => {
+                let id = self.clip_paths.len();
+                self.clip_paths.insert(vert.to_vec(), id);
+                let id = format!("plotters-clip-{}", id);
+
+                let mut attrwriter = self.open_tag(SVGTag::ClipPath);
+                attrwriter.write_key("id").write_value(id.as_str());
+                attrwriter.finish_without_closing();
+                let mut attrwriter = self.open_tag(SVGTag::Polygon);
+                attrwriter
+                    .write_key("points")
+                    .write_value(FormatEscapedIter(vert.iter().map(|c| (c.0, ',', c.1, ' '))));
+                attrwriter.close();
+                self.close_tag();
+                id
+            }
+        };
+
+        let mut attrwriter = self.open_tag(SVGTag::Group);
+        attrwriter
+            .write_key("clip-path")
+            .write_value(("url(#", id.as_str(), ')'));
+        attrwriter.finish_without_closing();
+        Ok(true)
+    }
+
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if let Some(SVGTag::Group) = self.tag_stack.last() {
+            self.close_tag();
+        }
+        Ok(())
+    }
+
+    fn add_metadata(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let mut attrwriter = self.open_tag(SVGTag::Metadata);
+        attrwriter.write_key("data-key").write_value(key);
+        attrwriter.finish_without_closing();
+
+        Self::escape_and_push(self.target.get_mut(), value);
+        self.target.get_mut().push('\n');
+
+        self.close_tag();
+        Ok(true)
+    }
+
     fn draw_line<S: BackendStyle>(
         &mut self,
         from: BackendCoord,
@@ -341,13 +778,19 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         let mut attrwriter = self.open_tag(SVGTag::Line);
         attrwriter
             .write_key("opacity")
-            .write_value(style.color().alpha);
+            .write_float(style.color().alpha);
         attrwriter
             .write_key("stroke")
             .write_value(make_svg_color(style.color()));
         attrwriter
             .write_key("stroke-width")
             .write_value(style.stroke_width());
+        if style.line_cap() != LineCap::Butt {
+            attrwriter
+                .write_key("stroke-linecap")
+                .write_value(style.line_cap().as_str());
+        }
+        attrwriter.write_dash_pattern(style.dash_pattern());
         attrwriter.write_key("x1").write_value(from.0);
         attrwriter.write_key("y1").write_value(from.1);
         attrwriter.write_key("x2").write_value(to.0);
@@ -363,32 +806,19 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         style: &S,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
-            return Ok(());
-        }
-
-        let color = make_svg_color(style.color());
-        let (fill, stroke) = if !fill {
-            (None, Some(color))
-        } else {
-            (Some(color), None)
-        };
+        self.write_rect(upper_left, bottom_right, 0, style, fill);
+        Ok(())
+    }
 
-        let mut attrwriter = self.open_tag(SVGTag::Rectangle);
-        attrwriter.write_key("x").write_value(upper_left.0);
-        attrwriter.write_key("y").write_value(upper_left.1);
-        attrwriter
-            .write_key("width")
-            .write_value(bottom_right.0 - upper_left.0);
-        attrwriter
-            .write_key("height")
-            .write_value(bottom_right.1 - upper_left.1);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter.write_key("fill").write_value(fill);
-        attrwriter.write_key("stroke").write_value(stroke);
-        attrwriter.close();
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_rect(upper_left, bottom_right, radius, style, fill);
         Ok(())
     }
 
@@ -404,13 +834,24 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         attrwriter.write_key("fill").write_value("none");
         attrwriter
             .write_key("opacity")
-            .write_value(style.color().alpha);
+            .write_float(style.color().alpha);
         attrwriter
             .write_key("stroke")
             .write_value(make_svg_color(style.color()));
         attrwriter
             .write_key("stroke-width")
             .write_value(style.stroke_width());
+        if style.line_join() != LineJoin::Miter {
+            attrwriter
+                .write_key("stroke-linejoin")
+                .write_value(style.line_join().as_str());
+        }
+        if style.line_cap() != LineCap::Butt {
+            attrwriter
+                .write_key("stroke-linecap")
+                .write_value(style.line_cap().as_str());
+        }
+        attrwriter.write_dash_pattern(style.dash_pattern());
         attrwriter
             .write_key("points")
             .write_value(FormatEscapedIter(
@@ -431,7 +872,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         let mut attrwriter = self.open_tag(SVGTag::Polygon);
         attrwriter
             .write_key("opacity")
-            .write_value(style.color().alpha);
+            .write_float(style.color().alpha);
         attrwriter
             .write_key("fill")
             .write_value(make_svg_color(style.color()));
@@ -467,7 +908,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         attrwriter.write_key("r").write_value(radius);
         attrwriter
             .write_key("opacity")
-            .write_value(style.color().alpha);
+            .write_float(style.color().alpha);
         attrwriter.write_key("fill").write_value(fill);
         attrwriter.write_key("stroke").write_value(stroke);
         attrwriter
@@ -488,21 +929,9 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             return Ok(());
         }
 
-        let (x0, y0) = pos;
-        let text_anchor = match style.anchor().h_pos {
-            HPos::Left => "start",
-            HPos::Right => "end",
-            HPos::Center => "middle",
-        };
-
-        let dy = match style.anchor().v_pos {
-            VPos::Top => "0.76em",
-            VPos::Center => "0.5ex",
-            VPos::Bottom => "-0.5ex",
-        };
-
         #[cfg(feature = "debug")]
         {
+            let (x0, y0) = pos;
             let ((fx0, fy0), (fx1, fy1)) =
                 font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
             let x0 = match style.anchor().h_pos {
@@ -526,58 +955,44 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .unwrap();
         }
 
-        let mut attrwriter = self.open_tag(SVGTag::Text);
-        attrwriter.write_key("x").write_value(x0);
-        attrwriter.write_key("y").write_value(y0);
-        attrwriter.write_key("dy").write_value(dy);
-        attrwriter.write_key("text-anchor").write_value(text_anchor);
-        attrwriter
-            .write_key("font-family")
-            .write_value(style.family().as_str());
-        attrwriter
-            .write_key("font-size")
-            .write_value(style.size() / 1.24);
-        attrwriter.write_key("opacity").write_value(color.alpha);
-        attrwriter
-            .write_key("fill")
-            .write_value(make_svg_color(color));
+        self.write_text(text, style, pos, true);
 
-        match style.style() {
-            FontStyle::Normal => {}
-            FontStyle::Bold => {
-                attrwriter.write_key("font-weight").write_value("bold");
-            }
-            other_style => {
-                attrwriter
-                    .write_key("font-style")
-                    .write_value(other_style.as_str());
-            }
+        Ok(())
+    }
+
+    fn draw_texts<S: BackendTextStyle>(
+        &mut self,
+        texts: &[(&str, BackendCoord, &S)],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let texts: Vec<_> = texts
+            .iter()
+            .filter(|(_, _, style)| style.color().alpha != 0.0)
+            .collect();
+        let first = match texts.first() {
+            Some((_, _, style)) => *style,
+            None => return Ok(()),
         };
 
-        let trans = style.transform();
-        match trans {
-            FontTransform::Rotate90 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(90, ", x0, ", ", y0, ')'));
-            }
-            FontTransform::Rotate180 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(180, ", x0, ", ", y0, ')'));
-            }
-            FontTransform::Rotate270 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(270, ", x0, ", ", y0, ')'));
-            }
-            _ => {}
+        // The font attributes shared by all the texts are written once to the enclosing group
+        let shared = texts.iter().all(|(_, _, style)| {
+            let (a, b) = (style.color(), first.color());
+            style.family().as_str() == first.family().as_str()
+                && style.size() == first.size()
+                && style.style().as_str() == first.style().as_str()
+                && a.rgb == b.rgb
+                && a.alpha == b.alpha
+                && style.halo().map(|(c, w)| (c.rgb, c.alpha, w))
+                    == first.halo().map(|(c, w)| (c.rgb, c.alpha, w))
+        });
+
+        let mut attrwriter = self.open_tag(SVGTag::Group);
+        if shared {
+            Self::write_font_attrs(&mut attrwriter, first);
         }
         attrwriter.finish_without_closing();
-
-        Self::escape_and_push(self.target.get_mut(), text);
-        self.target.get_mut().push('\n');
-
+        for (text, pos, style) in texts {
+            self.write_text(text, *style, *pos, !shared);
+        }
         self.close_tag();
 
         Ok(())
@@ -664,7 +1079,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
     }
 }
 
-impl Drop for SVGBackend<'_> {
+impl<W: Write> Drop for SVGBackend<'_, W> {
     fn drop(&mut self) {
         if !self.saved {
             // drop should not panic, so we ignore a failed present
@@ -678,8 +1093,8 @@ mod test {
     use super::*;
     use plotters::element::Circle;
     use plotters::prelude::{
-        ChartBuilder, Color, IntoDrawingArea, IntoFont, SeriesLabelPosition, TextStyle, BLACK,
-        BLUE, RED, WHITE,
+        ChartBuilder, Color, IntoDrawingArea, IntoFont, PathElement, Rectangle,
+        SeriesLabelPosition, ShapeStyle, TextStyle, BLACK, BLUE, RED, WHITE,
     };
     use plotters::style::text_anchor::{HPos, Pos, VPos};
     use std::fs;
@@ -699,6 +1114,31 @@ mod test {
         fs::write(file_path, &content).unwrap();
     }
 
+    #[test]
+    fn test_clip_polygon() {
+        let mut content = String::new();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            assert!(backend
+                .begin_clip_polygon(&[(0, 0), (50, 0), (0, 50)])
+                .unwrap());
+            backend.draw_circle((20, 20), 10, &RED, true).unwrap();
+            backend.end_clip().unwrap();
+            backend.draw_circle((80, 80), 10, &BLUE, true).unwrap();
+        }
+
+        assert!(content.contains(
+            "<clipPath id=\"plotters-clip-0\">\n<polygon points=\"0,0 50,0 0,50 \"/>\n</clipPath>"
+        ));
+        let group = content
+            .find("<g clip-path=\"url(#plotters-clip-0)\">")
+            .unwrap();
+        let group_end = content.find("</g>").unwrap();
+        let red = content.find("#FF0000").unwrap();
+        let blue = content.find("#0000FF").unwrap();
+        assert!(group < red && red < group_end && group_end < blue);
+    }
+
     fn draw_mesh_with_custom_ticks(tick_size: i32, test_name: &str) {
         let mut content: String = Default::default();
         {
@@ -766,6 +1206,110 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_draw_texts() {
+        let mut content: String = Default::default();
+        {
+            let mut root = SVGBackend::with_string(&mut content, (500, 500));
+
+            let left = TextStyle::from(("sans-serif", 20).into_font());
+            let right = left.pos(Pos::new(HPos::Right, VPos::Top));
+            root.draw_texts(&[("first", (10, 10), &left), ("second", (100, 10), &right)])
+                .unwrap();
+
+            let other = TextStyle::from(("serif", 10).into_font());
+            root.draw_texts(&[("third", (10, 50), &left), ("fourth", (10, 80), &other)])
+                .unwrap();
+        }
+
+        checked_save_file("test_draw_texts", &content);
+
+        // The shared font is written once on the group
+        assert!(content.contains("<g font-family=\"sans-serif\""));
+        assert_eq!(content.matches("font-family=\"sans-serif\"").count(), 2);
+        assert!(content.contains("text-anchor=\"end\""));
+        assert_eq!(content.matches("<g").count(), 2);
+        assert_eq!(content.matches("</g>").count(), 2);
+    }
+
+    #[test]
+    fn test_line_join_and_cap() {
+        let mut content: String = Default::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (200, 100)).into_drawing_area();
+            let style = ShapeStyle::from(BLUE)
+                .stroke_width(5)
+                .line_join(LineJoin::Round)
+                .line_cap(LineCap::Square);
+            root.draw(&PathElement::new(vec![(10, 10), (50, 90), (90, 10)], style))
+                .unwrap();
+            root.draw(&PathElement::new(
+                vec![(100, 10), (190, 90)],
+                ShapeStyle::from(BLUE).stroke_width(5),
+            ))
+            .unwrap();
+        }
+
+        checked_save_file("test_line_join_and_cap", &content);
+
+        assert_eq!(content.matches("stroke-linejoin=\"round\"").count(), 1);
+        assert_eq!(content.matches("stroke-linecap=\"square\"").count(), 1);
+    }
+
+    #[test]
+    fn test_dash_pattern() {
+        let mut content: String = Default::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (200, 100)).into_drawing_area();
+            let style = ShapeStyle::from(BLUE).dash_pattern(DashPattern::new(6, 3).offset(2));
+            root.draw(&PathElement::new(vec![(10, 10), (50, 90), (90, 10)], style))
+                .unwrap();
+            root.draw(&Rectangle::new([(100, 10), (190, 90)], style))
+                .unwrap();
+            root.draw(&Rectangle::new([(120, 30), (170, 70)], style.filled()))
+                .unwrap();
+        }
+
+        checked_save_file("test_dash_pattern", &content);
+
+        assert_eq!(content.matches("stroke-dasharray=\"6 3\"").count(), 2);
+        assert_eq!(content.matches("stroke-dashoffset=\"2\"").count(), 2);
+    }
+
+    #[test]
+    fn test_rounded_rect() {
+        let mut content: String = Default::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (200, 100)).into_drawing_area();
+            let mut rect = Rectangle::new([(10, 10), (190, 90)], BLUE.filled());
+            rect.set_corner_radius(8);
+            root.draw(&rect).unwrap();
+            root.draw(&Rectangle::new([(0, 0), (5, 5)], RED)).unwrap();
+        }
+
+        checked_save_file("test_rounded_rect", &content);
+
+        assert!(content.contains("rx=\"8\" ry=\"8\""));
+        assert_eq!(content.matches("<rect").count(), 2);
+        assert_eq!(content.matches("rx=").count(), 1);
+    }
+
+    #[test]
+    fn test_text_halo() {
+        let mut content: String = Default::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (200, 100)).into_drawing_area();
+            let style = TextStyle::from(("sans-serif", 20).into_font()).halo(&WHITE, 3);
+            root.draw_text("halo", &style, (10, 10)).unwrap();
+        }
+
+        checked_save_file("test_text_halo", &content);
+
+        assert!(content.contains("stroke=\"#FFFFFF\""));
+        assert!(content.contains("stroke-width=\"6\""));
+        assert!(content.contains("paint-order=\"stroke\""));
+    }
+
     #[test]
     fn test_text_draw() {
         let mut content: String = Default::default();
@@ -933,4 +1477,53 @@ mod test {
 
         checked_save_file("test_draw_pixel_alphas", &content);
     }
+
+    #[test]
+    fn test_add_metadata() {
+        let mut content = String::new();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            assert!(backend.add_metadata("source", "a < b & \"c\"").unwrap());
+        }
+
+        assert!(content
+            .contains("<metadata data-key=\"source\">\na &lt; b &amp; &quot;c&quot;\n</metadata>"));
+    }
+
+    #[test]
+    fn test_document_options() {
+        let mut content = String::from("<!-- header -->\n");
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (300, 150))
+                .with_physical_size(SVGUnit::Inch, 100.0)
+                .with_view_box((-10.0, -10.0, 320.0, 170.0))
+                .with_precision(3);
+            let style = BLACK.mix(2.0 / 3.0);
+            backend.draw_rect((0, 0), (10, 10), &style, true).unwrap();
+        }
+
+        assert!(content.starts_with(
+            "<!-- header -->\n<svg width=\"3in\" height=\"1.5in\" viewBox=\"-10 -10 320 170\" "
+        ));
+        assert!(content.contains("opacity=\"0.667\""));
+        assert!(content.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_write_to_writer() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+        }
+
+        let mut backend = SVGBackend::with_writer(Vec::new(), (100, 100));
+        backend.draw_rect((0, 0), (100, 100), &WHITE, true).unwrap();
+        let size = backend.present_to_writer().unwrap();
+        assert_eq!(backend.present_to_writer().unwrap(), 0);
+
+        let buf = backend.into_inner().unwrap().unwrap();
+        assert_eq!(buf.len(), size);
+        assert_eq!(String::from_utf8(buf).unwrap(), content);
+    }
 }
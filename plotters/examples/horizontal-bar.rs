@@ -0,0 +1,50 @@
+use plotters::prelude::*;
+const OUT_FILE_NAME: &str = "plotters-doc-data/horizontal-bar.png";
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let languages = ["Rust", "Python", "TypeScript", "Go", "C++", "Java"];
+    let share = [13.2, 49.3, 38.5, 13.5, 22.4, 30.3];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (640, 480)).into_drawing_area();
+
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .x_label_area_size(35)
+        .y_label_area_size(90)
+        .margin(10)
+        .caption("Language Usage", ("sans-serif", 40.0))
+        .build_cartesian_2d(0.0..60.0, (0..languages.len() - 1).into_segmented())?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .bold_line_style(WHITE.mix(0.3))
+        .x_desc("Share of Respondents")
+        .x_label_formatter(&|x| format!("{:.0}%", x))
+        .y_label_formatter(&|y| match y {
+            SegmentValue::CenterOf(idx) => languages[*idx].to_string(),
+            _ => String::new(),
+        })
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart.draw_series(
+        Histogram::horizontal(&chart)
+            .margin(8)
+            .style_func(|y, _| match y {
+                SegmentValue::Exact(0) => RED.mix(0.8).filled(),
+                _ => BLUE.mix(0.5).filled(),
+            })
+            .data(share.iter().enumerate().map(|(idx, share)| (idx, *share))),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
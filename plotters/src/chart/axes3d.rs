@@ -28,11 +28,12 @@ pub struct Axes3dStyle<'a, 'b, X: Ranged, Y: Ranged, Z: Ranged, DB: DrawingBacke
     pub(super) bold_line_style: ShapeStyle,
     pub(super) light_line_style: ShapeStyle,
     pub(super) axis_panel_style: ShapeStyle,
-    pub(super) axis_style: ShapeStyle,
+    pub(super) axis_line_style: ShapeStyle,
+    pub(super) tick_style: ShapeStyle,
     pub(super) label_style: TextStyle<'b>,
-    pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
-    pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
-    pub(super) format_z: &'b dyn Fn(&Z::ValueType) -> String,
+    pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
+    pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) format_z: Option<&'b dyn Fn(&Z::ValueType) -> String>,
     _phantom: PhantomData<&'a (X, Y, Z)>,
 }
 
@@ -166,6 +167,38 @@ where
         self
     }
 
+    /**
+    Sets the style of both the axis lines and the tick marks.
+
+    See [`ChartContext::configure_axes()`] for more information and examples.
+    */
+    pub fn axis_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        let style = style.into();
+        self.axis_line_style = style;
+        self.tick_style = style;
+        self
+    }
+
+    /**
+    Sets the style of the axis lines.
+
+    See [`ChartContext::configure_axes()`] for more information and examples.
+    */
+    pub fn axis_line_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.axis_line_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the tick marks.
+
+    See [`ChartContext::configure_axes()`] for more information and examples.
+    */
+    pub fn tick_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.tick_style = style.into();
+        self
+    }
+
     /**
     Sets the text style of the axis labels.
 
@@ -182,7 +215,7 @@ where
     See [`ChartContext::configure_axes()`] for more information and examples.
     */
     pub fn x_formatter<F: Fn(&X::ValueType) -> String>(&mut self, f: &'b F) -> &mut Self {
-        self.format_x = f;
+        self.format_x = Some(f);
         self
     }
 
@@ -192,7 +225,7 @@ where
     See [`ChartContext::configure_axes()`] for more information and examples.
     */
     pub fn y_formatter<F: Fn(&Y::ValueType) -> String>(&mut self, f: &'b F) -> &mut Self {
-        self.format_y = f;
+        self.format_y = Some(f);
         self
     }
 
@@ -202,7 +235,7 @@ where
     See [`ChartContext::configure_axes()`] for more information and examples.
     */
     pub fn z_formatter<F: Fn(&Z::ValueType) -> String>(&mut self, f: &'b F) -> &mut Self {
-        self.format_z = f;
+        self.format_z = Some(f);
         self
     }
 
@@ -224,11 +257,12 @@ where
             bold_line_style: Into::<ShapeStyle>::into(BLACK.mix(0.2)),
             light_line_style: Into::<ShapeStyle>::into(TRANSPARENT),
             axis_panel_style: Into::<ShapeStyle>::into(BLACK.mix(0.1)),
-            axis_style: Into::<ShapeStyle>::into(BLACK.mix(0.8)),
+            axis_line_style: Into::<ShapeStyle>::into(BLACK.mix(0.8)),
+            tick_style: Into::<ShapeStyle>::into(BLACK.mix(0.8)),
             label_style: ("sans-serif", (12).percent().max(12).in_pixels(&parent_size)).into(),
-            format_x: &X::format,
-            format_y: &Y::format,
-            format_z: &Z::format,
+            format_x: None,
+            format_y: None,
+            format_z: None,
             _phantom: PhantomData,
             target: Some(chart),
         }
@@ -270,13 +304,16 @@ where
         )?;
 
         for i in 0..3 {
-            let axis = chart.draw_axis(i, &panels, self.axis_style)?;
+            let axis = chart.draw_axis(i, &panels, self.axis_line_style)?;
+            let coord = chart.plotting_area().as_coord_spec();
             let labels: Vec<_> = match i {
                 0 => kps_bold
                     .x_points
                     .iter()
                     .map(|x| {
-                        let x_text = (self.format_x)(x);
+                        let x_text = self
+                            .format_x
+                            .map_or_else(|| coord.logic_x.format_ext(x), |f| f(x));
                         let mut p = axis[0].clone();
                         p[0] = Coord3D::X(x.clone());
                         (p, x_text)
@@ -286,7 +323,9 @@ where
                     .y_points
                     .iter()
                     .map(|y| {
-                        let y_text = (self.format_y)(y);
+                        let y_text = self
+                            .format_y
+                            .map_or_else(|| coord.logic_y.format_ext(y), |f| f(y));
                         let mut p = axis[0].clone();
                         p[1] = Coord3D::Y(y.clone());
                         (p, y_text)
@@ -296,7 +335,9 @@ where
                     .z_points
                     .iter()
                     .map(|z| {
-                        let z_text = (self.format_z)(z);
+                        let z_text = self
+                            .format_z
+                            .map_or_else(|| coord.logic_z.format_ext(z), |f| f(z));
                         let mut p = axis[0].clone();
                         p[2] = Coord3D::Z(z.clone());
                         (p, z_text)
@@ -307,7 +348,7 @@ where
                 axis,
                 &labels[..],
                 self.tick_size,
-                self.axis_style,
+                self.tick_style,
                 self.label_style.clone(),
             )?;
         }
@@ -1,11 +1,13 @@
 use super::context::ChartContext;
+use super::SeriesLabelPosition;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
 use crate::coord::ranged1d::AsRangedCoord;
 use crate::coord::Shift;
 
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, Rect};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoTextStyle, OwnedTextStyle, ShapeStyle, SizeDesc, TextStyle, WHITE};
 
 use plotters_backend::DrawingBackend;
 
@@ -35,7 +37,7 @@ The result is a chart with a spacious X label area and a narrow Y label area:
 
 [`ChartBuilder::set_left_and_bottom_label_area_size()`]
 */
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LabelAreaPosition {
     /// Top of the figure
     Top = 0,
@@ -47,6 +49,158 @@ pub enum LabelAreaPosition {
     Right = 3,
 }
 
+/**
+The configuration of the four label areas around the plotting area, which is applied with
+[`ChartBuilder::label_areas()`].
+
+For each side, it specifies the size of the label area, an optional text style of the labels
+on that side and whether the labels are visible. A hidden label area still takes its space,
+but nothing is drawn on it. This makes mirrored axes easy, for example, a chart with the X labels
+only on the top.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("label_area_config.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .label_areas(
+        LabelAreaConfig::new()
+            .x_axes(30)
+            .y_axes(40)
+            .style(LabelAreaPosition::Top, ("sans-serif", 15).into_font().color(&BLUE))
+            .visible(LabelAreaPosition::Bottom, false)
+            .visible(LabelAreaPosition::Right, false),
+    )
+    .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+```
+*/
+#[derive(Clone, Default)]
+pub struct LabelAreaConfig<'b> {
+    size: [Option<i32>; 4],
+    style: [Option<TextStyle<'b>>; 4],
+    hidden: [Option<bool>; 4],
+}
+
+impl<'b> LabelAreaConfig<'b> {
+    /// Create a new label area configuration, which doesn't change anything unless configured
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the size of the label area on the given side in pixels. Just like
+    /// [`ChartBuilder::set_label_area_size()`], a negative size makes the label area overlap
+    /// with the plotting area.
+    pub fn size(mut self, pos: LabelAreaPosition, size: i32) -> Self {
+        self.size[pos as usize] = Some(size);
+        self
+    }
+
+    /// Set the size of the top label area
+    pub fn top(self, size: i32) -> Self {
+        self.size(LabelAreaPosition::Top, size)
+    }
+
+    /// Set the size of the bottom label area
+    pub fn bottom(self, size: i32) -> Self {
+        self.size(LabelAreaPosition::Bottom, size)
+    }
+
+    /// Set the size of the left label area
+    pub fn left(self, size: i32) -> Self {
+        self.size(LabelAreaPosition::Left, size)
+    }
+
+    /// Set the size of the right label area
+    pub fn right(self, size: i32) -> Self {
+        self.size(LabelAreaPosition::Right, size)
+    }
+
+    /// Set the sizes of both the top and the bottom label areas, which hold the X labels
+    pub fn x_axes(self, size: i32) -> Self {
+        self.top(size).bottom(size)
+    }
+
+    /// Set the sizes of both the left and the right label areas, which hold the Y labels
+    pub fn y_axes(self, size: i32) -> Self {
+        self.left(size).right(size)
+    }
+
+    /// Set the text style of the labels on the given side. This overrides the label style
+    /// configured on the mesh for that side.
+    pub fn style<S: Into<TextStyle<'b>>>(mut self, pos: LabelAreaPosition, style: S) -> Self {
+        self.style[pos as usize] = Some(style.into());
+        self
+    }
+
+    /// Set if the label area on the given side is visible, the default is visible.
+    /// A hidden label area still takes its space, but nothing is drawn on it.
+    pub fn visible(mut self, pos: LabelAreaPosition, visible: bool) -> Self {
+        self.hidden[pos as usize] = Some(!visible);
+        self
+    }
+}
+
+/**
+Specifies where the caption of the chart is placed.
+
+This is used with [`ChartBuilder::caption_position()`].
+
+The captions inside the plotting area don't take any space from the chart. They are drawn in a box
+with a background by [`ChartContext::draw_caption()`], which should be called after the series are
+drawn, so that the caption stays on top. The series labels are moved below the caption box if they
+would overlap with it.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("caption_position.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .caption("Inside the plot", ("sans-serif", 15))
+    .caption_position(CaptionPosition::InsideTopLeft)
+    .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+chart_context
+    .draw_series(LineSeries::new((0..5).map(|x| (x as f64, x as f64 * 0.5)), &BLUE))
+    .unwrap()
+    .label("Line");
+chart_context
+    .configure_series_labels()
+    .position(SeriesLabelPosition::UpperLeft)
+    .draw()
+    .unwrap();
+chart_context.draw_caption().unwrap();
+```
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CaptionPosition {
+    /// Centered above the chart, which is the default
+    #[default]
+    Top,
+    /// Centered below the chart
+    Bottom,
+    /// Inside the plotting area, at the upper left corner
+    InsideTopLeft,
+    /// Inside the plotting area, centered at the top
+    InsideTopCenter,
+}
+
+/// A caption placed inside the plotting area, which is drawn by [`ChartContext::draw_caption()`]
+#[derive(Clone)]
+pub(crate) struct InsideCaption {
+    pub(crate) text: String,
+    pub(crate) style: OwnedTextStyle,
+    /// The caption box, relative to the plotting area
+    pub(crate) rect: Rect,
+    pub(crate) background: ShapeStyle,
+}
+
 /**
 The helper object to create a chart context, which is used for the high-level figure drawing.
 
@@ -60,7 +214,13 @@ pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     overlap_plotting_area: [bool; 4],
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
+    caption_position: CaptionPosition,
+    caption_background: ShapeStyle,
     margin: [u32; 4],
+    label_area_style: [Option<TextStyle<'b>>; 4],
+    label_area_hidden: [bool; 4],
+    legend_area: Option<(SeriesLabelPosition, u32)>,
+    colorbar_area_size: Option<u32>,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -92,8 +252,14 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             label_area_size: [0; 4],
             root_area: root,
             title: None,
+            caption_position: CaptionPosition::Top,
+            caption_background: WHITE.mix(0.8).filled(),
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            label_area_style: [None, None, None, None],
+            label_area_hidden: [false; 4],
+            legend_area: None,
+            colorbar_area_size: None,
         }
     }
 
@@ -258,13 +424,39 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /**
+    Configures the sizes, label styles and visibility of the four label areas at once.
+
+    - `config`: The label area configuration, the sizes, styles and visibility which aren't
+      configured keep their current values
+
+    See [`LabelAreaConfig`] for more information and examples.
+    */
+    pub fn label_areas(&mut self, config: LabelAreaConfig<'b>) -> &mut Self {
+        let mut config = config;
+        for idx in 0..4 {
+            if let Some(size) = config.size[idx] {
+                self.label_area_size[idx] = size.unsigned_abs();
+                self.overlap_plotting_area[idx] = size < 0;
+            }
+            if let Some(style) = config.style[idx].take() {
+                self.label_area_style[idx] = Some(style);
+            }
+            if let Some(hidden) = config.hidden[idx] {
+                self.label_area_hidden[idx] = hidden;
+            }
+        }
+        self
+    }
+
     /**
     Sets the title or caption of the chart.
 
     - `caption`: The caption of the chart
     - `style`: The text style
 
-    The title or caption will be centered at the top of the drawing area.
+    By default, the title or caption will be centered at the top of the drawing area, use
+    [`ChartBuilder::caption_position()`] to place it elsewhere.
 
     See [`ChartBuilder::on()`] for more information and examples.
     */
@@ -280,6 +472,191 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /**
+    Sets the position of the caption.
+
+    - `pos`: The position of the caption
+
+    See [`CaptionPosition`] for more information and examples.
+    */
+    pub fn caption_position(&mut self, pos: CaptionPosition) -> &mut Self {
+        self.caption_position = pos;
+        self
+    }
+
+    /**
+    Sets the style of the box behind the caption, which is only used when the caption is inside
+    the plotting area. The default is a translucent white box.
+
+    - `style`: The style of the caption box
+
+    See [`CaptionPosition`] for more information and examples.
+    */
+    pub fn caption_background<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.caption_background = style.into();
+        self
+    }
+
+    /**
+    Reserves the space for the series labels outside of the plotting area, thus the legend never
+    covers the data. The space is taken from the side of the chart, outside of the label areas,
+    and the series labels are placed there by default.
+
+    - `pos`: The side of the space, either [`SeriesLabelPosition::OutsideRight`] or
+      [`SeriesLabelPosition::OutsideBottom`], the other positions are ignored
+    - `size`: The width of the space at the right side, or the height of the space at the bottom,
+      in backend units (pixels)
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("legend_area.svg", (400, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(20)
+        .legend_area(SeriesLabelPosition::OutsideRight, 100)
+        .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+        .unwrap();
+    chart
+        .draw_series(LineSeries::new((0..5).map(|x| (x as f64, x as f64 * 0.7)), &BLUE))
+        .unwrap()
+        .label("Line")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+    chart.configure_series_labels().border_style(BLACK).draw().unwrap();
+    ```
+    */
+    pub fn legend_area<S: SizeDesc>(&mut self, pos: SeriesLabelPosition, size: S) -> &mut Self {
+        let size = size.in_pixels(self.root_area).max(0) as u32;
+        self.legend_area = match pos {
+            SeriesLabelPosition::OutsideRight | SeriesLabelPosition::OutsideBottom => {
+                Some((pos, size))
+            }
+            _ => None,
+        };
+        self
+    }
+
+    /**
+    Reserves the space for a colorbar at the right side of the chart, between the plotting area
+    and the legend area reserved by [`ChartBuilder::legend_area()`]. The colorbar is drawn into
+    this space by [`ChartContext::draw_colorbar()`].
+
+    - `size`: The width of the space, which includes the bar and its labels
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("colorbar_area.svg", (400, 300)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(20)
+        .colorbar_area(60)
+        .build_cartesian_2d(0..3, 0..3)
+        .unwrap();
+    let values = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]];
+    chart
+        .draw_series(HeatmapSeries::new(0..=3, 0..=3, &values, ViridisRGBA))
+        .unwrap();
+    chart
+        .draw_colorbar(ColorBar::continuous((0, 0), (15, 0), &ViridisRGBA, 0.0..8.0))
+        .unwrap();
+    ```
+    */
+    pub fn colorbar_area<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.colorbar_area_size = Some(size.in_pixels(self.root_area).max(0) as u32);
+        self
+    }
+
+    // Take the space reserved for the colorbar from the right side of the drawing area
+    fn layout_colorbar_area(
+        &self,
+        drawing_area: &mut DrawingArea<DB, Shift>,
+    ) -> Option<DrawingArea<DB, Shift>> {
+        let size = self.colorbar_area_size?;
+        let (w, _) = drawing_area.dim_in_pixel();
+        let (rest, colorbar) = drawing_area.split_horizontally(w.saturating_sub(size));
+        *drawing_area = rest;
+        Some(colorbar)
+    }
+
+    // Take the space reserved for the series labels from the drawing area
+    fn layout_legend_area(
+        &self,
+        drawing_area: &mut DrawingArea<DB, Shift>,
+    ) -> Option<(SeriesLabelPosition, DrawingArea<DB, Shift>)> {
+        let (pos, size) = self.legend_area.clone()?;
+        let (w, h) = drawing_area.dim_in_pixel();
+        let (rest, legend) = if pos == SeriesLabelPosition::OutsideRight {
+            drawing_area.split_horizontally(w.saturating_sub(size))
+        } else {
+            drawing_area.split_vertically(h.saturating_sub(size))
+        };
+        *drawing_area = rest;
+        Some((pos, legend))
+    }
+
+    // Draw the caption outside of the plotting area and take the space it uses from the drawing
+    // area, returns the offset of the upper left corner of the drawing area
+    fn layout_outside_caption(
+        &self,
+        drawing_area: &mut DrawingArea<DB, Shift>,
+    ) -> Result<(i32, i32), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (title, style) = match self.title {
+            Some((ref title, ref style)) => (title, style),
+            None => return Ok((0, 0)),
+        };
+        match self.caption_position {
+            CaptionPosition::Top => {
+                let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+                *drawing_area = drawing_area.titled(title, style.clone())?;
+                let (current_dx, current_dy) = drawing_area.get_base_pixel();
+                Ok((current_dx - origin_dx, current_dy - origin_dy))
+            }
+            CaptionPosition::Bottom => {
+                let (w, h) = drawing_area.dim_in_pixel();
+                let (_, text_h) = drawing_area.estimate_text_size(title, style)?;
+                let padding = (text_h / 2).min(5) as i32;
+                let (upper, lower) =
+                    drawing_area.split_vertically(h as i32 - text_h as i32 - padding * 2);
+                lower.draw_text(
+                    title,
+                    &style.pos(Pos::new(HPos::Center, VPos::Top)),
+                    (w as i32 / 2, padding),
+                )?;
+                *drawing_area = upper;
+                Ok((0, 0))
+            }
+            CaptionPosition::InsideTopLeft | CaptionPosition::InsideTopCenter => Ok((0, 0)),
+        }
+    }
+
+    // Layout the caption inside the plotting area, which is drawn later
+    fn layout_inside_caption(
+        &self,
+        plotting_area: &DrawingArea<DB, Shift>,
+    ) -> Result<Option<InsideCaption>, DrawingAreaErrorKind<DB::ErrorType>> {
+        const PADDING: u32 = 4;
+        const MARGIN: i32 = 5;
+        let (title, style) = match (&self.title, self.caption_position) {
+            (Some((title, style)), CaptionPosition::InsideTopLeft)
+            | (Some((title, style)), CaptionPosition::InsideTopCenter) => (title, style),
+            _ => return Ok(None),
+        };
+        let (text_w, text_h) = plotting_area.estimate_text_size(title, style)?;
+        let (box_w, box_h) = (text_w + PADDING * 2, text_h + PADDING * 2);
+        let x = if self.caption_position == CaptionPosition::InsideTopLeft {
+            MARGIN
+        } else {
+            (plotting_area.dim_in_pixel().0 as i32 - box_w as i32) / 2
+        };
+        Ok(Some(InsideCaption {
+            text: title.clone(),
+            style: OwnedTextStyle::new(style),
+            rect: Rect::from_size((x, MARGIN), (box_w, box_h)),
+            background: self.caption_background,
+        }))
+    }
+
     /// This function has been renamed to [`ChartBuilder::build_cartesian_2d()`] and is to be removed in the future.
     #[allow(clippy::type_complexity)]
     #[deprecated(
@@ -327,14 +704,9 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
+        let (title_dx, title_dy) = self.layout_outside_caption(&mut drawing_area)?;
+        let legend_area = self.layout_legend_area(&mut drawing_area);
+        let colorbar_area = self.layout_colorbar_area(&mut drawing_area);
 
         let (w, h) = drawing_area.dim_in_pixel();
 
@@ -381,6 +753,9 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         // Initialize the label areas - since the label area might be overlapping
         // with the plotting area, in this case, we need handle them differently
         for (src_idx, dst_idx) in [1, 7, 3, 5].iter().zip(0..4) {
+            if self.label_area_hidden[dst_idx] {
+                continue;
+            }
             if !self.overlap_plotting_area[dst_idx] {
                 let (h, w) = split[*src_idx].as_ref().unwrap().dim_in_pixel();
                 if h > 0 && w > 0 {
@@ -410,6 +785,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             }
         }
 
+        let caption = self.layout_inside_caption(&drawing_area)?;
+
         let mut pixel_range = drawing_area.get_pixel_range();
         pixel_range.0.end -= 1;
         pixel_range.1.end -= 1;
@@ -436,6 +813,15 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
                 actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
             ),
+            label_area_style: self
+                .label_area_style
+                .clone()
+                .map(|style| style.as_ref().map(OwnedTextStyle::new)),
+            caption,
+            legend_area,
+            colorbar_area,
+            clip_series: true,
+            content_drawn: false,
         })
     }
 
@@ -470,15 +856,11 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             );
         }
 
-        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
-            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
-            drawing_area = drawing_area.titled(title, style.clone())?;
-            let (current_dx, current_dy) = drawing_area.get_base_pixel();
-            (current_dx - origin_dx, current_dy - origin_dy)
-        } else {
-            (0, 0)
-        };
+        let (title_dx, title_dy) = self.layout_outside_caption(&mut drawing_area)?;
+        let legend_area = self.layout_legend_area(&mut drawing_area);
+        let colorbar_area = self.layout_colorbar_area(&mut drawing_area);
 
+        let caption = self.layout_inside_caption(&drawing_area)?;
         let pixel_range = drawing_area.get_pixel_range();
 
         Ok(ChartContext {
@@ -495,6 +877,12 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
             ),
+            label_area_style: [None, None, None, None],
+            caption,
+            legend_area,
+            colorbar_area,
+            clip_series: true,
+            content_drawn: false,
         })
     }
 }
@@ -529,6 +917,49 @@ mod test {
         assert_eq!(chart.label_area_size[3], 200);
     }
 
+    #[test]
+    fn test_label_area_config() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area);
+
+        chart.right_y_label_area_size(50).label_areas(
+            LabelAreaConfig::new()
+                .x_axes(10)
+                .left(-20)
+                .style(LabelAreaPosition::Top, ("serif", 15))
+                .visible(LabelAreaPosition::Bottom, false),
+        );
+        assert_eq!(chart.label_area_size, [10, 10, 20, 50]);
+        assert_eq!(chart.overlap_plotting_area, [false, false, true, false]);
+
+        // The sides which aren't configured again keep their visibility and style
+        chart.label_areas(LabelAreaConfig::new().top(15));
+        assert_eq!(chart.label_area_hidden, [false, true, false, false]);
+        assert!(chart.label_area_style[0].is_some());
+        chart.label_areas(LabelAreaConfig::new().top(10));
+
+        let chart = chart.build_cartesian_2d(0..10, 0..10).unwrap();
+        assert!(chart.x_label_area[0].is_some());
+        assert!(chart.x_label_area[1].is_none());
+        assert!(chart.y_label_area[0].is_some());
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (150, 180));
+        assert_eq!(
+            chart.label_area_style[0]
+                .as_ref()
+                .unwrap()
+                .get()
+                .font
+                .get_size(),
+            15.0
+        );
+        assert!(chart.label_area_style[1].is_none());
+
+        let chart = chart.into_chart_state().restore(&drawing_area);
+        let style = chart.label_area_style[0].as_ref().unwrap().get();
+        assert_eq!(style.font.get_name(), "serif");
+        assert_eq!(style.font.get_size(), 15.0);
+    }
+
     #[test]
     fn test_margin_configure() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -566,6 +997,82 @@ mod test {
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
 
+    #[test]
+    fn test_caption_position() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "Bottom");
+                assert!(pos.1 > 180);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .caption("Bottom", ("serif", 10))
+            .caption_position(CaptionPosition::Bottom)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        assert_eq!(chart.plotting_area().get_base_pixel(), (0, 0));
+        assert!(chart.plotting_area().dim_in_pixel().1 < 190);
+        assert!(chart.caption.is_none());
+
+        let mut caption = None;
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .caption("Inside", ("serif", 10))
+                .caption_position(CaptionPosition::InsideTopLeft)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            assert_eq!(chart.plotting_area().dim_in_pixel(), (200, 200));
+            let rect = chart.caption.as_ref().unwrap().rect.clone();
+            assert_eq!(rect.upper_left(), (5, 5));
+            caption = Some(rect);
+
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 5), 3, RED)))
+                .unwrap()
+                .label("Series");
+            chart
+                .configure_series_labels()
+                .position(SeriesLabelPosition::UpperLeft)
+                .draw()
+                .unwrap();
+            chart.draw_caption().unwrap();
+        });
+
+        let caption = caption.unwrap();
+        let rects: Vec<_> = calls
+            .rects
+            .iter()
+            .map(|r| Rect::new(r.upper_left, r.bottom_right))
+            .collect();
+        assert_eq!(rects.last(), Some(&caption));
+        for legend in &rects[..rects.len() - 1] {
+            assert!(!legend.intersects(&caption));
+        }
+    }
+
+    #[test]
+    fn test_chart_outlives_builder_styles() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, family, _, _, text| {
+                assert_eq!(text, "Inside");
+                assert_eq!(family, "serif");
+            });
+        });
+        // The chart context doesn't borrow the font family names from the builder
+        let chart = {
+            let family = String::from("serif");
+            ChartBuilder::on(&drawing_area)
+                .caption("Inside", (family.as_str(), 10))
+                .caption_position(CaptionPosition::InsideTopLeft)
+                .label_areas(
+                    LabelAreaConfig::new().style(LabelAreaPosition::Top, (family.as_str(), 10)),
+                )
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap()
+        };
+        chart.draw_caption().unwrap();
+    }
+
     #[test]
     fn test_zero_limit_with_log_scale() {
         let drawing_area = create_mocked_drawing_area(640, 480, |_| {});
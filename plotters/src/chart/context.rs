@@ -2,10 +2,13 @@ use std::borrow::Borrow;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::{SeriesAnno, SeriesLabelStyle};
-use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{CoordMapper, Drawable, PointCollection};
+use super::builder::InsideCaption;
+use crate::chart::{DirectLabelStyle, SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
+use crate::coord::{CoordTranslate, NonFinitePolicy, ReverseCoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, Rect};
+use crate::element::{ColorBar, CoordMapper, Drawable, PointCollection, Rectangle};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::OwnedTextStyle;
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
@@ -28,6 +31,12 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) drawing_area: DrawingArea<DB, CT>,
     pub(crate) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(crate) drawing_area_pos: (i32, i32),
+    pub(crate) label_area_style: [Option<OwnedTextStyle>; 4],
+    pub(crate) caption: Option<InsideCaption>,
+    pub(crate) legend_area: Option<(SeriesLabelPosition, DrawingArea<DB, Shift>)>,
+    pub(crate) colorbar_area: Option<DrawingArea<DB, Shift>>,
+    pub(crate) clip_series: bool,
+    pub(crate) content_drawn: bool,
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -74,16 +83,113 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         SeriesLabelStyle::new(self)
     }
 
+    /**
+    Configures the direct labels, which place the name of each series at the end of its line,
+    instead of a legend box. The labels are drawn by [`DirectLabelStyle::draw()`] after the series
+    are drawn.
+
+    See [`DirectLabelStyle`] for more information and examples.
+    */
+    pub fn configure_direct_labels<'b>(&self) -> DirectLabelStyle<'b, CT::From> {
+        DirectLabelStyle::new(self.drawing_area.dim_in_pixel())
+    }
+
+    /**
+    Draws the caption placed inside the plotting area, does nothing if the caption is placed
+    outside of the plotting area, since it's already drawn when the chart is built.
+
+    Call this after the series are drawn, so that the caption stays on top of them.
+
+    See [`crate::chart::CaptionPosition`] for more information and examples.
+    */
+    pub fn draw_caption(&self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if let Some(caption) = self.caption.as_ref() {
+            let area = self.drawing_area.strip_coord_spec();
+            area.draw(&Rectangle::new(
+                [caption.rect.upper_left(), caption.rect.bottom_right()],
+                caption.background,
+            ))?;
+            area.draw_text(
+                &caption.text,
+                &caption
+                    .style
+                    .get()
+                    .pos(Pos::new(HPos::Center, VPos::Center)),
+                caption.rect.center(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /**
+    Draws the colorbar in the space reserved by [`crate::chart::ChartBuilder::colorbar_area()`].
+    The bar is placed at the left of the space and spans the full height of the plotting area,
+    thus the position and the height of the colorbar are ignored, and its labels are drawn on
+    the right of the bar.
+
+    If no space is reserved, the colorbar is drawn as is, in the pixel coordinates of the
+    plotting area.
+
+    - `colorbar`: The colorbar to draw
+    */
+    pub fn draw_colorbar(
+        &self,
+        mut colorbar: ColorBar<BackendCoord>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = match self.colorbar_area.as_ref() {
+            Some(area) => area,
+            None => return self.drawing_area.strip_coord_spec().draw(&colorbar),
+        };
+        let (_, y_range) = self.drawing_area.get_pixel_range();
+        let (_, base_y) = area.get_base_pixel();
+        let width = colorbar.size().0;
+        colorbar.relocate(
+            (10, y_range.start - base_y),
+            (width, (y_range.end - y_range.start).max(0) as u32),
+        );
+        area.draw(&colorbar)
+    }
+
     /// Get a reference of underlying plotting area
     pub fn plotting_area(&self) -> &DrawingArea<DB, CT> {
         &self.drawing_area
     }
 
+    /// Get the rectangle of the plotting area in the backend pixels, which excludes the margins,
+    /// the caption and the label areas. The layout is fixed when the chart is built, thus the
+    /// rectangle is available before anything is drawn, and it's useful to align the widgets
+    /// outside of Plotters, such as sliders or overlays, with the plot.
+    ///
+    /// ```rust
+    /// use plotters::drawing::Rect;
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("plotting_area_rect.svg", (400, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .margin(10)
+    ///     .x_label_area_size(30)
+    ///     .y_label_area_size(40)
+    ///     .build_cartesian_2d(0..10, 0..10)
+    ///     .unwrap();
+    /// chart.configure_mesh().draw().unwrap();
+    ///
+    /// assert_eq!(chart.plotting_area_rect(), Rect::new((50, 10), (390, 260)));
+    /// ```
+    pub fn plotting_area_rect(&self) -> Rect {
+        self.drawing_area.get_rect().clone()
+    }
+
     /// Cast the reference to a chart context to a reference to underlying coordinate specification.
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
     }
 
+    /// Convert the chart context into its coordinate spec, which is used for the coordinate
+    /// math without drawing, see [`crate::chart::ChartState::coord_spec()`] for details.
+    pub fn into_coord_spec(self) -> CT {
+        self.drawing_area.into_coord_spec()
+    }
+
     // TODO: All draw_series_impl is overly strict about lifetime, because we don't have stable HKT,
     //       what we can ensure is for all lifetime 'b the element reference &'b E is a iterator
     //       of points reference with the same lifetime.
@@ -100,10 +206,55 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
+        // The series is drawn as one batch, which stops at the first non-finite element under
+        // the error policy, and skips the non-finite elements under the skip policy
+        let draw_all = |area: &DrawingArea<DB, CT>| {
+            let coord = area.as_coord_spec();
+            let policy = coord.non_finite_policy();
+            let non_finite = std::cell::Cell::new(false);
+            let elements = series
+                .into_iter()
+                .enumerate()
+                .take_while(|_| policy != NonFinitePolicy::Error || !non_finite.get())
+                .filter(|(_, element)| {
+                    let finite = policy == NonFinitePolicy::ClampToEdge
+                        || element
+                            .borrow()
+                            .point_iter()
+                            .into_iter()
+                            .all(|p| coord.is_finite_coord(p.borrow()));
+                    non_finite.set(non_finite.get() || !finite);
+                    finite
+                });
+            area.draw_indexed_iter(elements)?;
+            if non_finite.get() && policy == NonFinitePolicy::Error {
+                return Err(DrawingAreaErrorKind::NonFiniteValue);
+            }
+            Ok(())
+        };
+        self.content_drawn = true;
+        if self.clip_series {
+            self.drawing_area.with_clip(draw_all)
+        } else {
+            draw_all(&self.drawing_area)
         }
-        Ok(())
+    }
+
+    /**
+    Sets if the series are clipped to the plotting area, which is enabled by default. The
+    elements partially outside of the plotting area, such as the wide lines and the markers of
+    the out-of-range data points, are cut at the edge of the plotting area rather than spilling
+    into the label areas.
+
+    Disable the clipping to draw the series that intentionally extend beyond the plotting area,
+    such as the annotations in the margin.
+
+    - `enabled`: If the series are clipped
+    - **returns**: The chart context, thus the calls can be chained
+    */
+    pub fn set_series_clipping(&mut self, enabled: bool) -> &mut Self {
+        self.clip_series = enabled;
+        self
     }
 
     pub(crate) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
@@ -115,6 +266,14 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     /**
     Draws a data series. A data series in Plotters is abstracted as an iterator of elements.
 
+    The elements with a value that can't be mapped to a meaningful position, such as NaN, are
+    handled according to the [`NonFinitePolicy`] of the coordinate system. The series is clipped
+    to the plotting area, unless it's disabled by [`ChartContext::set_series_clipping()`].
+
+    The series is drawn as one batch, see [`DrawingArea::draw_iter()`]. When an element fails
+    to draw, the error is reported as [`DrawingAreaErrorKind::ElementError`] with the index of the
+    element in the series, and the remaining elements are not drawn.
+
     See [`crate::series::LineSeries`] and [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn draw_series<B, E, R, S>(
@@ -131,6 +290,52 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         self.draw_series_impl(series)?;
         Ok(self.alloc_series_anno())
     }
+
+    /**
+    Draws a data series in the order of a key rather than the order of the iterator. The series
+    yields the elements along with their keys, the elements with smaller keys are drawn first,
+    thus they are covered by the ones with larger keys. The elements with the same key keep their
+    order in the series.
+
+    This is useful when the overlapping elements hide each other, for example, drawing the large
+    markers first so that the small ones stay visible, or drawing the categories of a scatter plot
+    in a deterministic shuffled order so no category is always on the top. Unlike
+    [`ChartContext::draw_series()`], the whole series is collected before drawing.
+
+    - `series`: The data series to draw, as pairs of the drawing order key and the element
+    - **returns**: The series annotation, the same as [`ChartContext::draw_series()`]
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("draw_series_by_key.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(0.0..1.0, 0.0..1.0).unwrap();
+    let points = (0..100).map(|i| ((i as f64 * 0.618) % 1.0, (i as f64 * 0.382) % 1.0, i % 10));
+    chart
+        .draw_series_by_key(points.map(|(x, y, size)| {
+            // The large circles are drawn first
+            let circle = Circle::new((x, y), 2 + size, BLUE.mix(0.5).filled());
+            (std::cmp::Reverse(size), circle)
+        }))
+        .unwrap();
+    ```
+    */
+    pub fn draw_series_by_key<B, E, R, S, K>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = (K, R)>,
+        K: Ord,
+    {
+        let mut elements: Vec<(K, R)> = series.into_iter().collect();
+        // The sort is stable, thus the ties are drawn in the order of the series
+        elements.sort_by(|a, b| a.0.cmp(&b.0));
+        self.draw_series(elements.into_iter().map(|(_, e)| e))
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +391,856 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[test]
+    fn test_separate_axis_line_and_tick_style() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, s, path| {
+                if path[0].0 == path[1].0 && path[0].0 == 19
+                    || path[0].1 == path[1].1 && path[0].1 == 180
+                {
+                    assert_eq!(c, RED.to_rgba());
+                    assert_eq!(s, 2);
+                } else {
+                    assert_eq!(c, BLUE.to_rgba());
+                    assert_eq!(s, 1);
+                }
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_path_call > 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .axis_style(GREEN)
+            .axis_line_style(RED.stroke_width(2))
+            .tick_style(BLUE)
+            .draw()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_minor_ticks_and_direction() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .disable_mesh()
+                .tick_style(BLUE)
+                .x_labels(6)
+                .x_minor_ticks(4)
+                .tick_direction(TickDirection::Inside)
+                .draw()
+                .unwrap();
+        });
+
+        // The ticks point into the plotting area, which ends at y = 180
+        let ticks: Vec<_> = calls
+            .paths
+            .iter()
+            .filter(|p| p.color == BLUE.to_rgba() && p.path[0].0 == p.path[1].0)
+            .map(|p| (p.path[0].1, p.path[1].1))
+            .collect();
+        assert_eq!(ticks.iter().filter(|&&t| t == (175, 180)).count(), 6);
+        assert_eq!(ticks.iter().filter(|&&t| t == (178, 180)).count(), 15);
+        assert_eq!(ticks.len(), 21);
+    }
+
+    #[test]
+    fn test_x_label_overlap() {
+        let draw_labels = |strategy| {
+            let calls = record_draw_calls(200, 200, |drawing_area| {
+                let mut chart = ChartBuilder::on(drawing_area)
+                    .x_label_area_size(40)
+                    .build_cartesian_2d(0..10, 0..10)
+                    .unwrap();
+                chart
+                    .configure_mesh()
+                    .disable_y_axis()
+                    .x_label_formatter(&|x| format!("{} requests", x))
+                    .x_label_overlap(strategy)
+                    .draw()
+                    .unwrap();
+            });
+            calls
+                .texts
+                .into_iter()
+                .map(|t| (t.pos, t.text))
+                .collect::<Vec<_>>()
+        };
+
+        let allowed = draw_labels(TickLabelOverlap::Allow);
+        assert_eq!(allowed.len(), 11);
+
+        let thin = draw_labels(TickLabelOverlap::Thin);
+        assert!(thin.len() > 1 && thin.len() < 11);
+        assert_eq!(thin[0].1, "0 requests");
+
+        let staggered = draw_labels(TickLabelOverlap::Stagger);
+        assert_eq!(staggered.len(), 11);
+        assert!(staggered[1].0 .1 > staggered[0].0 .1);
+        assert_eq!(staggered[2].0 .1, staggered[0].0 .1);
+
+        // The rotated labels are anchored at the same points, with a different anchor position
+        let rotated = draw_labels(TickLabelOverlap::Rotate);
+        assert_eq!(rotated, allowed);
+    }
+
+    #[test]
+    fn test_tick_highlight() {
+        use crate::chart::TickHighlight;
+
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(20)
+                .y_label_area_size(20)
+                .build_cartesian_2d(0..10, -5..5)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .y_max_light_lines(1)
+                .x_tick_highlight(&|x| {
+                    (*x == 4).then(|| {
+                        TickHighlight::new().label_style(("sans-serif", 20).into_font().color(&RED))
+                    })
+                })
+                .y_tick_highlight(&|y| {
+                    (*y == 0).then(|| TickHighlight::new().grid_style(GREEN.stroke_width(3)))
+                })
+                .draw()
+                .unwrap();
+        });
+
+        let texts = &calls.texts;
+        let highlighted: Vec<_> = texts.iter().filter(|t| t.color == RED.to_rgba()).collect();
+        assert_eq!(highlighted.len(), 1);
+        assert_eq!(highlighted[0].text, "4");
+        assert_eq!(highlighted[0].size, 20.0);
+        assert!(texts
+            .iter()
+            .filter(|t| t.text != "4")
+            .all(|t| t.color == BLACK.to_rgba()));
+
+        let lines = &calls.lines;
+        assert_eq!(
+            lines.iter().filter(|l| l.color == GREEN.to_rgba()).count(),
+            1
+        );
+        assert!(lines
+            .iter()
+            .any(|l| l.color == GREEN.to_rgba() && l.width == 3));
+    }
+
+    #[test]
+    fn test_indexed_label_formatter() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(20)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .x_labels(11)
+                .x_label_formatter_indexed(&|idx, count, x| {
+                    assert_eq!(count, 11);
+                    assert_eq!(idx as i32, *x);
+                    match idx {
+                        _ if idx + 1 == count => format!("{} km", x),
+                        _ if idx % 2 == 0 => format!("{}", x),
+                        _ => String::new(),
+                    }
+                })
+                .draw()
+                .unwrap();
+        });
+
+        let texts: Vec<_> = calls
+            .texts
+            .iter()
+            .map(|t| t.text.as_str())
+            .filter(|t| !t.is_empty())
+            .collect();
+        assert_eq!(texts, ["0", "2", "4", "6", "8", "10 km"]);
+    }
+
+    #[test]
+    fn test_script_label_formatter() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(20)
+                .build_cartesian_2d(0..2, 0..10)
+                .unwrap();
+
+            let mut mesh = chart.configure_mesh();
+            mesh.x_labels(3)
+                .y_labels(2)
+                .x_label_style(("sans-serif", 10))
+                .x_label_script_formatter(&|x: &i32| {
+                    ScriptText::new().normal("10").superscript(x.to_string())
+                });
+            let (x_labels, _) = mesh.computed_labels();
+            assert_eq!(x_labels[1].text, "10^{1}");
+            mesh.draw().unwrap();
+        });
+
+        let base = calls.texts.iter().find(|t| t.text == "10").unwrap();
+        let exponent = calls.texts.iter().find(|t| t.text == "1").unwrap();
+        assert_eq!(base.size, 10.0);
+        assert!(exponent.size < base.size);
+        // The exponent follows the base and is raised above it
+        assert!(exponent.pos.0 > base.pos.0);
+        assert!(exponent.pos.1 < base.pos.1);
+    }
+
+    #[test]
+    fn test_multi_line_label() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(40)
+                .build_cartesian_2d(0..2, 0..10)
+                .unwrap();
+            chart
+                .configure_mesh()
+                .x_labels(3)
+                .y_labels(2)
+                .x_label_formatter(&|x| format!("{}\nsecond line", x))
+                .draw()
+                .unwrap();
+        });
+
+        let first = calls.texts.iter().find(|t| t.text == "1").unwrap();
+        let second = calls
+            .texts
+            .iter()
+            .filter(|t| t.text == "second line")
+            .nth(1)
+            .unwrap();
+        // The lines are stacked and centered below the tick
+        assert!(second.pos.1 > first.pos.1);
+        assert!(second.pos.0 < first.pos.0);
+    }
+
+    #[test]
+    fn test_label_exponent_format() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .y_label_area_size(20)
+            .build_cartesian_2d(0..1_000_000, (1e-3f64..1e3).log_scale())
+            .unwrap();
+
+        let mut mesh = chart.configure_mesh();
+        mesh.x_labels(6)
+            .x_label_exponent_format()
+            .y_label_exponent_format();
+        let (x_labels, y_labels) = mesh.computed_labels();
+        let x_texts: Vec<_> = x_labels.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(x_texts[0], "0");
+        assert!(x_texts.contains(&"2×10^{5}"));
+        assert!(x_texts.contains(&"10^{6}"));
+        let y_texts: Vec<_> = y_labels.iter().map(|l| l.text.as_str()).collect();
+        assert!(y_texts.contains(&"10^{-2}"));
+        assert!(y_texts.contains(&"1"));
+        mesh.draw().unwrap();
+    }
+
+    #[test]
+    fn test_series_anno_data() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "CPU 3 (87%)");
+            });
+            // The background and the border of the label area
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_rect(|c, _, f, u, d| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(f);
+                assert_eq!(d.0 - u.0, 8);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 1);
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let anno = chart
+            .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+            .expect("Drawing error")
+            .data(50u32)
+            .label("CPU 3")
+            .label_with_data(|usage: &u32| format!("CPU 3 ({}%)", usage))
+            .legend_with_data(|(x, y), usage: &u32| {
+                Rectangle::new([(x, y - 5), (x + *usage as i32 / 10, y + 5)], RED.filled())
+            });
+
+        assert_eq!(anno.get_data::<i32>(), None);
+        *anno.get_data_mut::<u32>().unwrap() = 87;
+
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_legend_collector() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "Price");
+            });
+            m.check_draw_text(|_, _, _, _, text| {
+                assert_eq!(text, "Volume");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let (upper, lower) = drawing_area.split_vertically(150);
+        let mut price = ChartBuilder::on(&upper)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        let mut volume = ChartBuilder::on(&lower)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        price
+            .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+            .expect("Drawing error")
+            .label("Price");
+        volume
+            .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+            .expect("Drawing error")
+            .label("Volume");
+
+        let mut legend = LegendCollector::new();
+        legend.collect(&mut price).collect(&mut volume);
+
+        // The collected series are no longer drawn by the charts
+        price
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+        legend
+            .configure_legend(&drawing_area)
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_size_legend() {
+        let calls = record_draw_calls(300, 300, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+            chart
+                .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+                .expect("Drawing error")
+                .label("Cities");
+            let legend = SizeLegend::for_range(0.0..100.0, 3, |v| v / 5.0, BLUE).title("Size");
+            chart
+                .configure_series_labels()
+                .size_legend(legend.entry("Huge", 30))
+                .draw()
+                .expect("Drawing error");
+        });
+
+        let texts: Vec<_> = calls.texts.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["Cities", "Size", "50.0", "100.0", "Huge"]);
+        let circles = &calls.circles;
+        assert_eq!(
+            circles.iter().map(|c| c.radius).collect::<Vec<_>>(),
+            [10, 20, 30]
+        );
+        // The bubbles are stacked without overlapping
+        assert!(circles
+            .windows(2)
+            .all(|w| w[1].center.1 - w[0].center.1 >= (w[0].radius + w[1].radius) as i32));
+        assert!(circles.iter().all(|c| c.center.0 == circles[0].center.0));
+    }
+
+    #[test]
+    fn test_legend_area() {
+        let calls = record_draw_calls(400, 300, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .legend_area(SeriesLabelPosition::OutsideRight, 100)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+            assert_eq!(chart.plotting_area().get_pixel_range().0, 0..300);
+            chart
+                .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+                .expect("Drawing error")
+                .label("Series");
+            chart
+                .configure_series_labels()
+                .draw()
+                .expect("Drawing error");
+        });
+        assert_eq!(calls.texts.len(), 1);
+        assert!(calls.texts[0].pos.0 >= 300 && calls.texts[0].pos.1 < 50);
+
+        // The legend is placed inside the plotting area if no space is reserved for it
+        let calls = record_draw_calls(400, 300, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+            chart
+                .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+                .expect("Drawing error")
+                .label("Series");
+            chart
+                .configure_series_labels()
+                .position(SeriesLabelPosition::OutsideBottom)
+                .draw()
+                .expect("Drawing error");
+        });
+        assert!(calls.texts[0].pos.1 > 250 && calls.texts[0].pos.1 < 300);
+    }
+
+    #[cfg(feature = "colormaps")]
+    #[test]
+    fn test_colorbar_area() {
+        let mut y_range = 0..0;
+        let calls = record_draw_calls(400, 300, |drawing_area| {
+            let chart = ChartBuilder::on(drawing_area)
+                .margin(10)
+                .legend_area(SeriesLabelPosition::OutsideRight, 100)
+                .colorbar_area(50)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+            let (x_range, plotting_y_range) = chart.plotting_area().get_pixel_range();
+            assert_eq!(x_range, 10..240);
+            y_range = plotting_y_range;
+            chart
+                .draw_colorbar(ColorBar::continuous(
+                    (0, 0),
+                    (15, 0),
+                    &ViridisRGBA,
+                    0.0..1.0,
+                ))
+                .expect("Drawing error");
+        });
+
+        // The gradient is resampled for each pixel row of the plotting area, and the bar is
+        // placed between the plotting area and the legend area
+        let border = calls.rects.last().unwrap();
+        assert_eq!(calls.rects.len() as i32, y_range.end - y_range.start + 1);
+        assert_eq!(
+            (border.upper_left, border.bottom_right),
+            ((250, y_range.start), (265, y_range.end))
+        );
+    }
+
+    #[test]
+    fn test_legend_values() {
+        let mut values = None;
+        let calls = record_draw_calls(300, 300, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .margin(20)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart");
+            for label in &["CPU", "Memory"] {
+                chart
+                    .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+                    .expect("Drawing error")
+                    .label(*label);
+            }
+            let drawn = chart
+                .configure_series_labels()
+                .background_style(WHITE)
+                .draw_with_values(40)
+                .expect("Drawing error");
+            values = Some(drawn);
+        });
+        let values = values.unwrap();
+
+        let regions = values.regions().to_vec();
+        assert_eq!(regions.len(), 2);
+        assert!(regions.iter().all(|r| r.width() == 40));
+        assert!(regions[0].bottom_right().1 <= regions[1].upper_left().1 + 1);
+        // The value column is inside the legend box, and to the right of the labels
+        let (legend, label) = (&calls.rects[0], &calls.texts[1]);
+        assert!(
+            regions
+                .iter()
+                .all(|r| r.upper_left().0 > label.pos.0
+                    && r.bottom_right().0 <= legend.bottom_right.0)
+        );
+
+        let mut dirty = vec![];
+        let calls = record_draw_calls(300, 300, |drawing_area| {
+            dirty = values
+                .update(drawing_area, &["42%", "1.5GB"])
+                .expect("Drawing error");
+        });
+        assert_eq!(dirty, regions);
+        assert_eq!(
+            calls
+                .texts
+                .iter()
+                .map(|t| t.text.as_str())
+                .collect::<Vec<_>>(),
+            ["42%", "1.5GB"]
+        );
+        assert!(calls
+            .texts
+            .iter()
+            .zip(regions.iter())
+            .all(|(t, r)| r.contains(t.pos)));
+        assert_eq!(calls.rects.len(), 2);
+        assert!(calls.rects.iter().all(|r| r.fill));
+    }
+
+    #[test]
+    fn test_secondary_axis_as_unit_conversion() {
+        let drawing_area = create_mocked_drawing_area(500, 300, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, (1.0..1000.0).log_scale())
+            .expect("Create chart")
+            .secondary_x_as(|c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0);
+        let secondary = chart.borrow_secondary();
+        for &(c, f) in &[(0.0, 32.0), (37.0, 98.6), (100.0, 212.0)] {
+            let primary = chart.backend_coord(&(c, 10.0));
+            let converted = secondary.backend_coord(&(f, 10.0));
+            assert_eq!(primary, converted);
+        }
+        let ticks = secondary.as_coord_spec().x_spec().key_points(5);
+        assert!(ticks
+            .iter()
+            .all(|f| (32.0..=212.0).contains(f) && f % 50.0 == 0.0));
+
+        // The conversion also works on the nonlinear axis
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..100.0, (1.0..1000.0).log_scale())
+            .expect("Create chart")
+            .secondary_y_as(|km| km * 1000.0, |m| m / 1000.0);
+        let secondary = chart.borrow_secondary();
+        for &y in &[1.0, 20.0, 500.0] {
+            let primary = chart.backend_coord(&(50.0, y));
+            let converted = secondary.backend_coord(&(50.0, y * 1000.0));
+            assert_eq!(primary, converted);
+        }
+    }
+
+    #[test]
+    fn test_secondary_x_coord() {
+        let draw_axes = |secondary_axes: bool| {
+            record_draw_calls(400, 300, |drawing_area| {
+                let mut chart = ChartBuilder::on(drawing_area)
+                    .set_all_label_area_size(30)
+                    .build_cartesian_2d(0..28, 0..10)
+                    .expect("Create chart")
+                    .set_secondary_x_coord(0..4);
+
+                let secondary = chart.borrow_secondary();
+                assert_eq!(
+                    chart.backend_coord(&(14, 5)),
+                    secondary.backend_coord(&(2, 5))
+                );
+                assert!(secondary.x_label_area[0].is_some() && secondary.y_label_area[1].is_none());
+                assert!(chart.y_label_area[1].is_some());
+
+                chart
+                    .configure_mesh()
+                    .x_labels(3)
+                    .y_labels(3)
+                    .draw()
+                    .expect("Drawing error");
+                if secondary_axes {
+                    chart
+                        .configure_secondary_axes()
+                        .x_labels(5)
+                        .draw()
+                        .expect("Drawing error");
+                }
+            })
+        };
+        let primary_labels = draw_axes(false).texts.len();
+        let calls = draw_axes(true);
+
+        // The secondary axes only draw the week labels on the top
+        let weeks = &calls.texts[primary_labels..];
+        assert_eq!(
+            weeks.iter().map(|t| t.text.as_str()).collect::<Vec<_>>(),
+            ["0", "1", "2", "3", "4"]
+        );
+        assert!(weeks.iter().all(|t| t.pos.1 < 30));
+    }
+
+    #[test]
+    fn test_broken_axis_marks() {
+        let mut break_y = 0;
+        let calls = record_draw_calls(300, 300, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .set_left_and_bottom_label_area_size(30)
+                .build_cartesian_2d(0..10, (0..10).break_to(1000..1010))
+                .expect("Create chart");
+            chart.configure_mesh().draw().expect("Drawing error");
+
+            break_y = chart.backend_coord(&(0, 500)).1;
+            assert_eq!(
+                break_y,
+                (chart.backend_coord(&(0, 10)).1 + chart.backend_coord(&(0, 1000)).1) / 2
+            );
+        });
+
+        // Two slanted lines crossing the left axis at the break, while the mesh lines and the
+        // axes are either horizontal or vertical
+        let marks: Vec<_> = calls
+            .paths
+            .iter()
+            .map(|p| &p.path)
+            .filter(|p| p.len() == 2 && p[0].0 != p[1].0 && p[0].1 != p[1].1)
+            .collect();
+        assert_eq!(marks.len(), 2);
+        assert!(marks
+            .iter()
+            .all(|m| ((m[0].1 + m[1].1) / 2 - break_y).abs() <= 2 && m[0].0 < 30 && m[1].0 >= 28));
+    }
+
+    #[test]
+    fn test_non_finite_policy() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (49, 50));
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (49, 50));
+            });
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert_eq!(center, (99, 50));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .expect("Create chart");
+
+        let data = [(0.5, 0.5), (f64::NAN, 0.5), (f64::INFINITY, 0.5)];
+        let series = || data.iter().map(|&p| Circle::new(p, 1, RED));
+
+        // Only the finite point is drawn by default
+        chart.draw_series(series()).expect("Drawing error");
+
+        // The elements before the first non-finite value are still drawn
+        chart.set_non_finite_policy(NonFinitePolicy::Error);
+        match chart.draw_series(series()) {
+            Err(DrawingAreaErrorKind::NonFiniteValue) => {}
+            _ => panic!("Expected a non-finite value error"),
+        }
+
+        // The infinity is clamped to the right edge, NaN is placed at the start of the axis
+        assert_eq!(chart.backend_coord(&(f64::NAN, 0.5)), (0, 50));
+        chart.set_non_finite_policy(NonFinitePolicy::ClampToEdge);
+        chart
+            .draw_series(data[2..].iter().map(|&p| Circle::new(p, 1, RED)))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_non_finite_policy_wrapped_axes() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 0));
+        });
+
+        // The nested axis checks the value of its secondary axis
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).nested_coord(|_| 0.0..1.0), 0.0..1.0)
+            .expect("Create chart");
+        chart.set_non_finite_policy(NonFinitePolicy::Error);
+        let point = (NestedValue::Value(1, f64::NAN), 0.5);
+        match chart.draw_series(std::iter::once(Circle::new(point, 1, RED))) {
+            Err(DrawingAreaErrorKind::NonFiniteValue) => {}
+            _ => panic!("Expected a non-finite value error"),
+        }
+
+        // The segmented axis checks the value inside of the segment
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0.0..1.0).step(0.1).into_segmented(), 0.0..1.0)
+            .expect("Create chart");
+        chart.set_non_finite_policy(NonFinitePolicy::Error);
+        let point = (SegmentValue::CenterOf(f64::NAN), 0.5);
+        match chart.draw_series(std::iter::once(Circle::new(point, 1, RED))) {
+            Err(DrawingAreaErrorKind::NonFiniteValue) => {}
+            _ => panic!("Expected a non-finite value error"),
+        }
+    }
+
+    #[test]
+    fn test_draw_series_element_error() {
+        use crate::element::{Drawable, PointCollection};
+        use plotters_backend::{BackendCoord, DrawingErrorKind};
+
+        // The element fails to draw on the right half of the chart
+        struct Marker((f64, f64));
+        impl<'a> PointCollection<'a, (f64, f64)> for &'a Marker {
+            type Point = &'a (f64, f64);
+            type IntoIter = std::iter::Once<&'a (f64, f64)>;
+            fn point_iter(self) -> Self::IntoIter {
+                std::iter::once(&self.0)
+            }
+        }
+        impl<DB: DrawingBackend> Drawable<DB> for Marker {
+            fn draw<I: Iterator<Item = BackendCoord>>(
+                &self,
+                mut pos: I,
+                backend: &mut DB,
+                _: (u32, u32),
+            ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+                let pos = pos.next().unwrap();
+                if pos.0 >= 50 {
+                    return Err(DrawingErrorKind::FontError(Box::new(std::fmt::Error)));
+                }
+                backend.draw_pixel(pos, BLACK.to_backend_color())
+            }
+        }
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_pixel_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .expect("Create chart");
+
+        // The index counts the skipped non-finite elements of the series
+        let series = vec![
+            Marker((0.1, 0.1)),
+            Marker((f64::NAN, 0.2)),
+            Marker((0.2, 0.2)),
+            Marker((0.9, 0.9)),
+            Marker((0.3, 0.3)),
+        ];
+        match chart.draw_series(series) {
+            Err(DrawingAreaErrorKind::ElementError(3, e)) => {
+                assert!(matches!(*e, DrawingAreaErrorKind::BackendError(_)))
+            }
+            _ => panic!("Expected an element error"),
+        }
+    }
+
+    #[test]
+    fn test_axis_pixel_conversion() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .build_cartesian_2d(0..10, 0.0..1.0)
+            .expect("Create chart");
+
+        assert_eq!(chart.x_pixel_range(), 10..189);
+        assert_eq!(chart.y_pixel_range(), 10..89);
+        assert_eq!(chart.x_to_pixel(&0), 10);
+        assert_eq!(chart.y_to_pixel(&0.0), 89);
+        assert_eq!(
+            (chart.x_to_pixel(&5), chart.y_to_pixel(&0.5)),
+            chart.backend_coord(&(5, 0.5))
+        );
+    }
+
+    #[test]
+    fn test_plotting_area_rect() {
+        let drawing_area = create_mocked_drawing_area(200, 100, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .caption("Title", ("sans-serif", 10))
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .right_y_label_area_size(15)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        let rect = chart.plotting_area_rect();
+        chart.configure_mesh().draw().expect("Drawing error");
+        assert_eq!(chart.plotting_area_rect(), rect);
+        assert_eq!(rect.upper_left().0, 40);
+        assert_eq!(rect.bottom_right(), (175, 70));
+        assert!(rect.upper_left().1 > 10);
+        assert_eq!(chart.x_pixel_range().start, rect.upper_left().0);
+    }
+
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_chart_background_image() {
+        let mut buffer = vec![0; 100 * 100 * 3];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+                .expect("Create chart");
+
+            // Left half red and right half blue, twice as wide as the chart
+            let image =
+                BitMapElement::with_owned_buffer((0, 0), (2, 1), vec![255, 0, 0, 0, 0, 255])
+                    .unwrap();
+            chart
+                .set_background_image(&image, (-10.0..10.0, 0.0..10.0))
+                .expect("Draw background");
+        }
+
+        // Only the blue half is visible and nothing is drawn outside the plotting area
+        assert_eq!(&buffer[0..3], &[0, 0, 255]);
+        assert_eq!(&buffer[(99 * 100 + 99) * 3..], &[0, 0, 255]);
+        assert!(buffer.chunks(3).all(|p| p == [0, 0, 255]));
+    }
+
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_chart_background_image_under_mesh() {
+        let mut buffer = vec![0; 100 * 100 * 3];
+        let x = {
+            let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+                .expect("Create chart");
+
+            let image = BitMapElement::with_owned_buffer((0, 0), (1, 1), vec![0, 0, 255]).unwrap();
+            chart
+                .set_background_image(&image, (0.0..10.0, 0.0..10.0))
+                .expect("Draw background");
+            chart
+                .configure_mesh()
+                .disable_y_mesh()
+                .max_light_lines(0)
+                .bold_line_style(RED)
+                .draw()
+                .expect("Draw mesh");
+
+            // The image can't be placed under the mesh which is already drawn
+            match chart.set_background_image(&image, (0.0..10.0, 0.0..10.0)) {
+                Err(DrawingAreaErrorKind::LayoutError) => {}
+                _ => panic!("Expected a layout error"),
+            }
+            chart.x_to_pixel(&5.0) as usize
+        };
+
+        // The mesh line at x = 5 is on top of the image
+        let pixel = |x: usize, y: usize| &buffer[(y * 100 + x) * 3..(y * 100 + x) * 3 + 3];
+        assert_eq!(pixel(x, 50), &[255, 0, 0]);
+        assert_eq!(pixel(x + 5, 50), &[0, 0, 255]);
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -218,4 +1273,219 @@ mod test {
             .draw_series(std::iter::once(Circle::new((5, 5, 5), 5, RED)))
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_chart_context_3d_log_scale() {
+        let calls = record_draw_calls(300, 300, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_3d(
+                    (1.0..1000.0).log_scale(),
+                    (1..100).log_scale(),
+                    (0.01..10.0).log_scale(),
+                )
+                .expect("Create chart");
+            chart
+                .configure_axes()
+                .x_labels(4)
+                .y_formatter(&|y| format!("y={}", y))
+                .draw()
+                .expect("Drawing axes");
+            chart
+                .draw_series(LineSeries::new(
+                    (0..=10).map(|i| (10f64.powi(i % 4), 10, 0.01 * 10f64.powi(i % 4))),
+                    RED,
+                ))
+                .expect("Drawing error");
+        });
+
+        // Each axis is labeled at the powers of 10, including the exact bounds of the range
+        let texts: Vec<_> = calls.texts.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "1.0", "10.0", "100.0", "1000.0", "y=1", "y=10", "y=100", "0.01", "0.1", "1.0",
+                "10.0"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_draw_series_by_key() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            let circles = vec![(3, RED), (5, RED), (3, BLUE), (1, BLUE)];
+            chart
+                .draw_series_by_key(
+                    circles
+                        .into_iter()
+                        .map(|(r, color)| (std::cmp::Reverse(r), Circle::new((5, 5), r, color))),
+                )
+                .unwrap();
+        });
+
+        // The larger circles first, the equal ones in the original order
+        assert_eq!(
+            calls
+                .circles
+                .iter()
+                .map(|c| (c.radius, c.color))
+                .collect::<Vec<_>>(),
+            vec![
+                (5, RED.to_rgba()),
+                (3, RED.to_rgba()),
+                (3, BLUE.to_rgba()),
+                (1, BLUE.to_rgba())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_secondary_mesh() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap()
+                .set_secondary_coord(0..10, 0..25);
+            chart
+                .configure_secondary_axes()
+                .enable_y_mesh()
+                .bold_line_style(RED)
+                .light_line_style(TRANSPARENT)
+                .draw()
+                .unwrap();
+        });
+
+        // Only the horizontal grid lines, at 0, 5, .., 25 of the secondary Y axis
+        let lines: Vec<_> = calls
+            .lines
+            .iter()
+            .filter(|l| l.color == RED.to_rgba())
+            .collect();
+        assert_eq!(lines.len(), 6);
+        for (idx, line) in lines.iter().enumerate() {
+            assert_eq!((line.from.0, line.to.0), (0, 200));
+            assert_eq!(
+                (line.from.1, line.to.1),
+                (200 - 40 * idx as i32, 200 - 40 * idx as i32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_computed_labels() {
+        let mut expected = vec![];
+        let calls = record_draw_calls(300, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .set_left_and_bottom_label_area_size(30)
+                .build_cartesian_2d(0..100, 0.0..1.0)
+                .unwrap();
+            let mut mesh = chart.configure_mesh();
+            mesh.x_labels(5)
+                .x_label_formatter(&|x| format!("{}s", x))
+                .disable_y_axis();
+
+            let (x_labels, y_labels) = mesh.computed_labels();
+            assert!(y_labels.is_empty());
+            let values: Vec<_> = x_labels.iter().map(|l| l.value).collect();
+            assert_eq!(values, [0, 50, 100]);
+            assert_eq!(x_labels[1].text, "50s");
+            assert!(x_labels.windows(2).all(|w| w[0].pos < w[1].pos));
+
+            mesh.draw().unwrap();
+            assert!(mesh.computed_labels().0.is_empty());
+            expected = x_labels.into_iter().map(|l| l.text).collect();
+        });
+
+        let texts: Vec<_> = calls.texts.into_iter().map(|t| t.text).collect();
+        assert_eq!(texts, expected);
+    }
+
+    #[test]
+    fn test_computed_labels_thinned() {
+        let mut expected = vec![];
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(40)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            let mut mesh = chart.configure_mesh();
+            mesh.disable_y_axis()
+                .x_label_formatter(&|x| format!("{} requests", x))
+                .x_label_overlap(TickLabelOverlap::Thin);
+
+            let (x_labels, _) = mesh.computed_labels();
+            assert!(x_labels.len() > 1 && x_labels.len() < 11);
+            mesh.draw().unwrap();
+            expected = x_labels.into_iter().map(|l| l.text).collect();
+        });
+
+        let texts: Vec<_> = calls.texts.into_iter().map(|t| t.text).collect();
+        assert_eq!(texts, expected);
+    }
+
+    #[test]
+    fn test_secondary_x_label_overlap() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .x_label_area_size(40)
+                .top_x_label_area_size(40)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap()
+                .set_secondary_coord(0..10, 0..10);
+            chart
+                .configure_secondary_axes()
+                .x_label_formatter(&|x| format!("{} requests", x))
+                .x_label_overlap(TickLabelOverlap::Thin)
+                .draw()
+                .unwrap();
+        });
+
+        let x_texts = calls
+            .texts
+            .iter()
+            .filter(|t| t.text.ends_with("requests"))
+            .count();
+        assert!(x_texts > 1 && x_texts < 11);
+    }
+
+    #[cfg(feature = "bitmap_backend")]
+    #[test]
+    fn test_series_clipping() {
+        // Draws a wide line through the out-of-range points, returns the number of the pixels
+        // drawn in the left label area and in the plotting area
+        let draw = |clip: bool| {
+            let mut buffer = vec![0; 100 * 100 * 3];
+            {
+                let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+                let mut chart = ChartBuilder::on(&root)
+                    .y_label_area_size(20)
+                    .build_cartesian_2d(0..10, 0..10)
+                    .unwrap();
+                chart.set_series_clipping(clip);
+                chart
+                    .draw_series(LineSeries::new(
+                        vec![(-5, 5), (15, 5)],
+                        WHITE.stroke_width(5),
+                    ))
+                    .unwrap();
+                chart
+                    .draw_series(std::iter::once(Circle::new((0, 0), 8, WHITE.filled())))
+                    .unwrap();
+            }
+            let count = |x: std::ops::Range<usize>| {
+                x.flat_map(|x| (0..100).map(move |y| (x, y)))
+                    .filter(|(x, y)| buffer[(y * 100 + x) * 3] != 0)
+                    .count()
+            };
+            (count(0..20), count(20..100))
+        };
+
+        let (outside, inside) = draw(true);
+        assert_eq!(outside, 0);
+        assert!(inside > 0);
+        let (outside, _) = draw(false);
+        assert!(outside > 0);
+    }
 }
@@ -1,8 +1,9 @@
+use std::borrow::Cow;
 use std::ops::Range;
 
 use plotters_backend::DrawingBackend;
 
-use crate::chart::ChartContext;
+use crate::chart::{ChartContext, TickDirection, TickHighlight, TickLabelOverlap};
 use crate::coord::{
     cartesian::{Cartesian2d, MeshLine},
     ranged1d::{KeyPointHint, Ranged},
@@ -12,22 +13,102 @@ use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::element::PathElement;
 use crate::style::{
     text_anchor::{HPos, Pos, VPos},
-    FontTransform, ShapeStyle, TextStyle,
+    FontTransform, OwnedTextStyle, ScriptText, ShapeStyle, TextStyle,
 };
 
+// The position, the text and the overridden style of the axis labels
+type AxisLabels<'s> = Vec<(i32, ScriptText, Option<TextStyle<'s>>)>;
+
+// The direction of the tick marks, the positions of the minor ticks on both axes, and how the
+// overlapping X labels are avoided
+pub(crate) struct TickMarks {
+    pub(crate) direction: TickDirection,
+    pub(crate) x_label_overlap: TickLabelOverlap,
+    pub(crate) x_minor: Vec<i32>,
+    pub(crate) y_minor: Vec<i32>,
+    pub(crate) minor_tick_size: Option<i32>,
+}
+
+// The X labels hidden, moved away from the axis or rotated to avoid the overlap, which is shared
+// by the drawing of the mesh and `MeshStyle::computed_labels`
+pub(crate) struct XLabelOverlap {
+    pub(crate) hidden: Vec<bool>,
+    pub(crate) staggered: Vec<bool>,
+    pub(crate) rotate: bool,
+    pub(crate) stagger_dist: i32,
+}
+
+impl XLabelOverlap {
+    fn new(count: usize) -> Self {
+        Self {
+            hidden: vec![false; count],
+            staggered: vec![false; count],
+            rotate: false,
+            stagger_dist: 0,
+        }
+    }
+
+    // The extents are the index, the position along the axis, the width and the height of the
+    // labels in the axis range, and `label_dist` is the distance from the axis to the labels
+    pub(crate) fn resolve(
+        strategy: TickLabelOverlap,
+        count: usize,
+        mut extents: Vec<(usize, i32, i32, i32)>,
+        label_dist: i32,
+    ) -> Self {
+        let mut result = Self::new(count);
+        extents.sort_by_key(|&(_, p, _, _)| p);
+
+        let overlaps = |step: usize| {
+            let mut shown = extents.iter().step_by(step);
+            let mut prev = shown.next();
+            shown.any(|cur| {
+                let (_, p0, w0, _) = prev.replace(cur).unwrap();
+                (cur.1 - p0) * 2 < w0 + cur.2 + label_dist * 2
+            })
+        };
+
+        if !overlaps(1) {
+            return result;
+        }
+
+        match strategy {
+            TickLabelOverlap::Thin => {
+                let step = (2..extents.len().max(2))
+                    .find(|&step| !overlaps(step))
+                    .unwrap_or_else(|| extents.len().max(1));
+                for (n, (idx, ..)) in extents.iter().enumerate() {
+                    result.hidden[*idx] = n % step != 0;
+                }
+            }
+            TickLabelOverlap::Rotate => result.rotate = true,
+            TickLabelOverlap::Stagger => {
+                for (n, (idx, ..)) in extents.iter().enumerate() {
+                    result.staggered[*idx] = n % 2 == 1;
+                }
+                result.stagger_dist = extents.iter().map(|e| e.3).max().unwrap_or(0);
+            }
+            TickLabelOverlap::Allow => {}
+        }
+        result
+    }
+}
+
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
     #[allow(clippy::type_complexity)]
-    fn draw_mesh_lines<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+    fn draw_mesh_lines<'s, FmtLabel, Highlight, YH: KeyPointHint, XH: KeyPointHint>(
         &mut self,
         (r, c): (YH, XH),
         (x_mesh, y_mesh): (bool, bool),
         mesh_line_style: &ShapeStyle,
         mut fmt_label: FmtLabel,
-    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+        mut highlight: Highlight,
+    ) -> Result<(AxisLabels<'s>, AxisLabels<'s>), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<ScriptText>,
+        Highlight: FnMut(&MeshLine<X, Y>) -> Option<TickHighlight<'s>>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
@@ -35,22 +116,26 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let yr = self.drawing_area.as_coord_spec().y_spec();
         self.drawing_area.draw_mesh(
             |b, l| {
+                let TickHighlight {
+                    grid_style,
+                    label_style,
+                } = highlight(&l).unwrap_or_default();
                 let draw = match l {
                     MeshLine::XMesh((x, _), _, _) => {
                         if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            x_labels.push((x, label_text));
+                            x_labels.push((x, label_text, label_style));
                         }
                         x_mesh
                     }
                     MeshLine::YMesh((_, y), _, _) => {
                         if let Some(label_text) = fmt_label(xr, yr, &l) {
-                            y_labels.push((y, label_text));
+                            y_labels.push((y, label_text, label_style));
                         }
                         y_mesh
                     }
                 };
                 if draw {
-                    l.draw(b, mesh_line_style)
+                    l.draw(b, grid_style.as_ref().unwrap_or(mesh_line_style))
                 } else {
                     Ok(())
                 }
@@ -132,12 +217,14 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         &self,
         area: Option<&DrawingArea<DB, Shift>>,
         axis_style: Option<&ShapeStyle>,
-        labels: &[(i32, String)],
+        tick_style: Option<&ShapeStyle>,
+        labels: &[(i32, ScriptText, Option<TextStyle>)],
         label_style: &TextStyle,
         label_offset: i32,
         orientation: (i16, i16),
         axis_desc: Option<(&str, &TextStyle)>,
         tick_size: i32,
+        ticks: &TickMarks,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -161,12 +248,13 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
          * So that we are able decide if we should apply right alignment for the text. */
         let label_width: Vec<_> = labels
             .iter()
-            .map(|(_, text)| {
+            .map(|(_, text, style)| {
                 if orientation.0 > 0 && orientation.1 == 0 && tick_size >= 0 {
-                    self.drawing_area
-                        .estimate_text_size(text, label_style)
-                        .map(|(w, _)| w)
-                        .unwrap_or(0) as i32
+                    text.layout(style.as_ref().unwrap_or(label_style), |text, style| {
+                        self.drawing_area.estimate_text_size(text, style)
+                    })
+                    .map(|((w, _), _)| w)
+                    .unwrap_or(0) as i32
                 } else {
                     // Don't ever do the layout estimationfor the drawing area that is either not
                     // the right one or the tick mark is inward.
@@ -183,14 +271,76 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             .unwrap_or(&min_width);
         let right_align_width = (min_width * 2).min(max_width);
 
+        let in_axis_range = |p: i32| {
+            let rp = if orientation.0 == 0 { p - x0 } else { p - y0 };
+            axis_range.start.min(axis_range.end) <= rp && rp <= axis_range.end.max(axis_range.start)
+        };
+
+        /* The tick marks start from the axis, which is on the side of the label area next to the
+         * plotting area, or on the other side if the labels are inward. The sign is the direction
+         * from the axis toward the labels. */
+        let sign = i32::from(orientation.0 + orientation.1) * tick_size.signum();
+        let base = match (sign > 0, orientation.0 == 0) {
+            (true, _) => 0,
+            (false, true) => th as i32 - 1,
+            (false, false) => tw as i32 - 1,
+        };
+        /* The tick marks pointing inside may go beyond the label area, thus they are drawn in
+         * the backend coordinate, which isn't clipped to the label area */
+        let (ax, ay) = area.get_base_pixel();
+        let draw_tick = |p: i32, size: i32, style: &ShapeStyle| {
+            let (from, to) = match ticks.direction {
+                TickDirection::Outside => (0, size),
+                TickDirection::Inside => (-size, 0),
+                TickDirection::Both => (-size, size),
+            };
+            let (a, b) = (base + sign * from, base + sign * to);
+            let (a, b) = (a.min(b), a.max(b));
+            let line = if orientation.0 == 0 {
+                [(ax + p - x0, ay + a), (ax + p - x0, ay + b)]
+            } else {
+                [(ax + a, ay + p - y0), (ax + b, ay + p - y0)]
+            };
+            area.backend_ops(|b| b.draw_path(line.iter().copied(), style))
+        };
+
+        /* The X labels next to each other may overlap when they are long or the chart is narrow,
+         * which is avoided by hiding some of them, rotating all of them or moving every other
+         * label away from the axis. */
+        let mut overlap = XLabelOverlap::new(labels.len());
+        if orientation.0 == 0 && ticks.x_label_overlap != TickLabelOverlap::Allow {
+            let mut extents = vec![];
+            for (idx, (p, text, style)) in labels.iter().enumerate() {
+                let style = style.as_ref().unwrap_or(label_style);
+                let ((w, h), _) = text.layout(style, |text, style| {
+                    self.drawing_area.estimate_text_size(text, style)
+                })?;
+                if in_axis_range(*p) && w > 0 {
+                    extents.push((idx, *p, w as i32, h as i32));
+                }
+            }
+            overlap =
+                XLabelOverlap::resolve(ticks.x_label_overlap, labels.len(), extents, label_dist);
+        }
+
+        /* The labels are collected and drawn as a batch after the tick marks */
+        let mut label_texts = vec![];
+
         /* Then we need to draw the tick mark and the label */
-        for ((p, t), w) in labels.iter().zip(label_width.into_iter()) {
+        for (idx, ((p, t, style), w)) in labels.iter().zip(label_width.into_iter()).enumerate() {
             /* Make sure we are actually in the visible range */
-            let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
+            if !in_axis_range(*p) {
+                continue;
+            }
+
+            if tick_size != 0 {
+                if let Some(style) = tick_style {
+                    draw_tick(*p, tick_size.abs(), style)?;
+                }
+            }
 
-            if rp < axis_range.start.min(axis_range.end)
-                || axis_range.end.max(axis_range.start) < rp
-            {
+            /* The tick mark of a hidden label is still drawn */
+            if overlap.hidden[idx] {
                 continue;
             }
 
@@ -246,45 +396,65 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             } else {
                 (cx, cy + label_offset)
             };
+            let text_y = if overlap.staggered[idx] {
+                text_y + sign * overlap.stagger_dist
+            } else {
+                text_y
+            };
 
-            let label_style = &label_style.pos(Pos::new(h_pos, v_pos));
-            area.draw_text(t, label_style, (text_x, text_y))?;
+            let mut style = Cow::Borrowed(style.as_ref().unwrap_or(label_style));
+            let (h_pos, v_pos) = if overlap.rotate {
+                // The rotated labels read downward and start from the side next to the axis
+                style = Cow::Owned(style.transform(FontTransform::Rotate90));
+                let h_pos = if sign > 0 { HPos::Left } else { HPos::Right };
+                (h_pos, VPos::Center)
+            } else {
+                (h_pos, v_pos)
+            };
 
-            if tick_size != 0 {
-                if let Some(style) = axis_style {
-                    let xmax = tw as i32 - 1;
-                    let ymax = th as i32 - 1;
-                    let (kx0, ky0, kx1, ky1) = if tick_size > 0 {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, tick_size, *p - y0),
-                            (dx, dy) if dx < 0 && dy == 0 => {
-                                (xmax - tick_size, *p - y0, xmax, *p - y0)
-                            }
-                            (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, tick_size),
-                            (dx, dy) if dx == 0 && dy < 0 => {
-                                (*p - x0, ymax - tick_size, *p - x0, ymax)
-                            }
-                            _ => panic!("Bug: Invalid orientation specification"),
-                        }
-                    } else {
-                        match orientation {
-                            (dx, dy) if dx > 0 && dy == 0 => {
-                                (xmax, *p - y0, xmax + tick_size, *p - y0)
-                            }
-                            (dx, dy) if dx < 0 && dy == 0 => (0, *p - y0, -tick_size, *p - y0),
-                            (dx, dy) if dx == 0 && dy > 0 => {
-                                (*p - x0, ymax, *p - x0, ymax + tick_size)
-                            }
-                            (dx, dy) if dx == 0 && dy < 0 => (*p - x0, 0, *p - x0, -tick_size),
-                            _ => panic!("Bug: Invalid orientation specification"),
-                        }
-                    };
-                    let line = PathElement::new(vec![(kx0, ky0), (kx1, ky1)], *style);
-                    area.draw(&line)?;
+            if let Some(text) = t.plain_text(&style) {
+                label_texts.push((text, (text_x, text_y), style.pos(Pos::new(h_pos, v_pos))));
+            } else {
+                // The runs with scripts are placed by hand, since they don't share the anchor
+                let ((w, h), runs) = t.layout(&style, |text, style| {
+                    self.drawing_area.estimate_text_size(text, style)
+                })?;
+                let dx = match h_pos {
+                    HPos::Left => 0,
+                    HPos::Center => w as i32 / 2,
+                    HPos::Right => w as i32,
+                };
+                let dy = match v_pos {
+                    VPos::Top => 0,
+                    VPos::Center => h as i32 / 2,
+                    VPos::Bottom => h as i32,
+                };
+                for (text, style, (x, y)) in runs {
+                    label_texts.push((text, (text_x - dx + x, text_y - dy + y), style));
+                }
+            }
+        }
+
+        if tick_size != 0 {
+            if let Some(style) = tick_style {
+                let minor = if orientation.0 == 0 {
+                    &ticks.x_minor
+                } else {
+                    &ticks.y_minor
+                };
+                let size = ticks.minor_tick_size.unwrap_or(tick_size.abs() / 2);
+                for p in minor.iter().filter(|p| in_axis_range(**p)) {
+                    draw_tick(*p, size, style)?;
                 }
             }
         }
 
+        let label_texts: Vec<_> = label_texts
+            .iter()
+            .map(|(text, pos, style): &(Cow<str>, _, _)| (&**text, *pos, style))
+            .collect();
+        area.draw_texts(&label_texts)?;
+
         if let Some((text, style)) = axis_desc {
             let actual_style = if orientation.0 == 0 {
                 style.clone()
@@ -313,14 +483,84 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         Ok(())
     }
 
+    /// Draw the squiggle which marks that the axis doesn't start at zero. The mark crosses the
+    /// Y axis on the left side, or the X axis on the bottom side, near the start of the axis.
+    pub(crate) fn draw_truncation_mark(
+        &self,
+        y_axis: bool,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        let (gap, size) = (10, 4);
+
+        // The axes are drawn right outside of the plotting area
+        let path = if y_axis {
+            let (x, y) = (x_range.start - 1, y_range.end - 1 - gap);
+            [
+                (x, y + size * 2),
+                (x - size, y + size),
+                (x + size, y - size),
+                (x, y - size * 2),
+            ]
+        } else {
+            let (x, y) = (x_range.start + gap, y_range.end);
+            [
+                (x - size * 2, y),
+                (x - size, y - size),
+                (x + size, y + size),
+                (x + size * 2, y),
+            ]
+        };
+
+        self.drawing_area
+            .backend_ops(|b| b.draw_path(path.iter().copied(), style))
+    }
+
+    /// Draw the break marks, which are two slanted lines crossing the X axis on the bottom side
+    /// or the Y axis on the left side, at each break of a discontinuous axis.
+    pub(crate) fn draw_axis_break_marks(
+        &self,
+        y_axis: bool,
+        style: &ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let spec = self.drawing_area.as_coord_spec();
+        let (x_limit, y_limit) = spec.pixel_range();
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        let size = 4;
+
+        let breaks = if y_axis {
+            spec.y_spec().axis_breaks((y_limit.start, y_limit.end))
+        } else {
+            spec.x_spec().axis_breaks((x_limit.start, x_limit.end))
+        };
+
+        for pos in breaks {
+            for offset in [-2, 2].iter() {
+                let p = pos + offset;
+                // The axes are drawn right outside of the plotting area
+                let path = if y_axis {
+                    let x = x_range.start - 1;
+                    [(x - size, p + size / 2), (x + size, p - size / 2)]
+                } else {
+                    let y = y_range.end;
+                    [(p - size / 2, y + size), (p + size / 2, y - size)]
+                };
+                self.drawing_area
+                    .backend_ops(|b| b.draw_path(path.iter().copied(), style))?;
+            }
+        }
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
-    pub(crate) fn draw_mesh<FmtLabel, YH: KeyPointHint, XH: KeyPointHint>(
+    pub(crate) fn draw_mesh<'s, FmtLabel, Highlight, YH: KeyPointHint, XH: KeyPointHint>(
         &mut self,
         (r, c): (YH, XH),
         mesh_line_style: &ShapeStyle,
         x_label_style: &TextStyle,
         y_label_style: &TextStyle,
         fmt_label: FmtLabel,
+        highlight: Highlight,
         x_mesh: bool,
         y_mesh: bool,
         x_label_offset: i32,
@@ -328,39 +568,57 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         x_axis: bool,
         y_axis: bool,
         axis_style: &ShapeStyle,
+        tick_style: &ShapeStyle,
         axis_desc_style: &TextStyle,
         x_desc: Option<String>,
         y_desc: Option<String>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        ticks: &TickMarks,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<ScriptText>,
+        Highlight: FnMut(&MeshLine<X, Y>) -> Option<TickHighlight<'s>>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        self.content_drawn = true;
+        let (x_labels, y_labels) = self.draw_mesh_lines(
+            (r, c),
+            (x_mesh, y_mesh),
+            mesh_line_style,
+            fmt_label,
+            highlight,
+        )?;
 
         for idx in 0..2 {
+            let x_area_style = self.label_area_style[idx].as_ref().map(OwnedTextStyle::get);
+            let y_area_style = self.label_area_style[2 + idx]
+                .as_ref()
+                .map(OwnedTextStyle::get);
+
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
                 if x_axis { Some(axis_style) } else { None },
+                if x_axis { Some(tick_style) } else { None },
                 &x_labels[..],
-                x_label_style,
+                x_area_style.as_ref().unwrap_or(x_label_style),
                 x_label_offset,
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 x_tick_size[idx],
+                ticks,
             )?;
 
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
                 if y_axis { Some(axis_style) } else { None },
+                if y_axis { Some(tick_style) } else { None },
                 &y_labels[..],
-                y_label_style,
+                y_area_style.as_ref().unwrap_or(y_label_style),
                 y_label_offset,
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 y_tick_size[idx],
+                ticks,
             )?;
         }
 
@@ -5,13 +5,48 @@ use plotters_backend::{BackendCoord, DrawingBackend};
 use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle};
 use crate::coord::{
     cartesian::Cartesian2d,
-    ranged1d::{AsRangedCoord, Ranged, ValueFormatter},
-    Shift,
+    combinators::RangedFn,
+    ranged1d::{AsRangedCoord, KeyPointHint, Ranged, ValueFormatter},
+    types::RangedCoordf64,
+    NonFinitePolicy, Shift,
 };
 use crate::drawing::DrawingArea;
+#[cfg(feature = "bitmap_backend")]
+use crate::{
+    drawing::DrawingAreaErrorKind,
+    element::{BitMapElement, ClipPath, Drawable, PointCollection},
+};
 
 mod draw_impl;
 
+pub(crate) use draw_impl::{TickMarks, XLabelOverlap};
+
+// The pixel resolution used to map the converted axis through the primary coordinate spec
+const CONVERTED_AXIS_RESOLUTION: i32 = 1 << 24;
+
+/// Make the coordinate spec of an axis which is a pure function of the primary axis
+fn converted_axis<'a, R, F, I>(primary: &R, forward: F, inverse: I) -> RangedFn<'a, f64>
+where
+    R: Ranged<ValueType = f64> + Clone + 'a,
+    F: Fn(f64) -> f64,
+    I: Fn(f64) -> f64 + 'a,
+{
+    let Range { start, end } = primary.range();
+    let primary = primary.clone();
+    RangedFn::new(
+        forward(start)..forward(end),
+        move |value: &f64| {
+            let limit = (0, CONVERTED_AXIS_RESOLUTION);
+            f64::from(primary.map(&inverse(*value), limit))
+        },
+        |range: &Range<f64>, max_points| {
+            let linear: RangedCoordf64 =
+                (range.start.min(range.end)..range.start.max(range.end)).into();
+            linear.key_points(max_points.bold_points())
+        },
+    )
+}
+
 impl<'a, DB, XT, YT, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
 where
     DB: DrawingBackend,
@@ -57,11 +92,117 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         self.drawing_area.get_y_range()
     }
 
+    /// Set how the values without a meaningful position (such as NaN) are handled when a series
+    /// is drawn, the default is [NonFinitePolicy::Skip](../coord/enum.NonFinitePolicy.html).
+    ///
+    /// - `policy`: The policy to use
+    /// - **returns**: The chart context, thus the calls can be chained
+    pub fn set_non_finite_policy(&mut self, policy: NonFinitePolicy) -> &mut Self {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_non_finite_policy(policy);
+        self
+    }
+
+    /// Get the horizontal range of the backend coordinate where the X axis is drawn, from the
+    /// leftmost pixel to the rightmost pixel of the axis
+    pub fn x_pixel_range(&self) -> Range<i32> {
+        self.drawing_area.get_x_axis_pixel_range()
+    }
+
+    /// Get the vertical range of the backend coordinate where the Y axis is drawn, from the
+    /// topmost pixel to the bottommost pixel of the axis
+    pub fn y_pixel_range(&self) -> Range<i32> {
+        self.drawing_area.get_y_axis_pixel_range()
+    }
+
+    /// Maps a value on the X axis to the horizontal backend coordinate, regardless of the Y axis.
+    /// This is useful to position custom decorations, for example, a bracket between two
+    /// categories of the X axis.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("x_to_pixel.svg", (400, 300)).into_drawing_area();
+    /// let chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d((0..4).into_segmented(), 0.0..10.0)
+    ///     .unwrap();
+    ///
+    /// let left = chart.x_to_pixel(&SegmentValue::CenterOf(1));
+    /// let right = chart.x_to_pixel(&SegmentValue::CenterOf(2));
+    /// let y = chart.y_to_pixel(&9.0);
+    /// root.draw(&PathElement::new(vec![(left, y + 5), (left, y), (right, y), (right, y + 5)], &BLACK))
+    ///     .unwrap();
+    /// ```
+    pub fn x_to_pixel(&self, x: &X::ValueType) -> i32 {
+        self.drawing_area.as_coord_spec().map_x(x)
+    }
+
+    /// Maps a value on the Y axis to the vertical backend coordinate, regardless of the X axis.
+    /// See [x_to_pixel](#method.x_to_pixel) for details.
+    pub fn y_to_pixel(&self, y: &Y::ValueType) -> i32 {
+        self.drawing_area.as_coord_spec().map_y(y)
+    }
+
     /// Maps the coordinate to the backend coordinate. This is typically used
     /// with an interactive chart.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
+
+    /// Draw a bitmap as the background of the plotting area, stretched to the given rectangle
+    /// in the data coordinate. The part of the image outside of the plotting area is clipped.
+    ///
+    /// The image is drawn immediately, thus this must be called before the mesh and the series
+    /// are drawn, so that they are placed on top of the image. Otherwise, the image isn't drawn
+    /// and a [DrawingAreaErrorKind::LayoutError](../drawing/enum.DrawingAreaErrorKind.html)
+    /// is returned. The position of the element is ignored and the element's interpolation
+    /// method is used for scaling.
+    ///
+    /// - `image`: The bitmap to draw
+    /// - `x_range`, `y_range`: The rectangle the image is stretched to in the data coordinate
+    #[cfg(feature = "bitmap_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
+    pub fn set_background_image<Coord>(
+        &mut self,
+        image: &BitMapElement<'_, Coord>,
+        (x_range, y_range): (Range<X::ValueType>, Range<Y::ValueType>),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        // The image would cover the mesh or the series which are already drawn
+        if self.content_drawn {
+            return Err(DrawingAreaErrorKind::LayoutError);
+        }
+        let (ax0, ay0) = self
+            .drawing_area
+            .map_coordinate(&(x_range.start, y_range.end));
+        let (ax1, ay1) = self
+            .drawing_area
+            .map_coordinate(&(x_range.end, y_range.start));
+        // The chart maps the data range to an inclusive pixel range
+        let (x0, x1) = (ax0.min(ax1), ax0.max(ax1) + 1);
+        let (y0, y1) = (ay0.min(ay1), ay0.max(ay1) + 1);
+
+        // The image is scaled by the backend and clipped to the plotting area
+        let (px, py) = self.drawing_area.get_pixel_range();
+        let (upper_left, bottom_right) = ((px.start, py.start), (px.end - 1, py.end - 1));
+        let (src_size, src) = (image.size(), image.buffer());
+        self.drawing_area.backend_ops(|b| {
+            if b.begin_clip_rect(upper_left, bottom_right)? {
+                b.blit_bitmap_scaled((x0, y0), (x1, y1), src_size, src, image.get_interpolation())?;
+                return b.end_clip();
+            }
+            // The backends without native clipping only draw the pixels in the plotting area
+            let element = ClipPath::new(vec![
+                upper_left,
+                (bottom_right.0, upper_left.1),
+                bottom_right,
+                (upper_left.0, bottom_right.1),
+            ])
+            .clip(image.copy_to((x0, y0)).stretch_to((x1, y1)));
+            let size = b.get_size();
+            element.draw(element.point_iter().copied(), b, size)
+        })
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
@@ -85,6 +226,150 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let mut pixel_range = self.drawing_area.get_pixel_range();
         pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
 
-        DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
+        DualCoordChartContext::new(
+            self,
+            Cartesian2d::new(x_coord, y_coord, pixel_range),
+            (true, true),
+        )
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged + Clone, Y: Ranged + Clone>
+    ChartContext<'a, DB, Cartesian2d<X, Y>>
+{
+    /// Convert this chart context into a dual axis chart context with an independent secondary X
+    /// axis, for example, days on the bottom axis and weeks on the top axis. The secondary Y axis
+    /// is the same as the primary one.
+    ///
+    /// Unlike [set_secondary_coord](#method.set_secondary_coord), only the top label area is
+    /// used by the secondary axes, the right label area remains on the primary chart, thus
+    /// [DualCoordChartContext::configure_secondary_axes](struct.DualCoordChartContext.html#method.configure_secondary_axes)
+    /// draws the top X axis only.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("secondary_x_coord.svg", (400, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .top_x_label_area_size(30)
+    ///     .y_label_area_size(30)
+    ///     .build_cartesian_2d(0..28, 0.0..1.0)
+    ///     .unwrap()
+    ///     .set_secondary_x_coord(0..4);
+    /// chart.configure_mesh().x_desc("Days").draw().unwrap();
+    /// chart.configure_secondary_axes().x_desc("Weeks").draw().unwrap();
+    /// ```
+    ///
+    /// - `x_coord`: The coordinate spec for the secondary X axis
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn set_secondary_x_coord<SX: AsRangedCoord>(
+        self,
+        x_coord: SX,
+    ) -> DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<SX::CoordDescType, Y>> {
+        let spec = self.as_coord_spec();
+        let y_coord = spec.y_spec().clone();
+        let secondary = Cartesian2d::new(x_coord, y_coord, spec.pixel_range());
+        DualCoordChartContext::new(self, secondary, (true, false))
+    }
+
+    /// Convert this chart context into a dual axis chart context with an independent secondary Y
+    /// axis, the secondary X axis is the same as the primary one. Only the right label area is
+    /// used by the secondary axes.
+    ///
+    /// See [set_secondary_x_coord](#method.set_secondary_x_coord) for details.
+    ///
+    /// - `y_coord`: The coordinate spec for the secondary Y axis
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn set_secondary_y_coord<SY: AsRangedCoord>(
+        self,
+        y_coord: SY,
+    ) -> DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<X, SY::CoordDescType>> {
+        let spec = self.as_coord_spec();
+        let x_coord = spec.x_spec().clone();
+        let secondary = Cartesian2d::new(x_coord, y_coord, spec.pixel_range());
+        DualCoordChartContext::new(self, secondary, (false, true))
+    }
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    X: Ranged<ValueType = f64> + Clone + 'a,
+    Y: Ranged + Clone,
+{
+    /// Convert this chart context into a dual axis chart context whose secondary X axis shows the
+    /// primary X axis in another unit, for example, degrees Fahrenheit for degrees Celsius.
+    ///
+    /// The secondary axis is placed at the same pixels as the primary axis, with its own ticks at
+    /// the round values of the converted unit. The secondary Y axis is the same as the primary one.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("secondary_x_as.svg", (400, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .top_x_label_area_size(30)
+    ///     .y_label_area_size(30)
+    ///     .build_cartesian_2d(-10.0..40.0, 0.0..1.0)
+    ///     .unwrap()
+    ///     .secondary_x_as(|c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0);
+    /// chart.configure_mesh().x_desc("Celsius").draw().unwrap();
+    /// chart.configure_secondary_axes().x_desc("Fahrenheit").draw().unwrap();
+    /// ```
+    ///
+    /// - `forward`: The monotonic function converting the primary unit to the secondary unit
+    /// - `inverse`: The inverse function of `forward`
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn secondary_x_as<F, I>(
+        self,
+        forward: F,
+        inverse: I,
+    ) -> DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<RangedFn<'a, f64>, Y>>
+    where
+        F: Fn(f64) -> f64,
+        I: Fn(f64) -> f64 + 'a,
+    {
+        let spec = self.as_coord_spec();
+        let x_coord = converted_axis(spec.x_spec(), forward, inverse);
+        let y_coord = spec.y_spec().clone();
+        let secondary = Cartesian2d::new(x_coord, y_coord, spec.pixel_range());
+        DualCoordChartContext::new(self, secondary, (true, true))
+    }
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    X: Ranged + Clone,
+    Y: Ranged<ValueType = f64> + Clone + 'a,
+{
+    /// Convert this chart context into a dual axis chart context whose secondary Y axis shows the
+    /// primary Y axis in another unit, for example, km/h for m/s.
+    ///
+    /// See [secondary_x_as](#method.secondary_x_as) for details.
+    ///
+    /// - `forward`: The monotonic function converting the primary unit to the secondary unit
+    /// - `inverse`: The inverse function of `forward`
+    /// - **returns** The newly created dual spec chart context
+    #[allow(clippy::type_complexity)]
+    pub fn secondary_y_as<F, I>(
+        self,
+        forward: F,
+        inverse: I,
+    ) -> DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<X, RangedFn<'a, f64>>>
+    where
+        F: Fn(f64) -> f64,
+        I: Fn(f64) -> f64 + 'a,
+    {
+        let spec = self.as_coord_spec();
+        let x_coord = spec.x_spec().clone();
+        let y_coord = converted_axis(spec.y_spec(), forward, inverse);
+        let secondary = Cartesian2d::new(x_coord, y_coord, spec.pixel_range());
+        DualCoordChartContext::new(self, secondary, (true, true))
     }
 }
@@ -0,0 +1,409 @@
+use super::ChartBuilder;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::style::{Color, RGBAColor, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+/// The position of a panel in the grid of a [Dashboard], the number of the rows and columns it
+/// spans, and its minimum size.
+///
+/// The grid grows to fit the panels, so a panel placed outside of the grid adds the rows or
+/// columns it needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PanelLayout {
+    row: usize,
+    col: usize,
+    row_span: usize,
+    col_span: usize,
+    min_size: (u32, u32),
+}
+
+impl PanelLayout {
+    /// Place a panel at a cell of the grid, the panel spans a single cell by default
+    ///
+    /// - `row`: The row of the upper-left cell of the panel, starting from zero
+    /// - `col`: The column of the upper-left cell of the panel, starting from zero
+    /// - **returns**: The newly created layout
+    pub fn at(row: usize, col: usize) -> Self {
+        Self {
+            row,
+            col,
+            row_span: 1,
+            col_span: 1,
+            min_size: (0, 0),
+        }
+    }
+
+    /// Set the number of the rows and columns the panel spans
+    pub fn span(mut self, rows: usize, cols: usize) -> Self {
+        self.row_span = rows.max(1);
+        self.col_span = cols.max(1);
+        self
+    }
+
+    /// Set the minimum size of the panel in pixels. The rows and the columns the panel spans are
+    /// enlarged to fit it, at the cost of the other rows and columns.
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.min_size = (width, height);
+        self
+    }
+}
+
+/// A panel of a [Dashboard], which is passed to the function that draws the panel
+pub struct DashboardPanel<'b, DB: DrawingBackend> {
+    name: &'b str,
+    area: DrawingArea<DB, Shift>,
+    margin: u32,
+    label_area_size: u32,
+    x_labels: bool,
+}
+
+impl<'b, DB: DrawingBackend> DashboardPanel<'b, DB> {
+    /// Get the name of the panel
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Get the drawing area of the panel, which excludes the title of the panel
+    pub fn area(&self) -> &DrawingArea<DB, Shift> {
+        &self.area
+    }
+
+    /// Check if the panel should have the labels of the X axis. It's false for the panels which
+    /// aren't at the bottom of the dashboard when the X axis is shared, see
+    /// [Dashboard::shared_x_axis](struct.Dashboard.html#method.shared_x_axis).
+    pub fn has_x_labels(&self) -> bool {
+        self.x_labels
+    }
+
+    /// Create a chart builder on the panel with the margin and the label area sizes of the
+    /// dashboard, so that the charts of the panels line up
+    pub fn chart_builder(&self) -> ChartBuilder<'_, '_, DB> {
+        let mut builder = ChartBuilder::on(&self.area);
+        builder
+            .margin(self.margin)
+            .y_label_area_size(self.label_area_size)
+            .x_label_area_size(if self.x_labels {
+                self.label_area_size
+            } else {
+                0
+            });
+        builder
+    }
+}
+
+type PanelDrawFn<'a, DB> = dyn FnOnce(
+        &DashboardPanel<'_, DB>,
+    ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
+    + 'a;
+
+/**
+A builder of the dashboards, which are made of multiple named panels rendered to a single image.
+
+The panels are laid out on a grid, each panel covers one or more cells and may require a minimum
+size, see [PanelLayout]. Each panel is drawn by its own function, which usually draws a chart from
+its own data source, while the dashboard applies the shared theme: the backgrounds, the titles of
+the panels, the spacing, and the margin and the label area sizes of the charts created by
+[DashboardPanel::chart_builder].
+
+```rust
+use plotters::chart::{Dashboard, PanelLayout};
+use plotters::prelude::*;
+
+let root = SVGBackend::new("dashboard.svg", (800, 600)).into_drawing_area();
+let cpu: Vec<(f64, f64)> = (0..60).map(|t| (t as f64, (t % 10) as f64 * 10.0)).collect();
+let memory: Vec<(f64, f64)> = (0..60).map(|t| (t as f64, 40.0 + (t / 10) as f64)).collect();
+
+Dashboard::new(2, 1)
+    .background(WHITE)
+    .panel_titles(("sans-serif", 16))
+    .shared_x_axis(true)
+    .panel("CPU", PanelLayout::at(0, 0), move |panel| {
+        let mut chart = panel.chart_builder().build_cartesian_2d(0.0..60.0, 0.0..100.0)?;
+        chart.configure_mesh().draw()?;
+        chart.draw_series(LineSeries::new(cpu, &RED))?;
+        Ok(())
+    })
+    .panel("Memory", PanelLayout::at(1, 0).min_size(0, 300), move |panel| {
+        let mut chart = panel.chart_builder().build_cartesian_2d(0.0..60.0, 0.0..100.0)?;
+        chart.configure_mesh().draw()?;
+        chart.draw_series(LineSeries::new(memory, &BLUE))?;
+        Ok(())
+    })
+    .render(&root)
+    .unwrap();
+```
+*/
+pub struct Dashboard<'a, DB: DrawingBackend> {
+    rows: usize,
+    cols: usize,
+    panels: Vec<(String, PanelLayout, Box<PanelDrawFn<'a, DB>>)>,
+    background: Option<RGBAColor>,
+    panel_background: Option<RGBAColor>,
+    title_style: Option<TextStyle<'a>>,
+    spacing: u32,
+    margin: u32,
+    label_area_size: u32,
+    shared_x_axis: bool,
+}
+
+impl<'a, DB: DrawingBackend> Dashboard<'a, DB> {
+    /// Create a new dashboard
+    ///
+    /// - `rows`: The number of the rows of the grid
+    /// - `cols`: The number of the columns of the grid
+    /// - **returns**: The newly created dashboard
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            panels: vec![],
+            background: None,
+            panel_background: None,
+            title_style: None,
+            spacing: 10,
+            margin: 5,
+            label_area_size: 40,
+            shared_x_axis: false,
+        }
+    }
+
+    /// Set the background color of the whole dashboard
+    pub fn background<C: Color>(mut self, color: C) -> Self {
+        self.background = Some(color.to_rgba());
+        self
+    }
+
+    /// Set the background color of the panels
+    pub fn panel_background<C: Color>(mut self, color: C) -> Self {
+        self.panel_background = Some(color.to_rgba());
+        self
+    }
+
+    /// Draw the names of the panels as their titles with the given style
+    pub fn panel_titles<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.title_style = Some(style.into());
+        self
+    }
+
+    /// Set the space between the panels in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the margin of the charts of the panels in pixels
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the size of the label areas of the charts of the panels in pixels
+    pub fn label_area_size(mut self, size: u32) -> Self {
+        self.label_area_size = size;
+        self
+    }
+
+    /// Share the X axis among the panels, such as a common time axis. Only the panels at the
+    /// bottom of the dashboard have the labels of the X axis, thus the panels stacked in a column
+    /// line up. The panels should use the same range of X.
+    pub fn shared_x_axis(mut self, shared: bool) -> Self {
+        self.shared_x_axis = shared;
+        self
+    }
+
+    /// Add a panel to the dashboard
+    ///
+    /// - `name`: The name of the panel, which is used as the title of the panel
+    /// - `layout`: The position and the size of the panel
+    /// - `draw`: The function that draws the panel
+    /// - **returns**: The dashboard with the panel added
+    pub fn panel<F>(mut self, name: &str, layout: PanelLayout, draw: F) -> Self
+    where
+        F: FnOnce(&DashboardPanel<'_, DB>) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> + 'a,
+    {
+        self.panels.push((name.to_string(), layout, Box::new(draw)));
+        self
+    }
+
+    /// Lay out the panels and draw them on the drawing area
+    ///
+    /// - `root`: The drawing area of the whole dashboard
+    /// - **returns**: The result, the first error returned by the panels is reported
+    pub fn render(
+        self,
+        root: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        if let Some(color) = &self.background {
+            root.fill(color)?;
+        }
+
+        let rows = self
+            .panels
+            .iter()
+            .map(|(_, l, _)| l.row + l.row_span)
+            .fold(self.rows, usize::max);
+        let cols = self
+            .panels
+            .iter()
+            .map(|(_, l, _)| l.col + l.col_span)
+            .fold(self.cols, usize::max);
+
+        let mut min_heights = vec![0; rows];
+        let mut min_widths = vec![0; cols];
+        for (_, l, _) in self.panels.iter() {
+            spread_min_size(
+                &mut min_heights[l.row..l.row + l.row_span],
+                l.min_size.1,
+                self.spacing,
+            );
+            spread_min_size(
+                &mut min_widths[l.col..l.col + l.col_span],
+                l.min_size.0,
+                self.spacing,
+            );
+        }
+        let (w, h) = root.dim_in_pixel();
+        let row_offsets = track_offsets(h, &min_heights, self.spacing);
+        let col_offsets = track_offsets(w, &min_widths, self.spacing);
+
+        for (name, l, draw) in self.panels {
+            let (x0, y0) = (col_offsets[l.col].0, row_offsets[l.row].0);
+            let x1 = col_offsets[l.col + l.col_span - 1].1;
+            let y1 = row_offsets[l.row + l.row_span - 1].1;
+            let area = root.clone().shrink((x0, y0), (x1 - x0, y1 - y0));
+
+            if let Some(color) = &self.panel_background {
+                area.fill(color)?;
+            }
+            let area = match &self.title_style {
+                Some(style) => area.titled(&name, style.clone())?,
+                None => area,
+            };
+
+            draw(&DashboardPanel {
+                name: &name,
+                area,
+                margin: self.margin,
+                label_area_size: self.label_area_size,
+                x_labels: !self.shared_x_axis || l.row + l.row_span == rows,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// Spread the minimum size of a panel to the tracks it spans
+fn spread_min_size(tracks: &mut [u32], min_size: u32, spacing: u32) {
+    let n = tracks.len() as u32;
+    let size = min_size.saturating_sub(spacing * (n - 1));
+    let each = if size == 0 { 0 } else { (size - 1) / n + 1 };
+    for track in tracks.iter_mut() {
+        *track = (*track).max(each);
+    }
+}
+
+// Compute the start and the end of the tracks, the tracks have the same size unless the minimum
+// sizes require otherwise
+fn track_offsets(total: u32, min_sizes: &[u32], spacing: u32) -> Vec<(i32, i32)> {
+    let n = min_sizes.len();
+    let mut available = total.saturating_sub(spacing * (n as u32).saturating_sub(1));
+
+    // The tracks whose minimum sizes are larger than the even share are fixed at the minimum
+    // sizes, and the rest of the space is shared by the other tracks
+    let mut fixed = vec![false; n];
+    let mut flexible = n as u32;
+    loop {
+        let share = available.checked_div(flexible).unwrap_or(0);
+        let mut changed = false;
+        for (idx, &min) in min_sizes.iter().enumerate() {
+            if !fixed[idx] && min > share {
+                fixed[idx] = true;
+                available = available.saturating_sub(min);
+                flexible -= 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut remainder = available.checked_rem(flexible).unwrap_or(0);
+    let mut offsets = Vec::with_capacity(n);
+    let mut pos = 0;
+    for (idx, &min) in min_sizes.iter().enumerate() {
+        let size = if fixed[idx] {
+            min
+        } else {
+            // The pixels left by the division go to the first flexible tracks
+            let extra = if remainder > 0 { 1 } else { 0 };
+            remainder -= extra;
+            available / flexible + extra
+        };
+        offsets.push((pos, pos + size as i32));
+        pos += size as i32 + spacing as i32;
+    }
+    offsets
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::Rect;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_track_offsets() {
+        assert_eq!(track_offsets(100, &[0, 0], 10), vec![(0, 45), (55, 100)]);
+        assert_eq!(
+            track_offsets(100, &[0, 60, 0], 5),
+            vec![(0, 15), (20, 80), (85, 100)]
+        );
+        let mut mins = vec![0; 2];
+        spread_min_size(&mut mins, 91, 10);
+        assert_eq!(mins, vec![41, 41]);
+    }
+
+    #[test]
+    fn test_dashboard() {
+        let panels = RefCell::new(vec![]);
+        let drawing_area = create_mocked_drawing_area(300, 200, |_| {});
+        let record = || {
+            |panel: &DashboardPanel<'_, _>| {
+                panels.borrow_mut().push((
+                    panel.name().to_string(),
+                    panel.area().get_rect().clone(),
+                    panel.has_x_labels(),
+                ));
+                let chart = panel.chart_builder().build_cartesian_2d(0..10, 0..10)?;
+                assert_eq!(
+                    chart.plotting_area_rect().bottom_right().1,
+                    panel.area().get_rect().bottom_right().1
+                        - 5
+                        - if panel.has_x_labels() { 40 } else { 0 }
+                );
+                Ok(())
+            }
+        };
+
+        Dashboard::new(2, 2)
+            .shared_x_axis(true)
+            .panel("top", PanelLayout::at(0, 0).span(1, 2), record())
+            .panel("left", PanelLayout::at(1, 0), record())
+            .panel("right", PanelLayout::at(1, 1).min_size(200, 0), record())
+            .render(&drawing_area)
+            .unwrap();
+
+        assert_eq!(
+            panels.into_inner(),
+            vec![
+                ("top".to_string(), Rect::new((0, 0), (300, 95)), false),
+                ("left".to_string(), Rect::new((0, 105), (90, 200)), true),
+                ("right".to_string(), Rect::new((100, 105), (300, 200)), true),
+            ]
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use super::ChartContext;
+use crate::coord::CoordTranslate;
+use crate::drawing::{DrawingAreaErrorKind, Rect};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, IntoTextStyle, ShapeStyle, TextStyle};
+
+use plotters_backend::{DrawingBackend, DrawingErrorKind};
+
+/**
+Useful to specify how the direct labels are placed when they collide with each other.
+
+See [`DirectLabelStyle`] for more information and examples.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelOverlap {
+    /// Places the labels at the ends of the lines, even if they overlap
+    Allow,
+    /// Moves the colliding labels vertically apart, and draws the leader lines from the ends of
+    /// the lines to the moved labels
+    Nudge,
+    /// Hides the labels which collide with the labels added before them
+    Hide,
+}
+
+struct DirectLabel<P> {
+    text: String,
+    end: P,
+    style: ShapeStyle,
+}
+
+/**
+The struct to specify the direct labels of a chart, which places the name of each series at the
+end of its line, instead of a legend box. It's usually easier to read than a legend when there
+are many lines, since the lines don't need to be matched with the legend by the colors.
+
+The labels are placed to the right of the line ends, thus there should be some room at the right
+side of the plotting area, for example, by a larger right margin of the chart.
+
+```rust
+use plotters::prelude::*;
+
+let root = SVGBackend::new("direct_labels.svg", (400, 300)).into_drawing_area();
+let mut chart = ChartBuilder::on(&root)
+    .margin_right(80)
+    .build_cartesian_2d(0..10, 0.0..10.0)
+    .unwrap();
+
+let series = [("Rust", 8.0, RED), ("C++", 7.8, BLUE), ("Go", 3.0, GREEN)];
+let mut labels = chart.configure_direct_labels();
+for &(name, last, color) in series.iter() {
+    let data: Vec<_> = (0..10).map(|x| (x, last * x as f64 / 9.0)).collect();
+    chart.draw_series(LineSeries::new(data, color)).unwrap();
+    labels.label(name, (9, last), color);
+}
+labels.overlap(LabelOverlap::Nudge).draw(&chart).unwrap();
+```
+*/
+pub struct DirectLabelStyle<'b, P> {
+    labels: Vec<DirectLabel<P>>,
+    dim: (u32, u32),
+    label_font: Option<TextStyle<'b>>,
+    overlap: LabelOverlap,
+    offset: u32,
+    spacing: u32,
+    leader_style: Option<ShapeStyle>,
+}
+
+impl<'b, P> DirectLabelStyle<'b, P> {
+    pub(super) fn new(dim: (u32, u32)) -> Self {
+        Self {
+            labels: vec![],
+            dim,
+            label_font: None,
+            overlap: LabelOverlap::Nudge,
+            offset: 10,
+            spacing: 2,
+            leader_style: None,
+        }
+    }
+
+    /**
+    Adds the label of a series.
+
+    - `text`: The label, which is usually the name of the series
+    - `end`: The end point of the line in the guest coordinate
+    - `style`: The style of the series, the label and the leader line use its color
+    */
+    pub fn label<T: Into<String>, S: Into<ShapeStyle>>(
+        &mut self,
+        text: T,
+        end: P,
+        style: S,
+    ) -> &mut Self {
+        self.labels.push(DirectLabel {
+            text: text.into(),
+            end,
+            style: style.into(),
+        });
+        self
+    }
+
+    /**
+    Sets the font for the labels, the color of the font is replaced by the color of the series.
+
+    - `font`: Desired font
+    */
+    pub fn label_font<F: IntoTextStyle<'b>>(&mut self, font: F) -> &mut Self {
+        self.label_font = Some(font.into_text_style(&self.dim));
+        self
+    }
+
+    /**
+    Sets how the colliding labels are placed, the labels are nudged apart by default.
+
+    - `overlap`: The overlap resolution
+    */
+    pub fn overlap(&mut self, overlap: LabelOverlap) -> &mut Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /**
+    Sets the horizontal distance between the ends of the lines and the labels.
+
+    - `offset`: The distance in backend units (pixels)
+    */
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.offset = offset;
+        self
+    }
+
+    /**
+    Sets the minimum vertical gap between the nudged labels.
+
+    - `spacing`: The gap in backend units (pixels)
+    */
+    pub fn spacing(&mut self, spacing: u32) -> &mut Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /**
+    Sets the style of the leader lines, which are drawn in the color of the series by default.
+
+    - `style`: The style of the leader lines
+    */
+    pub fn leader_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.leader_style = Some(style.into());
+        self
+    }
+
+    /**
+    Draws the labels on the chart.
+
+    - `chart`: The chart which the series are drawn on
+    */
+    pub fn draw<'a, DB: DrawingBackend, CT: CoordTranslate<From = P>>(
+        &self,
+        chart: &ChartContext<'a, DB, CT>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let plotting_area = chart.plotting_area();
+        let area = plotting_area.strip_coord_spec();
+        let (base_x, base_y) = plotting_area.get_base_pixel();
+
+        // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
+        // resolved
+        let font = self
+            .label_font
+            .clone()
+            .unwrap_or_else(|| ("sans-serif", 12).into_font().into())
+            .pos(Pos::new(HPos::Left, VPos::Center));
+        let font_error =
+            |e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)));
+
+        let mut ends = vec![];
+        let mut boxes = vec![];
+        for label in self.labels.iter() {
+            let (x, y) = plotting_area.map_coordinate(&label.end);
+            let (x, y) = (x - base_x, y - base_y);
+            let (w, h) = font.font.box_size(&label.text).map_err(font_error)?;
+            let upper_left = (x + self.offset as i32, y - h as i32 / 2);
+            ends.push((x, y));
+            boxes.push(Rect::from_size(upper_left, (w, h)));
+        }
+
+        let centers: Vec<Option<i32>> = match self.overlap {
+            LabelOverlap::Allow => ends.iter().map(|&(_, y)| Some(y)).collect(),
+            LabelOverlap::Nudge => {
+                let targets: Vec<_> = ends
+                    .iter()
+                    .zip(boxes.iter())
+                    .map(|(&(_, y), b)| (y, b.height() as i32))
+                    .collect();
+                let (_, h) = area.dim_in_pixel();
+                nudge_labels(&targets, self.spacing as i32, h as i32)
+                    .into_iter()
+                    .map(Some)
+                    .collect()
+            }
+            LabelOverlap::Hide => {
+                let mut shown: Vec<&Rect> = vec![];
+                boxes
+                    .iter()
+                    .zip(ends.iter())
+                    .map(|(b, &(_, y))| {
+                        if shown.iter().any(|s| s.intersects(b)) {
+                            return None;
+                        }
+                        shown.push(b);
+                        Some(y)
+                    })
+                    .collect()
+            }
+        };
+
+        for ((label, &(x, y)), center) in self.labels.iter().zip(ends.iter()).zip(centers) {
+            let center = match center {
+                Some(center) => center,
+                None => continue,
+            };
+            let label_x = x + self.offset as i32;
+            if center != y {
+                let style = self
+                    .leader_style
+                    .unwrap_or_else(|| label.style.color.stroke_width(1));
+                // The labels are usually outside of the plotting area, thus they aren't clipped
+                let path = vec![
+                    (base_x + x + 2, base_y + y),
+                    (base_x + label_x - 2, base_y + center),
+                ];
+                area.backend_ops(|b| b.draw_path(path, &style))?;
+            }
+            let style = TextStyle {
+                color: label.style.color.to_backend_color(),
+                ..font.clone()
+            };
+            area.draw_text(&label.text, &style, (label_x, center))?;
+        }
+        Ok(())
+    }
+}
+
+// Moves the labels apart vertically, the targets are the desired centers and the heights of the
+// labels, returns the centers of the labels. The colliding labels are merged into the clusters,
+// and each cluster is centered at the mean of its targets, then clamped into the range of `0..h`.
+fn nudge_labels(targets: &[(i32, i32)], spacing: i32, h: i32) -> Vec<i32> {
+    let mut order: Vec<_> = (0..targets.len()).collect();
+    order.sort_by_key(|&idx| targets[idx].0);
+
+    // The clusters in the order of the targets, as (the first item in `order`, the number of the
+    // items, the sum of the tops suggested by each item, the total height)
+    let mut clusters: Vec<(usize, i64, i64, i32)> = vec![];
+    let top_of = |cluster: &(usize, i64, i64, i32)| {
+        let top = (cluster.2 as f64 / cluster.1 as f64).round() as i32;
+        top.min(h - cluster.3).max(0)
+    };
+    for (pos, &idx) in order.iter().enumerate() {
+        let (center, height) = targets[idx];
+        let mut cluster = (pos, 1, i64::from(center - height / 2), height);
+        while let Some(prev) = clusters.last() {
+            if top_of(prev) + prev.3 + spacing <= top_of(&cluster) {
+                break;
+            }
+            // The items of the current cluster are shifted down by the previous cluster
+            let shift = i64::from(prev.3 + spacing);
+            cluster = (
+                prev.0,
+                prev.1 + cluster.1,
+                prev.2 + cluster.2 - shift * cluster.1,
+                prev.3 + spacing + cluster.3,
+            );
+            clusters.pop();
+        }
+        clusters.push(cluster);
+    }
+
+    let mut centers = vec![0; targets.len()];
+    for cluster in clusters.iter() {
+        let mut top = top_of(cluster);
+        for &idx in &order[cluster.0..cluster.0 + cluster.1 as usize] {
+            let height = targets[idx].1;
+            centers[idx] = top + height / 2;
+            top += height + spacing;
+        }
+    }
+    centers
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_nudge_labels() {
+        // The labels which don't collide aren't moved
+        assert_eq!(nudge_labels(&[(50, 10), (10, 10)], 2, 100), vec![50, 10]);
+        // The colliding labels are moved apart around the mean of the targets
+        assert_eq!(nudge_labels(&[(50, 10), (50, 10)], 2, 100), vec![44, 56]);
+        // The cluster is merged with the previous cluster when it grows
+        assert_eq!(
+            nudge_labels(&[(30, 10), (40, 10), (40, 10)], 0, 100),
+            vec![27, 37, 47]
+        );
+        // The labels are kept inside of the area
+        assert_eq!(nudge_labels(&[(98, 10), (99, 10)], 0, 100), vec![85, 95]);
+    }
+
+    #[test]
+    fn test_direct_labels() {
+        let draw = |overlap: LabelOverlap| {
+            let calls = record_draw_calls(200, 100, |drawing_area| {
+                let chart = ChartBuilder::on(drawing_area)
+                    .margin_right(100)
+                    .build_cartesian_2d(0..10, 0..100)
+                    .expect("Create chart");
+                chart
+                    .configure_direct_labels()
+                    .label("A", (10, 50), RED)
+                    .label("B", (10, 50), BLUE)
+                    .label("C", (10, 0), GREEN)
+                    .overlap(overlap)
+                    .draw(&chart)
+                    .expect("Drawing error");
+            });
+            (calls.texts, calls.paths.len())
+        };
+
+        let (texts, leaders) = draw(LabelOverlap::Allow);
+        assert_eq!(texts[0].pos, texts[1].pos);
+        assert_eq!(texts[0].pos.0, 109);
+        assert_eq!(leaders, 0);
+
+        let (texts, leaders) = draw(LabelOverlap::Nudge);
+        assert_eq!(texts.len(), 3);
+        assert!(texts[0].pos.1 < 50 && texts[1].pos.1 > 50);
+        assert!(texts[2].pos.1 < 100);
+        assert_eq!(leaders, 3);
+
+        let (texts, leaders) = draw(LabelOverlap::Hide);
+        let names: Vec<_> = texts.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(names, ["A", "C"]);
+        assert_eq!(leaders, 0);
+    }
+}
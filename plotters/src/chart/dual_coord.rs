@@ -103,7 +103,14 @@ impl<'b, DB: DrawingBackend, CT1: CoordTranslate + Clone, CT2: CoordTranslate +
 impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
     DualCoordChartContext<'a, DB, CT1, CT2>
 {
-    pub(super) fn new(mut primary: ChartContext<'a, DB, CT1>, secondary_coord: CT2) -> Self {
+    // The secondary axes take over the top and the right label areas, `axes` selects which of
+    // the X and the Y axes are secondary, the label areas of the others remain on the primary
+    // chart
+    pub(super) fn new(
+        mut primary: ChartContext<'a, DB, CT1>,
+        secondary_coord: CT2,
+        axes: (bool, bool),
+    ) -> Self {
         let secondary_drawing_area = primary
             .drawing_area
             .strip_coord_spec()
@@ -111,8 +118,15 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
         let mut secondary_x_label_area = [None, None];
         let mut secondary_y_label_area = [None, None];
 
-        std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
-        std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        if axes.0 {
+            std::mem::swap(&mut primary.x_label_area[0], &mut secondary_x_label_area[0]);
+        }
+        if axes.1 {
+            std::mem::swap(&mut primary.y_label_area[1], &mut secondary_y_label_area[1]);
+        }
+
+        let label_area_style = primary.label_area_style.clone();
+        let clip_series = primary.clip_series;
 
         Self {
             primary,
@@ -122,6 +136,12 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
                 drawing_area_pos: (0, 0),
+                label_area_style,
+                caption: None,
+                legend_area: None,
+                colorbar_area: None,
+                clip_series,
+                content_drawn: false,
             },
         }
     }
@@ -203,6 +223,9 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, SX: Ranged, SY: Ranged>
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
+        // The clipping may be changed on the primary chart after the secondary one is created
+        self.secondary.clip_series = self.primary.clip_series;
+        self.primary.content_drawn = true;
         self.secondary.draw_series_impl(series)?;
         Ok(self.primary.alloc_series_anno())
     }
@@ -0,0 +1,132 @@
+use super::{ChartContext, ChartState};
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use crate::element::BitMapElement;
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+use plotters_bitmap::{BitMapBackend, BitMapBackendError};
+
+/// A chart with its static layers rendered once, which is useful for the realtime rendering.
+///
+/// Typically, the mesh, the labels and some of the series don't change between frames. Instead of
+/// drawing them again for each frame, they are rendered into an in-memory bitmap along with the
+/// chart state. For each frame, [restore](#method.restore) blits the cached bitmap and restores
+/// the chart context, thus only the dynamic layers need to be drawn.
+///
+/// The static layers are the ones drawn by the function passed to [render](#method.render), for
+/// example the mesh and the first series. They're selected by drawing them rather than by a list
+/// of layers, such as `freeze_layers(&[Layer::Mesh, Layer::Series(0)])`, because the chart
+/// context doesn't keep a series once it's drawn, thus it can't render a series again by its
+/// index.
+///
+/// The cached bitmap is opaque, so the static layers should fill the background.
+///
+/// ```rust
+/// use plotters::chart::FrozenChart;
+/// use plotters::prelude::*;
+///
+/// let frozen = FrozenChart::render((1024, 768), |area| {
+///     area.fill(&WHITE)?;
+///     let mut chart = ChartBuilder::on(area)
+///         .set_all_label_area_size(30)
+///         .build_cartesian_2d(0..10, 0..10)?;
+///     // The static layers: the mesh and the first series
+///     chart.configure_mesh().draw()?;
+///     chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), &BLUE))?;
+///     Ok(chart.into_chart_state())
+/// })
+/// .unwrap();
+///
+/// let mut buffer = vec![0u8; 1024 * 768 * 3];
+/// for frame in 0..10 {
+///     let area = BitMapBackend::with_buffer(&mut buffer[..], (1024, 768)).into_drawing_area();
+///     let mut chart = frozen.restore(&area).unwrap();
+///     // The dynamic layer
+///     chart
+///         .draw_series(std::iter::once(Circle::new((frame, frame), 5, RED.filled())))
+///         .unwrap();
+///     area.present().unwrap();
+/// }
+/// ```
+pub struct FrozenChart<CT: CoordTranslate> {
+    layers: BitMapElement<'static, BackendCoord>,
+    state: ChartState<CT>,
+}
+
+impl<CT: CoordTranslate + Clone> FrozenChart<CT> {
+    /// Render the static layers of a chart
+    ///
+    /// - `size`: The size of the drawing area the chart is restored on later
+    /// - `draw`: The function that draws the static layers on the given drawing area and returns
+    ///   the chart state
+    /// - **returns**: The frozen chart, or the error occurred during drawing
+    pub fn render<F>(
+        size: (u32, u32),
+        draw: F,
+    ) -> Result<Self, DrawingAreaErrorKind<BitMapBackendError>>
+    where
+        F: FnOnce(
+            &DrawingArea<BitMapBackend<'_>, Shift>,
+        ) -> Result<ChartState<CT>, DrawingAreaErrorKind<BitMapBackendError>>,
+    {
+        let mut layers = BitMapElement::new((0, 0), size);
+        let state = {
+            let area = layers.as_bitmap_backend().into_drawing_area();
+            let state = draw(&area)?;
+            area.present()?;
+            state
+        };
+        Ok(Self { layers, state })
+    }
+
+    /// Blit the static layers on the given drawing area and restore the chart context for
+    /// drawing the dynamic layers
+    ///
+    /// - `area`: The drawing area to restore the chart on, which should have the same size as
+    ///   the frozen chart
+    /// - **returns**: The restored chart context
+    pub fn restore<'a, DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<ChartContext<'a, DB, CT>, DrawingAreaErrorKind<DB::ErrorType>> {
+        area.draw(&self.layers)?;
+        Ok(self.state.clone().restore(area))
+    }
+
+    /// Get the chart state of the frozen chart
+    pub fn chart_state(&self) -> &ChartState<CT> {
+        &self.state
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_frozen_chart() {
+        let frozen = FrozenChart::render((20, 20), |area| {
+            area.fill(&RED)?;
+            let chart = ChartBuilder::on(area)
+                .margin(5)
+                .build_cartesian_2d(0..10, 0..10)?;
+            Ok(chart.into_chart_state())
+        })
+        .unwrap();
+
+        let area = create_mocked_drawing_area(20, 20, |m| {
+            m.check_draw_pixel(|c, _| assert_eq!(c, RED.to_rgba()));
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (5, 14)));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_pixel_call, 400);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+
+        let mut chart = frozen.restore(&area).unwrap();
+        chart
+            .draw_series(std::iter::once(Circle::new((0, 0), 2, BLUE)))
+            .unwrap();
+    }
+}
@@ -1,17 +1,129 @@
 use std::marker::PhantomData;
 
 use super::builder::LabelAreaPosition;
+use super::context::cartesian2d::{TickMarks, XLabelOverlap};
 use super::context::ChartContext;
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
+use crate::coord::combinators::LogScalable;
 use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
 use crate::drawing::DrawingAreaErrorKind;
 use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ScriptText,
+    ShapeStyle, SizeDesc, TextStyle,
 };
 
 use plotters_backend::DrawingBackend;
 
+/**
+The style override of a single tick of the mesh, which is used to highlight the ticks with
+special values, such as the grid line at zero or the label of today.
+
+See [`MeshStyle::x_tick_highlight()`] for more information and examples.
+*/
+#[derive(Clone, Default)]
+pub struct TickHighlight<'a> {
+    pub(crate) grid_style: Option<ShapeStyle>,
+    pub(crate) label_style: Option<TextStyle<'a>>,
+}
+
+impl<'a> TickHighlight<'a> {
+    /// Create a tick highlight which doesn't override anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the style of the grid line of the tick
+    /// - `style`: The style of the grid line
+    pub fn grid_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.grid_style = Some(style.into());
+        self
+    }
+
+    /// Override the style of the label of the tick
+    /// - `style`: The text style of the label
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+}
+
+/// The direction of the tick marks on the axes, see [`MeshStyle::tick_direction()`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TickDirection {
+    /// The tick marks point from the axis toward the labels, which is outside of the plotting
+    /// area unless the labels are inward
+    #[default]
+    Outside,
+    /// The tick marks point from the axis away from the labels
+    Inside,
+    /// The tick marks cross the axis
+    Both,
+}
+
+/// How the X labels next to each other are kept from overlapping, which happens when the labels
+/// are long or the chart is narrow, see [`MeshStyle::x_label_overlap()`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TickLabelOverlap {
+    /// Draw all the labels even if they overlap
+    #[default]
+    Allow,
+    /// Draw every n-th label only, with the smallest n which avoids the overlap
+    Thin,
+    /// Rotate all the labels by 90 degrees, so that they read downward. The label area isn't
+    /// grown for the rotated labels, thus it should be as tall as the longest label, see
+    /// [`ChartBuilder::x_label_area_size()`](crate::chart::ChartBuilder::x_label_area_size)
+    Rotate,
+    /// Move every other label one line further from the axis. The label area isn't grown for
+    /// the moved labels, thus it should be tall enough for two lines of labels
+    Stagger,
+}
+
+/// A label of an axis computed by [`MeshStyle::computed_labels()`], which is exactly what the mesh
+/// draws with the current configuration
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComputedLabel<T> {
+    /// The data value of the tick
+    pub value: T,
+    /// The formatted text of the label, the superscripts and subscripts are written with the
+    /// markup of [`ScriptText::parse()`]
+    pub text: String,
+    /// The backend coordinate of the tick along the axis, the X coordinate for the X axis and
+    /// the Y coordinate for the Y axis
+    pub pos: i32,
+}
+
+// The integer coordinates map zero to a small positive number for the logarithmic scale, thus
+// the zero is checked before the conversion
+fn exponent_label<T: LogScalable + PartialEq>(value: &T) -> ScriptText {
+    if *value == T::from_f64(0.0) {
+        return ScriptText::scientific(0.0);
+    }
+    ScriptText::scientific(value.as_f64())
+}
+
+// The pixel positions of the minor ticks, which are the key points of the light grid lines
+// except the ones of the major ticks
+fn minor_tick_positions<R: Ranged, F: Fn(&R::ValueType) -> i32>(
+    spec: &R,
+    n_major: usize,
+    n_minor: usize,
+    map: F,
+) -> Vec<i32> {
+    if n_minor == 0 {
+        return vec![];
+    }
+    let major: Vec<_> = spec
+        .key_points(BoldPoints(n_major))
+        .iter()
+        .map(&map)
+        .collect();
+    spec.key_points(LightPoints::new(n_major, n_major * n_minor))
+        .iter()
+        .map(&map)
+        .filter(|p| !major.contains(p))
+        .collect()
+}
+
 /// The style used to describe the mesh and axis for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     style: MeshStyle<'a, 'b, X, Y, DB>,
@@ -30,6 +142,69 @@ where
         Self { style }
     }
 
+    /// Draw the grid lines of the secondary X axis, which is disabled by default.
+    ///
+    /// The grid is drawn along with the secondary axes, thus it's on the top of the primary mesh
+    /// if the secondary axes are drawn after the primary mesh, and under it otherwise.
+    pub fn enable_x_mesh(&mut self) -> &mut Self {
+        self.style.draw_x_mesh = true;
+        self
+    }
+
+    /// Draw the grid lines of the secondary Y axis, which is disabled by default, see
+    /// [`SecondaryMeshStyle::enable_x_mesh()`] for how the grids are stacked.
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    /// let drawing_area = SVGBackend::new("secondary_mesh.svg", (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .set_all_label_area_size(30)
+    ///     .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+    ///     .unwrap()
+    ///     .set_secondary_coord(0.0..10.0, -40.0..120.0);
+    ///
+    /// // The secondary grid goes first, so that it's under the primary one
+    /// chart
+    ///     .configure_secondary_axes()
+    ///     .enable_y_mesh()
+    ///     .bold_line_style(BLUE.mix(0.3))
+    ///     .light_line_style(BLUE.mix(0.1))
+    ///     .draw()
+    ///     .unwrap();
+    /// chart.configure_mesh().disable_x_mesh().draw().unwrap();
+    /// ```
+    pub fn enable_y_mesh(&mut self) -> &mut Self {
+        self.style.draw_y_mesh = true;
+        self
+    }
+
+    /// Draw the grid lines of both secondary axes, see [`SecondaryMeshStyle::enable_x_mesh()`]
+    pub fn enable_mesh(&mut self) -> &mut Self {
+        self.enable_x_mesh().enable_y_mesh()
+    }
+
+    /// Set the style of the bold grid lines of the secondary axes
+    /// - `style`: The style of the bold grid lines
+    pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.bold_line_style(style);
+        self
+    }
+
+    /// Set the style of the light grid lines of the secondary axes
+    /// - `style`: The style of the light grid lines
+    pub fn light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.light_line_style(style);
+        self
+    }
+
+    /// Set the maximum number of the light grid lines between two adjacent bold grid lines of
+    /// the secondary axes
+    /// - `value`: The maximum number of the light grid lines
+    pub fn max_light_lines(&mut self, value: usize) -> &mut Self {
+        self.style.max_light_lines(value);
+        self
+    }
+
     /// Set the style definition for the axis
     /// - `style`: The style for the axis
     pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -37,6 +212,20 @@ where
         self
     }
 
+    /// Set the style of the axis lines
+    /// - `style`: The style for the axis lines
+    pub fn axis_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.axis_line_style(style);
+        self
+    }
+
+    /// Set the style of the tick marks
+    /// - `style`: The style for the tick marks
+    pub fn tick_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.tick_style(style);
+        self
+    }
+
     /// The offset of x labels. This is used when we want to place the label in the middle of
     /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
     /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
@@ -83,6 +272,92 @@ where
         self
     }
 
+    /// Set the function which overrides the style of the X labels, see
+    /// [`MeshStyle::x_tick_highlight()`]
+    /// - `func`: The function which returns the style override of the tick value
+    pub fn x_tick_highlight(
+        &mut self,
+        func: &'b dyn Fn(&X::ValueType) -> Option<TickHighlight<'b>>,
+    ) -> &mut Self {
+        self.style.x_tick_highlight(func);
+        self
+    }
+
+    /// Set the function which overrides the style of the Y labels, see
+    /// [`MeshStyle::x_tick_highlight()`]
+    /// - `func`: The function which returns the style override of the tick value
+    pub fn y_tick_highlight(
+        &mut self,
+        func: &'b dyn Fn(&Y::ValueType) -> Option<TickHighlight<'b>>,
+    ) -> &mut Self {
+        self.style.y_tick_highlight(func);
+        self
+    }
+
+    /// Set the formatter function for the X label text, which also receives the index of the
+    /// label and the number of labels, see [`MeshStyle::x_label_formatter_indexed()`]
+    /// - `fmt`: The formatter function
+    pub fn x_label_formatter_indexed(
+        &mut self,
+        fmt: &'b dyn Fn(usize, usize, &X::ValueType) -> String,
+    ) -> &mut Self {
+        self.style.x_label_formatter_indexed(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which also receives the index of the
+    /// label and the number of labels, see [`MeshStyle::x_label_formatter_indexed()`]
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter_indexed(
+        &mut self,
+        fmt: &'b dyn Fn(usize, usize, &Y::ValueType) -> String,
+    ) -> &mut Self {
+        self.style.y_label_formatter_indexed(fmt);
+        self
+    }
+
+    /// Set the formatter function for the X label text, which returns a text with superscripts
+    /// and subscripts, see [`MeshStyle::x_label_script_formatter()`]
+    /// - `fmt`: The formatter function
+    pub fn x_label_script_formatter(
+        &mut self,
+        fmt: &'b dyn Fn(&X::ValueType) -> ScriptText,
+    ) -> &mut Self {
+        self.style.x_label_script_formatter(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which returns a text with superscripts
+    /// and subscripts, see [`MeshStyle::x_label_script_formatter()`]
+    /// - `fmt`: The formatter function
+    pub fn y_label_script_formatter(
+        &mut self,
+        fmt: &'b dyn Fn(&Y::ValueType) -> ScriptText,
+    ) -> &mut Self {
+        self.style.y_label_script_formatter(fmt);
+        self
+    }
+
+    /// Format the X labels in the mantissa-exponent form, see
+    /// [`MeshStyle::x_label_exponent_format()`]
+    pub fn x_label_exponent_format(&mut self) -> &mut Self
+    where
+        XT: LogScalable + PartialEq,
+    {
+        self.style.x_label_exponent_format();
+        self
+    }
+
+    /// Format the Y labels in the mantissa-exponent form, see
+    /// [`MeshStyle::x_label_exponent_format()`]
+    pub fn y_label_exponent_format(&mut self) -> &mut Self
+    where
+        YT: LogScalable + PartialEq,
+    {
+        self.style.y_label_exponent_format();
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -110,6 +385,36 @@ where
         self.style.draw()
     }
 
+    /// Draw the minor tick marks on the secondary X axis, see [`MeshStyle::x_minor_ticks()`]
+    /// - `value`: The maximum number of the minor ticks between two major ticks
+    pub fn x_minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.style.x_minor_ticks(value);
+        self
+    }
+
+    /// Draw the minor tick marks on the secondary Y axis, see [`MeshStyle::x_minor_ticks()`]
+    /// - `value`: The maximum number of the minor ticks between two major ticks
+    pub fn y_minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.style.y_minor_ticks(value);
+        self
+    }
+
+    /// Set the direction of the tick marks on the secondary axes, see
+    /// [`MeshStyle::tick_direction()`]
+    /// - `direction`: The direction of the tick marks
+    pub fn tick_direction(&mut self, direction: TickDirection) -> &mut Self {
+        self.style.tick_direction(direction);
+        self
+    }
+
+    /// Set how the labels on the secondary X axis are kept from overlapping each other, see
+    /// [`MeshStyle::x_label_overlap()`]
+    /// - `strategy`: How the overlapping labels are handled
+    pub fn x_label_overlap(&mut self, strategy: TickLabelOverlap) -> &mut Self {
+        self.style.x_label_overlap(strategy);
+        self
+    }
+
     /// Set the label style for the secondary axis
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
         self.style.label_style(style);
@@ -160,14 +465,29 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) bold_line_style: Option<ShapeStyle>,
     pub(super) light_line_style: Option<ShapeStyle>,
     pub(super) axis_style: Option<ShapeStyle>,
+    pub(super) axis_line_style: Option<ShapeStyle>,
+    pub(super) tick_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
     pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
     pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) format_x_indexed: Option<&'b dyn Fn(usize, usize, &X::ValueType) -> String>,
+    pub(super) format_y_indexed: Option<&'b dyn Fn(usize, usize, &Y::ValueType) -> String>,
+    pub(super) format_x_script: Option<&'b dyn Fn(&X::ValueType) -> ScriptText>,
+    pub(super) format_y_script: Option<&'b dyn Fn(&Y::ValueType) -> ScriptText>,
+    pub(super) x_highlight: Option<&'b dyn Fn(&X::ValueType) -> Option<TickHighlight<'b>>>,
+    pub(super) y_highlight: Option<&'b dyn Fn(&Y::ValueType) -> Option<TickHighlight<'b>>>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
     pub(super) y_tick_size: [i32; 2],
+    pub(super) x_minor_ticks: usize,
+    pub(super) y_minor_ticks: usize,
+    pub(super) minor_tick_size: Option<i32>,
+    pub(super) tick_direction: TickDirection,
+    pub(super) x_label_overlap: TickLabelOverlap,
+    pub(super) x_truncated: bool,
+    pub(super) y_truncated: bool,
 }
 
 impl<'a, 'b, X, Y, XT, YT, DB> MeshStyle<'a, 'b, X, Y, DB>
@@ -194,6 +514,8 @@ where
         MeshStyle {
             parent_size: chart.drawing_area.dim_in_pixel(),
             axis_style: None,
+            axis_line_style: None,
+            tick_style: None,
             x_label_offset: 0,
             y_label_offset: 0,
             draw_x_mesh: true,
@@ -210,6 +532,12 @@ where
             y_label_style: None,
             format_x: None,
             format_y: None,
+            format_x_indexed: None,
+            format_y_indexed: None,
+            format_x_script: None,
+            format_y_script: None,
+            x_highlight: None,
+            y_highlight: None,
             target: Some(chart),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -217,6 +545,13 @@ where
             axis_desc_style: None,
             x_tick_size,
             y_tick_size,
+            x_minor_ticks: 0,
+            y_minor_ticks: 0,
+            minor_tick_size: None,
+            tick_direction: TickDirection::Outside,
+            x_label_overlap: TickLabelOverlap::Allow,
+            x_truncated: false,
+            y_truncated: false,
         }
     }
 }
@@ -255,6 +590,88 @@ where
         self
     }
 
+    /// Set the length of the minor tick marks, which is half of the major tick marks by default.
+    /// The minor tick marks always point in the same direction as the major ones.
+    /// - `value`: The length of the minor tick marks
+    pub fn set_minor_tick_mark_size<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.minor_tick_size = Some(value.in_pixels(&self.parent_size).abs());
+        self
+    }
+
+    /**
+    Draw the minor tick marks on the X axis between the labeled ticks, without any grid lines.
+    The minor ticks are placed at the same positions as the light grid lines, thus there are at
+    most `value` minor ticks between two major ticks, depending on the range of the axis.
+
+    - `value`: The maximum number of the minor ticks between two major ticks, zero disables them
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_minor_ticks.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+        .unwrap();
+    // Draw the axes in the style of matplotlib, with the ticks crossing the axes
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_labels(6)
+        .x_minor_ticks(4)
+        .y_minor_ticks(4)
+        .tick_direction(TickDirection::Both)
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn x_minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.x_minor_ticks = value;
+        self
+    }
+
+    /// Draw the minor tick marks on the Y axis, see [`MeshStyle::x_minor_ticks()`]
+    /// - `value`: The maximum number of the minor ticks between two major ticks, zero disables them
+    pub fn y_minor_ticks(&mut self, value: usize) -> &mut Self {
+        self.y_minor_ticks = value;
+        self
+    }
+
+    /// Set the direction of all the tick marks, which point toward the labels by default
+    /// - `direction`: The direction of the tick marks
+    pub fn tick_direction(&mut self, direction: TickDirection) -> &mut Self {
+        self.tick_direction = direction;
+        self
+    }
+
+    /**
+    Set how the X labels are kept from overlapping each other. The extents of the labels are
+    measured when the mesh is drawn, and nothing changes if they don't overlap. By default, the
+    labels are drawn as they are.
+
+    - `strategy`: How the overlapping labels are handled
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_label_overlap.svg", (200, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(40)
+        .build_cartesian_2d(0..10, 0..10)
+        .unwrap();
+    chart
+        .configure_mesh()
+        .x_label_formatter(&|x| format!("{} requests", x))
+        .x_label_overlap(TickLabelOverlap::Thin)
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn x_label_overlap(&mut self, strategy: TickLabelOverlap) -> &mut Self {
+        self.x_label_overlap = strategy;
+        self
+    }
+
     /// The offset of x labels. This is used when we want to place the label in the middle of
     /// the grid. This is used to adjust label position for histograms, but since plotters 0.3, this
     /// use case is deprecated, see [SegmentedCoord coord decorator](../coord/ranged1d/trait.IntoSegmentedCoord.html) for more details
@@ -297,6 +714,22 @@ where
         self
     }
 
+    /// Draw a squiggle near the start of the X axis, which marks that the axis doesn't start at
+    /// zero. See [Histogram::clip_to_axis](../series/struct.Histogram.html#method.clip_to_axis)
+    /// for the bars starting from a truncated axis.
+    pub fn x_axis_truncated(&mut self) -> &mut Self {
+        self.x_truncated = true;
+        self
+    }
+
+    /// Draw a squiggle near the start of the Y axis, which marks that the axis doesn't start at
+    /// zero. See [Histogram::clip_to_axis](../series/struct.Histogram.html#method.clip_to_axis)
+    /// for the bars starting from a truncated axis.
+    pub fn y_axis_truncated(&mut self) -> &mut Self {
+        self.y_truncated = true;
+        self
+    }
+
     /// Disable drawing all meshes
     pub fn disable_mesh(&mut self) -> &mut Self {
         self.disable_x_mesh().disable_y_mesh()
@@ -307,13 +740,28 @@ where
         self.disable_x_axis().disable_y_axis()
     }
 
-    /// Set the style definition for the axis
+    /// Set the style definition for the axis, which applies to both the axis lines and the tick
+    /// marks unless they are configured separately
     /// - `style`: The style for the axis
     pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
         self.axis_style = Some(style.into());
         self
     }
 
+    /// Set the style of the axis lines, this overrides the [axis_style](#method.axis_style)
+    /// - `style`: The style for the axis lines
+    pub fn axis_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.axis_line_style = Some(style.into());
+        self
+    }
+
+    /// Set the style of the tick marks, this overrides the [axis_style](#method.axis_style)
+    /// - `style`: The style for the tick marks
+    pub fn tick_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.tick_style = Some(style.into());
+        self
+    }
+
     /// Set the maximum number of divisions for the minor grid
     /// - `value`: Maximum desired divisions between two consecutive X labels
     pub fn x_max_light_lines(&mut self, value: usize) -> &mut Self {
@@ -364,6 +812,38 @@ where
         self
     }
 
+    /// Set the style of the major grid lines, this is the same as
+    /// [bold_line_style](#method.bold_line_style)
+    /// - `style`: The major grid style
+    pub fn bold_grid_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.bold_line_style(style)
+    }
+
+    /// Set the style of the minor grid lines, this is the same as
+    /// [light_line_style](#method.light_line_style)
+    /// - `style`: The minor grid style
+    pub fn light_grid_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.light_line_style(style)
+    }
+
+    /// Set the style for the coarse grind grid, this is the 0.2 name of
+    /// [bold_line_style](#method.bold_line_style)
+    #[cfg(feature = "compat")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "compat")))]
+    #[deprecated(note = "`line_style_1` has been renamed to `bold_line_style`")]
+    pub fn line_style_1<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.bold_line_style(style)
+    }
+
+    /// Set the style for the fine grind grid, this is the 0.2 name of
+    /// [light_line_style](#method.light_line_style)
+    #[cfg(feature = "compat")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "compat")))]
+    #[deprecated(note = "`line_style_2` has been renamed to `light_line_style`")]
+    pub fn line_style_2<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.light_line_style(style)
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -391,6 +871,8 @@ where
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
         self.format_x = Some(fmt);
+        self.format_x_indexed = None;
+        self.format_x_script = None;
         self
     }
 
@@ -398,6 +880,195 @@ where
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
         self.format_y = Some(fmt);
+        self.format_y_indexed = None;
+        self.format_y_script = None;
+        self
+    }
+
+    /**
+    Set the formatter function for the X label text, which also receives the index of the label,
+    counting from the smallest value, and the number of labels on the axis. This replaces the
+    formatter set by [`MeshStyle::x_label_formatter()`].
+
+    - `fmt`: The formatter function, which is called with the index, the number of labels and the
+      value of the label
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_label_formatter_indexed.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0..100, 0..10)
+        .unwrap();
+    chart
+        .configure_mesh()
+        // Label every other tick, and append the unit to the last label
+        .x_label_formatter_indexed(&|idx, count, x| match idx {
+            _ if idx + 1 == count => format!("{} km", x),
+            _ if idx % 2 == 0 => format!("{}", x),
+            _ => String::new(),
+        })
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn x_label_formatter_indexed(
+        &mut self,
+        fmt: &'b dyn Fn(usize, usize, &X::ValueType) -> String,
+    ) -> &mut Self {
+        self.format_x_indexed = Some(fmt);
+        self.format_x = None;
+        self.format_x_script = None;
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which also receives the index of the
+    /// label, counting from the smallest value, and the number of labels on the axis, see
+    /// [`MeshStyle::x_label_formatter_indexed()`]
+    /// - `fmt`: The formatter function
+    pub fn y_label_formatter_indexed(
+        &mut self,
+        fmt: &'b dyn Fn(usize, usize, &Y::ValueType) -> String,
+    ) -> &mut Self {
+        self.format_y_indexed = Some(fmt);
+        self.format_y = None;
+        self.format_y_script = None;
+        self
+    }
+
+    /**
+    Set the formatter function for the X label text, which returns a text with superscripts and
+    subscripts, such as `10⁴` or `CO₂`. This replaces the formatter set by
+    [`MeshStyle::x_label_formatter()`].
+
+    - `fmt`: The formatter function
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_label_script_formatter.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0..6, 0..10)
+        .unwrap();
+    chart
+        .configure_mesh()
+        // Label the ticks of a log scale with the powers of ten
+        .x_label_script_formatter(&|x: &i32| {
+            ScriptText::new().normal("10").superscript(x.to_string())
+        })
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn x_label_script_formatter(
+        &mut self,
+        fmt: &'b dyn Fn(&X::ValueType) -> ScriptText,
+    ) -> &mut Self {
+        self.format_x_script = Some(fmt);
+        self.format_x = None;
+        self.format_x_indexed = None;
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which returns a text with superscripts
+    /// and subscripts, see [`MeshStyle::x_label_script_formatter()`]
+    /// - `fmt`: The formatter function
+    pub fn y_label_script_formatter(
+        &mut self,
+        fmt: &'b dyn Fn(&Y::ValueType) -> ScriptText,
+    ) -> &mut Self {
+        self.format_y_script = Some(fmt);
+        self.format_y = None;
+        self.format_y_indexed = None;
+        self
+    }
+
+    /**
+    Format the X labels in the mantissa-exponent form, such as `10⁶` or `2.5×10⁶`, which reads
+    better than the plain numbers on a logarithmic axis or a linear axis with a large range.
+    See [`ScriptText::scientific()`] for the details of the format.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_label_exponent_format.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d((1.0..1e8).log_scale(), 0.0..1e6)
+        .unwrap();
+    let mut mesh = chart.configure_mesh();
+    mesh.x_label_exponent_format().y_label_exponent_format();
+    let (x_labels, y_labels) = mesh.computed_labels();
+    assert!(x_labels.iter().any(|l| l.text == "10^{4}"));
+    assert!(y_labels.iter().any(|l| l.text == "2×10^{5}"));
+    mesh.draw().unwrap();
+    ```
+    */
+    pub fn x_label_exponent_format(&mut self) -> &mut Self
+    where
+        X::ValueType: LogScalable + PartialEq,
+    {
+        self.x_label_script_formatter(&exponent_label::<X::ValueType>)
+    }
+
+    /// Format the Y labels in the mantissa-exponent form, see
+    /// [`MeshStyle::x_label_exponent_format()`]
+    pub fn y_label_exponent_format(&mut self) -> &mut Self
+    where
+        Y::ValueType: LogScalable + PartialEq,
+    {
+        self.y_label_script_formatter(&exponent_label::<Y::ValueType>)
+    }
+
+    /**
+    Set the function which overrides the style of the X ticks. The function is called with the
+    value of each labeled tick, and the returned style replaces the bold grid line style and the
+    label style of that tick.
+
+    - `func`: The function which returns the style override of the tick value, or `None` to keep
+      the configured style
+
+    ```
+    use plotters::chart::TickHighlight;
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("x_tick_highlight.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(-5..5, -1.0..1.0)
+        .unwrap();
+    let today = 2;
+    chart
+        .configure_mesh()
+        // Show the label of today in red, and draw a thicker grid line at zero
+        .x_tick_highlight(&|x| {
+            let style = ("sans-serif", 15).into_font().color(&RED);
+            (*x == today).then(|| TickHighlight::new().label_style(style))
+        })
+        .y_tick_highlight(&|y| {
+            (*y == 0.0).then(|| TickHighlight::new().grid_style(BLACK.stroke_width(2)))
+        })
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn x_tick_highlight(
+        &mut self,
+        func: &'b dyn Fn(&X::ValueType) -> Option<TickHighlight<'b>>,
+    ) -> &mut Self {
+        self.x_highlight = Some(func);
+        self
+    }
+
+    /// Set the function which overrides the style of the Y ticks, see
+    /// [`MeshStyle::x_tick_highlight()`]
+    /// - `func`: The function which returns the style override of the tick value
+    pub fn y_tick_highlight(
+        &mut self,
+        func: &'b dyn Fn(&Y::ValueType) -> Option<TickHighlight<'b>>,
+    ) -> &mut Self {
+        self.y_highlight = Some(func);
         self
     }
 
@@ -422,6 +1093,166 @@ where
         self
     }
 
+    fn x_label_text(&self, xr: &X, idx: usize, count: usize, v: &X::ValueType) -> ScriptText
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+    {
+        if let Some(fmt_func) = self.format_x_script {
+            fmt_func(v)
+        } else if let Some(fmt_func) = self.format_x_indexed {
+            fmt_func(idx, count, v).into()
+        } else if let Some(fmt_func) = self.format_x {
+            fmt_func(v).into()
+        } else {
+            xr.format_ext(v).into()
+        }
+    }
+
+    fn y_label_text(&self, yr: &Y, idx: usize, count: usize, v: &Y::ValueType) -> ScriptText
+    where
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        if let Some(fmt_func) = self.format_y_script {
+            fmt_func(v)
+        } else if let Some(fmt_func) = self.format_y_indexed {
+            fmt_func(idx, count, v).into()
+        } else if let Some(fmt_func) = self.format_y {
+            fmt_func(v).into()
+        } else {
+            yr.format_ext(v).into()
+        }
+    }
+
+    // The font of the labels when no label style is set
+    fn default_label_font(&self) -> FontDesc<'b> {
+        FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
+            FontStyle::Normal,
+        )
+    }
+
+    /**
+    Compute the labels of both axes with the current configuration, without drawing anything.
+
+    The key points, the formatters and the visibility are resolved in the same way as
+    [`MeshStyle::draw()`], thus the result is exactly the labels the mesh draws, which is useful
+    for checking the tick labels in tests or laying out something else against them. An axis
+    which is disabled by `disable_x_axis` or `disable_y_axis` has no labels. The X labels hidden
+    by [`TickLabelOverlap::Thin`] are left out, while the rotated or staggered labels are kept
+    at the position of their ticks.
+
+    - **returns**: The labels of the X axis and the labels of the Y axis, or empty vectors if
+      the mesh has already been drawn
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("computed_labels.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0..10, 0.0..1.0)
+        .unwrap();
+    let mut mesh = chart.configure_mesh();
+    mesh.x_labels(3).y_label_formatter(&|y| format!("{:.0}%", y * 100.0));
+
+    let (x_labels, y_labels) = mesh.computed_labels();
+    let x_texts: Vec<_> = x_labels.iter().map(|l| l.text.as_str()).collect();
+    assert_eq!(x_texts, ["0", "5", "10"]);
+    assert_eq!(y_labels[0].text, "0%");
+    mesh.draw().unwrap();
+    ```
+    */
+    pub fn computed_labels(
+        &self,
+    ) -> (
+        Vec<ComputedLabel<X::ValueType>>,
+        Vec<ComputedLabel<Y::ValueType>>,
+    )
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        let target = match self.target.as_ref() {
+            Some(target) => target,
+            None => return (vec![], vec![]),
+        };
+        let coord = target.as_coord_spec();
+        // The labels out of the axis are skipped when the axis is drawn
+        let in_range =
+            |r: std::ops::Range<i32>, p: i32| r.start.min(r.end) <= p && p <= r.start.max(r.end);
+
+        let mut x_labels = vec![];
+        if self.draw_x_axis {
+            let key_points = coord.x_spec().key_points(BoldPoints(self.n_x_labels));
+            let count = key_points.len();
+            let mut texts = vec![];
+            for (idx, value) in key_points.into_iter().enumerate() {
+                let pos = coord.map_x(&value);
+                if in_range(coord.get_x_axis_pixel_range(), pos) {
+                    let text = self.x_label_text(coord.x_spec(), idx, count, &value);
+                    texts.push((value, text, pos));
+                }
+            }
+
+            // The labels are thinned out as they are in the bottom label area, or the top one
+            let mut hidden = vec![false; texts.len()];
+            if self.x_label_overlap != TickLabelOverlap::Allow {
+                let style = self
+                    .x_label_style
+                    .clone()
+                    .unwrap_or_else(|| self.default_label_font().into());
+                let tick_size = if target.x_label_area[1].is_some() {
+                    self.x_tick_size[1]
+                } else {
+                    self.x_tick_size[0]
+                };
+                let extents = texts
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, (_, text, pos))| {
+                        let ((w, h), _) = text
+                            .layout(&style, |text, style| {
+                                target.drawing_area.estimate_text_size(text, style)
+                            })
+                            .ok()?;
+                        Some((idx, *pos, w as i32, h as i32)).filter(|e| e.2 > 0)
+                    })
+                    .collect();
+                hidden = XLabelOverlap::resolve(
+                    self.x_label_overlap,
+                    texts.len(),
+                    extents,
+                    tick_size.abs() * 2,
+                )
+                .hidden;
+            }
+
+            for ((value, text, pos), hidden) in texts.into_iter().zip(hidden) {
+                if !hidden {
+                    let text = text.to_string();
+                    x_labels.push(ComputedLabel { value, text, pos });
+                }
+            }
+        }
+
+        let mut y_labels = vec![];
+        if self.draw_y_axis {
+            let key_points = coord.y_spec().key_points(BoldPoints(self.n_y_labels));
+            let count = key_points.len();
+            for (idx, value) in key_points.into_iter().enumerate() {
+                let pos = coord.map_y(&value);
+                if in_range(coord.get_y_axis_pixel_range(), pos) {
+                    let text = self
+                        .y_label_text(coord.y_spec(), idx, count, &value)
+                        .to_string();
+                    y_labels.push(ComputedLabel { value, text, pos });
+                }
+            }
+        }
+
+        (x_labels, y_labels)
+    }
+
     /// Draw the configured mesh on the target plot
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
@@ -433,11 +1264,7 @@ where
         let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
         let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
         let default_axis_color = RGBColor(0, 0, 0);
-        let default_label_font = FontDesc::new(
-            FontFamily::SansSerif,
-            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
-            FontStyle::Normal,
-        );
+        let default_label_font = self.default_label_font();
 
         let bold_style = self
             .bold_line_style
@@ -448,6 +1275,8 @@ where
         let axis_style = self
             .axis_style
             .unwrap_or_else(|| (&default_axis_color).into());
+        let axis_line_style = self.axis_line_style.unwrap_or(axis_style);
+        let tick_style = self.tick_style.unwrap_or(axis_style);
 
         let x_label_style = self
             .x_label_style
@@ -464,6 +1293,25 @@ where
             .clone()
             .unwrap_or_else(|| x_label_style.clone());
 
+        let coord = target.as_coord_spec();
+        let ticks = TickMarks {
+            direction: self.tick_direction,
+            x_label_overlap: self.x_label_overlap,
+            x_minor: minor_tick_positions(
+                coord.x_spec(),
+                self.n_x_labels,
+                self.x_minor_ticks,
+                |x| coord.map_x(x),
+            ),
+            y_minor: minor_tick_positions(
+                coord.y_spec(),
+                self.n_y_labels,
+                self.y_minor_ticks,
+                |y| coord.map_y(y),
+            ),
+            minor_tick_size: self.minor_tick_size,
+        };
+
         target.draw_mesh(
             (
                 LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
@@ -473,20 +1321,40 @@ where
             &x_label_style,
             &y_label_style,
             |_, _, _| None,
+            |_| None,
             self.draw_x_mesh,
             self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             false,
             false,
-            &axis_style,
+            &axis_line_style,
+            &tick_style,
             &axis_desc_style,
             self.x_desc.clone(),
             self.y_desc.clone(),
             self.x_tick_size,
             self.y_tick_size,
+            &ticks,
         )?;
 
+        // The number of the labels is only needed by the indexed formatters
+        let x_count = self.format_x_indexed.map_or(0, |_| {
+            target
+                .as_coord_spec()
+                .x_spec()
+                .key_points(BoldPoints(self.n_x_labels))
+                .len()
+        });
+        let y_count = self.format_y_indexed.map_or(0, |_| {
+            target
+                .as_coord_spec()
+                .y_spec()
+                .key_points(BoldPoints(self.n_y_labels))
+                .len()
+        });
+        let (mut x_idx, mut y_idx) = (0, 0);
+
         target.draw_mesh(
             (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
             &bold_style,
@@ -494,40 +1362,55 @@ where
             &y_label_style,
             |xr, yr, m| match m {
                 MeshLine::XMesh(_, _, v) => {
+                    x_idx += 1;
                     if self.draw_x_axis {
-                        if let Some(fmt_func) = self.format_x {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(xr.format_ext(v))
-                        }
+                        Some(self.x_label_text(xr, x_idx - 1, x_count, v))
                     } else {
                         None
                     }
                 }
                 MeshLine::YMesh(_, _, v) => {
+                    y_idx += 1;
                     if self.draw_y_axis {
-                        if let Some(fmt_func) = self.format_y {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(yr.format_ext(v))
-                        }
+                        Some(self.y_label_text(yr, y_idx - 1, y_count, v))
                     } else {
                         None
                     }
                 }
             },
+            |m| match m {
+                MeshLine::XMesh(_, _, v) => self.x_highlight.and_then(|func| func(v)),
+                MeshLine::YMesh(_, _, v) => self.y_highlight.and_then(|func| func(v)),
+            },
             self.draw_x_mesh,
             self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
             self.draw_x_axis,
             self.draw_y_axis,
-            &axis_style,
+            &axis_line_style,
+            &tick_style,
             &axis_desc_style,
             None,
             None,
             self.x_tick_size,
             self.y_tick_size,
-        )
+            &ticks,
+        )?;
+
+        if self.draw_x_axis && self.x_truncated {
+            target.draw_truncation_mark(false, &axis_line_style)?;
+        }
+        if self.draw_y_axis && self.y_truncated {
+            target.draw_truncation_mark(true, &axis_line_style)?;
+        }
+        if self.draw_x_axis {
+            target.draw_axis_break_marks(false, &axis_line_style)?;
+        }
+        if self.draw_y_axis {
+            target.draw_axis_break_marks(true, &axis_line_style)?;
+        }
+
+        Ok(())
     }
 }
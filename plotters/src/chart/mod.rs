@@ -15,16 +15,38 @@ detailed description for each struct.
 mod axes3d;
 mod builder;
 mod context;
+mod dashboard;
+mod direct_labels;
 mod dual_coord;
+#[cfg(feature = "bitmap_backend")]
+mod frozen;
 mod mesh;
 mod series;
 mod state;
+mod templates;
+#[cfg(feature = "bitmap_backend")]
+mod tiles;
 
-pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use builder::{CaptionPosition, ChartBuilder, LabelAreaConfig, LabelAreaPosition};
 pub use context::ChartContext;
+pub use dashboard::{Dashboard, DashboardPanel, PanelLayout};
+pub use direct_labels::{DirectLabelStyle, LabelOverlap};
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
-pub use mesh::{MeshStyle, SecondaryMeshStyle};
-pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
+#[cfg(feature = "bitmap_backend")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
+pub use frozen::FrozenChart;
+pub use mesh::{
+    ComputedLabel, MeshStyle, SecondaryMeshStyle, TickDirection, TickHighlight, TickLabelOverlap,
+};
+pub use series::{
+    LegendCollector, LegendValues, SeriesAnno, SeriesLabelPosition, SeriesLabelStyle, SizeLegend,
+};
 pub use state::ChartState;
+pub use templates::{
+    FigureTemplate, MarginalCharts, PanelContext, ResidualCharts, ScatterMatrixCharts,
+};
+#[cfg(feature = "bitmap_backend")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
+pub use tiles::{TileId, TileLayer};
 
 use context::Coord3D;
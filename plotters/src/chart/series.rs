@@ -1,36 +1,66 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::ops::Range;
+
 use super::ChartContext;
-use crate::coord::CoordTranslate;
-use crate::drawing::DrawingAreaErrorKind;
-use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
-use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
+use crate::coord::ranged1d::{KeyPointHint, Ranged, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, Rect};
+use crate::element::{
+    Circle, DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle, Text,
+};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{
+    IntoFont, IntoTextStyle, OwnedTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT,
+};
 
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
-type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a;
+type SeriesAnnoDrawFn<'a, DB> =
+    dyn Fn(BackendCoord, Option<&dyn Any>) -> DynElement<'a, DB, BackendCoord> + 'a;
+
+type SeriesAnnoLabelFn<'a> = dyn Fn(Option<&dyn Any>) -> Option<String> + 'a;
 
 /// The annotations (such as the label of the series, the legend element, etc)
 /// When a series is drawn onto a drawing area, an series annotation object
 /// is created and a mutable reference is returned.
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
+    label_func: Option<Box<SeriesAnnoLabelFn<'a>>>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    data: Option<Box<dyn Any>>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
     #[allow(clippy::option_as_ref_deref)]
-    pub(crate) fn get_label(&self) -> &str {
+    pub(crate) fn get_label(&self) -> Cow<'_, str> {
+        if let Some(label) = self
+            .label_func
+            .as_ref()
+            .and_then(|f| f(self.get_data_any()))
+        {
+            return Cow::Owned(label);
+        }
         // TODO: Change this when we bump the MSRV
-        self.label.as_ref().map(|x| x.as_str()).unwrap_or("")
+        Cow::Borrowed(self.label.as_ref().map(|x| x.as_str()).unwrap_or(""))
     }
 
     pub(crate) fn get_draw_func(&self) -> Option<&SeriesAnnoDrawFn<'a, DB>> {
         self.draw_func.as_ref().map(|x| x.as_ref())
     }
 
+    pub(crate) fn get_data_any(&self) -> Option<&dyn Any> {
+        self.data.as_ref().map(|x| x.as_ref())
+    }
+
     pub(crate) fn new() -> Self {
         Self {
             label: None,
+            label_func: None,
             draw_func: None,
+            data: None,
         }
     }
 
@@ -62,7 +92,97 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         &mut self,
         func: T,
     ) -> &mut Self {
-        self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
+        self.draw_func = Some(Box::new(move |p, _| func(p).into_dyn()));
+        self
+    }
+
+    /**
+    Attaches the user data to the series annotation, the previously attached data is replaced.
+
+    The data is passed to the functions set by [`SeriesAnno::label_with_data()`] and
+    [`SeriesAnno::legend_with_data()`] when the series labels are drawn. Thus the label can be
+    updated by attaching new data, without recreating the label text.
+
+    ```rust
+    use plotters::prelude::*;
+
+    let drawing_area = SVGBackend::new("series_anno_data.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(0..10, 0..100).unwrap();
+    chart
+        .draw_series(LineSeries::new((0..10).map(|x| (x, x * 10)), &RED))
+        .unwrap()
+        .data(87)
+        .label_with_data(|usage: &i32| format!("CPU 3 ({}%)", usage))
+        .legend_with_data(|(x, y), usage: &i32| {
+            PathElement::new(vec![(x, y), (x + *usage / 5, y)], &RED)
+        });
+    chart.configure_series_labels().draw().unwrap();
+    ```
+
+    - `data`: The data to attach
+    */
+    pub fn data<T: Any>(&mut self, data: T) -> &mut Self {
+        self.data = Some(Box::new(data));
+        self
+    }
+
+    /**
+    Gets the user data attached by [`SeriesAnno::data()`].
+
+    - **returns**: The attached data, or `None` if there's no data or the data isn't of type `T`
+    */
+    pub fn get_data<T: Any>(&self) -> Option<&T> {
+        self.get_data_any().and_then(|data| data.downcast_ref())
+    }
+
+    /**
+    Gets the mutable reference to the user data attached by [`SeriesAnno::data()`].
+
+    - **returns**: The attached data, or `None` if there's no data or the data isn't of type `T`
+    */
+    pub fn get_data_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.data.as_mut().and_then(|data| data.downcast_mut())
+    }
+
+    /**
+    Sets the function that builds the series label from the attached user data.
+
+    The function is called when the series labels are drawn. If there's no data of type `T`
+    attached, the label set by [`SeriesAnno::label()`] is used instead.
+
+    - `func`: The function that formats the attached data into the label
+    */
+    pub fn label_with_data<T: Any, F: Fn(&T) -> String + 'a>(&mut self, func: F) -> &mut Self {
+        self.label_func = Some(Box::new(move |data| {
+            data.and_then(|data| data.downcast_ref()).map(&func)
+        }));
+        self
+    }
+
+    /**
+    Sets the legend element creator function, which also receives the attached user data.
+
+    If there's no data of type `T` attached, no legend element is drawn.
+
+    - `func`: The function use to create the element, from the position and the attached data
+
+    # Note
+
+    The creation function uses the same shifted coordinate system as [`SeriesAnno::legend()`].
+    */
+    pub fn legend_with_data<T, E, F>(&mut self, func: F) -> &mut Self
+    where
+        DB: 'a,
+        T: Any,
+        E: IntoDynElement<'a, DB, BackendCoord>,
+        F: Fn(BackendCoord, &T) -> E + 'a,
+    {
+        self.draw_func = Some(Box::new(move |p, data| {
+            match data.and_then(|data| data.downcast_ref()) {
+                Some(data) => func(p, data).into_dyn(),
+                None => EmptyElement::at(p).into_dyn(),
+            }
+        }));
         self
     }
 }
@@ -73,6 +193,7 @@ Useful to specify the position of the series label.
 See [`ChartContext::configure_series_labels()`] for more information and examples.
 */
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum SeriesLabelPosition {
     /// Places the series label at the upper left
     UpperLeft,
@@ -94,6 +215,14 @@ pub enum SeriesLabelPosition {
     LowerRight,
     /// Places the series label at the specific location in backend coordinates
     Coordinate(i32, i32),
+    /// Places the series label right to the plotting area, in the space reserved by
+    /// [`ChartBuilder::legend_area()`](crate::chart::ChartBuilder::legend_area). Without the
+    /// reserved space, the series label is placed at the upper right of the plotting area
+    OutsideRight,
+    /// Places the series label below the plotting area, in the space reserved by
+    /// [`ChartBuilder::legend_area()`](crate::chart::ChartBuilder::legend_area). Without the
+    /// reserved space, the series label is placed at the lower middle of the plotting area
+    OutsideBottom,
 }
 
 impl SeriesLabelPosition {
@@ -101,13 +230,15 @@ impl SeriesLabelPosition {
         use SeriesLabelPosition::*;
         (
             match self {
-                UpperLeft | MiddleLeft | LowerLeft => 5,
-                UpperMiddle | MiddleMiddle | LowerMiddle => (area_dim.0 as i32 - label_dim.0) / 2,
+                UpperLeft | MiddleLeft | LowerLeft | OutsideRight => 5,
+                UpperMiddle | MiddleMiddle | LowerMiddle | OutsideBottom => {
+                    (area_dim.0 as i32 - label_dim.0) / 2
+                }
                 UpperRight | MiddleRight | LowerRight => area_dim.0 as i32 - label_dim.0 - 5,
                 Coordinate(x, _) => *x,
             },
             match self {
-                UpperLeft | UpperMiddle | UpperRight => 5,
+                UpperLeft | UpperMiddle | UpperRight | OutsideRight | OutsideBottom => 5,
                 MiddleLeft | MiddleMiddle | MiddleRight => (area_dim.1 as i32 - label_dim.1) / 2,
                 LowerLeft | LowerMiddle | LowerRight => area_dim.1 as i32 - label_dim.1 - 5,
                 Coordinate(_, y) => *y,
@@ -116,27 +247,236 @@ impl SeriesLabelPosition {
     }
 }
 
+/**
+Collects the series annotations of multiple charts, so that the series of all the charts are
+shown in a single legend box. This is useful when a drawing area is split into several charts
+sharing the same legend, for example, a price chart and a volume chart.
+
+```rust
+use plotters::prelude::*;
+
+let root = SVGBackend::new("legend_collector.svg", (400, 400)).into_drawing_area();
+let (upper, lower) = root.split_vertically(300);
+let mut price = ChartBuilder::on(&upper).build_cartesian_2d(0..10, 0..100).unwrap();
+let mut volume = ChartBuilder::on(&lower).build_cartesian_2d(0..10, 0..10).unwrap();
+
+price
+    .draw_series(LineSeries::new((0..10).map(|x| (x, x * 10)), &RED))
+    .unwrap()
+    .label("Price")
+    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+volume
+    .draw_series((0..10).map(|x| Rectangle::new([(x, 0), (x + 1, x)], BLUE.filled())))
+    .unwrap()
+    .label("Volume")
+    .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLUE.filled()));
+
+let mut legend = LegendCollector::new();
+legend.collect(&mut price).collect(&mut volume);
+legend
+    .configure_legend(&upper)
+    .position(SeriesLabelPosition::UpperLeft)
+    .draw()
+    .unwrap();
+```
+*/
+pub struct LegendCollector<'a, DB: DrawingBackend> {
+    series_anno: Vec<SeriesAnno<'a, DB>>,
+}
+
+impl<'a, DB: DrawingBackend + 'a> Default for LegendCollector<'a, DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, DB: DrawingBackend + 'a> LegendCollector<'a, DB> {
+    /// Create an empty legend collector
+    pub fn new() -> Self {
+        Self {
+            series_anno: vec![],
+        }
+    }
+
+    /**
+    Moves the series annotations of the chart into the collector. The series are shown in the
+    order they are collected, and they are no longer shown by the series labels of the chart.
+
+    - `chart`: The chart to collect the series annotations from
+    - **returns**: The collector, thus the calls can be chained
+    */
+    pub fn collect<CT: CoordTranslate>(
+        &mut self,
+        chart: &mut ChartContext<'a, DB, CT>,
+    ) -> &mut Self {
+        self.series_anno.append(&mut chart.series_anno);
+        self
+    }
+
+    /**
+    Configures the combined legend box, which is drawn on the given drawing area.
+
+    - `area`: The drawing area where the legend box is placed, the position of the legend is
+      relative to this area
+    - **returns**: The series label style, which is used to configure and draw the legend
+
+    See [`ChartContext::configure_series_labels()`] for more information on the style.
+    */
+    pub fn configure_legend<'b>(
+        &'b self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> SeriesLabelStyle<'a, 'b, DB, Shift> {
+        SeriesLabelStyle::with_series_anno(area.clone(), &self.series_anno)
+    }
+}
+
+/**
+The legend of the marker sizes, which shows a few reference bubbles with their values. This is
+useful for the bubble charts, where the marker size encodes a value of the data point.
+
+The entries are appended below the series labels, in the same legend box.
+
+```rust
+use plotters::prelude::*;
+
+let root = SVGBackend::new("size_legend.svg", (400, 300)).into_drawing_area();
+let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0.0..10.0, 0.0..10.0).unwrap();
+
+// The area of the bubble is proportional to the population
+let radius = |population: f64| population.sqrt() * 2.0;
+let cities = [(2.0, 3.0, 12.0), (5.0, 7.0, 45.0), (8.0, 4.0, 90.0)];
+chart
+    .draw_series(cities.iter().map(|&(x, y, p)| {
+        Circle::new((x, y), radius(p) as u32, BLUE.mix(0.5).filled())
+    }))
+    .unwrap()
+    .label("Cities")
+    .legend(|(x, y)| Circle::new((x + 10, y), 5, BLUE.mix(0.5).filled()));
+
+chart
+    .configure_series_labels()
+    .size_legend(SizeLegend::for_range(0.0..100.0, 4, radius, BLUE.mix(0.5)).title("Population"))
+    .border_style(&BLACK)
+    .draw()
+    .unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct SizeLegend {
+    title: Option<String>,
+    entries: Vec<(String, u32)>,
+    style: ShapeStyle,
+}
+
+impl SizeLegend {
+    /**
+    Creates an empty size legend.
+
+    - `style`: The style of the reference bubbles
+    */
+    pub fn new<S: Into<ShapeStyle>>(style: S) -> Self {
+        Self {
+            title: None,
+            entries: vec![],
+            style: style.into(),
+        }
+    }
+
+    /**
+    Creates the size legend for the values in the range. A few round values in the range are
+    picked as the reference values, the values mapped to an empty bubble are skipped.
+
+    - `range`: The range of the values encoded by the marker size
+    - `count`: The maximum number of the reference bubbles, usually 3 or 4
+    - `radius`: The function that maps the value to the radius of the marker in pixels
+    - `style`: The style of the reference bubbles
+    */
+    pub fn for_range<F: Fn(f64) -> f64, S: Into<ShapeStyle>>(
+        range: Range<f64>,
+        count: usize,
+        radius: F,
+        style: S,
+    ) -> Self {
+        let mut ret = Self::new(style);
+        let coord: RangedCoordf64 = range.into();
+        for value in coord.key_points(count.max(1).bold_points()) {
+            let r = radius(value).round();
+            if r >= 1.0 {
+                ret = ret.entry(RangedCoordf64::format(&value), r as u32);
+            }
+        }
+        ret
+    }
+
+    /**
+    Sets the title shown above the reference bubbles.
+
+    - `title`: The title, such as the name of the value
+    */
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /**
+    Appends a reference bubble.
+
+    - `label`: The label of the bubble, which is usually the value
+    - `radius`: The radius of the bubble in pixels
+    */
+    pub fn entry<L: Into<String>>(mut self, label: L, radius: u32) -> Self {
+        self.entries.push((label.into(), radius));
+        self
+    }
+}
+
 /// The struct to specify the series label of a target chart context
 pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
-    target: &'b mut ChartContext<'a, DB, CT>,
+    area: DrawingArea<DB, Shift>,
+    series_anno: &'b [SeriesAnno<'a, DB>],
     position: SeriesLabelPosition,
+    outside: Option<(SeriesLabelPosition, DrawingArea<DB, Shift>)>,
     legend_area_size: u32,
     border_style: ShapeStyle,
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    avoid: Option<Rect>,
+    size_legend: Option<SizeLegend>,
+    _phantom: PhantomData<CT>,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
     pub(super) fn new(target: &'b mut ChartContext<'a, DB, CT>) -> Self {
+        let mut ret = Self::with_series_anno(
+            target.plotting_area().strip_coord_spec(),
+            &target.series_anno,
+        );
+        ret.avoid = target.caption.as_ref().map(|caption| caption.rect.clone());
+        if let Some((pos, _)) = target.legend_area.as_ref() {
+            ret.position = pos.clone();
+        }
+        ret.outside = target.legend_area.clone();
+        ret
+    }
+
+    fn with_series_anno(
+        area: DrawingArea<DB, Shift>,
+        series_anno: &'b [SeriesAnno<'a, DB>],
+    ) -> Self {
         Self {
-            target,
+            area,
+            series_anno,
             position: SeriesLabelPosition::MiddleRight,
+            outside: None,
             legend_area_size: 30,
             border_style: (&TRANSPARENT).into(),
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            avoid: None,
+            size_legend: None,
+            _phantom: PhantomData,
         }
     }
 
@@ -160,9 +500,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn margin<S: SizeDesc>(&mut self, value: S) -> &mut Self {
-        self.margin = value
-            .in_pixels(&self.target.plotting_area().dim_in_pixel())
-            .max(0) as u32;
+        self.margin = value.in_pixels(&self.area.dim_in_pixel()).max(0) as u32;
         self
     }
 
@@ -174,9 +512,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn legend_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
-        let size = size
-            .in_pixels(&self.target.plotting_area().dim_in_pixel())
-            .max(0) as u32;
+        let size = size.in_pixels(&self.area.dim_in_pixel()).max(0) as u32;
         self.legend_area_size = size;
         self
     }
@@ -213,7 +549,19 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn label_font<F: IntoTextStyle<'b>>(&mut self, font: F) -> &mut Self {
-        self.label_font = Some(font.into_text_style(&self.target.plotting_area().dim_in_pixel()));
+        self.label_font = Some(font.into_text_style(&self.area.dim_in_pixel()));
+        self
+    }
+
+    /**
+    Appends the legend of the marker sizes below the series labels.
+
+    - `legend`: The size legend
+
+    See [`SizeLegend`] for more information and examples.
+    */
+    pub fn size_legend(&mut self, legend: SizeLegend) -> &mut Self {
+        self.size_legend = Some(legend);
         self
     }
 
@@ -223,7 +571,78 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
-        let drawing_area = self.target.plotting_area().strip_coord_spec();
+        self.draw_impl(0).map(|_| ())
+    }
+
+    /**
+    Draws the series label area with a column reserved for the live values of the series, such
+    as the latest reading of a sensor. The static part of the legend is drawn only once, and the
+    values are drawn by [`LegendValues::update()`] on each frame, which only redraws the value
+    column. This is useful for the streaming charts, where the legend is redrawn many times per
+    second.
+
+    The value regions are refilled with the background of the legend, thus an opaque
+    [`SeriesLabelStyle::background_style()`] is required.
+
+    - `width`: The width of the value column in backend units (pixels)
+    - **returns**: The cached layout of the value column, which is used to update the values
+
+    ```rust
+    use plotters::prelude::*;
+
+    let root = SVGBackend::new("legend_values.svg", (400, 300)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0..100, 0.0..1.0).unwrap();
+    chart
+        .draw_series(LineSeries::new((0..100).map(|x| (x, x as f64 / 100.0)), &RED))
+        .unwrap()
+        .label("Temperature");
+
+    let values = chart
+        .configure_series_labels()
+        .background_style(&WHITE)
+        .draw_with_values(50)
+        .unwrap();
+    for reading in &[20.5, 21.0, 21.3] {
+        let dirty = values.update(&root, &[format!("{:.1}", reading)]).unwrap();
+        assert_eq!(dirty.len(), 1);
+    }
+    ```
+    */
+    pub fn draw_with_values(
+        &mut self,
+        width: u32,
+    ) -> Result<LegendValues, DrawingAreaErrorKind<DB::ErrorType>> {
+        let background = self.background.filled();
+        let (regions, font) = self.draw_impl(width as i32)?;
+        Ok(LegendValues {
+            regions,
+            background,
+            font: OwnedTextStyle::new(&font),
+        })
+    }
+
+    // Draws the legend, returns the value regions in the backend coordinates and the label font
+    #[allow(clippy::type_complexity)]
+    fn draw_impl(
+        &mut self,
+        value_width: i32,
+    ) -> Result<(Vec<Rect>, TextStyle<'b>), DrawingAreaErrorKind<DB::ErrorType>> {
+        // The outside positions use the reserved space if there's any, otherwise they fall back
+        // to the positions inside the plotting area
+        let (drawing_area, position, avoid) = match (&self.position, self.outside.as_ref()) {
+            (pos, Some((outside_pos, area))) if pos == outside_pos => (area, pos.clone(), None),
+            (SeriesLabelPosition::OutsideRight, _) => (
+                &self.area,
+                SeriesLabelPosition::UpperRight,
+                self.avoid.as_ref(),
+            ),
+            (SeriesLabelPosition::OutsideBottom, _) => (
+                &self.area,
+                SeriesLabelPosition::LowerMiddle,
+                self.avoid.as_ref(),
+            ),
+            (pos, _) => (&self.area, pos.clone(), self.avoid.as_ref()),
+        };
 
         // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
         // resolved
@@ -236,10 +655,10 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        let mut label_element = MultiLineText::<_, Cow<str>>::new((0, 0), &font);
         let mut funcs = vec![];
 
-        for anno in self.target.series_anno.iter() {
+        for anno in self.series_anno.iter() {
             let label_text = anno.get_label();
             let draw_func = anno.get_draw_func();
 
@@ -247,27 +666,65 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
                 continue;
             }
 
-            funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
+            funcs.push((
+                draw_func.unwrap_or(&|p: BackendCoord, _| EmptyElement::at(p).into_dyn()),
+                anno.get_data_any(),
+            ));
             label_element.push_line(label_text);
         }
 
-        let (mut w, mut h) = label_element.estimate_dimension().map_err(|e| {
-            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-        })?;
+        let font_error =
+            |e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)));
+
+        let (mut w, mut h) = label_element.estimate_dimension().map_err(font_error)?;
+        let (series_w, series_h) = (w, h);
+
+        // The rows of the size legend, in which the title row has no bubble
+        let mut size_rows = vec![];
+        let mut legend_area_size = self.legend_area_size as i32;
+        if let Some(size_legend) = self.size_legend.as_ref() {
+            let title = size_legend.title.as_ref().map(|title| (title, None));
+            let entries = size_legend
+                .entries
+                .iter()
+                .map(|(l, r)| (l, Some(*r as i32)));
+            for (label, radius) in title.into_iter().chain(entries) {
+                let (tw, th) = font.font.box_size(label).map_err(font_error)?;
+                let row_h = (th as i32).max(radius.unwrap_or(0) * 2) + 4;
+                legend_area_size = legend_area_size.max(radius.unwrap_or(0) * 2 + 4);
+                size_rows.push((label, radius, tw as i32, row_h));
+                h += row_h;
+            }
+            for (_, radius, tw, _) in size_rows.iter() {
+                w = w.max(if radius.is_some() {
+                    *tw
+                } else {
+                    *tw - legend_area_size
+                });
+            }
+        }
 
         let margin = self.margin as i32;
 
-        w += self.legend_area_size as i32 + margin * 2;
+        if value_width > 0 {
+            w = w.max(series_w + margin + value_width);
+        }
+
+        w += legend_area_size + margin * 2;
         h += margin * 2;
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
-        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
+        let (label_x, mut label_y) = position.layout_label_area((w, h), (area_w, area_h));
+
+        // Move the label area below the caption placed inside the plotting area
+        if let Some(avoid) = avoid {
+            if Rect::from_size((label_x, label_y), (w as u32, h as u32)).intersects(avoid) {
+                label_y = avoid.bottom_right().1 + 5;
+            }
+        }
 
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
+        label_element.relocate((label_x + legend_area_size + margin, label_y + margin));
 
         drawing_area.draw(&Rectangle::new(
             [(label_x, label_y), (label_x + w, label_y + h)],
@@ -279,18 +736,102 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         ))?;
         drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
+        let (base_x, base_y) = drawing_area.get_base_pixel();
+        let value_x = base_x + label_x + margin + legend_area_size + series_w + margin;
+        let mut regions = vec![];
+        for (((_, y0), (_, y1)), (make_elem, data)) in label_element
             .compute_line_layout()
-            .map_err(|e| {
-                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-            })?
+            .map_err(font_error)?
             .into_iter()
             .zip(funcs.into_iter())
         {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
+            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2), data);
             drawing_area.draw(&legend_element)?;
+            if value_width > 0 {
+                regions.push(Rect::new(
+                    (value_x, base_y + y0),
+                    (value_x + value_width, base_y + y1),
+                ));
+            }
+        }
+
+        if let Some(size_legend) = self.size_legend.as_ref() {
+            let left_style = font.pos(Pos::new(HPos::Left, VPos::Center));
+            let mut y = label_y + margin + series_h;
+            for (label, radius, _, row_h) in size_rows {
+                let cy = y + row_h / 2;
+                if let Some(radius) = radius {
+                    let cx = label_x + margin + legend_area_size / 2;
+                    drawing_area.draw(&Circle::new((cx, cy), radius, size_legend.style))?;
+                    let pos = (label_x + margin + legend_area_size, cy);
+                    drawing_area.draw(&Text::new(label.as_str(), pos, &left_style))?;
+                } else {
+                    let pos = (label_x + margin, cy);
+                    drawing_area.draw(&Text::new(label.as_str(), pos, &left_style))?;
+                }
+                y += row_h;
+            }
         }
 
-        Ok(())
+        Ok((regions, font))
+    }
+}
+
+/**
+The cached layout of the value column of the legend, which is created by
+[`SeriesLabelStyle::draw_with_values()`].
+
+Updating the values only redraws the value regions, and the updated regions are returned, thus
+they can be used as the dirty regions when the frame is partially presented, for example, by
+copying only the changed part of the bitmap to the window.
+*/
+pub struct LegendValues {
+    regions: Vec<Rect>,
+    background: ShapeStyle,
+    // The label font is owned, thus the values don't borrow the chart
+    font: OwnedTextStyle,
+}
+
+impl LegendValues {
+    /**
+    Gets the value regions, one for each series shown in the legend, in the order of the legend
+    rows.
+
+    - **returns**: The value regions in the backend coordinates
+    */
+    pub fn regions(&self) -> &[Rect] {
+        &self.regions
+    }
+
+    /**
+    Redraws the values of the series. The value regions are cleared with the background of the
+    legend before the new values are drawn.
+
+    - `area`: Any drawing area on the backend which the legend is drawn on, the values are
+      clipped to this area
+    - `values`: The values of the series in the order of the legend rows, the extra values are
+      ignored and the rows without a value are cleared
+    - **returns**: The regions that are redrawn, in the backend coordinates
+    */
+    pub fn update<DB: DrawingBackend, CT: CoordTranslate, S: AsRef<str>>(
+        &self,
+        area: &DrawingArea<DB, CT>,
+        values: &[S],
+    ) -> Result<Vec<Rect>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = area.use_screen_coord();
+        let style = self.font.get().pos(Pos::new(HPos::Left, VPos::Center));
+        for (idx, region) in self.regions.iter().enumerate() {
+            let (x0, y0) = region.upper_left();
+            let (x1, y1) = region.bottom_right();
+            area.draw(&Rectangle::new(
+                [(x0, y0), (x1 - 1, y1 - 1)],
+                self.background,
+            ))?;
+            if let Some(value) = values.get(idx) {
+                let pos = (x0, region.center().1);
+                area.draw(&Text::new(value.as_ref(), pos, &style))?;
+            }
+        }
+        Ok(self.regions.clone())
     }
 }
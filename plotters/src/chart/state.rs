@@ -3,6 +3,7 @@ use std::sync::Arc;
 use super::ChartContext;
 use crate::coord::{CoordTranslate, Shift};
 use crate::drawing::DrawingArea;
+use crate::style::OwnedTextStyle;
 use plotters_backend::DrawingBackend;
 
 /// A chart context state - This is the data that is needed to reconstruct the chart context
@@ -36,6 +37,7 @@ pub struct ChartState<CT: CoordTranslate> {
     drawing_area_pos: (i32, i32),
     drawing_area_size: (u32, u32),
     coord: CT,
+    label_area_style: [Option<OwnedTextStyle>; 4],
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>> for ChartState<CT> {
@@ -44,6 +46,7 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>>
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
             coord: chart.drawing_area.into_coord_spec(),
+            label_area_style: chart.label_area_style,
         }
     }
 }
@@ -64,6 +67,7 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
             drawing_area_pos: self.drawing_area_pos,
             drawing_area_size: self.drawing_area.dim_in_pixel(),
             coord: Arc::new(self.drawing_area.into_coord_spec()),
+            label_area_style: self.label_area_style,
         }
     }
 }
@@ -78,6 +82,7 @@ where
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
             coord: chart.drawing_area.as_coord_spec().clone(),
+            label_area_style: chart.label_area_style.clone(),
         }
     }
 }
@@ -90,6 +95,48 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate + Clone> ChartContext<'a, DB, CT
 }
 
 impl<CT: CoordTranslate> ChartState<CT> {
+    /// Get the coordinate spec of the chart, which maps the guest coordinates to the pixels of
+    /// the backend the chart was built on.
+    ///
+    /// This is a stable API for doing the coordinate math of a chart without drawing anything,
+    /// for example, a server that maps many point sets into the pixel space of a chart rendered
+    /// once, or that finds the data point under the mouse cursor. The spec translates points with
+    /// [CoordTranslate::translate](../coord/trait.CoordTranslate.html#tymethod.translate), and
+    /// maps the pixels back with
+    /// [ReverseCoordTranslate::reverse_translate](../coord/trait.ReverseCoordTranslate.html#tymethod.reverse_translate)
+    /// if the underlying ranges are reversible.
+    ///
+    /// ```rust
+    /// use plotters::coord::ReverseCoordTranslate;
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("coord_spec.svg", (640, 480)).into_drawing_area();
+    /// let state = ChartBuilder::on(&root)
+    ///     .margin(10)
+    ///     .set_all_label_area_size(40)
+    ///     .build_cartesian_2d(0.0..100.0, 0.0..1.0)
+    ///     .unwrap()
+    ///     .into_chart_state();
+    ///
+    /// let coord = state.coord_spec();
+    /// let pixels: Vec<_> = [(0.0, 0.0), (50.0, 0.5), (100.0, 1.0)]
+    ///     .iter()
+    ///     .map(|point| coord.translate(point))
+    ///     .collect();
+    /// assert_eq!(pixels[0], (50, 429));
+    /// let (x, y): (f64, f64) = coord.reverse_translate(pixels[1]).unwrap();
+    /// assert!((x - 50.0).abs() < 0.5 && (y - 0.5).abs() < 0.01);
+    /// ```
+    pub fn coord_spec(&self) -> &CT {
+        &self.coord
+    }
+
+    /// Convert the chart state into its coordinate spec, see
+    /// [ChartState::coord_spec](#method.coord_spec) for details
+    pub fn into_coord_spec(self) -> CT {
+        self.coord
+    }
+
     /// Restore the chart context on the given drawing area
     ///
     /// - `area`: The given drawing area where we want to restore the chart context
@@ -107,6 +154,12 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            label_area_style: self.label_area_style,
+            caption: None,
+            legend_area: None,
+            colorbar_area: None,
+            clip_series: true,
+            content_drawn: false,
         }
     }
 }
@@ -0,0 +1,606 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::{ChartBuilder, ChartContext};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::types::RangedCoordf64;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Circle, PathElement, Polygon, Rectangle};
+use crate::style::{Color, RGBAColor, BLACK, BLUE};
+
+use plotters_backend::DrawingBackend;
+
+/// The chart context of a single panel of a figure template
+pub type PanelContext<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
+/// The panels of a figure with the marginal distributions of the data, see
+/// [FigureTemplate::scatter_with_marginals](struct.FigureTemplate.html#method.scatter_with_marginals)
+/// and [FigureTemplate::joint_density](struct.FigureTemplate.html#method.joint_density).
+///
+/// The X axis of the top panel is the X axis of the main panel, and the Y axis of the right panel
+/// is the Y axis of the main panel, both with the same pixel ranges, thus anything drawn on the
+/// panels lines up.
+pub struct MarginalCharts<'a, DB: DrawingBackend> {
+    /// The main panel, which shows the joint distribution
+    pub main: PanelContext<'a, DB>,
+    /// The panel above the main panel, which shows the distribution of X
+    pub top: PanelContext<'a, DB>,
+    /// The panel at the right of the main panel, which shows the distribution of Y
+    pub right: PanelContext<'a, DB>,
+}
+
+/// The panels of a fit with its residuals below, see
+/// [FigureTemplate::residual_plot](struct.FigureTemplate.html#method.residual_plot).
+///
+/// Both panels share the same X axis, with the same pixel range.
+pub struct ResidualCharts<'a, DB: DrawingBackend> {
+    /// The panel showing the data and the fitted curve
+    pub fit: PanelContext<'a, DB>,
+    /// The panel below the fit, showing the residuals of the data
+    pub residual: PanelContext<'a, DB>,
+}
+
+/// The panels of a scatter plot matrix, see
+/// [FigureTemplate::scatter_matrix](struct.FigureTemplate.html#method.scatter_matrix).
+///
+/// The panels in the same column share the X axis and the panels in the same row share the Y
+/// axis, both with the same pixel ranges.
+pub struct ScatterMatrixCharts<'a, DB: DrawingBackend> {
+    /// The panels indexed by the row and then the column, the panel at the row `i` and the
+    /// column `j` plots the column `i` of the data against the column `j`
+    pub panels: Vec<Vec<PanelContext<'a, DB>>>,
+}
+
+/**
+Ready-made layouts of the common multi-panel scientific figures.
+
+Each template splits the drawing area into panels, computes the ranges from the data, wires the
+shared axes, draws the data and the meshes, and then returns the chart contexts of the panels,
+which can be used for further drawing, such as fitted curves or annotations.
+
+```rust
+use plotters::chart::FigureTemplate;
+use plotters::prelude::*;
+
+let data: Vec<(f64, f64)> = (0..100)
+    .map(|i| {
+        let x = i as f64 / 10.0;
+        (x, x.sin() + (i % 7) as f64 / 10.0)
+    })
+    .collect();
+
+let root = SVGBackend::new("marginals.svg", (640, 640)).into_drawing_area();
+let mut charts = FigureTemplate::new()
+    .bins(15)
+    .scatter_with_marginals(&root, &data)
+    .unwrap();
+charts
+    .main
+    .draw_series(std::iter::once(PathElement::new(
+        vec![(0.0, 0.0), (10.0, 0.0)],
+        RED,
+    )))
+    .unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct FigureTemplate {
+    bins: usize,
+    color: RGBAColor,
+    label_area_size: u32,
+    panel_ratio: f64,
+}
+
+impl Default for FigureTemplate {
+    fn default() -> Self {
+        Self {
+            bins: 20,
+            color: BLUE.to_rgba(),
+            label_area_size: 40,
+            panel_ratio: 0.25,
+        }
+    }
+}
+
+impl FigureTemplate {
+    /// Create a new figure template with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of bins of the histograms and of the density plot along the X axis
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = bins.max(1);
+        self
+    }
+
+    /// Set the color of the data
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = color.to_rgba();
+        self
+    }
+
+    /// Set the size of the label areas of the panels in pixels
+    pub fn label_area_size(mut self, size: u32) -> Self {
+        self.label_area_size = size;
+        self
+    }
+
+    /// Set the size of the marginal panels and of the residual panel, as the ratio to the size of
+    /// the whole figure
+    pub fn panel_ratio(mut self, ratio: f64) -> Self {
+        self.panel_ratio = ratio.clamp(0.05, 0.95);
+        self
+    }
+
+    /// Draw a scatter plot with the histograms of X and Y on its margins
+    ///
+    /// - `area`: The drawing area of the whole figure
+    /// - `data`: The data points, the points with non-finite values are ignored
+    /// - **returns**: The chart contexts of the panels
+    pub fn scatter_with_marginals<'a, DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        data: &[(f64, f64)],
+    ) -> Result<MarginalCharts<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let data = finite_points(data);
+        let mut charts = self.build_marginals(area, &data)?;
+        let style = self.color.filled();
+        charts
+            .main
+            .draw_series(data.iter().map(|&(x, y)| Circle::new((x, y), 2, style)))?;
+        Ok(charts)
+    }
+
+    /// Draw a joint density plot of the data, which counts the data points in hexagonal bins, with
+    /// the histograms of X and Y on its margins. The denser bins are drawn with more opaque colors.
+    ///
+    /// - `area`: The drawing area of the whole figure
+    /// - `data`: The data points, the points with non-finite values are ignored
+    /// - **returns**: The chart contexts of the panels
+    pub fn joint_density<'a, DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        data: &[(f64, f64)],
+    ) -> Result<MarginalCharts<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let data = finite_points(data);
+        let mut charts = self.build_marginals(area, &data)?;
+
+        let (x, y) = (charts.main.x_range(), charts.main.y_range());
+        let (w, h) = charts.main.plotting_area().dim_in_pixel();
+        let (w, h) = (f64::from(w.max(1)), f64::from(h.max(1)));
+        // The bins are regular hexagons in pixels, with the pointy top
+        let radius = w / self.bins as f64 / 3f64.sqrt();
+
+        let mut counts = HashMap::new();
+        for &(px, py) in data.iter() {
+            let u = (px - x.start) / (x.end - x.start) * w;
+            let v = (py - y.start) / (y.end - y.start) * h;
+            *counts.entry(hex_bin(u, v, radius)).or_insert(0usize) += 1;
+        }
+        let max_count = counts.values().copied().max().unwrap_or(1) as f64;
+
+        let mut bins: Vec<_> = counts.into_iter().collect();
+        bins.sort_unstable();
+        charts
+            .main
+            .draw_series(bins.into_iter().map(|((q, r), count)| {
+                let (cu, cv) = (
+                    radius * 3f64.sqrt() * (q as f64 + r as f64 / 2.0),
+                    radius * 1.5 * r as f64,
+                );
+                let points = (0..6)
+                    .map(|i| {
+                        let angle = (30.0 + 60.0 * i as f64).to_radians();
+                        let u = (cu + radius * angle.cos()).clamp(0.0, w);
+                        let v = (cv + radius * angle.sin()).clamp(0.0, h);
+                        (
+                            x.start + u / w * (x.end - x.start),
+                            y.start + v / h * (y.end - y.start),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let alpha = 0.15 + 0.85 * count as f64 / max_count;
+                Polygon::new(points, self.color.mix(alpha).filled())
+            }))?;
+
+        Ok(charts)
+    }
+
+    /// Draw the data with a fitted curve, and the residuals of the data below, sharing the X axis
+    ///
+    /// - `area`: The drawing area of the whole figure
+    /// - `data`: The data points, the points with non-finite values are ignored
+    /// - `fit`: The fitted function
+    /// - **returns**: The chart contexts of the panels
+    pub fn residual_plot<'a, DB: DrawingBackend, F: Fn(f64) -> f64>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        data: &[(f64, f64)],
+        fit: F,
+    ) -> Result<ResidualCharts<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let data = finite_points(data);
+        let x = padded_range(data.iter().map(|p| p.0));
+        let curve: Vec<_> = (0..=200)
+            .map(|i| x.start + (x.end - x.start) * i as f64 / 200.0)
+            .map(|px| (px, fit(px)))
+            .filter(|p| p.1.is_finite())
+            .collect();
+        let y = padded_range(data.iter().chain(curve.iter()).map(|p| p.1));
+        let residuals: Vec<_> = data
+            .iter()
+            .map(|&(px, py)| (px, py - fit(px)))
+            .filter(|p| p.1.is_finite())
+            .collect();
+        let max_residual = residuals.iter().fold(0.0f64, |m, p| m.max(p.1.abs()));
+        let max_residual = if max_residual > 0.0 {
+            max_residual * 1.1
+        } else {
+            1.0
+        };
+
+        let (_, h) = area.dim_in_pixel();
+        let (upper, lower) =
+            area.split_vertically(h - (f64::from(h) * self.panel_ratio).round() as u32);
+
+        let mut fit_chart = ChartBuilder::on(&upper)
+            .y_label_area_size(self.label_area_size)
+            .build_cartesian_2d(x.clone(), y)?;
+        fit_chart.configure_mesh().draw()?;
+
+        let mut residual = ChartBuilder::on(&lower)
+            .x_label_area_size(self.label_area_size)
+            .y_label_area_size(self.label_area_size)
+            .build_cartesian_2d(x.clone(), -max_residual..max_residual)?;
+        residual.configure_mesh().y_labels(5).draw()?;
+
+        let style = self.color.filled();
+        fit_chart.draw_series(data.iter().map(|&(px, py)| Circle::new((px, py), 2, style)))?;
+        fit_chart.draw_series(std::iter::once(PathElement::new(curve, BLACK)))?;
+        residual.draw_series(std::iter::once(PathElement::new(
+            vec![(x.start, 0.0), (x.end, 0.0)],
+            BLACK,
+        )))?;
+        residual.draw_series(
+            residuals
+                .into_iter()
+                .map(|(px, py)| Circle::new((px, py), 2, style)),
+        )?;
+
+        Ok(ResidualCharts {
+            fit: fit_chart,
+            residual,
+        })
+    }
+
+    /// Draw a scatter plot matrix, also known as a pairs plot, which plots each pair of the data
+    /// columns in a grid, with the histograms of the columns on the diagonal. The panels in the
+    /// same column or row share the range of the column, and the tick labels are only drawn on the
+    /// outer edges of the grid, at the left and the bottom.
+    ///
+    /// The histograms on the diagonal are scaled to the height of the panel, so the Y axis of the
+    /// row still applies to the other panels in the row.
+    ///
+    /// ```rust
+    /// use plotters::chart::FigureTemplate;
+    /// use plotters::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+    /// let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+    /// let z: Vec<f64> = x.iter().map(|x| x * x).collect();
+    ///
+    /// let root = SVGBackend::new("scatter_matrix.svg", (640, 640)).into_drawing_area();
+    /// let charts = FigureTemplate::new()
+    ///     .scatter_matrix(&root, &["x", "sin(x)", "x^2"], &[x, y, z])
+    ///     .unwrap();
+    /// assert_eq!(charts.panels.len(), 3);
+    /// ```
+    ///
+    /// - `area`: The drawing area of the whole figure
+    /// - `names`: The names of the columns, which are drawn as the axis descriptions
+    /// - `columns`: The data columns, each value of a row of the data is at the same index of the
+    ///   columns, the rows with non-finite values are ignored in the panels of those columns
+    /// - **returns**: The chart contexts of the panels
+    pub fn scatter_matrix<'a, DB: DrawingBackend, C: AsRef<[f64]>>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        names: &[&str],
+        columns: &[C],
+    ) -> Result<ScatterMatrixCharts<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let k = columns.len();
+        let ranges: Vec<_> = columns
+            .iter()
+            .map(|c| padded_range(c.as_ref().iter().copied()))
+            .collect();
+
+        // The label areas are only at the left and the bottom of the grid
+        let (w, h) = area.dim_in_pixel();
+        let label = self.label_area_size;
+        let cells = k.max(1) as u32;
+        let (cw, ch) = (
+            w.saturating_sub(label) / cells,
+            h.saturating_sub(label) / cells,
+        );
+        let xs: Vec<_> = (1..cells).map(|i| label + cw * i).collect();
+        let ys: Vec<_> = (1..cells).map(|i| ch * i).collect();
+        let cells = area.split_by_breakpoints(xs, ys);
+
+        let point_style = self.color.filled();
+        let bar_style = self.color.mix(0.6).filled();
+        let mut panels: Vec<Vec<PanelContext<'a, DB>>> = vec![];
+        for (idx, cell) in cells.iter().enumerate().take(k * k) {
+            let (row, col) = (idx / k, idx % k);
+            let mut chart = ChartBuilder::on(cell)
+                .margin(3)
+                .x_label_area_size(if row + 1 == k { label } else { 0 })
+                .y_label_area_size(if col == 0 { label } else { 0 })
+                .build_cartesian_2d(ranges[col].clone(), ranges[row].clone())?;
+            let mut mesh = chart.configure_mesh();
+            mesh.x_labels(5).y_labels(5);
+            if let Some(name) = names.get(col) {
+                mesh.x_desc(*name);
+            }
+            if let Some(name) = names.get(row) {
+                mesh.y_desc(*name);
+            }
+            mesh.draw()?;
+
+            let (x, y) = (&ranges[col], &ranges[row]);
+            if row == col {
+                let counts = histogram(columns[col].as_ref().iter().copied(), x, self.bins);
+                let max_count = counts.iter().copied().max().unwrap_or(0).max(1) as f64;
+                let size = (x.end - x.start) / self.bins as f64;
+                chart.draw_series(
+                    counts
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, &count)| count > 0)
+                        .map(|(idx, &count)| {
+                            let x0 = x.start + size * idx as f64;
+                            let top = y.start + (y.end - y.start) * count as f64 / max_count;
+                            Rectangle::new([(x0, y.start), (x0 + size, top)], bar_style)
+                        }),
+                )?;
+            } else {
+                let points = columns[col].as_ref().iter().zip(columns[row].as_ref());
+                chart.draw_series(
+                    points
+                        .filter(|(px, py)| px.is_finite() && py.is_finite())
+                        .map(|(&px, &py)| Circle::new((px, py), 2, point_style)),
+                )?;
+            }
+
+            if col == 0 {
+                panels.push(vec![]);
+            }
+            if let Some(panel_row) = panels.last_mut() {
+                panel_row.push(chart);
+            }
+        }
+
+        Ok(ScatterMatrixCharts { panels })
+    }
+
+    /// Build the panels of the marginal layouts and draw the marginal histograms
+    fn build_marginals<'a, DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        data: &[(f64, f64)],
+    ) -> Result<MarginalCharts<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let x = padded_range(data.iter().map(|p| p.0));
+        let y = padded_range(data.iter().map(|p| p.1));
+        let x_counts = histogram(data.iter().map(|p| p.0), &x, self.bins);
+        let y_counts = histogram(data.iter().map(|p| p.1), &y, self.bins);
+        let count_range =
+            |counts: &[usize]| 0.0..(counts.iter().copied().max().unwrap_or(0).max(1) as f64 * 1.1);
+
+        let (w, h) = area.dim_in_pixel();
+        let (mw, mh) = (
+            (f64::from(w) * self.panel_ratio).round() as u32,
+            (f64::from(h) * self.panel_ratio).round() as u32,
+        );
+        let panels = area.split_by_breakpoints([w - mw], [mh]);
+
+        // The label areas are sized the same on the shared sides, thus the panels line up
+        let mut main = ChartBuilder::on(&panels[2])
+            .x_label_area_size(self.label_area_size)
+            .y_label_area_size(self.label_area_size)
+            .build_cartesian_2d(x.clone(), y.clone())?;
+        main.configure_mesh().draw()?;
+
+        let mut top = ChartBuilder::on(&panels[0])
+            .y_label_area_size(self.label_area_size)
+            .build_cartesian_2d(x.clone(), count_range(&x_counts))?;
+        top.configure_mesh().disable_mesh().y_labels(3).draw()?;
+
+        let mut right = ChartBuilder::on(&panels[3])
+            .x_label_area_size(self.label_area_size)
+            .build_cartesian_2d(count_range(&y_counts), y.clone())?;
+        right.configure_mesh().disable_mesh().x_labels(3).draw()?;
+
+        let style = self.color.mix(0.6).filled();
+        let bin_range = |range: &Range<f64>, idx: usize| {
+            let size = (range.end - range.start) / self.bins as f64;
+            (
+                range.start + size * idx as f64,
+                range.start + size * (idx + 1) as f64,
+            )
+        };
+        top.draw_series(
+            x_counts
+                .iter()
+                .enumerate()
+                .filter(|(_, &count)| count > 0)
+                .map(|(idx, &count)| {
+                    let (x0, x1) = bin_range(&x, idx);
+                    Rectangle::new([(x0, 0.0), (x1, count as f64)], style)
+                }),
+        )?;
+        right.draw_series(
+            y_counts
+                .iter()
+                .enumerate()
+                .filter(|(_, &count)| count > 0)
+                .map(|(idx, &count)| {
+                    let (y0, y1) = bin_range(&y, idx);
+                    Rectangle::new([(0.0, y0), (count as f64, y1)], style)
+                }),
+        )?;
+
+        Ok(MarginalCharts { main, top, right })
+    }
+}
+
+/// Drop the points with non-finite values
+fn finite_points(data: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    data.iter()
+        .copied()
+        .filter(|p| p.0.is_finite() && p.1.is_finite())
+        .collect()
+}
+
+/// Compute the range of the values with 5% of padding on both sides
+fn padded_range<I: Iterator<Item = f64>>(values: I) -> Range<f64> {
+    let (min, max) = values
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+    if min > max {
+        return 0.0..1.0;
+    }
+    if max - min <= f64::EPSILON * max.abs().max(1.0) {
+        return min - 0.5..max + 0.5;
+    }
+    let pad = (max - min) * 0.05;
+    min - pad..max + pad
+}
+
+/// Count the values in the evenly split bins of the range
+fn histogram<I: Iterator<Item = f64>>(values: I, range: &Range<f64>, bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    for v in values {
+        let idx = ((v - range.start) / (range.end - range.start) * bins as f64).floor();
+        if idx >= 0.0 && idx < bins as f64 {
+            counts[idx as usize] += 1;
+        }
+    }
+    counts
+}
+
+/// Find the axial coordinate of the pointy-top hexagon containing the point
+fn hex_bin(u: f64, v: f64, radius: f64) -> (i64, i64) {
+    let q = (3f64.sqrt() / 3.0 * u - v / 3.0) / radius;
+    let r = (2.0 / 3.0 * v) / radius;
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    (rq as i64, rr as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hex_bin() {
+        assert_eq!(hex_bin(0.0, 0.0, 10.0), (0, 0));
+        assert_eq!(hex_bin(3f64.sqrt() * 10.0, 0.0, 10.0), (1, 0));
+        assert_eq!(hex_bin(3f64.sqrt() * 5.0, 15.0, 10.0), (0, 1));
+        assert_eq!(
+            histogram(vec![0.0, 0.5, 0.99, 1.0].into_iter(), &(0.0..1.0), 2),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn test_marginal_charts_line_up() {
+        let data: Vec<_> = (0..50).map(|i| (i as f64, (i % 10) as f64)).collect();
+        let da = crate::create_mocked_drawing_area(400, 400, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 50);
+            });
+        });
+        let charts = FigureTemplate::new()
+            .bins(10)
+            .scatter_with_marginals(&da, &data)
+            .unwrap();
+
+        assert_eq!(charts.main.x_range(), charts.top.x_range());
+        assert_eq!(charts.main.y_range(), charts.right.y_range());
+        let main = charts.main.plotting_area().get_pixel_range();
+        assert_eq!(main.0, charts.top.plotting_area().get_pixel_range().0);
+        assert_eq!(main.1, charts.right.plotting_area().get_pixel_range().1);
+    }
+
+    #[test]
+    fn test_joint_density() {
+        // All the points fall in two bins
+        let data: Vec<_> = (0..30).map(|i| ((i % 2) as f64, (i % 2) as f64)).collect();
+        let da = crate::create_mocked_drawing_area(400, 400, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_circle_call, 0);
+            });
+        });
+        FigureTemplate::new().joint_density(&da, &data).unwrap();
+    }
+
+    #[test]
+    fn test_scatter_matrix() {
+        let columns: Vec<Vec<f64>> = vec![
+            (0..20).map(|i| i as f64).collect(),
+            (0..20).map(|i| (i % 5) as f64).collect(),
+            (0..20)
+                .map(|i| if i == 3 { f64::NAN } else { -i as f64 })
+                .collect(),
+        ];
+        let da = crate::create_mocked_drawing_area(600, 600, |m| {
+            m.drop_check(|b| {
+                // Each pair of the columns is plotted twice, the row with NaN is dropped
+                assert_eq!(b.num_draw_circle_call, 2 * (20 + 19 + 19));
+            });
+        });
+        let charts = FigureTemplate::new()
+            .scatter_matrix(&da, &["a", "b", "c"], &columns)
+            .unwrap();
+
+        let panels = &charts.panels;
+        assert_eq!(panels.len(), 3);
+        assert!(panels.iter().all(|row| row.len() == 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                let panel = &panels[i][j];
+                assert_eq!(panel.x_range(), panels[0][j].x_range());
+                assert_eq!(panel.y_range(), panels[i][0].y_range());
+                let (x, y) = panel.plotting_area().get_pixel_range();
+                let (x0, _) = panels[0][j].plotting_area().get_pixel_range();
+                let (_, y0) = panels[i][0].plotting_area().get_pixel_range();
+                assert_eq!((x, y), (x0, y0));
+            }
+        }
+        assert_eq!(panels[1][1].x_range(), -0.2..4.2);
+    }
+
+    #[test]
+    fn test_residual_plot() {
+        let data: Vec<_> = (0..20).map(|i| (i as f64, 2.0 * i as f64 + 1.0)).collect();
+        let da = crate::create_mocked_drawing_area(400, 400, |_| {});
+        let charts = FigureTemplate::new()
+            .residual_plot(&da, &data, |x| 2.0 * x)
+            .unwrap();
+
+        assert_eq!(charts.fit.x_range(), charts.residual.x_range());
+        assert_eq!(
+            charts.fit.plotting_area().get_pixel_range().0,
+            charts.residual.plotting_area().get_pixel_range().0
+        );
+        assert_eq!(charts.residual.y_range(), -1.1..1.1);
+    }
+}
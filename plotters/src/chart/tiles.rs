@@ -0,0 +1,290 @@
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use super::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::BitMapElement;
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+// The latitude limit of the Web Mercator projection, which makes the world a square
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+// The deepest zoom level whose tile columns and rows all fit in an u32
+const MAX_TILE_ZOOM: u32 = 31;
+
+/// The address of a raster map tile in the Web Mercator tiling scheme used by most of the online
+/// maps (also known as the "slippy map" scheme). At zoom level `z`, the world is split into
+/// `2^z * 2^z` tiles, `x` counts from the west and `y` counts from the north.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TileId {
+    /// The zoom level
+    pub zoom: u32,
+    /// The column of the tile, from the west
+    pub x: u32,
+    /// The row of the tile, from the north
+    pub y: u32,
+}
+
+impl TileId {
+    /// Find the tile containing the given location
+    ///
+    /// - `lon`, `lat`: The location in degrees, the latitude is clamped to the range covered by
+    ///   the tiles
+    /// - `zoom`: The zoom level, the levels deeper than 31 are clamped to 31
+    /// - **returns**: The tile containing the location
+    pub fn containing(lon: f64, lat: f64, zoom: u32) -> Self {
+        let zoom = zoom.min(MAX_TILE_ZOOM);
+        let n = f64::from(1u32 << zoom);
+        let last = (1u32 << zoom) - 1;
+        let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+        let x = ((lon + 180.0) / 360.0 * n).floor();
+        let y = ((1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0 * n).floor();
+        Self {
+            zoom,
+            x: (x.max(0.0) as u32).min(last),
+            y: (y.max(0.0) as u32).min(last),
+        }
+    }
+
+    /// Get the area covered by the tile, the zoom levels deeper than 31 are treated as 31
+    ///
+    /// - **returns**: The longitude range and the latitude range in degrees, from the west to the
+    ///   east and from the south to the north
+    pub fn bounds(&self) -> (Range<f64>, Range<f64>) {
+        let n = f64::from(1u32 << self.zoom.min(MAX_TILE_ZOOM));
+        let lon = |x: f64| x / n * 360.0 - 180.0;
+        let lat = |y: f64| (PI * (1.0 - 2.0 * y / n)).sinh().atan().to_degrees();
+        let (x, y) = (f64::from(self.x), f64::from(self.y));
+        (lon(x)..lon(x + 1.0), lat(y + 1.0)..lat(y))
+    }
+}
+
+/**
+A layer of raster map tiles which is composed as the background of a chart whose X axis is the
+longitude and Y axis is the latitude, both in degrees.
+
+For the current view of the chart, the layer picks the zoom level that gives at least one tile
+pixel per screen pixel, requests the tiles covering the view from the user-provided loader and
+draws each of them stretched to its area. The loader is responsible for fetching or caching the
+tiles, and it may return `None` for a missing tile, which is skipped.
+
+Each tile is stretched linearly, while the tile images are in the Web Mercator projection, thus
+the latitude inside a tile is slightly off when the view spans a large range of latitudes with
+only a few tiles.
+
+```rust
+use plotters::chart::{TileId, TileLayer};
+use plotters::prelude::*;
+
+let mut buffer = vec![0; 640 * 480 * 3];
+let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+let mut chart = ChartBuilder::on(&root)
+    .build_cartesian_2d(-10.0..30.0, 35.0..60.0)
+    .unwrap();
+
+// A real loader would read the tile images from the disk or a cache
+let mut tiles = TileLayer::new(|tile: TileId| {
+    let shade = if (tile.x + tile.y) % 2 == 0 { 200 } else { 240 };
+    BitMapElement::with_owned_buffer((0, 0), (1, 1), vec![shade; 3])
+});
+chart.draw_tile_layer(&mut tiles).unwrap();
+chart
+    .draw_series(std::iter::once(Circle::new((2.35, 48.85), 5, RED.filled())))
+    .unwrap();
+```
+*/
+pub struct TileLayer<F> {
+    loader: F,
+    tile_size: u32,
+    max_zoom: u32,
+}
+
+impl<F> TileLayer<F>
+where
+    F: FnMut(TileId) -> Option<BitMapElement<'static, BackendCoord>>,
+{
+    /// Create a new tile layer
+    ///
+    /// - `loader`: The function that loads the image of the given tile
+    /// - **returns**: The tile layer, with 256 pixel tiles and the maximum zoom level of 19
+    pub fn new(loader: F) -> Self {
+        Self {
+            loader,
+            tile_size: 256,
+            max_zoom: 19,
+        }
+    }
+
+    /// Set the size of the tile images in pixels, which is used to pick the zoom level
+    pub fn tile_size(mut self, size: u32) -> Self {
+        self.tile_size = size.max(1);
+        self
+    }
+
+    /// Set the maximum zoom level provided by the tile source
+    pub fn max_zoom(mut self, zoom: u32) -> Self {
+        self.max_zoom = zoom.min(30);
+        self
+    }
+
+    /// Compute the zoom level for the view
+    ///
+    /// - `lon`: The longitude range of the view in degrees
+    /// - `width`: The width of the view in pixels
+    /// - **returns**: The lowest zoom level giving at least one tile pixel per screen pixel,
+    ///   limited by the maximum zoom level
+    pub fn zoom_for_view(&self, lon: &Range<f64>, width: u32) -> u32 {
+        let span = (lon.end - lon.start).abs().min(360.0);
+        if span.is_nan() || span <= 0.0 {
+            return self.max_zoom;
+        }
+        let world_tiles = f64::from(width) * 360.0 / (span * f64::from(self.tile_size));
+        let zoom = world_tiles.log2().ceil().max(0.0);
+        (zoom as u32).min(self.max_zoom)
+    }
+
+    /// Compute the tiles covering the view
+    ///
+    /// - `lon`, `lat`: The longitude and latitude range of the view in degrees
+    /// - `size`: The size of the view in pixels
+    /// - **returns**: The tiles covering the view, row by row from the north-west
+    pub fn tiles_for_view(
+        &self,
+        lon: Range<f64>,
+        lat: Range<f64>,
+        size: (u32, u32),
+    ) -> Vec<TileId> {
+        let zoom = self.zoom_for_view(&lon, size.0);
+        let north_west = TileId::containing(lon.start.min(lon.end), lat.start.max(lat.end), zoom);
+        let south_east = TileId::containing(lon.start.max(lon.end), lat.start.min(lat.end), zoom);
+        (north_west.y..=south_east.y)
+            .flat_map(|y| (north_west.x..=south_east.x).map(move |x| TileId { zoom, x, y }))
+            .collect()
+    }
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    X: Ranged<ValueType = f64> + ValueFormatter<f64>,
+    Y: Ranged<ValueType = f64> + ValueFormatter<f64>,
+{
+    /// Draw the map tiles covering the current view as the background of the plotting area,
+    /// the X axis is the longitude and the Y axis is the latitude in degrees.
+    ///
+    /// Like [set_background_image](#method.set_background_image), this must be called before
+    /// the mesh and the series are drawn. See [TileLayer](struct.TileLayer.html) for details.
+    ///
+    /// - `layer`: The tile layer to draw
+    pub fn draw_tile_layer<F>(
+        &mut self,
+        layer: &mut TileLayer<F>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        F: FnMut(TileId) -> Option<BitMapElement<'static, BackendCoord>>,
+    {
+        let coord = self.as_coord_spec();
+        let (lon, lat) = (coord.get_x_range(), coord.get_y_range());
+        let size = self.plotting_area().dim_in_pixel();
+        for tile in layer.tiles_for_view(lon, lat, size) {
+            if let Some(image) = (layer.loader)(tile) {
+                self.set_background_image(&image, tile.bounds())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_tile_math() {
+        assert_eq!(
+            TileId::containing(0.0, 0.0, 0),
+            TileId {
+                zoom: 0,
+                x: 0,
+                y: 0
+            }
+        );
+        assert_eq!(
+            TileId::containing(2.35, 48.85, 10),
+            TileId {
+                zoom: 10,
+                x: 518,
+                y: 352
+            }
+        );
+        assert_eq!(
+            TileId::containing(180.0, -90.0, 2),
+            TileId {
+                zoom: 2,
+                x: 3,
+                y: 3
+            }
+        );
+
+        let (lon, lat) = TileId {
+            zoom: 1,
+            x: 1,
+            y: 0,
+        }
+        .bounds();
+        assert_eq!(lon, 0.0..180.0);
+        assert!(lat.start.abs() < 1e-9 && (lat.end - MAX_LATITUDE).abs() < 1e-9);
+
+        // The zoom levels whose tiles don't fit in an u32 are clamped
+        let deepest = TileId::containing(180.0, -90.0, 31);
+        assert_eq!((deepest.x, deepest.y), (u32::MAX >> 1, u32::MAX >> 1));
+        assert_eq!(TileId::containing(180.0, -90.0, 32), deepest);
+        assert_eq!(TileId::containing(180.0, -90.0, u32::MAX), deepest);
+        let (lon, _) = TileId {
+            zoom: 32,
+            x: u32::MAX,
+            y: u32::MAX,
+        }
+        .bounds();
+        assert!(lon.start.is_finite() && lon.start < lon.end);
+
+        let layer = TileLayer::new(|_| None).max_zoom(5);
+        assert_eq!(layer.zoom_for_view(&(-180.0..180.0), 256), 0);
+        assert_eq!(layer.zoom_for_view(&(-180.0..180.0), 512), 1);
+        assert_eq!(layer.zoom_for_view(&(0.0..1.0), 512), 5);
+        assert_eq!(
+            layer.tiles_for_view(-180.0..180.0, -80.0..80.0, (512, 512)),
+            [(0, 0), (1, 0), (0, 1), (1, 1)]
+                .iter()
+                .map(|&(x, y)| TileId { zoom: 1, x, y })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_draw_tile_layer() {
+        let mut buffer = vec![0; 100 * 100 * 3];
+        let mut requested = vec![];
+        {
+            let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(-180.0..180.0, -MAX_LATITUDE..MAX_LATITUDE)
+                .unwrap();
+            let mut layer = TileLayer::new(|tile: TileId| {
+                requested.push(tile);
+                let color = if tile.x == 0 { 255 } else { 128 };
+                BitMapElement::with_owned_buffer((0, 0), (1, 1), vec![color; 3])
+            })
+            .tile_size(64);
+            chart.draw_tile_layer(&mut layer).unwrap();
+        }
+
+        assert_eq!(requested.len(), 4);
+        assert_eq!(&buffer[0..3], &[255, 255, 255]);
+        assert_eq!(&buffer[(99 * 100 + 99) * 3..], &[128, 128, 128]);
+    }
+}
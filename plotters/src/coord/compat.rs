@@ -0,0 +1,71 @@
+/*!
+The compatibility shim for the code written against the 0.2 coordinate API.
+
+Plotters 0.3 moved the 1D coordinate types into [types](../types/index.html) and renamed the 2D
+coordinate `RangedCoord` to [Cartesian2d](../cartesian/struct.Cartesian2d.html). With the `compat`
+feature enabled, the old names are available again under their old paths as deprecated aliases,
+thus a large codebase is able to upgrade first and then migrate by following the deprecation
+warnings. Each warning names the one-to-one replacement.
+
+| 0.2 name | Replacement |
+|----------|-------------|
+| `coord::RangedCoord<X, Y>` | `coord::cartesian::Cartesian2d<X, Y>` |
+| `coord::RangedCoordf32` (and other numeric types) | `coord::types::RangedCoordf32` |
+| `ChartBuilder::build_ranged` | `ChartBuilder::build_cartesian_2d` |
+| `MeshStyle::line_style_1` | `MeshStyle::bold_line_style` |
+| `MeshStyle::line_style_2` | `MeshStyle::light_line_style` |
+*/
+#![allow(deprecated)]
+
+use super::cartesian::Cartesian2d;
+use super::types;
+
+/// The 0.2 name of the 2D Cartesian coordinate
+#[deprecated(note = "`RangedCoord` has been renamed to `plotters::coord::cartesian::Cartesian2d`")]
+pub type RangedCoord<X, Y> = Cartesian2d<X, Y>;
+
+macro_rules! compat_numeric_coord {
+    ($($name:ident => $note:literal),*) => {
+        $(
+            #[doc = concat!("The 0.2 path of [", stringify!($name), "](../types/struct.", stringify!($name), ".html)")]
+            #[deprecated(note = $note)]
+            pub type $name = types::$name;
+        )*
+    };
+}
+
+compat_numeric_coord!(
+    RangedCoordf32 => "`RangedCoordf32` has been moved to `plotters::coord::types::RangedCoordf32`",
+    RangedCoordf64 => "`RangedCoordf64` has been moved to `plotters::coord::types::RangedCoordf64`",
+    RangedCoordi32 => "`RangedCoordi32` has been moved to `plotters::coord::types::RangedCoordi32`",
+    RangedCoordi64 => "`RangedCoordi64` has been moved to `plotters::coord::types::RangedCoordi64`",
+    RangedCoordi128 => "`RangedCoordi128` has been moved to `plotters::coord::types::RangedCoordi128`",
+    RangedCoordisize => "`RangedCoordisize` has been moved to `plotters::coord::types::RangedCoordisize`",
+    RangedCoordu32 => "`RangedCoordu32` has been moved to `plotters::coord::types::RangedCoordu32`",
+    RangedCoordu64 => "`RangedCoordu64` has been moved to `plotters::coord::types::RangedCoordu64`",
+    RangedCoordu128 => "`RangedCoordu128` has been moved to `plotters::coord::types::RangedCoordu128`",
+    RangedCoordusize => "`RangedCoordusize` has been moved to `plotters::coord::types::RangedCoordusize`"
+);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_compat_names() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.0..1.0, 0..10)
+            .unwrap();
+        chart
+            .configure_mesh()
+            .line_style_1(BLACK)
+            .line_style_2(WHITE)
+            .draw()
+            .unwrap();
+
+        let coord: &super::RangedCoord<super::RangedCoordf64, super::RangedCoordi32> =
+            chart.plotting_area().as_coord_spec();
+        assert_eq!(coord.x_spec().range(), 0.0..1.0);
+    }
+}
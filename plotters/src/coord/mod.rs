@@ -52,8 +52,15 @@ pub mod cartesian {
     pub use super::ranged3d::Cartesian3d;
 }
 
+#[cfg(feature = "compat")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "compat")))]
+pub mod compat;
+#[cfg(feature = "compat")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "compat")))]
+pub use compat::*;
+
 mod translate;
-pub use translate::{CoordTranslate, ReverseCoordTranslate};
+pub use translate::{CoordTranslate, NonFinitePolicy, ReverseCoordTranslate};
 
 /// The coordinate translation that only impose shift
 #[derive(Debug, Clone)]
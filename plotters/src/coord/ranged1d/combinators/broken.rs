@@ -0,0 +1,249 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, BoldPoints, KeyPointHint, KeyPointWeight, LightPoints, NoDefaultFormatting,
+    Ranged, ReversibleRanged, ValueFormatter,
+};
+use std::ops::Range;
+
+/// The coordinate decorator that excludes the intervals between its segments from the axis, for
+/// example, an axis showing 0 to 10 and 1000 to 1010, which keeps both the regular data and the
+/// outliers readable.
+///
+/// Each segment takes a part of the axis proportional to its weight, which is 1 by default, and
+/// the segments are separated by a small gap. The mesh draws the break marks on the axis at the
+/// gaps, and the values in the excluded intervals are placed at the middle of the gap.
+///
+/// See [IntoBrokenAxis::break_to](trait.IntoBrokenAxis.html#method.break_to) for details.
+#[derive(Clone)]
+pub struct BrokenAxis<R: Ranged> {
+    segments: Vec<R>,
+    weights: Vec<f64>,
+    gap: u32,
+}
+
+impl<R: Ranged> BrokenAxis<R> {
+    /// Append another segment after a break, the segments should be in ascending order
+    ///
+    /// - `next`: The range of the segment
+    /// - **returns**: The coordinate spec with the new segment
+    pub fn break_to<S: Into<R>>(mut self, next: S) -> Self {
+        self.segments.push(next.into());
+        self
+    }
+
+    /// Set the weights of the segments, which decide the share of the axis taken by each segment.
+    /// The segments without a weight use weight 1.
+    ///
+    /// - `weights`: The weights of the segments, from the first segment to the last one
+    pub fn weights(mut self, weights: &[f64]) -> Self {
+        self.weights = weights.to_vec();
+        self
+    }
+
+    /// Set the gap between the adjacent segments in pixels, the default is 8
+    ///
+    /// - `gap`: The size of the gap
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Get the segments of the axis
+    pub fn segments(&self) -> &[R] {
+        &self.segments
+    }
+
+    fn weight(&self, idx: usize) -> f64 {
+        self.weights
+            .get(idx)
+            .copied()
+            .filter(|w| *w > 0.0)
+            .unwrap_or(1.0)
+    }
+
+    // The pixel limits of each segment, which split the limit of the axis by the weights
+    fn segment_limits(&self, limit: (i32, i32)) -> Vec<(i32, i32)> {
+        let n = self.segments.len() as i32;
+        let dir = if limit.0 <= limit.1 { 1 } else { -1 };
+        let gap = self.gap as i32;
+        let usable = f64::from(((limit.1 - limit.0).abs() - gap * (n - 1)).max(0));
+        let total: f64 = (0..self.segments.len()).map(|i| self.weight(i)).sum();
+
+        let mut acc = 0.0;
+        (0..self.segments.len())
+            .map(|i| {
+                let start = (acc / total * usable).round() as i32 + gap * i as i32;
+                acc += self.weight(i);
+                let end = (acc / total * usable).round() as i32 + gap * i as i32;
+                (limit.0 + dir * start, limit.0 + dir * end)
+            })
+            .collect()
+    }
+}
+
+/// The trait that allows a coordinate spec to be broken into segments. See
+/// [BrokenAxis](struct.BrokenAxis.html) for details.
+pub trait IntoBrokenAxis: AsRangedCoord {
+    /// Break the axis after this range, and continue with the next range.
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 1024 * 768 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+    ///
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .set_left_and_bottom_label_area_size(40)
+    ///     // The Y axis shows 0 to 10, and then 1000 to 1010 taking a quarter of the axis
+    ///     .build_cartesian_2d(0..10, (0.0..10.0).break_to(1000.0..1010.0).weights(&[3.0, 1.0]))
+    ///     .unwrap();
+    /// chart.configure_mesh().draw().unwrap();
+    /// chart
+    ///     .draw_series(LineSeries::new(vec![(0, 1.0), (5, 1005.0), (10, 3.0)], &RED))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `next`: The range after the break, which should be greater than this range
+    /// - **returns**: The broken coordinate spec
+    fn break_to<S: Into<Self::CoordDescType>>(self, next: S) -> BrokenAxis<Self::CoordDescType> {
+        BrokenAxis {
+            segments: vec![self.into(), next.into()],
+            weights: vec![],
+            gap: 8,
+        }
+    }
+}
+
+impl<T: AsRangedCoord> IntoBrokenAxis for T {}
+
+impl<R: Ranged> Ranged for BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let limits = self.segment_limits(limit);
+        let last = self.segments.len() - 1;
+        let idx = self
+            .segments
+            .iter()
+            .position(|segment| *value <= segment.range().end)
+            .unwrap_or(last);
+
+        // The value in the excluded interval is placed at the middle of the gap
+        if idx > 0 && *value < self.segments[idx].range().start {
+            return (limits[idx - 1].1 + limits[idx].0) / 2;
+        }
+        self.segments[idx].map(value, limits[idx])
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let total: f64 = (0..self.segments.len()).map(|i| self.weight(i)).sum();
+        let share =
+            |n: usize, idx: usize| ((n as f64 * self.weight(idx) / total).round() as usize).max(1);
+        self.segments
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, segment)| {
+                let max_points = share(hint.max_num_points(), idx);
+                match hint.weight() {
+                    KeyPointWeight::Bold => segment.key_points(BoldPoints(max_points)),
+                    KeyPointWeight::Any => segment
+                        .key_points(LightPoints::new(share(hint.bold_points(), idx), max_points)),
+                }
+            })
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        let start = self.segments[0].range().start;
+        let end = self.segments[self.segments.len() - 1].range().end;
+        start..end
+    }
+
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        self.segments[0].is_finite_value(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.segment_limits(limit)
+            .windows(2)
+            .map(|w| (w[0].1 + w[1].0) / 2)
+            .collect()
+    }
+}
+
+impl<R: ReversibleRanged> ReversibleRanged for BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        self.segments
+            .iter()
+            .zip(self.segment_limits(limit))
+            .find(|(_, (a, b))| (*a.min(b)..=*a.max(b)).contains(&input))
+            .and_then(|(segment, limit)| segment.unmap(input, limit))
+    }
+}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T> for BrokenAxis<R> {
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.segments[0].format_ext(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_broken_axis() {
+        let coord = (0.0..10.0).break_to(1000.0..1010.0).gap(10);
+        assert_eq!(coord.range(), 0.0..1010.0);
+        assert_eq!(coord.map(&0.0, (0, 110)), 0);
+        assert_eq!(coord.map(&10.0, (0, 110)), 50);
+        assert_eq!(coord.map(&1000.0, (0, 110)), 60);
+        assert_eq!(coord.map(&1010.0, (0, 110)), 110);
+        // The excluded interval collapses to the break
+        assert_eq!(coord.map(&500.0, (0, 110)), 55);
+        assert_eq!(coord.axis_breaks((0, 110)), [55]);
+        assert_eq!(coord.axis_breaks((110, 0)), [55]);
+        assert_eq!(coord.unmap(55, (0, 110)), None);
+        assert_eq!(coord.unmap(85, (0, 110)), Some(1005.0));
+
+        let key_points = coord.key_points(BoldPoints(6));
+        assert!(key_points.contains(&0.0) && key_points.contains(&1010.0));
+        assert!(key_points.iter().all(|v| *v <= 10.0 || *v >= 1000.0));
+
+        let coord = (0..10)
+            .break_to(100..110)
+            .break_to(1000..1010)
+            .weights(&[2.0]);
+        assert_eq!(coord.segments().len(), 3);
+        assert_eq!(coord.axis_breaks((0, 416)), [204, 312]);
+        assert_eq!(coord.map(&105, (416, 0)), 158);
+    }
+
+    #[test]
+    fn test_wrapped_broken_axis() {
+        use crate::prelude::*;
+
+        let coord = (0.0..10.0).break_to(1000.0..1010.0).gap(10);
+        let with_key_points = coord.clone().with_key_points(vec![0.0, 1010.0]);
+        assert_eq!(with_key_points.axis_breaks((0, 110)), [55]);
+        assert_eq!(coord.clone().step(1.0).axis_breaks((0, 110)), [55]);
+        assert_eq!(coord.clone().padded(5).axis_breaks((0, 120)), [60]);
+        let partial = coord.clone().partial_axis(0.0..1010.0);
+        assert_eq!(partial.axis_breaks((0, 110)), [55]);
+        // The break isn't on the displayed part of the axis
+        let partial = coord.clone().partial_axis(0.0..5.0);
+        assert!(partial.axis_breaks((0, 110)).is_empty());
+        let nested = (0..1).nested_coord(|_| coord.clone());
+        assert_eq!(nested.axis_breaks((0, 220)), [55, 166]);
+    }
+}
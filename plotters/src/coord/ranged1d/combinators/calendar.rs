@@ -0,0 +1,365 @@
+use chrono::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Weekday,
+};
+use std::ops::Range;
+
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, KeyPointWeight, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// The date and time types that can be used with [CalendarFilteredRange](struct.CalendarFilteredRange.html)
+pub trait CalendarValue {
+    /// Get the local date and time of the value, which the calendar filter is applied to
+    fn to_naive_datetime(&self) -> NaiveDateTime;
+}
+
+impl CalendarValue for NaiveDate {
+    fn to_naive_datetime(&self) -> NaiveDateTime {
+        self.and_hms_opt(0, 0, 0).unwrap()
+    }
+}
+
+impl CalendarValue for NaiveDateTime {
+    fn to_naive_datetime(&self) -> NaiveDateTime {
+        *self
+    }
+}
+
+impl<Z: TimeZone> CalendarValue for DateTime<Z> {
+    fn to_naive_datetime(&self) -> NaiveDateTime {
+        self.naive_local()
+    }
+}
+
+fn seconds_of_day(value: &NaiveDateTime) -> f64 {
+    f64::from(value.num_seconds_from_midnight()) + f64::from(value.nanosecond()) * 1e-9
+}
+
+/// The date or date time coordinate that skips the excluded periods, such as the weekends,
+/// the holidays and the time outside of the trading session. The excluded periods take no space
+/// on the axis and no key point is placed in them, thus the candles of a financial time series
+/// are contiguous.
+///
+/// See [IntoCalendarFiltered::calendar_filtered](trait.IntoCalendarFiltered.html#method.calendar_filtered)
+/// for details.
+#[derive(Clone)]
+pub struct CalendarFilteredRange<R: Ranged> {
+    inner: R,
+    excluded_weekdays: Vec<Weekday>,
+    excluded_dates: Vec<NaiveDate>,
+    session: Option<(NaiveTime, NaiveTime)>,
+    // The included seconds before the start of each day, from the first day of the range
+    prefix: Vec<f64>,
+}
+
+/// The trait that allows a date or date time coordinate to skip some periods. See
+/// [CalendarFilteredRange](struct.CalendarFilteredRange.html) for details.
+pub trait IntoCalendarFiltered: AsRangedCoord
+where
+    Self::Value: CalendarValue,
+{
+    /// Skip the excluded periods on this axis, the periods are configured with the methods of
+    /// [CalendarFilteredRange](struct.CalendarFilteredRange.html).
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveTime};
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 1024 * 768 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+    ///
+    /// let from = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    /// let to = NaiveDate::from_ymd_opt(2024, 1, 9).unwrap().and_hms_opt(16, 0, 0).unwrap();
+    ///
+    /// let chart = ChartBuilder::on(&root)
+    ///     .build_cartesian_2d(
+    ///         RangedDateTime::from(from..to)
+    ///             .calendar_filtered()
+    ///             .exclude_weekends()
+    ///             .exclude_dates(vec![NaiveDate::from_ymd_opt(2024, 1, 5).unwrap()])
+    ///             .daily_session(
+    ///                 NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+    ///                 NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+    ///             ),
+    ///         0.0..100.0,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - **returns**: The coordinate without any excluded period
+    fn calendar_filtered(self) -> CalendarFilteredRange<Self::CoordDescType> {
+        let mut ret = CalendarFilteredRange {
+            inner: self.into(),
+            excluded_weekdays: vec![],
+            excluded_dates: vec![],
+            session: None,
+            prefix: vec![],
+        };
+        ret.update_prefix();
+        ret
+    }
+}
+
+impl<T: AsRangedCoord> IntoCalendarFiltered for T where T::Value: CalendarValue {}
+
+impl<R: Ranged> CalendarFilteredRange<R>
+where
+    R::ValueType: CalendarValue,
+{
+    /// Exclude Saturdays and Sundays
+    pub fn exclude_weekends(self) -> Self {
+        self.exclude_weekdays(vec![Weekday::Sat, Weekday::Sun])
+    }
+
+    /// Exclude the given days of the week
+    ///
+    /// - `weekdays`: The days of the week to exclude
+    /// - **returns**: The updated coordinate
+    pub fn exclude_weekdays<I: IntoIterator<Item = Weekday>>(mut self, weekdays: I) -> Self {
+        self.excluded_weekdays.extend(weekdays);
+        self.update_prefix();
+        self
+    }
+
+    /// Exclude the given dates, such as the holidays
+    ///
+    /// - `dates`: The dates to exclude
+    /// - **returns**: The updated coordinate
+    pub fn exclude_dates<I: IntoIterator<Item = NaiveDate>>(mut self, dates: I) -> Self {
+        self.excluded_dates.extend(dates);
+        self.excluded_dates.sort_unstable();
+        self.excluded_dates.dedup();
+        self.update_prefix();
+        self
+    }
+
+    /// Only include the given time of each day, for example the trading session of an
+    /// exchange. If `start` is later than `end`, the session spans midnight and the time between
+    /// `end` and `start` is excluded.
+    ///
+    /// - `start`: The beginning of the session
+    /// - `end`: The end of the session
+    /// - **returns**: The updated coordinate
+    pub fn daily_session(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.session = Some((start, end));
+        self.update_prefix();
+        self
+    }
+
+    /// Check if the value is in an excluded period
+    pub fn is_excluded(&self, value: &R::ValueType) -> bool {
+        let value = value.to_naive_datetime();
+        let time = seconds_of_day(&value);
+        !self
+            .included_intervals(value.date())
+            .iter()
+            .any(|&(a, b)| a <= time && time < b)
+    }
+
+    /// Get a reference to the underlying coordinate spec
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    // The included parts of the day, in seconds from midnight
+    fn included_intervals(&self, date: NaiveDate) -> [(f64, f64); 2] {
+        if self.excluded_weekdays.contains(&date.weekday())
+            || self.excluded_dates.binary_search(&date).is_ok()
+        {
+            return [(0.0, 0.0); 2];
+        }
+        match self.session {
+            None => [(0.0, SECONDS_PER_DAY), (0.0, 0.0)],
+            Some((start, end)) => {
+                let start = f64::from(start.num_seconds_from_midnight());
+                let end = f64::from(end.num_seconds_from_midnight());
+                if start <= end {
+                    [(start, end), (0.0, 0.0)]
+                } else {
+                    [(0.0, end), (start, SECONDS_PER_DAY)]
+                }
+            }
+        }
+    }
+
+    // The included seconds of the day before the given time
+    fn included_before(&self, date: NaiveDate, time: f64) -> f64 {
+        self.included_intervals(date)
+            .iter()
+            .map(|&(a, b)| (b.min(time) - a).max(0.0))
+            .sum()
+    }
+
+    fn update_prefix(&mut self) {
+        let range = self.inner.range();
+        let first = range.start.to_naive_datetime().date();
+        let last = range.end.to_naive_datetime().date();
+        let mut acc = 0.0;
+        self.prefix = first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .map(|date| {
+                let ret = acc;
+                acc += self.included_before(date, SECONDS_PER_DAY);
+                ret
+            })
+            .collect();
+    }
+
+    // The included seconds from the first day of the range to the value
+    fn included_offset(&self, value: &NaiveDateTime) -> f64 {
+        let first = self.inner.range().start.to_naive_datetime().date();
+        let days = (value.date() - first).num_days();
+        let time = seconds_of_day(value);
+        if days < 0 {
+            return (days as f64) * SECONDS_PER_DAY + time;
+        }
+        match self.prefix.get(days as usize) {
+            Some(prefix) => prefix + self.included_before(value.date(), time),
+            None => {
+                // After the last day of the range, which is only used for the points out of range
+                let last = self.prefix.len() as f64 - 1.0;
+                let last_date = first + chrono::Duration::days(last as i64);
+                self.prefix.last().copied().unwrap_or(0.0)
+                    + self.included_before(last_date, SECONDS_PER_DAY)
+                    + (days as f64 - last - 1.0) * SECONDS_PER_DAY
+                    + time
+            }
+        }
+    }
+}
+
+// The hint used to query more key points from the underlying coordinate, since some of them are
+// dropped as they are in the excluded periods
+struct ScaledHint<H: KeyPointHint>(H, usize);
+
+impl<H: KeyPointHint> KeyPointHint for ScaledHint<H> {
+    fn max_num_points(&self) -> usize {
+        self.0.max_num_points().saturating_mul(self.1)
+    }
+
+    fn weight(&self) -> KeyPointWeight {
+        self.0.weight()
+    }
+
+    fn bold_points(&self) -> usize {
+        self.0.bold_points().saturating_mul(self.1)
+    }
+}
+
+impl<R: Ranged> Ranged for CalendarFilteredRange<R>
+where
+    R::ValueType: CalendarValue,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let range = self.inner.range();
+        let begin = self.included_offset(&range.start.to_naive_datetime());
+        let end = self.included_offset(&range.end.to_naive_datetime());
+        let value = self.included_offset(&value.to_naive_datetime());
+        if end <= begin {
+            return limit.0;
+        }
+        limit.0 + (f64::from(limit.1 - limit.0) * (value - begin) / (end - begin) + 1e-10) as i32
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        let range = self.inner.range();
+        let (begin, end) = (
+            range.start.to_naive_datetime(),
+            range.end.to_naive_datetime(),
+        );
+        let total = (end - begin).num_seconds() as f64;
+        let included = self.included_offset(&end) - self.included_offset(&begin);
+        let scale = if included > 0.0 {
+            ((total / included).ceil() as usize).clamp(1, 64)
+        } else {
+            1
+        };
+
+        let points: Vec<_> = self
+            .inner
+            .key_points(ScaledHint(hint, scale))
+            .into_iter()
+            .filter(|v| !self.is_excluded(v))
+            .collect();
+
+        if max_points == 0 || points.len() <= max_points {
+            return points;
+        }
+        let step = (points.len() - 1) / max_points + 1;
+        points.into_iter().step_by(step).collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.inner.range()
+    }
+}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>> ValueFormatter<T> for CalendarFilteredRange<R>
+where
+    T: CalendarValue,
+{
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.inner.format_ext(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::types::{RangedDate, RangedDateTime};
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, d).unwrap()
+    }
+
+    #[test]
+    fn test_exclude_weekends_and_holidays() {
+        // 2024-01-01 is a Monday
+        let coord = RangedDate::from(date(1)..date(15))
+            .calendar_filtered()
+            .exclude_weekends();
+        assert_eq!(coord.map(&date(1), (0, 100)), 0);
+        assert_eq!(coord.map(&date(5), (0, 100)), 40);
+        assert_eq!(coord.map(&date(6), (0, 100)), 50);
+        assert_eq!(coord.map(&date(8), (0, 100)), 50);
+        assert_eq!(coord.map(&date(15), (0, 100)), 100);
+        assert!(coord
+            .key_points(100)
+            .iter()
+            .all(|d| d.weekday() != Weekday::Sat && d.weekday() != Weekday::Sun));
+        assert!(coord.key_points(3).len() <= 3);
+
+        let coord = coord.exclude_dates(vec![date(10)]);
+        assert_eq!(coord.map(&date(11), (0, 90)), coord.map(&date(10), (0, 90)));
+        assert_eq!(coord.map(&date(12), (0, 90)), 80);
+        assert_eq!(coord.map(&date(15), (0, 90)), 90);
+    }
+
+    #[test]
+    fn test_daily_session() {
+        let at = |d, h, m| date(d).and_hms_opt(h, m, 0).unwrap();
+        let coord = RangedDateTime::from(at(2, 9, 30)..at(4, 16, 0))
+            .calendar_filtered()
+            .daily_session(
+                NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            );
+        assert_eq!(coord.map(&at(2, 16, 0), (0, 300)), 100);
+        assert_eq!(coord.map(&at(2, 20, 0), (0, 300)), 100);
+        assert_eq!(coord.map(&at(3, 9, 30), (0, 300)), 100);
+        assert_eq!(coord.map(&at(3, 12, 45), (0, 300)), 150);
+        assert!(coord.is_excluded(&at(3, 8, 0)));
+        assert!(coord.key_points(20).iter().all(|t| !coord.is_excluded(t)));
+    }
+}
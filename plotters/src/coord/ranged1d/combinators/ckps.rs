@@ -64,6 +64,10 @@ where
         self.inner.map(value, limit)
     }
 
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        self.inner.is_finite_value(value)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         if hint.weight().allow_light_points() {
             self.light_points.clone()
@@ -75,6 +79,10 @@ where
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
@@ -189,6 +197,10 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
         self.inner.map(value, limit)
     }
 
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        self.inner.is_finite_value(value)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         if hint.weight().allow_light_points() {
             (self.light_func)(hint.max_num_points())
@@ -200,6 +212,10 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPointMethod<R> {
@@ -67,6 +67,9 @@ impl<T: DiscreteRanged> Ranged for GroupBy<T> {
     fn map(&self, value: &T::ValueType, limit: (i32, i32)) -> i32 {
         self.0.map(value, limit)
     }
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
     fn range(&self) -> Range<T::ValueType> {
         self.0.range()
     }
@@ -0,0 +1,281 @@
+use chrono::{Datelike, Duration, Weekday};
+use std::ops::{Add, Range, Sub};
+
+use crate::coord::ranged1d::types::{RangedDate, TimeValue};
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+
+/// The calendar period used by [GroupByPeriod](struct.GroupByPeriod.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarPeriod {
+    /// The weeks starting on the given day of the week
+    Week(Weekday),
+    /// The quarters of the year, starting on January, April, July and October
+    Quarter,
+    /// The periods of the given number of days, counting from the start of the range
+    Days(u32),
+}
+
+/// Grouping the dates by the calendar periods, such as the weeks starting on Sunday or the
+/// quarters of the year.
+///
+/// Unlike [GroupBy](struct.GroupBy.html), which groups a fixed number of values counting from the
+/// start of the range, the groups are aligned to the calendar, thus the length of the groups may
+/// vary and the first group may be partial. The key points are the first days of the groups
+/// which begin in the range, and the labels are the names of the groups, for example `2024-01-08`
+/// for the week starting on that day and `2024 Q1` for the first quarter of 2024.
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// let from = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+/// let chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(
+///         (from..to).group_by_period(CalendarPeriod::Week(Weekday::Sun)),
+///         0.0..100.0,
+///     )
+///     .unwrap();
+/// ```
+///
+/// To apply this combinator, call
+/// [ToGroupByPeriod::group_by_period](trait.ToGroupByPeriod.html#method.group_by_period) on a
+/// date range.
+#[derive(Clone)]
+pub struct GroupByPeriod<D: Datelike> {
+    inner: RangedDate<D>,
+    period: CalendarPeriod,
+}
+
+/// The trait that provides method `Self::group_by_period`, which groups a date coordinate by the
+/// calendar periods. See [GroupByPeriod](struct.GroupByPeriod.html) for details.
+pub trait ToGroupByPeriod<D: Datelike> {
+    /// Make a date coordinate grouped by the calendar periods
+    ///
+    /// - `period`: The calendar period of the groups
+    /// - **returns**: The newly created coordinate
+    fn group_by_period(self, period: CalendarPeriod) -> GroupByPeriod<D>;
+}
+
+impl<D: Datelike> ToGroupByPeriod<D> for RangedDate<D> {
+    fn group_by_period(self, period: CalendarPeriod) -> GroupByPeriod<D> {
+        GroupByPeriod {
+            inner: self,
+            period,
+        }
+    }
+}
+
+impl<D: Datelike> ToGroupByPeriod<D> for Range<D> {
+    fn group_by_period(self, period: CalendarPeriod) -> GroupByPeriod<D> {
+        RangedDate::from(self).group_by_period(period)
+    }
+}
+
+fn quarter_of<D: Datelike>(value: &D) -> i32 {
+    value.year() * 4 + (value.month0() / 3) as i32
+}
+
+impl<D> GroupByPeriod<D>
+where
+    D: Datelike
+        + TimeValue<DateType = D>
+        + Sub<D, Output = Duration>
+        + Add<Duration, Output = D>
+        + Clone,
+{
+    // The first day of the period that the value falls into
+    fn period_start(&self, value: &D) -> D {
+        match self.period {
+            CalendarPeriod::Week(start) => {
+                let days =
+                    (value.weekday().num_days_from_monday() + 7 - start.num_days_from_monday()) % 7;
+                value.clone() + Duration::days(-i64::from(days))
+            }
+            CalendarPeriod::Quarter => self.quarter_start(quarter_of(value)),
+            CalendarPeriod::Days(_) => self.nth_period_start(self.index_of_unclamped(value)),
+        }
+    }
+
+    fn quarter_start(&self, quarter: i32) -> D {
+        let start = self.inner.range().start;
+        let month = quarter.rem_euclid(4) as u32 * 3 + 1;
+        start.ymd(quarter.div_euclid(4), month, 1)
+    }
+
+    // The index of the period that the value falls into, which is negative before the range
+    fn index_of_unclamped(&self, value: &D) -> i64 {
+        let start = self.inner.range().start;
+        match self.period {
+            CalendarPeriod::Week(_) => (self.period_start(value) - self.period_start(&start))
+                .num_days()
+                .div_euclid(7),
+            CalendarPeriod::Quarter => i64::from(quarter_of(value) - quarter_of(&start)),
+            CalendarPeriod::Days(days) => (value.clone() - start)
+                .num_days()
+                .div_euclid(i64::from(days.max(1))),
+        }
+    }
+
+    // The first day of the period with the given index, which may be earlier than the range
+    fn nth_period_start(&self, index: i64) -> D {
+        let start = self.inner.range().start;
+        match self.period {
+            CalendarPeriod::Week(_) => self.period_start(&start) + Duration::weeks(index),
+            CalendarPeriod::Quarter => self.quarter_start(quarter_of(&start) + index as i32),
+            CalendarPeriod::Days(days) => start + Duration::days(index * i64::from(days.max(1))),
+        }
+    }
+}
+
+impl<D> ValueFormatter<D> for GroupByPeriod<D>
+where
+    D: Datelike
+        + TimeValue<DateType = D>
+        + Sub<D, Output = Duration>
+        + Add<Duration, Output = D>
+        + Clone,
+{
+    fn format(value: &D) -> String {
+        format!("{}-{:02}-{:02}", value.year(), value.month(), value.day())
+    }
+
+    fn format_ext(&self, value: &D) -> String {
+        match self.period {
+            CalendarPeriod::Quarter => {
+                format!("{} Q{}", value.year(), value.month0() / 3 + 1)
+            }
+            _ => Self::format(value),
+        }
+    }
+}
+
+impl<D> Ranged for GroupByPeriod<D>
+where
+    D: Datelike
+        + TimeValue<DateType = D>
+        + Sub<D, Output = Duration>
+        + Add<Duration, Output = D>
+        + Clone,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = D;
+
+    fn map(&self, value: &D, limit: (i32, i32)) -> i32 {
+        self.inner.map(value, limit)
+    }
+
+    fn range(&self) -> Range<D> {
+        self.inner.range()
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<D> {
+        let max_points = hint.max_num_points();
+        let range = self.inner.range();
+        if max_points == 0 || (range.end.clone() - range.start.clone()).num_days() < 0 {
+            return vec![];
+        }
+
+        // The first period is skipped if it begins before the range
+        let first = if self.period_start(&range.start) == range.start {
+            0
+        } else {
+            1
+        };
+        let last = self.index_of_unclamped(&range.end);
+        if last < first {
+            return vec![];
+        }
+
+        let count = (last - first + 1) as usize;
+        let stride = (count - 1) / max_points + 1;
+        (first..=last)
+            .step_by(stride)
+            .map(|idx| self.nth_period_start(idx))
+            .collect()
+    }
+}
+
+impl<D> DiscreteRanged for GroupByPeriod<D>
+where
+    D: Datelike
+        + TimeValue<DateType = D>
+        + Sub<D, Output = Duration>
+        + Add<Duration, Output = D>
+        + Clone,
+{
+    fn size(&self) -> usize {
+        (self.index_of_unclamped(&self.inner.range().end).max(-1) + 1) as usize
+    }
+
+    fn index_of(&self, value: &D) -> Option<usize> {
+        let idx = self.index_of_unclamped(value);
+        if idx < 0 {
+            return None;
+        }
+        Some(idx as usize)
+    }
+
+    fn from_index(&self, index: usize) -> Option<D> {
+        // The first period may begin before the range, which is truncated to the range
+        if index == 0 {
+            return Some(self.inner.range().start);
+        }
+        Some(self.nth_period_start(index as i64))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_group_by_period() {
+        // 2024-01-03 is a Wednesday
+        let coord = (date(2024, 1, 3)..date(2024, 3, 31))
+            .group_by_period(CalendarPeriod::Week(Weekday::Mon));
+        assert_eq!(coord.size(), 13);
+        assert_eq!(coord.index_of(&date(2024, 1, 7)), Some(0));
+        assert_eq!(coord.index_of(&date(2024, 1, 8)), Some(1));
+        assert_eq!(coord.from_index(0), Some(date(2024, 1, 3)));
+        assert_eq!(coord.from_index(2), Some(date(2024, 1, 15)));
+        let key_points = coord.key_points(20);
+        assert_eq!(key_points.len(), 12);
+        assert_eq!(key_points[0], date(2024, 1, 8));
+        assert_eq!(coord.format_ext(&key_points[0]), "2024-01-08");
+        assert_eq!(coord.key_points(5).len(), 4);
+
+        let coord = (date(2023, 11, 15)..date(2024, 8, 1)).group_by_period(CalendarPeriod::Quarter);
+        assert_eq!(coord.size(), 4);
+        assert_eq!(coord.index_of(&date(2024, 5, 1)), Some(2));
+        let labels: Vec<_> = coord
+            .key_points(10)
+            .iter()
+            .map(|d| coord.format_ext(d))
+            .collect();
+        assert_eq!(labels, ["2024 Q1", "2024 Q2", "2024 Q3"]);
+
+        let coord = (date(2024, 1, 1)..date(2024, 1, 31)).group_by_period(CalendarPeriod::Days(10));
+        assert_eq!(coord.size(), 4);
+        assert_eq!(coord.index_of(&date(2024, 1, 20)), Some(1));
+        assert_eq!(
+            coord.key_points(10),
+            [
+                date(2024, 1, 1),
+                date(2024, 1, 11),
+                date(2024, 1, 21),
+                date(2024, 1, 31)
+            ]
+        );
+    }
+}
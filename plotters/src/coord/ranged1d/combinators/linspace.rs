@@ -169,6 +169,11 @@ where
 /// - **Round**,   the value falls into the nearst bucket. See [Linearspace::use_round](struct.Linspace.html#method.use_round)
 /// - **Ceiling**, the value falls into the nearst bucket larger than itself. See [Linspace::use_ceil](struct.Linspace.html#method.use_ceil)
 /// - **Exact Matchting**, the value must be exactly same as the butcket value.  See [Linspace::use_exact](struct.Linspace.html#method.use_exact)
+///
+/// By default, the buckets start from the start of the range and the end of the range is excluded.
+/// The buckets can be anchored to the multiples of the step from another value with
+/// [Linspace::align_to](struct.Linspace.html#method.align_to), and the exact ends of the range can
+/// be added with [Linspace::include_endpoints](struct.Linspace.html#method.include_endpoints).
 #[derive(Clone)]
 pub struct Linspace<T: Ranged, S: Clone, R: LinspaceRoundingMethod<T::ValueType>>
 where
@@ -177,6 +182,8 @@ where
     step: S,
     inner: T,
     grid_value: Vec<T::ValueType>,
+    first: Option<T::ValueType>,
+    endpoints: bool,
     _phatom: PhantomData<R>,
 }
 
@@ -186,14 +193,16 @@ where
 {
     fn compute_grid_values(&mut self) {
         let range = self.inner.range();
+        let start = self.first.clone().unwrap_or_else(|| range.start.clone());
+        self.grid_value.clear();
 
         match (
             range.start.partial_cmp(&range.end),
-            (range.start.clone() + self.step.clone()).partial_cmp(&range.end),
+            (start.clone() + self.step.clone()).partial_cmp(&range.end),
         ) {
             (Some(a), Some(b)) if a != b || a == Ordering::Equal || b == Ordering::Equal => (),
             (Some(a), Some(_)) => {
-                let mut current = range.start;
+                let mut current = start;
                 while current.partial_cmp(&range.end) == Some(a) {
                     self.grid_value.push(current.clone());
                     current = current + self.step.clone();
@@ -201,6 +210,86 @@ where
             }
             _ => (),
         }
+
+        if self.endpoints {
+            if self.grid_value.first() != Some(&range.start) {
+                self.grid_value.insert(0, range.start.clone());
+            }
+            if self.grid_value.last() != Some(&range.end) {
+                self.grid_value.push(range.end);
+            }
+        }
+    }
+
+    /**
+    Anchor the buckets to the multiples of the step from the given origin, rather than the start
+    of the range. The first bucket is the first multiple which isn't before the start of the range.
+
+    - `origin`: The value the buckets are aligned to
+    - **returns**: The linspace with the aligned buckets
+
+    ```
+    use plotters::prelude::*;
+    let coord = (0.1..1.2).step(0.25).align_to(0.0);
+    assert_eq!(coord.values().collect::<Vec<_>>(), [0.25, 0.5, 0.75, 1.0]);
+    ```
+    */
+    pub fn align_to(mut self, origin: T::ValueType) -> Self
+    where
+        T::ValueType: Sub<S, Output = T::ValueType>,
+        S: Add<S, Output = S>,
+    {
+        let start = self.inner.range().start;
+        let order = start.partial_cmp(&(start.clone() + self.step.clone()));
+        if order != Some(Ordering::Less) && order != Some(Ordering::Greater) {
+            return self;
+        }
+        let before = |a: &T::ValueType, b: &T::ValueType| a.partial_cmp(b) == order;
+        let double = |s: &S| s.clone() + s.clone();
+
+        // The distance between the origin and the start may be a great number of steps, thus we
+        // move by the doubling jumps instead of a single step at a time
+        let mut current = origin;
+        while before(&current, &start) {
+            let mut jump = self.step.clone();
+            while before(&(current.clone() + double(&jump)), &start) {
+                jump = double(&jump);
+            }
+            current = current + jump;
+        }
+        while !before(&(current.clone() - self.step.clone()), &start) {
+            let mut jump = self.step.clone();
+            while !before(&(current.clone() - double(&jump)), &start) {
+                jump = double(&jump);
+            }
+            current = current - jump;
+        }
+
+        self.first = Some(current);
+        self.compute_grid_values();
+        self
+    }
+
+    /**
+    Set if the exact start and end of the range are always buckets, which gives the clean edges
+    to the histograms over a measured range. The ends are also always key points.
+
+    - `include`: If the ends of the range should be included
+    - **returns**: The linspace with the new configuration
+
+    ```
+    use plotters::prelude::*;
+    let coord = (0.1..1.2).step(0.25).align_to(0.0).include_endpoints(true);
+    assert_eq!(
+        coord.values().collect::<Vec<_>>(),
+        [0.1, 0.25, 0.5, 0.75, 1.0, 1.2]
+    );
+    ```
+    */
+    pub fn include_endpoints(mut self, include: bool) -> Self {
+        self.endpoints = include;
+        self.compute_grid_values();
+        self
     }
 
     /// Set the linspace use the round up method for value matching
@@ -211,6 +300,8 @@ where
             step: self.step,
             inner: self.inner,
             grid_value: self.grid_value,
+            first: self.first,
+            endpoints: self.endpoints,
             _phatom: PhantomData,
         }
     }
@@ -223,6 +314,8 @@ where
             step: self.step,
             inner: self.inner,
             grid_value: self.grid_value,
+            first: self.first,
+            endpoints: self.endpoints,
             _phatom: PhantomData,
         }
     }
@@ -239,6 +332,8 @@ where
             step: self.step,
             inner: self.inner,
             grid_value: self.grid_value,
+            first: self.first,
+            endpoints: self.endpoints,
             _phatom: PhantomData,
         }
     }
@@ -255,6 +350,8 @@ where
             step: self.step,
             inner: self.inner,
             grid_value: self.grid_value,
+            first: self.first,
+            endpoints: self.endpoints,
             _phatom: PhantomData,
         }
     }
@@ -287,14 +384,34 @@ where
         self.inner.map(value, limit)
     }
 
+    fn is_finite_value(&self, value: &T::ValueType) -> bool {
+        self.inner.is_finite_value(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_breaks(limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T::ValueType> {
         if self.grid_value.is_empty() {
             return vec![];
         }
-        let idx_range: RangedCoordusize = (0..(self.grid_value.len() - 1)).into();
+        let last = self.grid_value.len() - 1;
+        let max_points = hint.max_num_points();
+        let idx_range: RangedCoordusize = (0..last).into();
+        let mut indices = idx_range.key_points(hint);
+
+        if self.endpoints && indices.len() > 1 && indices.last() != Some(&last) {
+            let spacing = indices[1] - indices[0];
+            let tail = indices[indices.len() - 1];
+            // Replace the last key point if it's too close to the end, or there's no room left
+            if indices.len() >= max_points || last - tail < spacing / 2 {
+                indices.pop();
+            }
+            indices.push(last);
+        }
 
-        idx_range
-            .key_points(hint)
+        indices
             .into_iter()
             .map(|x| self.grid_value[x].clone())
             .collect()
@@ -334,6 +451,8 @@ pub trait IntoLinspace: AsRangedCoord {
             step: val,
             inner: self.into(),
             grid_value: vec![],
+            first: None,
+            endpoints: false,
             _phatom: PhantomData,
         };
 
@@ -430,4 +549,24 @@ mod test {
         assert_eq!(coord.range(), Duration::seconds(0)..Duration::seconds(100));
         assert_eq!(coord.map(&Duration::seconds(25), (0, 100_000)), 25000);
     }
+
+    #[test]
+    fn test_linspace_alignment() {
+        let coord = (-0.3f64..1.0).step(0.25).align_to(100.0);
+        assert_eq!(coord.size(), 5);
+        assert_eq!(coord.from_index(0), Some(-0.25));
+        assert_eq!(coord.from_index(4), Some(0.75));
+
+        let coord = (3..20).step(5).align_to(-1000).include_endpoints(true);
+        assert_eq!(coord.values().collect::<Vec<_>>(), [3, 5, 10, 15, 20]);
+        assert_eq!(coord.key_points(3), [3, 10, 20]);
+        assert_eq!(coord.index_of(&10), Some(2));
+
+        // The step greater than the range
+        assert_eq!((0..1).step(2).size(), 0);
+        assert_eq!((0..1).step(2).include_endpoints(true).size(), 2);
+
+        let coord = (10.0f64..0.0).step(-3.0).align_to(0.0);
+        assert_eq!(coord.values().collect::<Vec<_>>(), [9.0, 6.0, 3.0]);
+    }
 }
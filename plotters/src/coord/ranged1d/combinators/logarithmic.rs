@@ -1,5 +1,7 @@
 use crate::coord::ranged1d::types::RangedCoordf64;
-use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use crate::coord::ranged1d::{
+    AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged, ReversibleRanged,
+};
 use std::marker::PhantomData;
 use std::ops::Range;
 
@@ -196,6 +198,10 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.linear.map(&value_ln, limit)
     }
 
+    fn is_finite_value(&self, value: &V) -> bool {
+        self.value_to_f64(value).ln().is_finite()
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         let max_points = hint.max_num_points();
 
@@ -229,7 +235,9 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         }
 
         let mut ret = vec![];
-        let mut val = (base).powf((start.ln() / base_ln).ceil());
+        // The exponent of a bound which is an exact power, such as 0.01, may be off by a tiny
+        // amount, thus tolerate the rounding error, otherwise the bound isn't a key point
+        let mut val = (base).powf((start.ln() / base_ln - 1e-9).ceil());
 
         while val <= end {
             if !self.is_inf(val) {
@@ -257,6 +265,13 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
     }
 }
 
+impl<V: LogScalable> ReversibleRanged for LogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        let value_ln = self.linear.unmap(input, limit)?;
+        Some(self.f64_to_value(value_ln.exp()))
+    }
+}
+
 /// The logarithmic coordinate decorator.
 /// This decorator is used to make the axis rendered as logarithmically.
 #[deprecated(note = "LogRange is deprecated, use IntoLogRange trait method instead")]
@@ -285,4 +300,12 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_key_points_exact_bounds() {
+        let range: LogCoord<f64> = (0.01..10.0).log_scale().into();
+        assert_eq!(range.key_points(4), [0.01, 0.1, 1.0, 10.0]);
+        let range: LogCoord<f64> = (0.011..10.0).log_scale().into();
+        assert_eq!(range.key_points(4), [0.1, 1.0, 10.0]);
+    }
 }
@@ -1,9 +1,24 @@
+#[cfg(feature = "chrono")]
+mod calendar;
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+pub use calendar::{CalendarFilteredRange, CalendarValue, IntoCalendarFiltered};
+
+mod broken;
+pub use broken::{BrokenAxis, IntoBrokenAxis};
+
 mod ckps;
 pub use ckps::{BindKeyPointMethod, BindKeyPoints, WithKeyPointMethod, WithKeyPoints};
 
 mod group_by;
 pub use group_by::{GroupBy, ToGroupByRange};
 
+#[cfg(feature = "chrono")]
+mod group_by_period;
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+pub use group_by_period::{CalendarPeriod, GroupByPeriod, ToGroupByPeriod};
+
 mod linspace;
 pub use linspace::{IntoLinspace, Linspace};
 
@@ -16,5 +31,14 @@ pub use logarithmic::LogRange;
 mod nested;
 pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
+mod padded;
+pub use padded::{IntoPadded, Padded};
+
+mod power;
+pub use power::{IntoPowerScale, PowerCoord, PowerScaleRange};
+
+mod ranged_fn;
+pub use ranged_fn::RangedFn;
+
 mod partial_axis;
 pub use partial_axis::{make_partial_axis, IntoPartialAxis};
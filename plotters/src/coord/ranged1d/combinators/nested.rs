@@ -66,6 +66,24 @@ where
     }
 }
 
+impl<P: DiscreteRanged, S: Ranged> NestedRange<P, S> {
+    // The pixel range of the secondary coordinate of the category at the index
+    fn bucket(&self, idx: usize, limit: (i32, i32)) -> (i32, i32) {
+        let total = self.primary.size();
+
+        let bucket_size = (limit.1 - limit.0) / total as i32;
+        let mut residual = (limit.1 - limit.0) % total as i32;
+
+        if residual < 0 {
+            residual += total as i32;
+        }
+
+        let s_left = limit.0 + bucket_size * idx as i32 + residual.min(idx as i32);
+        let s_right = s_left + bucket_size + if (residual as usize) < idx { 1 } else { 0 };
+        (s_left, s_right)
+    }
+}
+
 impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
     type FormatOption = NoDefaultFormatting;
     type ValueType = NestedValue<P::ValueType, S::ValueType>;
@@ -82,17 +100,7 @@ impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
 
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
         let idx = self.primary.index_of(value.category()).unwrap_or(0);
-        let total = self.primary.size();
-
-        let bucket_size = (limit.1 - limit.0) / total as i32;
-        let mut residual = (limit.1 - limit.0) % total as i32;
-
-        if residual < 0 {
-            residual += total as i32;
-        }
-
-        let s_left = limit.0 + bucket_size * idx as i32 + residual.min(idx as i32);
-        let s_right = s_left + bucket_size + if (residual as usize) < idx { 1 } else { 0 };
+        let (s_left, s_right) = self.bucket(idx, limit);
 
         if let Some(secondary_value) = value.nested_value() {
             self.secondary[idx].map(secondary_value, (s_left, s_right))
@@ -127,6 +135,22 @@ impl<P: DiscreteRanged, S: Ranged> Ranged for NestedRange<P, S> {
                 .collect()
         }
     }
+
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        if !self.primary.is_finite_value(value.category()) {
+            return false;
+        }
+        let idx = self.primary.index_of(value.category()).unwrap_or(0);
+        value
+            .nested_value()
+            .map_or(true, |v| self.secondary[idx].is_finite_value(v))
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        (0..self.primary.size().min(self.secondary.len()))
+            .flat_map(|idx| self.secondary[idx].axis_breaks(self.bucket(idx, limit)))
+            .collect()
+    }
 }
 
 impl<P: DiscreteRanged, S: DiscreteRanged> DiscreteRanged for NestedRange<P, S> {
@@ -0,0 +1,141 @@
+use std::ops::Range;
+
+use crate::coord::ranged1d::{
+    AsRangedCoord, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use crate::style::SizeDesc;
+
+/// The coordinate decorator that leaves some space between the ends of the axis and the data
+/// range, so that the data points on the boundary don't sit exactly on the frame of the chart.
+///
+/// The padding is applied in the pixel space, thus it works with any coordinate spec. The range
+/// and the key points are still the ones of the underlying coordinate, only the mapping is
+/// squeezed into the padded part of the axis.
+///
+/// See [IntoPadded::padded](trait.IntoPadded.html#method.padded) for details.
+#[derive(Clone)]
+pub struct Padded<R: Ranged, S: SizeDesc> {
+    inner: R,
+    padding: S,
+}
+
+impl<R: Ranged, S: SizeDesc> Padded<R, S> {
+    fn padded_limit(&self, limit: (i32, i32)) -> (i32, i32) {
+        let len = (limit.1 - limit.0).abs();
+        let padding = self
+            .padding
+            .in_pixels(&(len as u32, len as u32))
+            .max(0)
+            .min((len - 1).max(0) / 2);
+        if limit.0 <= limit.1 {
+            (limit.0 + padding, limit.1 - padding)
+        } else {
+            (limit.0 - padding, limit.1 + padding)
+        }
+    }
+
+    /// Get a reference to the underlying coordinate spec
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+}
+
+/// The trait that allows a coordinate spec to be padded. See [Padded](struct.Padded.html) for details.
+pub trait IntoPadded: AsRangedCoord {
+    /// Add padding to both ends of the axis.
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 1024 * 768 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+    ///
+    /// let chart = ChartBuilder::on(&root)
+    ///     // 5% of the axis length on both sides of the X axis and 10 pixels for the Y axis
+    ///     .build_cartesian_2d((0.0..1.0).padded(5.percent()), (0..10).padded(10))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `padding`: The size of the padding, either in pixels or relative to the axis length
+    /// - **returns**: The padded coordinate spec
+    fn padded<S: SizeDesc>(self, padding: S) -> Padded<Self::CoordDescType, S> {
+        Padded {
+            inner: self.into(),
+            padding,
+        }
+    }
+}
+
+impl<T: AsRangedCoord> IntoPadded for T {}
+
+impl<R: Ranged, S: SizeDesc> Ranged for Padded<R, S> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.inner.map(value, self.padded_limit(limit))
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        self.inner.key_points(hint)
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.inner.range()
+    }
+
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        self.inner.is_finite_value(value)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.inner.axis_breaks(self.padded_limit(limit))
+    }
+}
+
+impl<R: DiscreteRanged, S: SizeDesc> DiscreteRanged for Padded<R, S> {
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        self.inner.index_of(value)
+    }
+
+    fn from_index(&self, index: usize) -> Option<Self::ValueType> {
+        self.inner.from_index(index)
+    }
+}
+
+impl<T, R: Ranged<ValueType = T> + ValueFormatter<T>, S: SizeDesc> ValueFormatter<T>
+    for Padded<R, S>
+{
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        self.inner.format_ext(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::AsRelative;
+
+    #[test]
+    fn test_padded_coord() {
+        let coord = (0.0..10.0).padded(10);
+        assert_eq!(coord.map(&0.0, (0, 100)), 10);
+        assert_eq!(coord.map(&10.0, (0, 100)), 90);
+        assert_eq!(coord.map(&10.0, (100, 0)), 10);
+        assert_eq!(coord.range(), 0.0..10.0);
+
+        let coord = (0..10i32).padded(10.percent());
+        assert_eq!(coord.map(&0, (0, 200)), 20);
+        assert_eq!(coord.map(&10, (0, 200)), 180);
+        assert_eq!(coord.size(), 11);
+        assert_eq!(coord.key_points(3), coord.inner().key_points(3));
+    }
+}
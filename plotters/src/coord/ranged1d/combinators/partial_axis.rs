@@ -36,6 +36,10 @@ where
         self.0.map(value, limit)
     }
 
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        self.0.is_finite_value(value)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         self.0.key_points(hint)
     }
@@ -50,6 +54,14 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        // Only the breaks on the displayed part of the axis are marked
+        let range = self.axis_pixel_range(limit);
+        let mut breaks = self.0.axis_breaks(limit);
+        breaks.retain(|x| range.contains(x));
+        breaks
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
@@ -0,0 +1,234 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{
+    AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged, ReversibleRanged,
+};
+use num_traits::NumCast;
+use std::ops::Range;
+
+/// Convert a range to a power scale coordinate spec
+pub trait IntoPowerScale {
+    /// The type of the value
+    type ValueType: NumCast + Clone;
+
+    /**
+    Make the power scale coordinate, where the values are mapped by `x^exponent` before they
+    are placed linearly on the axis. The negative values are mapped symmetrically, as
+    `-(-x)^exponent`.
+
+    - `exponent`: The exponent, a non-positive or non-finite exponent falls back to a linear
+      scale
+    - **returns**: The power scale coordinate spec
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("power_scale.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0.0..10.0, (0.0..1000.0).power_scale(0.5))
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    ```
+    */
+    fn power_scale(self, exponent: f64) -> PowerScaleRange<Self::ValueType>;
+
+    /// Make the square root scale coordinate, which is common for the count data and for the
+    /// area of the bubbles, see [`IntoPowerScale::power_scale()`]
+    fn sqrt_scale(self) -> PowerScaleRange<Self::ValueType>
+    where
+        Self: Sized,
+    {
+        self.power_scale(0.5)
+    }
+}
+
+impl<T: NumCast + Clone> IntoPowerScale for Range<T> {
+    type ValueType = T;
+    fn power_scale(self, exponent: f64) -> PowerScaleRange<T> {
+        PowerScaleRange {
+            range: self,
+            exponent: if exponent > 0.0 && exponent.is_finite() {
+                exponent
+            } else {
+                1.0
+            },
+        }
+    }
+}
+
+/// The power scale coordinate decorator.
+/// This decorator is used to make the axis rendered in a power scale, such as the square root
+/// scale.
+#[derive(Clone)]
+pub struct PowerScaleRange<V: NumCast + Clone> {
+    range: Range<V>,
+    exponent: f64,
+}
+
+impl<V: NumCast + Clone> From<PowerScaleRange<V>> for PowerCoord<V> {
+    fn from(spec: PowerScaleRange<V>) -> PowerCoord<V> {
+        // A type which can't hold 0.5 is an integer, whose key points must be integers as well
+        let half = num_traits::cast::<f64, V>(0.5).and_then(|v| num_traits::cast::<V, f64>(v));
+        let integral = !matches!(half, Some(v) if v == 0.5);
+        let start = to_f64(&spec.range.start);
+        let end = to_f64(&spec.range.end);
+        let exponent = spec.exponent;
+        PowerCoord {
+            linear: (power(start, exponent)..power(end, exponent)).into(),
+            logic: spec.range,
+            exponent,
+            integral,
+        }
+    }
+}
+
+impl<V: NumCast + Clone> AsRangedCoord for PowerScaleRange<V> {
+    type CoordDescType = PowerCoord<V>;
+    type Value = V;
+}
+
+fn to_f64<V: NumCast + Clone>(value: &V) -> f64 {
+    num_traits::cast(value.clone()).unwrap_or(f64::NAN)
+}
+
+/// Raise the magnitude of the value to the power, keeping its sign
+fn power(value: f64, exponent: f64) -> f64 {
+    value.signum() * value.abs().powf(exponent)
+}
+
+/// The largest step in the 1-2-5 sequence which is no more than the value
+fn nice_step_below(value: f64) -> f64 {
+    let magnitude = 10f64.powf(value.log10().floor());
+    let step = [5.0, 2.0, 1.0]
+        .iter()
+        .find(|m| *m * magnitude <= value)
+        .unwrap_or(&1.0);
+    step * magnitude
+}
+
+/// A power scaled coordinate axis
+#[derive(Clone)]
+pub struct PowerCoord<V: NumCast + Clone> {
+    linear: RangedCoordf64,
+    logic: Range<V>,
+    exponent: f64,
+    integral: bool,
+}
+
+impl<V: NumCast + Clone> PowerCoord<V> {
+    fn inverse(&self, value: f64) -> f64 {
+        power(value, 1.0 / self.exponent)
+    }
+}
+
+impl<V: NumCast + Clone> Ranged for PowerCoord<V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        self.linear.map(&power(to_f64(value), self.exponent), limit)
+    }
+
+    fn is_finite_value(&self, value: &V) -> bool {
+        to_f64(value).is_finite()
+    }
+
+    /// The key points are spread evenly on the axis, and each of them is rounded to a multiple
+    /// of 1, 2 or 5 times a power of ten in the data space, which is no more than the distance
+    /// to its neighbors, thus the labels are round numbers.
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+
+        let Range { mut start, mut end } = self.linear.range();
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+        let (lo, hi) = (self.inverse(start), self.inverse(end));
+        if !(lo.is_finite() && hi.is_finite()) {
+            return vec![];
+        }
+        if max_points == 1 || (hi - lo).abs() < f64::EPSILON {
+            return num_traits::cast(lo).into_iter().collect();
+        }
+
+        let spacing = (end - start) / (max_points - 1) as f64;
+        let positions: Vec<_> = (0..max_points)
+            .map(|idx| self.inverse(start + spacing * idx as f64))
+            .collect();
+
+        let mut ret: Vec<f64> = vec![];
+        for (idx, value) in positions.iter().enumerate() {
+            let gap = [idx.checked_sub(1), Some(idx + 1)]
+                .iter()
+                .filter_map(|n| positions.get((*n)?))
+                .map(|n| (n - value).abs())
+                .fold(f64::INFINITY, f64::min);
+            let mut step = nice_step_below(gap);
+            if self.integral {
+                step = step.max(1.0);
+            }
+            let mut rounded = (value / step).round() * step;
+            if rounded < lo {
+                rounded += step;
+            } else if rounded > hi {
+                rounded -= step;
+            }
+            // Adding zero turns -0.0 into 0.0
+            let rounded = rounded + 0.0;
+            if lo <= rounded
+                && rounded <= hi
+                && !matches!(ret.last(), Some(last) if *last >= rounded)
+            {
+                ret.push(rounded);
+            }
+        }
+
+        ret.into_iter().filter_map(num_traits::cast).collect()
+    }
+
+    fn range(&self) -> Range<V> {
+        self.logic.clone()
+    }
+}
+
+impl<V: NumCast + Clone> ReversibleRanged for PowerCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        let value = self.linear.unmap(input, limit)?;
+        let value = self.inverse(value);
+        num_traits::cast(if self.integral { value.round() } else { value })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_power_scale_key_points() {
+        let coord: PowerCoord<f64> = (0.0..100.0).sqrt_scale().into();
+        assert_eq!(coord.map(&25.0, (0, 100)), 50);
+        assert_eq!(coord.unmap(50, (0, 100)), Some(25.0));
+        assert_eq!(coord.key_points(6), [0.0, 4.0, 20.0, 40.0, 60.0, 100.0]);
+
+        for n in 1..20 {
+            let points = coord.key_points(n);
+            assert!(!points.is_empty() && points.len() <= n);
+            assert!(points.iter().all(|p| (0.0..=100.0).contains(p)));
+            assert!(points.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        // The integer values stay integers
+        let coord: PowerCoord<u32> = (0..10).sqrt_scale().into();
+        let points = coord.key_points(10);
+        assert!(points.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(points.first(), Some(&0));
+        assert_eq!(points.last(), Some(&10));
+
+        // The negative values are mapped symmetrically
+        let coord: PowerCoord<f64> = (-8.0..8.0).power_scale(1.0 / 3.0).into();
+        assert_eq!(coord.map(&-1.0, (0, 100)), 25);
+        assert_eq!(coord.map(&0.0, (0, 100)), 50);
+    }
+}
@@ -0,0 +1,179 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{DefaultFormatting, KeyPointHint, Ranged, ReversibleRanged};
+
+/// The coordinate spec built from closures, which makes a custom scale without implementing the
+/// [Ranged](../trait.Ranged.html) trait by hand.
+///
+/// The scale is defined by a monotonic function that maps the values to a linear space, for
+/// example `f64::sqrt` for a square root scale. The mapped space is then mapped to the pixels
+/// linearly. The key points can either be computed by a user-defined function, or, when the
+/// inverse function is given, they are the nice ticks of the mapped space converted back to the
+/// values.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// // A square root scale for the Y axis
+/// let sqrt_scale = RangedFn::monotonic(0.0..100.0, |v: &f64| v.sqrt(), |v| v * v);
+///
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(0.0..10.0, sqrt_scale)
+///     .unwrap();
+///
+/// chart
+///     .draw_series(LineSeries::new((0..=10).map(|x| (x as f64, (x * x) as f64)), &RED))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RangedFn<'a, V> {
+    range: Range<V>,
+    mapped: RangedCoordf64,
+    map_fn: Rc<dyn Fn(&V) -> f64 + 'a>,
+    inverse_fn: Option<Rc<dyn Fn(f64) -> V + 'a>>,
+    key_points_fn: Option<Rc<dyn Fn(&Range<V>, usize) -> Vec<V> + 'a>>,
+}
+
+impl<'a, V: 'a> RangedFn<'a, V> {
+    fn with_fns(
+        range: Range<V>,
+        map_fn: Rc<dyn Fn(&V) -> f64 + 'a>,
+        inverse_fn: Option<Rc<dyn Fn(f64) -> V + 'a>>,
+        key_points_fn: Option<Rc<dyn Fn(&Range<V>, usize) -> Vec<V> + 'a>>,
+    ) -> Self {
+        let mapped = (map_fn(&range.start)..map_fn(&range.end)).into();
+        Self {
+            range,
+            mapped,
+            map_fn,
+            inverse_fn,
+            key_points_fn,
+        }
+    }
+
+    /// Create a coordinate spec from the mapping function and the key points function.
+    ///
+    /// - `range`: The value range of the axis
+    /// - `map_fn`: The monotonic function which maps a value to the linear space
+    /// - `key_points_fn`: The function which takes the range and the maximum number of key points,
+    ///   and returns the key points of the axis
+    /// - **returns**: The newly created coordinate spec
+    pub fn new<F, K>(range: Range<V>, map_fn: F, key_points_fn: K) -> Self
+    where
+        F: Fn(&V) -> f64 + 'a,
+        K: Fn(&Range<V>, usize) -> Vec<V> + 'a,
+    {
+        Self::with_fns(range, Rc::new(map_fn), None, Some(Rc::new(key_points_fn)))
+    }
+
+    /// Create a coordinate spec from the mapping function and its inverse. The key points are the
+    /// nice ticks of the mapped space converted back with the inverse function, and the coordinate
+    /// spec is reversible.
+    ///
+    /// - `range`: The value range of the axis
+    /// - `map_fn`: The monotonic function which maps a value to the linear space
+    /// - `inverse_fn`: The inverse function of `map_fn`
+    /// - **returns**: The newly created coordinate spec
+    pub fn monotonic<F, I>(range: Range<V>, map_fn: F, inverse_fn: I) -> Self
+    where
+        F: Fn(&V) -> f64 + 'a,
+        I: Fn(f64) -> V + 'a,
+    {
+        Self::with_fns(range, Rc::new(map_fn), Some(Rc::new(inverse_fn)), None)
+    }
+
+    /// Override the key points of the coordinate spec.
+    ///
+    /// - `key_points_fn`: The function which takes the range and the maximum number of key points,
+    ///   and returns the key points of the axis
+    /// - **returns**: The updated coordinate spec
+    pub fn with_key_points<K>(mut self, key_points_fn: K) -> Self
+    where
+        K: Fn(&Range<V>, usize) -> Vec<V> + 'a,
+    {
+        self.key_points_fn = Some(Rc::new(key_points_fn));
+        self
+    }
+}
+
+impl<'a, V: Clone + 'a> Ranged for RangedFn<'a, V> {
+    type FormatOption = DefaultFormatting;
+    type ValueType = V;
+
+    fn map(&self, value: &V, limit: (i32, i32)) -> i32 {
+        self.mapped.map(&(self.map_fn)(value), limit)
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<V> {
+        if let Some(key_points_fn) = self.key_points_fn.as_ref() {
+            return key_points_fn(&self.range, hint.max_num_points());
+        }
+
+        let inverse_fn = match self.inverse_fn.as_ref() {
+            Some(inverse_fn) => inverse_fn,
+            None => return vec![],
+        };
+
+        let Range { start, end } = self.mapped.range();
+        let linear: RangedCoordf64 = (start.min(end)..start.max(end)).into();
+
+        linear
+            .key_points(hint)
+            .into_iter()
+            .map(|v| inverse_fn(v))
+            .collect()
+    }
+
+    fn range(&self) -> Range<V> {
+        self.range.clone()
+    }
+
+    fn is_finite_value(&self, value: &V) -> bool {
+        (self.map_fn)(value).is_finite()
+    }
+}
+
+impl<'a, V: Clone + 'a> ReversibleRanged for RangedFn<'a, V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        let inverse_fn = self.inverse_fn.as_ref()?;
+        self.mapped.unmap(input, limit).map(|v| inverse_fn(v))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_monotonic_fn() {
+        let coord = RangedFn::monotonic(0.0..100.0, |v: &f64| v.sqrt(), |v| v * v);
+
+        assert_eq!(coord.map(&0.0, (0, 100)), 0);
+        assert_eq!(coord.map(&25.0, (0, 100)), 50);
+        assert_eq!(coord.map(&100.0, (0, 100)), 100);
+        assert_eq!(coord.unmap(50, (0, 100)), Some(25.0));
+
+        let key_points = coord.key_points(11);
+        assert_eq!(key_points.len(), 11);
+        assert_eq!(key_points[1], 1.0);
+        assert_eq!(key_points[10], 100.0);
+    }
+
+    #[test]
+    fn test_key_points_fn() {
+        let coord = RangedFn::new(
+            1u32..1000,
+            |v: &u32| f64::from(*v).log10(),
+            |range, _| vec![range.start, 10, 100, range.end],
+        );
+
+        assert_eq!(coord.map(&10, (0, 300)), 100);
+        assert_eq!(coord.key_points(5), vec![1, 10, 100, 1000]);
+        assert_eq!(coord.unmap(100, (0, 300)), None);
+    }
+}
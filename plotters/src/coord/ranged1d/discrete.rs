@@ -1,6 +1,8 @@
 use crate::coord::ranged1d::{
     AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
 };
+use std::cmp::Ordering;
+use std::fmt;
 use std::ops::Range;
 
 /// The trait indicates the coordinate is discrete
@@ -95,8 +97,25 @@ where
 ///   The segmented coord always emits `CenterOf(value)` key points, thus it allows all the label and tick marks
 ///   of the coordinate rendered in the middle of each segment.
 ///   The corresponding trait [IntoSegmentedCoord](trait.IntoSegmentedCoord.html) is used to apply this decorator to coordinates.
+///   Use [edge_key_points](#method.edge_key_points) to put the tick marks and labels on the edges of the segments instead.
 #[derive(Clone)]
-pub struct SegmentedCoord<D: DiscreteRanged>(D);
+pub struct SegmentedCoord<D: DiscreteRanged>(D, bool);
+
+impl<D: DiscreteRanged> SegmentedCoord<D> {
+    /// Emit the key points on the left edges of the segments, i.e. `Exact(value)`, rather than
+    /// their centers, thus the mesh labels the boundaries of the buckets.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let coord = (0..10).into_segmented().edge_key_points();
+    /// assert!(coord.key_points(20).iter().all(|v| matches!(v, SegmentValue::Exact(_))));
+    /// ```
+    pub fn edge_key_points(mut self) -> Self {
+        self.1 = true;
+        self
+    }
+}
 
 /// The trait for types that can decorated by [SegmentedCoord](struct.SegmentedCoord.html) decorator.
 pub trait IntoSegmentedCoord: AsRangedCoord
@@ -105,14 +124,29 @@ where
 {
     /// Convert current ranged value into a segmented coordinate
     fn into_segmented(self) -> SegmentedCoord<Self::CoordDescType> {
-        SegmentedCoord(self.into())
+        SegmentedCoord(self.into(), false)
     }
 }
 
 impl<R: AsRangedCoord> IntoSegmentedCoord for R where R::CoordDescType: DiscreteRanged {}
 
 /// The value that used by the segmented coordinate.
-#[derive(Clone, Debug)]
+///
+/// The values are ordered by their positions along an ascending coordinate: `Exact(v)` is
+/// followed by `CenterOf(v)`, which is followed by `Exact` of any larger value, and `Last` is
+/// after everything else.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let value: SegmentValue<i32> = 3.into();
+/// assert_eq!(value.value(), Some(&3));
+/// assert!(SegmentValue::Exact(3) < SegmentValue::CenterOf(3));
+/// assert!(SegmentValue::CenterOf(3) < SegmentValue::Exact(4));
+/// assert_eq!(Option::<i32>::from(SegmentValue::CenterOf(3)), Some(3));
+/// assert_eq!(format!("{}", SegmentValue::CenterOf(3)), "3");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum SegmentValue<T> {
     /// Means we are referring the exact position of value `T`
     Exact(T),
@@ -122,6 +156,74 @@ pub enum SegmentValue<T> {
     Last,
 }
 
+impl<T> SegmentValue<T> {
+    /// Get the value of the segment, which is `None` for the last dummy element
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            SegmentValue::Exact(value) | SegmentValue::CenterOf(value) => Some(value),
+            SegmentValue::Last => None,
+        }
+    }
+
+    /// Convert into the value of the segment, which is `None` for the last dummy element
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            SegmentValue::Exact(value) | SegmentValue::CenterOf(value) => Some(value),
+            SegmentValue::Last => None,
+        }
+    }
+
+    /// Check if this refers the center of a segment
+    pub fn is_center(&self) -> bool {
+        matches!(self, SegmentValue::CenterOf(_))
+    }
+
+    // The position of the value within its segment, which breaks the tie of the same value
+    fn offset(&self) -> u8 {
+        match self {
+            SegmentValue::Exact(_) => 0,
+            SegmentValue::CenterOf(_) => 1,
+            SegmentValue::Last => 2,
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for SegmentValue<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.value(), other.value()) {
+            (Some(a), Some(b)) => match a.partial_cmp(b)? {
+                Ordering::Equal => self.offset().partial_cmp(&other.offset()),
+                ord => Some(ord),
+            },
+            _ => self.offset().partial_cmp(&other.offset()),
+        }
+    }
+}
+
+impl<T: Ord> Ord for SegmentValue<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.value(), other.value()) {
+            (Some(a), Some(b)) => a.cmp(b).then(self.offset().cmp(&other.offset())),
+            _ => self.offset().cmp(&other.offset()),
+        }
+    }
+}
+
+impl<T> From<SegmentValue<T>> for Option<T> {
+    fn from(value: SegmentValue<T>) -> Option<T> {
+        value.into_value()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SegmentValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value() {
+            Some(value) => value.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
 impl<T, D: DiscreteRanged + Ranged<ValueType = T>> ValueFormatter<SegmentValue<T>>
     for SegmentedCoord<D>
 where
@@ -163,17 +265,32 @@ impl<D: DiscreteRanged> Ranged for SegmentedCoord<D> {
     }
 
     fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
-        self.0
-            .key_points(hint)
-            .into_iter()
-            .map(SegmentValue::CenterOf)
-            .collect()
+        let key_points = self.0.key_points(hint).into_iter();
+        if self.1 {
+            key_points.map(SegmentValue::Exact).collect()
+        } else {
+            key_points.map(SegmentValue::CenterOf).collect()
+        }
     }
 
     fn range(&self) -> Range<Self::ValueType> {
         let range = self.0.range();
         SegmentValue::Exact(range.start)..SegmentValue::Exact(range.end)
     }
+
+    fn is_finite_value(&self, value: &Self::ValueType) -> bool {
+        match value {
+            SegmentValue::Exact(value) | SegmentValue::CenterOf(value) => {
+                self.0.is_finite_value(value)
+            }
+            SegmentValue::Last => true,
+        }
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        let margin = ((limit.1 - limit.0) as f32 / self.0.size() as f32).round() as i32;
+        self.0.axis_breaks((limit.0, limit.1 - margin))
+    }
 }
 
 impl<D: DiscreteRanged> DiscreteRanged for SegmentedCoord<D> {
@@ -271,4 +388,60 @@ mod test {
         assert_eq!(coord.map(&SegmentValue::Exact(0), (0, 24)), 0);
         assert_eq!(coord.map(&SegmentValue::Exact(1), (0, 24)), 2);
     }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_descending_centric_coord() {
+        let coord = (10..0).into_segmented();
+
+        assert_eq!(coord.size(), 12);
+        assert_eq!(coord.index_of(&SegmentValue::Exact(10)), Some(0));
+        assert_eq!(coord.index_of(&SegmentValue::Exact(0)), Some(10));
+        assert_eq!(coord.index_of(&SegmentValue::Exact(11)), None);
+        assert!(matches!(coord.from_index(1), Some(SegmentValue::Exact(9))));
+        assert!(matches!(coord.from_index(11), Some(SegmentValue::Last)));
+        assert!(coord.from_index(12).is_none());
+
+        assert_eq!(coord.map(&SegmentValue::Exact(10), (0, 22)), 0);
+        assert_eq!(coord.map(&SegmentValue::CenterOf(10), (0, 22)), 1);
+        assert_eq!(coord.map(&SegmentValue::Exact(0), (0, 22)), 20);
+        assert_eq!(coord.map(&SegmentValue::Last, (0, 22)), 22);
+
+        let range: crate::coord::ranged1d::types::RangedCoordu32 = (3..0).into();
+        assert_eq!(range.values().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!(range.from_index(4), None);
+    }
+
+    #[test]
+    fn test_segment_value() {
+        let mut values = vec![
+            SegmentValue::Last,
+            SegmentValue::Exact(2),
+            SegmentValue::CenterOf(1),
+            SegmentValue::CenterOf(2),
+            SegmentValue::Exact(1),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                SegmentValue::Exact(1),
+                SegmentValue::CenterOf(1),
+                SegmentValue::Exact(2),
+                SegmentValue::CenterOf(2),
+                SegmentValue::Last,
+            ]
+        );
+        assert_eq!(SegmentValue::<i32>::Last.value(), None);
+        assert_eq!(SegmentValue::CenterOf(5).into_value(), Some(5));
+        assert_eq!(SegmentValue::from(5), SegmentValue::Exact(5));
+        assert_eq!(SegmentValue::<i32>::Last.to_string(), "");
+
+        let coord = (0..10).into_segmented();
+        assert!(coord.key_points(20).iter().all(SegmentValue::is_center));
+        let coord = coord.edge_key_points();
+        let key_points = coord.key_points(20);
+        assert_eq!(key_points.first(), Some(&SegmentValue::Exact(0)));
+        assert_eq!(coord.map(&key_points[1], (0, 24)), 2);
+    }
 }
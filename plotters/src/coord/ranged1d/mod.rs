@@ -207,6 +207,19 @@ pub trait Ranged {
     /// Get the range of this value
     fn range(&self) -> Range<Self::ValueType>;
 
+    /// Check if the value has a meaningful position on the axis. For example, NaN and the
+    /// infinities of a floating point axis don't, and the chart handles them according to its
+    /// [NonFinitePolicy](../enum.NonFinitePolicy.html).
+    fn is_finite_value(&self, _value: &Self::ValueType) -> bool {
+        true
+    }
+
+    /// The pixel positions of the breaks of a discontinuous axis, where the mesh draws the break
+    /// marks. The axis is continuous by default, see [BrokenAxis](combinators/struct.BrokenAxis.html).
+    fn axis_breaks(&self, _limit: (i32, i32)) -> Vec<i32> {
+        vec![]
+    }
+
     /// This function provides the on-axis part of its range
     #[allow(clippy::range_plus_one)]
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
@@ -0,0 +1,241 @@
+use std::ops::Range;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDateTime};
+
+use crate::coord::ranged1d::combinators::{IntoLogRange, LogCoord};
+use crate::coord::ranged1d::types::RangedCoordf64;
+#[cfg(feature = "chrono")]
+use crate::coord::ranged1d::types::RangedDateTime;
+use crate::coord::ranged1d::{
+    KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+
+/// The kind of an axis, which is used to choose the coordinate spec of
+/// [AnyRanged](enum.AnyRanged.html) at runtime. The variants depend on the enabled features and
+/// more kinds may be added, thus the enum is non-exhaustive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AxisKind {
+    /// The linear axis
+    Linear,
+    /// The logarithmic axis
+    Log,
+    /// The time axis, the values are the seconds since the Unix epoch in UTC
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+    DateTime,
+}
+
+/// The coordinate spec of the f64 values whose kind of axis is chosen at runtime, for example,
+/// from a config file or a toggle in the UI.
+///
+/// The coordinate specs are statically typed, thus an application switching between the linear,
+/// the logarithmic and the time axes would otherwise need a code path for each combination of the
+/// axes. All the kinds of `AnyRanged` have the same type, and the values are always f64, thus the
+/// same chart code works for any kind of the axes. The time axis takes the seconds since the Unix
+/// epoch, and formats the labels as the date and time in UTC. Like [AxisKind](enum.AxisKind.html),
+/// the enum is non-exhaustive.
+///
+/// ```rust
+/// use plotters::coord::types::{AnyRanged, AxisKind};
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 1024 * 768 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+///
+/// // The kind of the Y axis is usually read from the settings of the application
+/// let use_log_scale = true;
+/// let y_kind = if use_log_scale {
+///     AxisKind::Log
+/// } else {
+///     AxisKind::Linear
+/// };
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_2d(
+///         AnyRanged::new(AxisKind::Linear, 0.0..10.0),
+///         AnyRanged::new(y_kind, 1.0..1e5),
+///     )
+///     .unwrap();
+/// chart
+///     .draw_series(LineSeries::new((0..=10).map(|x| (x as f64, 10f64.powi(x / 2))), &RED))
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum AnyRanged {
+    /// The linear axis
+    Linear(RangedCoordf64),
+    /// The logarithmic axis
+    Log(LogCoord<f64>),
+    /// The time axis, the values are the seconds since the Unix epoch in UTC
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+    DateTime(RangedDateTime<NaiveDateTime>),
+}
+
+#[cfg(feature = "chrono")]
+fn to_date_time(value: f64) -> NaiveDateTime {
+    let secs = value.floor();
+    let nanos = ((value - secs) * 1e9).round().min(999_999_999.0) as u32;
+    DateTime::from_timestamp(secs as i64, nanos)
+        .unwrap_or_default()
+        .naive_utc()
+}
+
+#[cfg(feature = "chrono")]
+fn to_timestamp(value: &NaiveDateTime) -> f64 {
+    let value = value.and_utc();
+    value.timestamp() as f64 + f64::from(value.timestamp_subsec_nanos()) / 1e9
+}
+
+impl AnyRanged {
+    /// Create the coordinate spec of the given kind
+    ///
+    /// - `kind`: The kind of the axis
+    /// - `range`: The range of the axis, which is in the seconds since the Unix epoch for the
+    ///   time axis
+    /// - **returns**: The newly created coordinate spec
+    pub fn new(kind: AxisKind, range: Range<f64>) -> Self {
+        match kind {
+            AxisKind::Linear => AnyRanged::Linear(range.into()),
+            AxisKind::Log => AnyRanged::Log(range.log_scale().into()),
+            #[cfg(feature = "chrono")]
+            AxisKind::DateTime => {
+                AnyRanged::DateTime((to_date_time(range.start)..to_date_time(range.end)).into())
+            }
+        }
+    }
+
+    /// Get the kind of the axis
+    pub fn kind(&self) -> AxisKind {
+        match self {
+            AnyRanged::Linear(_) => AxisKind::Linear,
+            AnyRanged::Log(_) => AxisKind::Log,
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(_) => AxisKind::DateTime,
+        }
+    }
+}
+
+impl Ranged for AnyRanged {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        match self {
+            AnyRanged::Linear(inner) => inner.map(value, limit),
+            AnyRanged::Log(inner) => inner.map(value, limit),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(inner) => inner.map(&to_date_time(*value), limit),
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        match self {
+            AnyRanged::Linear(inner) => inner.key_points(hint),
+            AnyRanged::Log(inner) => inner.key_points(hint),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(inner) => inner.key_points(hint).iter().map(to_timestamp).collect(),
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        match self {
+            AnyRanged::Linear(inner) => inner.range(),
+            AnyRanged::Log(inner) => inner.range(),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(inner) => {
+                let range = inner.range();
+                to_timestamp(&range.start)..to_timestamp(&range.end)
+            }
+        }
+    }
+
+    fn is_finite_value(&self, value: &f64) -> bool {
+        match self {
+            AnyRanged::Linear(inner) => inner.is_finite_value(value),
+            AnyRanged::Log(inner) => inner.is_finite_value(value),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(_) => {
+                value.is_finite() && DateTime::from_timestamp(value.floor() as i64, 0).is_some()
+            }
+        }
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        match self {
+            AnyRanged::Linear(inner) => inner.axis_pixel_range(limit),
+            AnyRanged::Log(inner) => inner.axis_pixel_range(limit),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(inner) => inner.axis_pixel_range(limit),
+        }
+    }
+}
+
+impl ReversibleRanged for AnyRanged {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        match self {
+            AnyRanged::Linear(inner) => inner.unmap(input, limit),
+            AnyRanged::Log(inner) => inner.unmap(input, limit),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(inner) => inner.unmap(input, limit).as_ref().map(to_timestamp),
+        }
+    }
+}
+
+impl ValueFormatter<f64> for AnyRanged {
+    fn format(value: &f64) -> String {
+        RangedCoordf64::format(value)
+    }
+
+    fn format_ext(&self, value: &f64) -> String {
+        match self {
+            AnyRanged::Linear(inner) => inner.format_ext(value),
+            AnyRanged::Log(_) => Self::format(value),
+            #[cfg(feature = "chrono")]
+            AnyRanged::DateTime(inner) => inner.format_ext(&to_date_time(*value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_any_ranged() {
+        let linear = AnyRanged::new(AxisKind::Linear, 0.0..10.0);
+        assert_eq!(linear.kind(), AxisKind::Linear);
+        assert_eq!(linear.map(&5.0, (0, 100)), 50);
+        assert_eq!(linear.unmap(50, (0, 100)), Some(5.0));
+        assert_eq!(linear.format_ext(&2.5), "2.5");
+
+        let log = AnyRanged::new(AxisKind::Log, 1.0..1000.0);
+        assert_eq!(log.kind(), AxisKind::Log);
+        assert_eq!(log.range(), 1.0..1000.0);
+        assert_eq!(log.map(&10.0, (0, 300)), 100);
+        assert!((log.unmap(200, (0, 300)).unwrap() - 100.0).abs() < 1e-6);
+        assert_eq!(log.key_points(4), [1.0, 10.0, 100.0, 1000.0]);
+        assert!(!log.is_finite_value(&0.0));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_any_ranged_date_time() {
+        // 2024-01-01T00:00:00Z and a day later
+        let start = 1_704_067_200.0;
+        let time = AnyRanged::new(AxisKind::DateTime, start..start + 86400.0);
+        assert_eq!(time.kind(), AxisKind::DateTime);
+        assert_eq!(time.range(), start..start + 86400.0);
+        assert_eq!(time.map(&(start + 43200.0), (0, 100)), 50);
+        assert_eq!(time.unmap(25, (0, 100)), Some(start + 21600.0));
+        // The labels are the same as the ones of the time coordinate
+        assert_eq!(time.format_ext(&start), "00:00\nJan 1");
+        assert_eq!(time.format_ext(&(start + 3600.0)), "01:00");
+        assert_eq!(time.format_ext(&(start + 3600.5)), "01:00:00.5");
+        let key_points = time.key_points(5);
+        assert!(!key_points.is_empty() && key_points.len() <= 5);
+        assert!(key_points.iter().all(|t| (t - start) % 3600.0 == 0.0));
+    }
+}
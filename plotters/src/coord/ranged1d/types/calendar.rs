@@ -0,0 +1,417 @@
+/*!
+  The calendar aware key points and labels, which are shared by the date and time coordinates of
+  `chrono` and `time`
+*/
+
+use std::sync::Mutex;
+
+const NS_PER_SECOND: i64 = 1_000_000_000;
+
+/// The fixed steps between the key points shorter than a day, in nanoseconds. All of them evenly
+/// divide a day, so the key points fall on the round times.
+pub(crate) const SUB_DAILY_STEPS: [i64; 44] = {
+    const S: i64 = NS_PER_SECOND;
+    [
+        1,
+        2,
+        5,
+        10,
+        20,
+        50,
+        100,
+        200,
+        500,
+        1_000,
+        2_000,
+        5_000,
+        10_000,
+        20_000,
+        50_000,
+        100_000,
+        200_000,
+        500_000,
+        1_000_000,
+        2_000_000,
+        5_000_000,
+        10_000_000,
+        20_000_000,
+        50_000_000,
+        100_000_000,
+        200_000_000,
+        500_000_000,
+        S,
+        2 * S,
+        5 * S,
+        10 * S,
+        15 * S,
+        30 * S,
+        60 * S,
+        120 * S,
+        300 * S,
+        600 * S,
+        900 * S,
+        1800 * S,
+        3600 * S,
+        7200 * S,
+        10800 * S,
+        21600 * S,
+        43200 * S,
+    ]
+};
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The number of days from 1970-01-01 to the date, in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The year, the month and the day of the date which is the number of days from 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+/// The number of the month from the January of the year 0
+fn month_index(days: i64) -> i64 {
+    let (year, month, _) = civil_from_days(days);
+    year * 12 + month - 1
+}
+
+/// The first day of the month which is the number of months from the January of the year 0
+fn first_day_of_month(index: i64) -> i64 {
+    days_from_civil(index.div_euclid(12), index.rem_euclid(12) + 1, 1)
+}
+
+/// A point of time which can be placed on the calendar. The days and the time of the day are
+/// those of the wall clock, thus the key points follow the local time of a time zone.
+pub trait CalendarValue: Clone + PartialOrd {
+    /// The day of the value, as the number of days from 1970-01-01
+    fn day_number(&self) -> i64;
+    /// The number of nanoseconds from the midnight to the value on the wall clock
+    fn nanosecond_of_day(&self) -> i64;
+    /// The earliest valid time of the day in the time zone of this value, which is the midnight
+    /// unless a daylight saving time transition skips it
+    fn start_of_day(&self, day: i64) -> Option<Self>;
+    /// Add a number of nanoseconds to the value
+    fn add_nanoseconds(&self, ns: i64) -> Option<Self>;
+    /// The number of nanoseconds from the other value to this value
+    fn nanoseconds_since(&self, other: &Self) -> Option<i128>;
+}
+
+/// The step between the key points, which snaps them to the natural boundaries of the calendar
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CalendarStep {
+    /// A fixed duration shorter than a day, in nanoseconds, counted from the midnight
+    Fixed(i64),
+    /// Every midnight
+    Day,
+    /// Every Monday
+    Week,
+    /// The first day of the months which are 1, 3 or 6 months apart, counted from January
+    Months(i64),
+    /// The first day of the years which are multiples of the number
+    Years(i64),
+}
+
+/// The step and the first key point of the key points last generated for an axis, thus the
+/// labels are formatted against the key points the axis actually shows
+pub(crate) struct AxisKeyPoints<T>(Mutex<Option<(CalendarStep, T)>>);
+
+impl<T: Clone> AxisKeyPoints<T> {
+    fn get(&self) -> Option<(CalendarStep, T)> {
+        self.0.lock().ok().and_then(|axis| axis.clone())
+    }
+
+    fn set(&self, axis: Option<(CalendarStep, T)>) {
+        if let Ok(mut current) = self.0.lock() {
+            *current = axis;
+        }
+    }
+}
+
+impl<T: Clone> Clone for AxisKeyPoints<T> {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.get()))
+    }
+}
+
+impl<T> Default for AxisKeyPoints<T> {
+    fn default() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+/// The key points and the labels of a date or time range on the calendar
+pub(crate) struct Calendar<'a, T> {
+    start: T,
+    end: T,
+    sub_daily: bool,
+    axis: &'a AxisKeyPoints<T>,
+}
+
+impl<'a, T: CalendarValue> Calendar<'a, T> {
+    /// Create the calendar of a range
+    /// - `start`: The start of the range
+    /// - `end`: The end of the range, which is excluded
+    /// - `sub_daily`: If the steps shorter than a day are used, which isn't the case for dates
+    /// - `axis`: The key points last generated for the axis of the range
+    pub(crate) fn new(start: T, end: T, sub_daily: bool, axis: &'a AxisKeyPoints<T>) -> Self {
+        Self {
+            start,
+            end,
+            sub_daily,
+            axis,
+        }
+    }
+
+    /// The steps from the finest to the coarsest
+    fn steps(&self) -> Vec<CalendarStep> {
+        let fixed = SUB_DAILY_STEPS.iter().map(|ns| CalendarStep::Fixed(*ns));
+        let years = (0..9).flat_map(|exp| {
+            [1, 2, 5]
+                .iter()
+                .map(move |m| CalendarStep::Years(m * 10i64.pow(exp)))
+        });
+        fixed
+            .filter(|_| self.sub_daily)
+            .chain(vec![
+                CalendarStep::Day,
+                CalendarStep::Week,
+                CalendarStep::Months(1),
+                CalendarStep::Months(3),
+                CalendarStep::Months(6),
+            ])
+            .chain(years)
+            .collect()
+    }
+
+    /// Generate the key points of the step in the range.
+    /// - **returns**: The key points, or `None` if there are more than `max_points` of them
+    fn step_key_points(&self, step: CalendarStep, max_points: usize) -> Option<Vec<T>> {
+        let mut ret = vec![];
+        let mut push = |point: Option<T>| match point {
+            Some(point) if point < self.end => {
+                if ret.len() == max_points {
+                    return None;
+                }
+                ret.push(point);
+                Some(true)
+            }
+            _ => Some(false),
+        };
+
+        if let CalendarStep::Fixed(step) = step {
+            let total_ns = self.end.nanoseconds_since(&self.start)?;
+            if total_ns / i128::from(step) > max_points as i128 {
+                return None;
+            }
+            // The points are aligned to the wall clock, so a daylight saving time transition in
+            // the middle of the range doesn't shift the points after it.
+            let to_next_point = |point: &T| {
+                let ns = (step - point.nanosecond_of_day() % step) % step;
+                point.add_nanoseconds(ns)
+            };
+            let mut point = to_next_point(&self.start);
+            while push(point.clone())? {
+                point = point
+                    .and_then(|p| p.add_nanoseconds(step))
+                    .and_then(|p| to_next_point(&p));
+            }
+            return Some(ret);
+        }
+
+        let mut first_day = self.start.day_number();
+        if !matches!(self.start.start_of_day(first_day), Some(p) if p >= self.start) {
+            first_day += 1;
+        }
+        match step {
+            CalendarStep::Day | CalendarStep::Week => {
+                let (mut day, days) = if step == CalendarStep::Day {
+                    (first_day, 1)
+                } else {
+                    // The day 0 is a Thursday
+                    (first_day + (4 - first_day).rem_euclid(7), 7)
+                };
+                while push(self.start.start_of_day(day))? {
+                    day += days;
+                }
+            }
+            CalendarStep::Months(months) => {
+                let (_, _, day) = civil_from_days(first_day);
+                let mut index = month_index(first_day) + (day > 1) as i64;
+                index += (months - index.rem_euclid(months)) % months;
+                while push(self.start.start_of_day(first_day_of_month(index)))? {
+                    index += months;
+                }
+            }
+            CalendarStep::Years(years) => {
+                let (mut year, month, day) = civil_from_days(first_day);
+                year += (month > 1 || day > 1) as i64;
+                year += (years - year.rem_euclid(years)) % years;
+                while push(self.start.start_of_day(days_from_civil(year, 1, 1)))? {
+                    year += years;
+                }
+            }
+            CalendarStep::Fixed(_) => unreachable!(),
+        }
+        Some(ret)
+    }
+
+    /// The key points of the finest step which yields no more than `max_points` of them, or the
+    /// start of the range if the range is too narrow to contain any boundary of the steps. The
+    /// step and the first key point are kept for labeling the key points.
+    pub(crate) fn key_points(&self, max_points: usize) -> Vec<T> {
+        if max_points == 0 {
+            return vec![];
+        }
+        match self
+            .steps()
+            .into_iter()
+            .find_map(|step| Some((step, self.step_key_points(step, max_points)?)))
+        {
+            Some((step, points)) if !points.is_empty() => {
+                self.axis.set(Some((step, points[0].clone())));
+                points
+            }
+            _ => {
+                self.axis.set(None);
+                vec![self.start.clone()]
+            }
+        }
+    }
+
+    /// Check if the value is one of the key points of the step
+    fn is_aligned(&self, value: &T, step: CalendarStep) -> bool {
+        if let CalendarStep::Fixed(ns) = step {
+            return value.nanosecond_of_day() % ns == 0;
+        }
+        let (year, month, day) = civil_from_days(value.day_number());
+        matches!(value.start_of_day(value.day_number()), Some(p) if p == *value)
+            && match step {
+                CalendarStep::Week => (value.day_number() - 4).rem_euclid(7) == 0,
+                CalendarStep::Months(months) => day == 1 && (month - 1) % months == 0,
+                CalendarStep::Years(years) => month == 1 && day == 1 && year % years == 0,
+                _ => true,
+            }
+    }
+
+    /// The key point before the given one with the step
+    fn previous(&self, value: &T, step: CalendarStep) -> Option<T> {
+        let day = value.day_number();
+        match step {
+            CalendarStep::Fixed(ns) => value.add_nanoseconds(-(ns)),
+            CalendarStep::Day => value.start_of_day(day - 1),
+            CalendarStep::Week => value.start_of_day(day - 7),
+            CalendarStep::Months(months) => {
+                value.start_of_day(first_day_of_month(month_index(day) - months))
+            }
+            CalendarStep::Years(_) => None,
+        }
+    }
+
+    /**
+    Format the value as the unit of the coarsest step it's a key point of, such as `14:30`,
+    `Mar 5`, `Mar` or `2024`. Only the steps with more than one key point in the range are
+    considered, thus the label shows every unit the value differs from the other key points in,
+    regardless of how many key points the axis has. The years are labeled as such only if the
+    key points of the axis are years, otherwise the first key point of a year is labeled as a
+    month. On the first key point of the axis, and the first key point in each day or each year,
+    the enclosing day or year is added on the second line, such as `Mar\n2024`.
+
+    - `value`: The value to format
+    - **returns**: The label, or `None` if the value isn't a key point of any step
+    */
+    pub(crate) fn label(&self, value: &T) -> Option<String> {
+        // The key points of the axis, if the value is one of them
+        let axis = self
+            .axis
+            .get()
+            .filter(|(step, _)| self.is_aligned(value, *step));
+        let years = matches!(axis, None | Some((CalendarStep::Years(_), _)));
+        let labeled_with = |step: &CalendarStep| {
+            self.is_aligned(value, *step) && self.step_key_points(*step, 1).is_none()
+        };
+        let steps = self.steps();
+        let step = steps
+            .iter()
+            .rev()
+            .filter(|step| years || !matches!(step, CalendarStep::Years(_)))
+            .find(|step| labeled_with(step))
+            .or_else(|| steps.iter().rev().find(|step| labeled_with(step)))
+            .copied()?;
+        let previous = self.previous(value, step);
+        let first = match &axis {
+            Some((_, first)) => value <= first,
+            None => !matches!(&previous, Some(p) if *p >= self.start),
+        };
+        let (year, month, day) = civil_from_days(value.day_number());
+        let month = MONTH_NAMES[month as usize - 1];
+
+        let (mut ret, context, new_context) = match step {
+            CalendarStep::Fixed(ns) => {
+                let of_day = value.nanosecond_of_day();
+                let seconds = of_day / NS_PER_SECOND;
+                let mut ret = format!("{:02}:{:02}", seconds / 3600, seconds / 60 % 60);
+                if ns % (60 * NS_PER_SECOND) != 0 {
+                    ret += &format!(":{:02}", seconds % 60);
+                }
+                if ns % NS_PER_SECOND != 0 {
+                    ret += format!(".{:09}", of_day % NS_PER_SECOND).trim_end_matches('0');
+                }
+                let new_day = !matches!(&previous, Some(p) if p.day_number() == value.day_number());
+                (ret, format!("{} {}", month, day), new_day)
+            }
+            CalendarStep::Years(_) => return Some(year.to_string()),
+            _ => {
+                let ret = match step {
+                    CalendarStep::Months(_) => month.to_string(),
+                    _ => format!("{} {}", month, day),
+                };
+                let new_year = !matches!(
+                    &previous,
+                    Some(p) if civil_from_days(p.day_number()).0 == year
+                );
+                (ret, year.to_string(), new_year)
+            }
+        };
+        if first || new_context {
+            ret += "\n";
+            ret += &context;
+        }
+        Some(ret)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        for days in (-1_000_000..1_000_000).step_by(997) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(civil_from_days(19_723), (2024, 1, 1));
+    }
+}
@@ -1,7 +1,9 @@
 /// The datetime coordinates
 use chrono::{Date, DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use std::convert::TryFrom;
 use std::ops::{Add, Range, Sub};
 
+use super::calendar::{AxisKeyPoints, Calendar, CalendarValue};
 use crate::coord::ranged1d::{
     AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
     ReversibleRanged, ValueFormatter,
@@ -135,14 +137,14 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
         self.date()
     }
     fn date_ceil(&self) -> Date<Z> {
-        if self.time().num_seconds_from_midnight() > 0 {
+        if *self > start_of_day(&self.date()) {
             self.date() + Duration::days(1)
         } else {
             self.date()
         }
     }
     fn earliest_after_date(date: Date<Z>) -> DateTime<Z> {
-        date.and_hms(0, 0, 0)
+        start_of_day(&date)
     }
 
     fn subtract(&self, other: &DateTime<Z>) -> Duration {
@@ -157,10 +159,24 @@ impl<Z: TimeZone> TimeValue for DateTime<Z> {
     }
 
     fn from_date(date: Self::DateType) -> Self {
-        date.and_hms(0, 0, 0)
+        start_of_day(&date)
     }
 }
 
+/// The earliest time of the date in its time zone. This is the midnight, unless a daylight
+/// saving time transition skips it, in which case it's the end of the skipped hours.
+fn start_of_day<Z: TimeZone>(date: &Date<Z>) -> DateTime<Z> {
+    let timezone = date.timezone();
+    let date = date.naive_local();
+    (0..24 * 60)
+        .step_by(15)
+        .find_map(|minutes| {
+            let time = date.and_hms(minutes / 60, minutes % 60, 0);
+            timezone.from_local_datetime(&time).earliest()
+        })
+        .unwrap_or_else(|| timezone.from_utc_datetime(&date.and_hms(0, 0, 0)))
+}
+
 impl TimeValue for NaiveDateTime {
     type DateType = NaiveDate;
 
@@ -626,9 +642,14 @@ impl<T: TimeValue> IntoYearly<T> for Range<T> {
     }
 }
 
-/// The ranged coordinate for the date and time
+/// The ranged coordinate for the date and time.
+///
+/// For a `DateTime<Tz>` range, with any time zone such as `FixedOffset` or the ones of
+/// `chrono-tz`, the key points and the labels follow the local time of the time zone. A day
+/// whose midnight is skipped by a daylight saving time transition starts at the first valid
+/// local time.
 #[derive(Clone)]
-pub struct RangedDateTime<DT: Datelike + Timelike + TimeValue>(DT, DT);
+pub struct RangedDateTime<DT: Datelike + Timelike + TimeValue>(DT, DT, AxisKeyPoints<DT>);
 
 impl<Z: TimeZone> AsRangedCoord for Range<DateTime<Z>> {
     type CoordDescType = RangedDateTime<DateTime<Z>>;
@@ -637,24 +658,87 @@ impl<Z: TimeZone> AsRangedCoord for Range<DateTime<Z>> {
 
 impl<Z: TimeZone> From<Range<DateTime<Z>>> for RangedDateTime<DateTime<Z>> {
     fn from(range: Range<DateTime<Z>>) -> Self {
-        Self(range.start, range.end)
+        Self(range.start, range.end, AxisKeyPoints::default())
     }
 }
 
 impl From<Range<NaiveDateTime>> for RangedDateTime<NaiveDateTime> {
     fn from(range: Range<NaiveDateTime>) -> Self {
-        Self(range.start, range.end)
+        Self(range.start, range.end, AxisKeyPoints::default())
+    }
+}
+
+/// The number of days from 0001-01-01 to 1970-01-01
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+fn nanosecond_of_day<T: Timelike>(value: &T) -> i64 {
+    i64::from(value.num_seconds_from_midnight()) * 1_000_000_000 + i64::from(value.nanosecond())
+}
+
+fn date_of_day(day: i64) -> Option<NaiveDate> {
+    let days_from_ce = i32::try_from(day + UNIX_EPOCH_DAYS_FROM_CE).ok()?;
+    NaiveDate::from_num_days_from_ce_opt(days_from_ce)
+}
+
+impl CalendarValue for NaiveDateTime {
+    fn day_number(&self) -> i64 {
+        i64::from(self.num_days_from_ce()) - UNIX_EPOCH_DAYS_FROM_CE
+    }
+    fn nanosecond_of_day(&self) -> i64 {
+        nanosecond_of_day(self)
+    }
+    fn start_of_day(&self, day: i64) -> Option<Self> {
+        date_of_day(day)?.and_hms_opt(0, 0, 0)
+    }
+    fn add_nanoseconds(&self, ns: i64) -> Option<Self> {
+        self.checked_add_signed(Duration::nanoseconds(ns))
+    }
+    fn nanoseconds_since(&self, other: &Self) -> Option<i128> {
+        (*self - *other).num_nanoseconds().map(i128::from)
+    }
+}
+
+impl<Z: TimeZone> CalendarValue for DateTime<Z> {
+    fn day_number(&self) -> i64 {
+        i64::from(self.num_days_from_ce()) - UNIX_EPOCH_DAYS_FROM_CE
+    }
+    fn nanosecond_of_day(&self) -> i64 {
+        nanosecond_of_day(self)
+    }
+    fn start_of_day(&self, day: i64) -> Option<Self> {
+        let date = date_of_day(day)?;
+        let date = self
+            .timezone()
+            .ymd_opt(date.year(), date.month(), date.day());
+        Some(start_of_day(&date.earliest()?))
+    }
+    fn add_nanoseconds(&self, ns: i64) -> Option<Self> {
+        self.clone().checked_add_signed(Duration::nanoseconds(ns))
+    }
+    fn nanoseconds_since(&self, other: &Self) -> Option<i128> {
+        (self.clone() - other.clone())
+            .num_nanoseconds()
+            .map(i128::from)
+    }
+}
+
+impl<DT> RangedDateTime<DT>
+where
+    DT: Datelike + Timelike + TimeValue + CalendarValue,
+{
+    fn calendar(&self) -> Calendar<'_, DT> {
+        Calendar::new(self.0.clone(), self.1.clone(), true, &self.2)
     }
 }
 
 impl<DT> Ranged for RangedDateTime<DT>
 where
-    DT: Datelike + Timelike + TimeValue + Clone + PartialOrd,
+    DT: Datelike + Timelike + TimeValue + CalendarValue,
     DT: Add<Duration, Output = DT>,
     DT: Sub<DT, Output = Duration>,
     RangedDate<DT::DateType>: Ranged<ValueType = DT::DateType>,
 {
-    type FormatOption = DefaultFormatting;
+    type FormatOption = NoDefaultFormatting;
     type ValueType = DT;
 
     fn range(&self) -> Range<DT> {
@@ -665,49 +749,52 @@ where
         TimeValue::map_coord(value, &self.0, &self.1, limit)
     }
 
+    /// The key points snap to the natural boundaries of the calendar: the round times of the
+    /// day, the midnights, the Mondays, the first days of the months, quarters or half years,
+    /// and the first days of the round years. The finest step which yields no more than the
+    /// requested number of key points is used.
     fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
-        let max_points = hint.max_num_points();
-        let total_span = self.1.clone() - self.0.clone();
-
-        if let Some(total_ns) = total_span.num_nanoseconds() {
-            if let Some(actual_ns_per_point) =
-                compute_period_per_point(total_ns as u64, max_points, true)
-            {
-                let start_time_ns = u64::from(self.0.num_seconds_from_midnight()) * 1_000_000_000
-                    + u64::from(self.0.nanosecond());
-
-                let mut start_time = DT::from_date(self.0.date_floor())
-                    + Duration::nanoseconds(if start_time_ns % actual_ns_per_point > 0 {
-                        start_time_ns + (actual_ns_per_point - start_time_ns % actual_ns_per_point)
-                    } else {
-                        start_time_ns
-                    } as i64);
-
-                let mut ret = vec![];
-
-                while start_time < self.1 {
-                    ret.push(start_time.clone());
-                    start_time = start_time + Duration::nanoseconds(actual_ns_per_point as i64);
-                }
+        self.calendar().key_points(hint.max_num_points())
+    }
+}
 
-                return ret;
-            }
+impl<DT> ValueFormatter<DT> for RangedDateTime<DT>
+where
+    DT: Datelike + Timelike + TimeValue + CalendarValue,
+    DT: Add<Duration, Output = DT>,
+    DT: Sub<DT, Output = Duration>,
+    RangedDate<DT::DateType>: Ranged<ValueType = DT::DateType>,
+{
+    fn format(value: &DT) -> String {
+        let mut ret = format!(
+            "{}-{:02}-{:02} {:02}:{:02}:{:02}",
+            value.year(),
+            value.month(),
+            value.day(),
+            value.hour(),
+            value.minute(),
+            value.second()
+        );
+        if value.nanosecond() > 0 {
+            ret += format!(".{:09}", value.nanosecond()).trim_end_matches('0');
         }
+        ret
+    }
 
-        // Otherwise, it actually behaves like a date
-        let date_range = RangedDate(self.0.date_ceil(), self.1.date_floor());
-
-        date_range
-            .key_points(max_points)
-            .into_iter()
-            .map(DT::from_date)
-            .collect()
+    /// Format the value as the unit of the coarsest step it is a key point of, such as `14:30`,
+    /// `Mar 5`, `Mar` or `2024`. On the first key point of the axis, and the first key point in
+    /// each day or each year, the enclosing day or year is added on the second line, such as
+    /// `Mar\n2024`.
+    fn format_ext(&self, value: &DT) -> String {
+        self.calendar()
+            .label(value)
+            .unwrap_or_else(|| Self::format(value))
     }
 }
 
 impl<DT> ReversibleRanged for RangedDateTime<DT>
 where
-    DT: Datelike + Timelike + TimeValue + Clone + PartialOrd,
+    DT: Datelike + Timelike + TimeValue + CalendarValue,
     DT: Add<Duration, Output = DT>,
     DT: Sub<DT, Output = Duration>,
     RangedDate<DT::DateType>: Ranged<ValueType = DT::DateType>,
@@ -901,7 +988,53 @@ fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -
 #[cfg(test)]
 mod test {
     use super::*;
-    use chrono::{TimeZone, Utc};
+    use chrono::{FixedOffset, LocalResult, TimeZone, Utc};
+
+    /// A time zone which moves from UTC-3 to UTC-2 at the midnight of 2019-11-03, thus the
+    /// local times from 00:00 to 01:00 of that day are skipped
+    #[derive(Clone, Copy, Debug)]
+    struct MidnightDst;
+
+    impl MidnightDst {
+        fn transition() -> NaiveDateTime {
+            NaiveDate::from_ymd(2019, 11, 3).and_hms(3, 0, 0)
+        }
+    }
+
+    impl TimeZone for MidnightDst {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            MidnightDst
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            match (
+                *local + Duration::hours(3) < Self::transition(),
+                *local + Duration::hours(2) >= Self::transition(),
+            ) {
+                (true, false) => LocalResult::Single(FixedOffset::west(3 * 3600)),
+                (false, true) => LocalResult::Single(FixedOffset::west(2 * 3600)),
+                _ => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0))
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            if *utc < Self::transition() {
+                FixedOffset::west(3 * 3600)
+            } else {
+                FixedOffset::west(2 * 3600)
+            }
+        }
+    }
 
     #[test]
     fn test_date_range_long() {
@@ -1051,20 +1184,113 @@ mod test {
         let kps = coord.key_points(23);
 
         assert!(kps.len() <= 23);
-        let max = kps
+        assert_eq!(kps[0], Utc.ymd(1000, 1, 1).and_hms(0, 0, 0));
+        assert!(kps
             .iter()
             .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_seconds())
-            .max()
-            .unwrap();
-        let min = kps
+            .all(|(p, n)| n.year() - p.year() == 100 && n.ordinal() == 1));
+    }
+
+    #[test]
+    fn test_datetime_calendar_key_points() {
+        let start = Utc.ymd(2023, 2, 17).and_hms(2, 17, 43);
+        let coord: RangedDateTime<_> = (start..Utc.ymd(2025, 8, 3).and_hms(9, 0, 0)).into();
+
+        // Quarterly, starting from January
+        let kps = coord.key_points(11);
+        assert_eq!(kps.len(), 10);
+        assert_eq!(kps[0], Utc.ymd(2023, 4, 1).and_hms(0, 0, 0));
+        assert!(kps.iter().all(|p| p.day() == 1 && p.month0() % 3 == 0));
+        let labels: Vec<_> = kps.iter().map(|p| coord.format_ext(p)).collect();
+        assert_eq!(labels[..4], ["Apr\n2023", "Jul", "Oct", "Jan\n2024"]);
+
+        // Weekly, on Mondays
+        let coord: RangedDateTime<_> = (start..start + Duration::days(60)).into();
+        let kps = coord.key_points(11);
+        assert!(!kps.is_empty() && kps.len() <= 11);
+        assert!(kps
             .iter()
-            .zip(kps.iter().skip(1))
-            .map(|(p, n)| (*n - *p).num_seconds())
-            .min()
-            .unwrap();
-        assert!(max == min);
-        assert!(max % (24 * 3600 * 7) == 0);
+            .all(|p| p.weekday() == chrono::Weekday::Mon && p.hour() == 0));
+
+        // Every 15 minutes
+        let coord: RangedDateTime<_> = (start..start + Duration::hours(2)).into();
+        let kps = coord.key_points(11);
+        assert_eq!(kps.len(), 8);
+        assert_eq!(kps[0], Utc.ymd(2023, 2, 17).and_hms(2, 30, 0));
+        let labels: Vec<_> = kps.iter().map(|p| coord.format_ext(p)).collect();
+        assert_eq!(labels[..2], ["02:30\nFeb 17", "02:45"]);
+
+        // Yearly labels don't have the second line
+        let coord: RangedDateTime<_> = (start..Utc.ymd(2043, 1, 1).and_hms(0, 0, 0)).into();
+        assert_eq!(
+            coord.format_ext(&Utc.ymd(2030, 1, 1).and_hms(0, 0, 0)),
+            "2030"
+        );
+        assert_eq!(
+            RangedDateTime::<chrono::DateTime<Utc>>::format(&start),
+            "2023-02-17 02:17:43"
+        );
+    }
+
+    #[test]
+    fn test_datetime_labels_with_many_key_points() {
+        let start = Utc.ymd(2023, 2, 17).and_hms(2, 0, 0);
+
+        // Every 15 seconds
+        let coord: RangedDateTime<_> = (start..start + Duration::minutes(10)).into();
+        let labels: Vec<_> = coord
+            .key_points(50)
+            .iter()
+            .map(|p| coord.format_ext(p))
+            .collect();
+        assert_eq!(labels.len(), 40);
+        assert_eq!(
+            labels[..5],
+            ["02:00\nFeb 17", "02:00:15", "02:00:30", "02:00:45", "02:01"]
+        );
+
+        // Every day, with the months on their first days
+        let coord: RangedDateTime<_> = (start..start + Duration::days(365)).into();
+        let labels: Vec<_> = coord
+            .key_points(400)
+            .iter()
+            .map(|p| coord.format_ext(p))
+            .collect();
+        assert_eq!(labels.len(), 365);
+        let may = labels.iter().position(|l| l == "May").unwrap();
+        assert_eq!(labels[may - 1..may + 2], ["Apr 30", "May", "May 2"]);
+        for (idx, label) in labels.iter().enumerate() {
+            assert!(!labels[idx + 1..].contains(label), "{:?}", label);
+        }
+    }
+
+    #[test]
+    fn test_datetime_time_zone() {
+        let tz = MidnightDst;
+
+        // The day whose midnight is skipped starts at 01:00
+        let coord: RangedDateTime<_> =
+            (tz.ymd(2019, 11, 1).and_hms(12, 0, 0)..tz.ymd(2019, 11, 10).and_hms(12, 0, 0)).into();
+        let kps = coord.key_points(10);
+        let days: Vec<_> = kps.iter().map(|p| (p.day(), p.hour())).collect();
+        assert_eq!(days.len(), 9);
+        assert_eq!(days[..3], [(2, 0), (3, 1), (4, 0)]);
+        assert_eq!(coord.format_ext(&kps[1]), "Nov 3");
+
+        // The points after the transition stay on the round hours of the wall clock
+        let coord: RangedDateTime<_> =
+            (tz.ymd(2019, 11, 2).and_hms(18, 0, 0)..tz.ymd(2019, 11, 3).and_hms(12, 0, 0)).into();
+        let hours: Vec<_> = coord.key_points(6).iter().map(|p| p.hour()).collect();
+        assert_eq!(hours, [18, 21, 3, 6, 9]);
+
+        let coord =
+            (tz.ymd(2019, 10, 1).and_hms(0, 0, 0)..tz.ymd(2020, 1, 1).and_hms(0, 0, 0)).monthly();
+        let kps = coord.key_points(3);
+        assert!(kps.iter().all(|p| p.day() == 1 && p.hour() == 0));
+        assert_eq!(
+            kps.iter().map(|p| p.month()).collect::<Vec<_>>(),
+            [10, 11, 12]
+        );
     }
 
     #[test]
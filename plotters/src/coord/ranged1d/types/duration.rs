@@ -0,0 +1,201 @@
+/// The coordinate for the durations of the standard library
+use std::ops::Range;
+use std::time::Duration;
+
+use crate::coord::ranged1d::{
+    AsRangedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged, ValueFormatter,
+};
+
+const NS_PER_SEC: u128 = 1_000_000_000;
+
+/// The units of the labels, from the largest, with their length in seconds
+const UNITS: [(&str, f64); 6] = [
+    ("h", 3600.0),
+    ("min", 60.0),
+    ("s", 1.0),
+    ("ms", 1e-3),
+    ("µs", 1e-6),
+    ("ns", 1e-9),
+];
+
+/// The steps between the key points from a second to a day, in seconds. They are the round
+/// numbers of seconds, minutes and hours.
+const CLOCK_STEPS: [u128; 18] = [
+    1, 2, 5, 10, 15, 30, 60, 120, 300, 600, 900, 1800, 3600, 7200, 10800, 21600, 43200, 86400,
+];
+
+/**
+The ranged coordinate for [`std::time::Duration`], which is useful for plotting the results of
+profiling or benchmarks.
+
+The key points are the round numbers of nanoseconds up to a second, the round numbers of
+seconds, minutes and hours up to a day, and 1, 2 or 5 times a power of ten days beyond. The
+labels are in the unit that fits the magnitude of the value, such as `250 ms`, `1.5 s` or
+`2 h`, see [`RangedStdDuration::format_seconds()`].
+
+```
+use plotters::coord::ranged1d::ValueFormatter;
+use plotters::prelude::*;
+use std::time::Duration;
+
+let coord: RangedStdDuration = (Duration::ZERO..Duration::from_secs(3)).into();
+let labels: Vec<_> = coord.key_points(4).iter().map(|d| coord.format_ext(d)).collect();
+assert_eq!(labels, ["0 s", "1 s", "2 s", "3 s"]);
+```
+*/
+#[derive(Clone)]
+pub struct RangedStdDuration(Duration, Duration);
+
+impl From<Range<Duration>> for RangedStdDuration {
+    fn from(range: Range<Duration>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for Range<Duration> {
+    type CoordDescType = RangedStdDuration;
+    type Value = Duration;
+}
+
+impl RangedStdDuration {
+    /**
+    Format a number of seconds in the unit which fits its magnitude, from nanoseconds to hours,
+    with at most three decimals. This is also handy for the labels of a plain `f64` axis holding
+    seconds.
+
+    - `seconds`: The number of seconds
+    - **returns**: The formatted duration
+
+    ```
+    use plotters::prelude::*;
+    assert_eq!(RangedStdDuration::format_seconds(0.25), "250 ms");
+    assert_eq!(RangedStdDuration::format_seconds(90.0), "1.5 min");
+    assert_eq!(RangedStdDuration::format_seconds(2e-6), "2 µs");
+    ```
+    */
+    pub fn format_seconds(seconds: f64) -> String {
+        if seconds == 0.0 || !seconds.is_finite() {
+            return format!("{} s", seconds);
+        }
+        let (unit, scale) = UNITS
+            .iter()
+            .find(|(_, scale)| seconds.abs() >= *scale * (1.0 - 1e-9))
+            .unwrap_or(&UNITS[UNITS.len() - 1]);
+        let value = format!("{:.3}", seconds / scale);
+        let value = value.trim_end_matches('0').trim_end_matches('.');
+        format!("{} {}", value, unit)
+    }
+}
+
+impl Ranged for RangedStdDuration {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Duration;
+
+    fn range(&self) -> Range<Duration> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Duration, limit: (i32, i32)) -> i32 {
+        let begin = self.0.as_nanos() as f64;
+        let total = self.1.as_nanos() as f64 - begin;
+        if total == 0.0 {
+            return limit.0;
+        }
+        let ratio = (value.as_nanos() as f64 - begin) / total;
+        limit.0 + (f64::from(limit.1 - limit.0) * ratio + 1e-10) as i32
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Duration> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let (begin, end) = (self.0.as_nanos(), self.1.as_nanos());
+        let (begin, end) = (begin.min(end), begin.max(end));
+
+        let sub_second = (0..9).flat_map(|exp| [1, 2, 5].iter().map(move |m| m * 10u128.pow(exp)));
+        let clock = CLOCK_STEPS.iter().map(|secs| secs * NS_PER_SEC);
+        let daily = (0..30).flat_map(|exp| {
+            [2, 5, 10]
+                .iter()
+                .map(move |m| m * 10u128.pow(exp) * 86400 * NS_PER_SEC)
+        });
+        let step = sub_second.chain(clock).chain(daily).find(|step| {
+            let first = begin / step + (begin % step > 0) as u128;
+            let last = end / step;
+            last < first + max_points as u128
+        });
+
+        let step = match step {
+            Some(step) => step,
+            None => return vec![self.0],
+        };
+        let mut ret = vec![];
+        let mut value = (begin / step + (begin % step > 0) as u128) * step;
+        while value <= end {
+            let secs = value / NS_PER_SEC;
+            if secs > u128::from(u64::MAX) {
+                break;
+            }
+            ret.push(Duration::new(secs as u64, (value % NS_PER_SEC) as u32));
+            value += step;
+        }
+        ret
+    }
+}
+
+impl ReversibleRanged for RangedStdDuration {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Duration> {
+        if limit.0 == limit.1 {
+            return Some(self.0);
+        }
+        let ratio = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+        let begin = self.0.as_secs_f64();
+        let secs = begin + ratio * (self.1.as_secs_f64() - begin);
+        if secs < 0.0 || !secs.is_finite() {
+            return None;
+        }
+        Some(Duration::from_secs_f64(secs))
+    }
+}
+
+impl ValueFormatter<Duration> for RangedStdDuration {
+    fn format(value: &Duration) -> String {
+        Self::format_seconds(value.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_std_duration_key_points() {
+        let coord: RangedStdDuration = (Duration::from_millis(3)..Duration::from_millis(47)).into();
+        assert_eq!(coord.map(&Duration::from_millis(3), (0, 100)), 0);
+        assert_eq!(coord.map(&Duration::from_millis(47), (0, 100)), 100);
+
+        let kps = coord.key_points(5);
+        assert_eq!(
+            kps,
+            [10, 20, 30, 40]
+                .iter()
+                .map(|ms| Duration::from_millis(*ms))
+                .collect::<Vec<_>>()
+        );
+        let labels: Vec<_> = kps.iter().map(|d| coord.format_ext(d)).collect();
+        assert_eq!(labels, ["10 ms", "20 ms", "30 ms", "40 ms"]);
+
+        // The steps longer than a second are the round minutes and hours
+        let coord: RangedStdDuration = (Duration::ZERO..Duration::from_secs(3 * 3600)).into();
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        assert_eq!(kps[1], Duration::from_secs(1800));
+        assert_eq!(coord.format_ext(&kps[1]), "30 min");
+        assert_eq!(coord.format_ext(&kps[3]), "1.5 h");
+
+        let coord: RangedStdDuration = (Duration::ZERO..Duration::from_secs(100)).into();
+        assert_eq!(coord.unmap(50, (0, 100)), Some(Duration::from_secs(50)));
+        assert!(coord.key_points(0).is_empty());
+    }
+}
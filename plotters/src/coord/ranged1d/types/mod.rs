@@ -1,3 +1,9 @@
+mod any_ranged;
+pub use any_ranged::{AnyRanged, AxisKind};
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod calendar;
+
 #[cfg(feature = "chrono")]
 mod datetime;
 #[cfg(feature = "chrono")]
@@ -5,11 +11,23 @@ mod datetime;
 pub use datetime::{
     IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
 };
+#[cfg(feature = "chrono")]
+pub(crate) use datetime::TimeValue;
+
+#[cfg(feature = "time")]
+mod time_crate;
+#[cfg(feature = "time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+pub use time_crate::{RangedOffsetDateTime, RangedTimeDate, RangedTimeDuration};
+
+mod duration;
+pub use duration::RangedStdDuration;
 
 mod numeric;
 pub use numeric::{
-    RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
-    RangedCoordisize, RangedCoordu128, RangedCoordu32, RangedCoordu64, RangedCoordusize,
+    DegenerateRangeError, RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32,
+    RangedCoordi64, RangedCoordisize, RangedCoordu128, RangedCoordu32, RangedCoordu64,
+    RangedCoordusize,
 };
 
 mod slice;
@@ -1,23 +1,114 @@
 use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
 use std::ops::Range;
 
-use crate::coord::{combinators::WithKeyPoints, ranged1d::{
-    AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged,
-    ReversibleRanged, ValueFormatter,
-}};
+use crate::coord::{
+    combinators::WithKeyPoints,
+    ranged1d::{
+        AsRangedCoord, DefaultFormatting, DiscreteRanged, KeyPointHint, NoDefaultFormatting,
+        Ranged, ReversibleRanged, ValueFormatter,
+    },
+};
+use crate::data::float::FloatPrettyPrinter;
+
+/// The error returned by the strict float coordinates, such as [`RangedCoordf64::strict()`], when
+/// the range is empty, not finite or too narrow to be labeled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DegenerateRangeError {
+    /// The start of the rejected range
+    pub start: f64,
+    /// The end of the rejected range
+    pub end: f64,
+}
+
+impl fmt::Display for DegenerateRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The range {}..{} is too narrow to be labeled",
+            self.start, self.end
+        )
+    }
+}
+
+impl Error for DegenerateRangeError {}
+
+/// Check if the range is degenerate: the ticks of the range are closer than the 5 decimal digits
+/// the labels are printed with, thus some of the labels would look the same.
+fn is_degenerate_span(start: f64, end: f64) -> bool {
+    let (low, span) = (start.min(end), (end - start).abs());
+    if span == 0.0 || !span.is_finite() {
+        return false;
+    }
+    let printer = FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 1,
+        max_decimal: 5,
+    };
+    let labels: Vec<_> = (0..=10)
+        .map(|idx| printer.print(low + span * f64::from(idx) / 10.0))
+        .collect();
+    labels.windows(2).any(|pair| pair[0] == pair[1])
+}
+
+// Label a value of a degenerate range as a round offset plus the delta from it, such as
+// `1.0+2e-11`, since the values themselves can't be told apart once they're printed
+fn format_with_offset(value: f64, (start, end): (f64, f64)) -> String {
+    let (low, span) = (start.min(end), (end - start).abs());
+    // The offset is the start of the range rounded down to the power of ten above the span, so
+    // that it's short to print and the deltas only have a few significant digits
+    let unit = (10f64).powf(span.log10().floor() + 1.0);
+    let mut offset = (low / unit).round() * unit;
+    if offset - low > unit * 1e-6 {
+        offset -= unit;
+    }
+
+    let delta = value - offset;
+    let delta_text = FloatPrettyPrinter {
+        allow_scientific: true,
+        min_decimal: 0,
+        max_decimal: 20,
+    }
+    .print(delta);
+    if offset == 0.0 {
+        return delta_text;
+    }
+
+    let offset_text = FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 1,
+        max_decimal: (-unit.log10()).round().max(0.0) as i32,
+    }
+    .print(offset);
+    if delta.abs() < span * 1e-3 {
+        offset_text
+    } else {
+        format!("{}+{}", offset_text, delta_text)
+    }
+}
 
 macro_rules! impl_discrete_trait {
     ($name:ident) => {
         impl DiscreteRanged for $name {
             fn size(&self) -> usize {
-                if &self.1 < &self.0 {
-                    return 0;
-                }
-                let values = self.1 - self.0;
+                // A descending range, such as `10..0`, enumerates the values from the start down to
+                // the end, which is useful for ranked charts
+                let values = if self.1 < self.0 {
+                    self.0 - self.1
+                } else {
+                    self.1 - self.0
+                };
                 (values + 1) as usize
             }
 
             fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+                if self.1 < self.0 {
+                    if value > &self.0 {
+                        return None;
+                    }
+                    return Some((self.0 - value) as usize);
+                }
                 if value < &self.0 {
                     return None;
                 }
@@ -27,7 +118,10 @@ macro_rules! impl_discrete_trait {
 
             fn from_index(&self, index: usize) -> Option<Self::ValueType> {
                 if let Ok(index) = Self::ValueType::try_from(index) {
-                    return Some(self.0 + index);
+                    if self.1 < self.0 {
+                        return self.0.checked_sub(index);
+                    }
+                    return self.0.checked_add(index);
                 }
                 None
             }
@@ -96,9 +190,13 @@ macro_rules! make_numeric_coord {
                 }
 
                 if actual_length > 0 {
-                    return limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32;
+                    return limit
+                        .0
+                        .saturating_add((actual_length as f64 * logic_length + 1e-3).floor() as i32);
                 } else {
-                    return limit.0 + (actual_length as f64 * logic_length - 1e-3).ceil() as i32;
+                    return limit
+                        .0
+                        .saturating_add((actual_length as f64 * logic_length - 1e-3).ceil() as i32);
                 }
             }
             fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<$type> {
@@ -107,6 +205,9 @@ macro_rules! make_numeric_coord {
             fn range(&self) -> Range<$type> {
                 return self.0..self.1;
             }
+            fn is_finite_value(&self, v: &$type) -> bool {
+                (*v as f64).is_finite()
+            }
         }
     };
     ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
@@ -114,6 +215,52 @@ macro_rules! make_numeric_coord {
     };
 }
 
+/// The tolerance used when checking if a multiple of the step falls into the range, so that the
+/// rounding errors of the division don't drop the points on the range boundaries.
+const KEY_POINT_TOLERANCE: f64 = 1e-9;
+
+/// The step following `step` in the 1-2-5 sequence, i.e. 1, 2, 5, 10, 20, 50, ...
+fn next_nice_step(step: f64) -> f64 {
+    let magnitude = 10f64.powf(step.log10().floor());
+    let next = match (step / magnitude).round() as u32 {
+        1 => 2.0,
+        2 => 5.0,
+        _ => 10.0,
+    };
+    next * magnitude
+}
+
+/// The range of the indices `i` whose multiple `i * step` falls into the range
+fn nice_step_indices(range: (f64, f64), step: f64) -> (f64, f64) {
+    (
+        (range.0 / step - KEY_POINT_TOLERANCE).ceil(),
+        (range.1 / step + KEY_POINT_TOLERANCE).floor(),
+    )
+}
+
+/// Find the smallest step in the 1-2-5 sequence, which is at least `min_step`, whose multiples
+/// in the range are no more than `max_points`.
+/// - `range`: The range, the lower bound comes first
+/// - `max_points`: The maximum number of key points, must be positive
+/// - `min_step`: The smallest step allowed, for example 1 for the integers
+/// - **returns**: The step
+fn nice_step(range: (f64, f64), max_points: usize, min_step: f64) -> f64 {
+    // Start one magnitude below the step which evenly divides the range into `max_points`
+    // parts, which always yields too many points, and go up from there.
+    let rough_step = (range.1 - range.0) / max_points as f64;
+    let mut step = 10f64.powf(rough_step.log10().floor() - 1.0).max(min_step);
+    if !step.is_finite() || step <= 0.0 {
+        step = min_step.max(f64::MIN_POSITIVE);
+    }
+    loop {
+        let (first, last) = nice_step_indices(range, step);
+        if last - first + 1.0 <= max_points as f64 || step.is_infinite() {
+            return step;
+        }
+        step = next_nice_step(step);
+    }
+}
+
 macro_rules! gen_key_points_comp {
     (float, $name:ident, $type:ty) => {
         fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
@@ -129,112 +276,105 @@ macro_rules! gen_key_points_comp {
                 return vec![range.0 as $type];
             }
 
-            let mut scale = (10f64).powf((range.1 - range.0).log(10.0).floor());
-            // The value granularity controls how we round the values.
-            // To avoid generating key points like 1.00000000001, we round to the nearest multiple of the
-            // value granularity.
-            // By default, we make the granularity as the 1/10 of the scale.
-            let mut value_granularity = scale / 10.0;
-            fn rem_euclid(a: f64, b: f64) -> f64 {
-                let ret = if b > 0.0 {
-                    a - (a / b).floor() * b
-                } else {
-                    a - (a / b).ceil() * b
-                };
-                if (ret - b).abs() < f64::EPSILON {
-                    0.0
-                } else {
-                    ret
+            let step = nice_step(range, max_points, 0.0);
+            // The step is a 1, 2 or 5 times a power of ten. When the power is negative, we divide
+            // by it rather than multiplying with its inverse, which can't be represented exactly,
+            // so that the key points are the exact decimals, rather than values like 0.30000000000000004.
+            let exponent = step.log10().floor();
+            let (mantissa, divisor) = if exponent < 0.0 {
+                ((step / 10f64.powf(exponent)).round(), 10f64.powf(-exponent))
+            } else {
+                (step, 1.0)
+            };
+
+            let (first, last) = nice_step_indices(range, step);
+            // The points are counted with an integer, since adding 1 to a float index beyond the
+            // precision of the mantissa doesn't change it
+            let count = ((last - first + 1.0).max(0.0) as usize).min(max_points);
+            let mut ret: Vec<$type> = Vec::with_capacity(count);
+            for offset in 0..count {
+                // Adding zero turns the -0.0 rounded up from a tiny negative value into 0.0
+                let idx = first + offset as f64 + 0.0;
+                // Clamp the points, so the tolerance never yields a point out of the range
+                let value = ((idx * mantissa / divisor).max(range.0).min(range.1)) as $type;
+                // The huge values which differ by less than their precision are the same point
+                if ret.last() != Some(&value) {
+                    ret.push(value);
                 }
             }
-
-            // At this point we need to make sure that the loop invariant:
-            // The scale must yield number of points than requested
-            if 1 + ((range.1 - range.0) / scale).floor() as usize > max_points {
-                scale *= 10.0;
-                value_granularity *= 10.0;
+            ret
+        }
+    };
+    (integer, $name:ident, $type:ty) => {
+        fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
+            if max_points == 0 {
+                return vec![];
             }
 
+            let range = (range.0.min(range.1), range.0.max(range.1));
+            let mut step = nice_step((range.0 as f64, range.1 as f64), max_points, 1.0);
+
             'outer: loop {
-                let old_scale = scale;
-                for nxt in [2.0, 5.0, 10.0].iter() {
-                    let mut new_left = range.0 - rem_euclid(range.0, old_scale / nxt);
-                    if new_left < range.0 {
-                        new_left += old_scale / nxt;
-                    }
-                    let new_right = range.1 - rem_euclid(range.1, old_scale / nxt);
+                // A step which doesn't fit the type only has zero as its multiple
+                if step > <$type>::MAX as f64 {
+                    let zero: $type = 0;
+                    return if range.0 <= zero && zero <= range.1 {
+                        vec![zero]
+                    } else {
+                        vec![]
+                    };
+                }
 
-                    let npoints = 1.0 + ((new_right - new_left) / old_scale * nxt);
+                let int_step = step as $type;
+                let rem = range.0 % int_step;
+                let mut value = if rem > 0 {
+                    range.0.checked_add(int_step - rem)
+                } else {
+                    Some(range.0 - rem)
+                };
 
-                    if npoints.round() as usize > max_points {
-                        break 'outer;
+                // The number of points is estimated with the floating points, which may be off
+                // for the huge values, so we move on to the next step if there are too many.
+                let mut ret = vec![];
+                while let Some(current) = value.filter(|v| *v <= range.1) {
+                    if ret.len() == max_points {
+                        step = next_nice_step(step);
+                        continue 'outer;
                     }
-
-                    scale = old_scale / nxt;
+                    ret.push(current);
+                    value = current.checked_add(int_step);
                 }
-                scale = old_scale / 10.0;
-                value_granularity /= 10.0;
+                return ret;
             }
-
-            let mut ret = vec![];
-            // In some extreme cases, left might be too big, so that (left + scale) - left == 0 due to
-            // floating point error.
-            // In this case, we may loop forever. To avoid this, we need to use two variables to store
-            // the current left value. So we need keep a left_base and a left_relative.
-            let left = {
-                let mut value = range.0 - rem_euclid(range.0, scale);
-                if value < range.0 {
-                    value += scale;
-                }
-                value
-            };
-            let left_base = (left / value_granularity).floor() * value_granularity;
-            let mut left_relative = left - left_base;
-            let right = range.1 - rem_euclid(range.1, scale);
-            while (right - left_relative - left_base) >= -f64::EPSILON {
-                let new_left_relative =
-                    (left_relative / value_granularity).round() * value_granularity;
-                if new_left_relative < 0.0 {
-                    left_relative += value_granularity;
-                }
-                ret.push((left_relative + left_base) as $type);
-                left_relative += scale;
-            }
-            return ret;
         }
     };
-    (integer, $name:ident, $type:ty) => {
-        fn $name(range: ($type, $type), max_points: usize) -> Vec<$type> {
-            let mut scale: $type = 1;
-            let range = (range.0.min(range.1), range.0.max(range.1));
-            let range_size = range.1 as f64 - range.0 as f64;
-            'outer: while (range_size / scale as f64).ceil() > max_points as f64 {
-                let next_scale = scale * 10;
-                for new_scale in [scale * 2, scale * 5, scale * 10].iter() {
-                    scale = *new_scale;
-                    if (range_size / *new_scale as f64).ceil() < max_points as f64 {
-                        break 'outer;
-                    }
-                }
-                scale = next_scale;
-            }
-
-            let (mut left, right) = (
-                range.0 + (scale - range.0 % scale) % scale,
-                range.1 - range.1 % scale,
-            );
+}
 
-            let mut ret = vec![];
-            while left <= right {
-                ret.push(left as $type);
-                if left < right {
-                    left += scale;
-                } else {
-                    break;
+macro_rules! impl_strict_float_coord {
+    ($type:ty, $name:ident) => {
+        impl $name {
+            /**
+            Create the coordinate in strict mode, which rejects the degenerate ranges.
+
+            By default, a range so narrow that its labels can't be told apart, such as
+            `1.0..1.0000000001`, is labeled as a round offset plus the delta from it, for
+            example, `1.0+2e-11`. The strict mode returns an error instead, which is useful
+            when such a range is a sign of bad data.
+
+            - `range`: The range of the coordinate
+            - **returns**: The coordinate, or the error if the range is empty, not finite or
+              too narrow to be labeled
+            */
+            pub fn strict(range: Range<$type>) -> Result<Self, DegenerateRangeError> {
+                let (start, end) = (range.start as f64, range.end as f64);
+                if !(start.is_finite() && end.is_finite())
+                    || start == end
+                    || is_degenerate_span(start, end)
+                {
+                    return Err(DegenerateRangeError { start, end });
                 }
+                Ok(range.into())
             }
-
-            return ret;
         }
     };
 }
@@ -258,19 +398,29 @@ make_numeric_coord!(
     NoDefaultFormatting
 );
 impl_reverse_mapping_trait!(f32, RangedCoordf32);
+impl_strict_float_coord!(f32, RangedCoordf32);
 impl ValueFormatter<f32> for RangedCoordf32 {
     fn format(value: &f32) -> String {
-        crate::data::float::FloatPrettyPrinter {
+        FloatPrettyPrinter {
             allow_scientific: false,
             min_decimal: 1,
             max_decimal: 5,
         }
         .print(*value as f64)
     }
+
+    fn format_ext(&self, value: &f32) -> String {
+        let range = (self.0 as f64, self.1 as f64);
+        if is_degenerate_span(range.0, range.1) {
+            format_with_offset(*value as f64, range)
+        } else {
+            Self::format(value)
+        }
+    }
 }
 impl ValueFormatter<f32> for WithKeyPoints<RangedCoordf32> {
     fn format(value: &f32) -> String {
-        crate::data::float::FloatPrettyPrinter {
+        FloatPrettyPrinter {
             allow_scientific: false,
             min_decimal: 1,
             max_decimal: 5,
@@ -287,19 +437,29 @@ make_numeric_coord!(
     NoDefaultFormatting
 );
 impl_reverse_mapping_trait!(f64, RangedCoordf64);
+impl_strict_float_coord!(f64, RangedCoordf64);
 impl ValueFormatter<f64> for RangedCoordf64 {
     fn format(value: &f64) -> String {
-        crate::data::float::FloatPrettyPrinter {
+        FloatPrettyPrinter {
             allow_scientific: false,
             min_decimal: 1,
             max_decimal: 5,
         }
         .print(*value)
     }
+
+    fn format_ext(&self, value: &f64) -> String {
+        let range = (self.0, self.1);
+        if is_degenerate_span(range.0, range.1) {
+            format_with_offset(*value, range)
+        } else {
+            Self::format(value)
+        }
+    }
 }
 impl ValueFormatter<f64> for WithKeyPoints<RangedCoordf64> {
     fn format(value: &f64) -> String {
-        crate::data::float::FloatPrettyPrinter {
+        FloatPrettyPrinter {
             allow_scientific: false,
             min_decimal: 1,
             max_decimal: 5,
@@ -473,10 +633,93 @@ mod test {
         assert!(points.len() <= 2);
     }
 
+    #[test]
+    fn test_nice_key_points() {
+        // The steps are 1, 2 or 5 times a power of ten
+        assert_eq!(compute_i32_key_points((1, 11), 10), vec![2, 4, 6, 8, 10]);
+        assert_eq!(compute_i32_key_points((-7, 7), 3), vec![-5, 0, 5]);
+        assert_eq!(compute_f64_key_points((0.0, 1.0), 5), vec![0.0, 0.5, 1.0]);
+        assert_eq!(
+            compute_f64_key_points((-0.25, 0.35), 7),
+            vec![-0.2, -0.1, 0.0, 0.1, 0.2, 0.3]
+        );
+
+        for n in 1..30 {
+            for &(lo, hi) in [(0.0, 1.0), (-3.7, 12.1), (1e6, 1e6 + 3.0), (0.001, 0.0013)].iter() {
+                let points = compute_f64_key_points((lo, hi), n);
+                assert!(!points.is_empty() && points.len() <= n);
+                assert!(points.iter().all(|p| lo <= *p && *p <= hi));
+            }
+            for &(lo, hi) in [(0, 999), (1, 11), (-13, 94), (i64::MIN, i64::MAX)].iter() {
+                let points = compute_i64_key_points((lo, hi), n);
+                assert!(points.len() <= n);
+                assert!(points.iter().all(|p| lo <= *p && *p <= hi));
+            }
+        }
+    }
+
+    #[test]
+    fn test_huge_offset_key_points() {
+        // The ranges narrower than the precision of their values used to loop forever
+        for &(lo, hi) in [(1e15, 1e15 + 0.5), (1e16, 1e16 + 4.0), (1e300, 1e300 * 1.5)].iter() {
+            let points = compute_f64_key_points((lo, hi), 10);
+            assert!(!points.is_empty() && points.len() <= 10);
+            assert!(points.iter().all(|p| lo <= *p && *p <= hi));
+            assert!(points.windows(2).all(|w| w[0] < w[1]));
+        }
+        let coord: RangedCoordf64 = (1e16..1e16 + 4.0).into();
+        assert_eq!(coord.key_points(10), [1e16, 1e16 + 2.0, 1e16 + 4.0]);
+    }
+
     #[test]
     fn regression_test_issue_304_intmax_keypoint_no_panic() {
         let coord: RangedCoordu32 = (0..u32::MAX).into();
         let p = coord.key_points(10);
         assert!(!p.is_empty() && p.len() <= 10);
     }
+
+    #[test]
+    fn test_degenerate_range_labels() {
+        let coord: RangedCoordf64 = (1.0..1.000_000_000_1).into();
+        let labels: Vec<_> = coord
+            .key_points(11)
+            .iter()
+            .map(|v| coord.format_ext(v))
+            .collect();
+        assert_eq!(labels[0], "1.0");
+        assert_eq!(labels[1], "1.0+1e-11");
+        assert_eq!(labels[10], "1.0+1e-10");
+        for (idx, label) in labels.iter().enumerate() {
+            assert!(!labels[..idx].contains(label));
+        }
+
+        let coord: RangedCoordf32 = (0f32..1e-8).into();
+        assert_eq!(coord.format_ext(&0.0), "0");
+        assert_eq!(coord.format_ext(&2e-9), "2e-9");
+
+        // The ranges wide enough keep the plain labels
+        let coord: RangedCoordf64 = (0.0..1.0).into();
+        assert_eq!(coord.format_ext(&0.5), "0.5");
+
+        // The narrow ranges whose labels can still be told apart keep the plain labels as well
+        let coord: RangedCoordf64 = (5.0..5.0005).into();
+        assert_eq!(coord.format_ext(&5.0001), "5.0001");
+        let coord: RangedCoordf64 = (0.0..0.0009).into();
+        assert_eq!(coord.format_ext(&0.0001), "0.0001");
+        assert!(RangedCoordf64::strict(5.0..5.0005).is_ok());
+    }
+
+    #[test]
+    fn test_strict_coord() {
+        assert!(RangedCoordf64::strict(0.0..1.0).is_ok());
+        assert_eq!(
+            RangedCoordf64::strict(1.0..1.000_000_000_1).err(),
+            Some(DegenerateRangeError {
+                start: 1.0,
+                end: 1.000_000_000_1
+            })
+        );
+        assert!(RangedCoordf64::strict(1.0..1.0).is_err());
+        assert!(RangedCoordf32::strict(0.0..f32::INFINITY).is_err());
+    }
 }
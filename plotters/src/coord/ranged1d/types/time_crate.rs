@@ -0,0 +1,447 @@
+/// The date and time coordinates for the types of the `time` crate
+use std::convert::TryFrom;
+use std::ops::Range;
+
+use time::{Date, Duration, OffsetDateTime};
+
+use super::calendar::{AxisKeyPoints, Calendar, CalendarValue, SUB_DAILY_STEPS};
+use crate::coord::ranged1d::{
+    AsRangedCoord, DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged,
+    ValueFormatter,
+};
+
+const NS_PER_DAY: i64 = 86_400_000_000_000;
+
+/// The Julian day of 1970-01-01
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+fn date_of_day(day: i64) -> Option<Date> {
+    Date::from_julian_day(i32::try_from(day + UNIX_EPOCH_JULIAN_DAY).ok()?).ok()
+}
+
+fn map_ns(value: i128, begin: i128, end: i128, limit: (i32, i32)) -> i32 {
+    let total = (end - begin) as f64;
+    if total == 0.0 {
+        return limit.0;
+    }
+    (f64::from(limit.1 - limit.0) * (value - begin) as f64 / total) as i32 + limit.0
+}
+
+fn unmap_ns(point: i32, begin: i128, end: i128, limit: (i32, i32)) -> i128 {
+    if limit.0 == limit.1 {
+        return begin;
+    }
+    let ratio = f64::from(point - limit.0) / f64::from(limit.1 - limit.0);
+    begin + (ratio * (end - begin) as f64).round() as i128
+}
+
+impl CalendarValue for Date {
+    fn day_number(&self) -> i64 {
+        i64::from(self.to_julian_day()) - UNIX_EPOCH_JULIAN_DAY
+    }
+    fn nanosecond_of_day(&self) -> i64 {
+        0
+    }
+    fn start_of_day(&self, day: i64) -> Option<Self> {
+        date_of_day(day)
+    }
+    fn add_nanoseconds(&self, _ns: i64) -> Option<Self> {
+        None
+    }
+    fn nanoseconds_since(&self, other: &Self) -> Option<i128> {
+        Some((*self - *other).whole_nanoseconds())
+    }
+}
+
+impl CalendarValue for OffsetDateTime {
+    fn day_number(&self) -> i64 {
+        self.date().day_number()
+    }
+    fn nanosecond_of_day(&self) -> i64 {
+        let (h, m, s, ns) = self.time().as_hms_nano();
+        ((i64::from(h) * 60 + i64::from(m)) * 60 + i64::from(s)) * 1_000_000_000 + i64::from(ns)
+    }
+    fn start_of_day(&self, day: i64) -> Option<Self> {
+        Some(date_of_day(day)?.midnight().assume_offset(self.offset()))
+    }
+    fn add_nanoseconds(&self, ns: i64) -> Option<Self> {
+        self.checked_add(Duration::nanoseconds(ns))
+    }
+    fn nanoseconds_since(&self, other: &Self) -> Option<i128> {
+        Some((*self - *other).whole_nanoseconds())
+    }
+}
+
+/// The ranged coordinate for [`time::Date`], the counterpart of
+/// [`RangedDate`](crate::coord::types::RangedDate) for the `time` crate
+#[derive(Clone)]
+pub struct RangedTimeDate(Date, Date, AxisKeyPoints<Date>);
+
+impl From<Range<Date>> for RangedTimeDate {
+    fn from(range: Range<Date>) -> Self {
+        Self(range.start, range.end, AxisKeyPoints::default())
+    }
+}
+
+impl AsRangedCoord for Range<Date> {
+    type CoordDescType = RangedTimeDate;
+    type Value = Date;
+}
+
+impl RangedTimeDate {
+    fn calendar(&self) -> Calendar<'_, Date> {
+        // The range end is a key point of the dates as well
+        Calendar::new(self.0, self.1.next_day().unwrap_or(self.1), false, &self.2)
+    }
+}
+
+impl Ranged for RangedTimeDate {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Date;
+
+    fn range(&self) -> Range<Date> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Date, limit: (i32, i32)) -> i32 {
+        map_ns(
+            i128::from(value.to_julian_day()),
+            i128::from(self.0.to_julian_day()),
+            i128::from(self.1.to_julian_day()),
+            limit,
+        )
+    }
+
+    /// The key points are the days, the Mondays, the first days of the months, quarters or half
+    /// years, or the first days of the round years, whichever is the finest step that yields no
+    /// more than the requested number of key points.
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Date> {
+        self.calendar().key_points(hint.max_num_points())
+    }
+}
+
+impl ValueFormatter<Date> for RangedTimeDate {
+    fn format(value: &Date) -> String {
+        format!(
+            "{}-{:02}-{:02}",
+            value.year(),
+            value.month() as u8,
+            value.day()
+        )
+    }
+
+    /// Format the value as the unit of the key points, such as `Mar 5`, `Mar` or `2024`, with
+    /// the year on the second line of the first key point of the axis and of each year
+    fn format_ext(&self, value: &Date) -> String {
+        self.calendar()
+            .label(value)
+            .unwrap_or_else(|| Self::format(value))
+    }
+}
+
+impl DiscreteRanged for RangedTimeDate {
+    fn size(&self) -> usize {
+        (self.1.to_julian_day() - self.0.to_julian_day()).max(-1) as usize + 1
+    }
+
+    fn index_of(&self, value: &Date) -> Option<usize> {
+        let ret = value.to_julian_day() - self.0.to_julian_day();
+        if ret < 0 {
+            return None;
+        }
+        Some(ret as usize)
+    }
+
+    fn from_index(&self, index: usize) -> Option<Date> {
+        self.0.checked_add(Duration::days(index as i64))
+    }
+}
+
+/// The ranged coordinate for [`time::OffsetDateTime`], the counterpart of
+/// [`RangedDateTime`](crate::coord::types::RangedDateTime) for the `time` crate.
+///
+/// The key points and the labels follow the wall clock of the offset of the range start.
+#[derive(Clone)]
+pub struct RangedOffsetDateTime(
+    OffsetDateTime,
+    OffsetDateTime,
+    AxisKeyPoints<OffsetDateTime>,
+);
+
+impl From<Range<OffsetDateTime>> for RangedOffsetDateTime {
+    fn from(range: Range<OffsetDateTime>) -> Self {
+        Self(
+            range.start,
+            range.end.to_offset(range.start.offset()),
+            AxisKeyPoints::default(),
+        )
+    }
+}
+
+impl AsRangedCoord for Range<OffsetDateTime> {
+    type CoordDescType = RangedOffsetDateTime;
+    type Value = OffsetDateTime;
+}
+
+impl RangedOffsetDateTime {
+    fn calendar(&self) -> Calendar<'_, OffsetDateTime> {
+        Calendar::new(self.0, self.1, true, &self.2)
+    }
+}
+
+impl Ranged for RangedOffsetDateTime {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = OffsetDateTime;
+
+    fn range(&self) -> Range<OffsetDateTime> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &OffsetDateTime, limit: (i32, i32)) -> i32 {
+        map_ns(
+            value.unix_timestamp_nanos(),
+            self.0.unix_timestamp_nanos(),
+            self.1.unix_timestamp_nanos(),
+            limit,
+        )
+    }
+
+    /// The key points snap to the natural boundaries of the calendar: the round times of the
+    /// day, the midnights, the Mondays, the first days of the months, quarters or half years,
+    /// and the first days of the round years. The finest step which yields no more than the
+    /// requested number of key points is used.
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<OffsetDateTime> {
+        self.calendar().key_points(hint.max_num_points())
+    }
+}
+
+impl ReversibleRanged for RangedOffsetDateTime {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<OffsetDateTime> {
+        let ns = unmap_ns(
+            input,
+            self.0.unix_timestamp_nanos(),
+            self.1.unix_timestamp_nanos(),
+            limit,
+        );
+        OffsetDateTime::from_unix_timestamp_nanos(ns)
+            .ok()
+            .map(|value| value.to_offset(self.0.offset()))
+    }
+}
+
+impl ValueFormatter<OffsetDateTime> for RangedOffsetDateTime {
+    fn format(value: &OffsetDateTime) -> String {
+        let mut ret = format!(
+            "{} {:02}:{:02}:{:02}",
+            RangedTimeDate::format(&value.date()),
+            value.hour(),
+            value.minute(),
+            value.second()
+        );
+        if value.nanosecond() > 0 {
+            ret += format!(".{:09}", value.nanosecond()).trim_end_matches('0');
+        }
+        ret
+    }
+
+    /// Format the value as the unit of the key points, such as `14:30`, `Mar 5`, `Mar` or
+    /// `2024`, in the offset of the range. On the first key point of the axis, and the first key
+    /// point in each day or each year, the enclosing day or year is added on the second line.
+    fn format_ext(&self, value: &OffsetDateTime) -> String {
+        let value = value.to_offset(self.0.offset());
+        self.calendar()
+            .label(&value)
+            .unwrap_or_else(|| Self::format(&value))
+    }
+}
+
+/// The ranged coordinate for [`time::Duration`], the counterpart of
+/// [`RangedDuration`](crate::coord::types::RangedDuration) for the `time` crate
+#[derive(Clone)]
+pub struct RangedTimeDuration(Duration, Duration);
+
+impl From<Range<Duration>> for RangedTimeDuration {
+    fn from(range: Range<Duration>) -> Self {
+        Self(range.start, range.end)
+    }
+}
+
+impl AsRangedCoord for Range<Duration> {
+    type CoordDescType = RangedTimeDuration;
+    type Value = Duration;
+}
+
+impl Ranged for RangedTimeDuration {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = Duration;
+
+    fn range(&self) -> Range<Duration> {
+        self.0..self.1
+    }
+
+    fn map(&self, value: &Duration, limit: (i32, i32)) -> i32 {
+        map_ns(
+            value.whole_nanoseconds(),
+            self.0.whole_nanoseconds(),
+            self.1.whole_nanoseconds(),
+            limit,
+        )
+    }
+
+    /// The key points are the multiples of a round duration, which is shorter than a day and
+    /// evenly divides it, or 1, 2, 5 or 7 days, or 1, 2 or 5 times a power of ten days.
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Duration> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let (begin, end) = (self.0.whole_nanoseconds(), self.1.whole_nanoseconds());
+        // The steps longer than a week go on until they overflow
+        let decades = (1..)
+            .map(|exp| 10i128.checked_pow(exp)?.checked_mul(i128::from(NS_PER_DAY)))
+            .take_while(Option::is_some)
+            .flatten();
+        let daily =
+            [1, 2, 5, 7]
+                .iter()
+                .map(|days| days * NS_PER_DAY)
+                .map(i128::from)
+                .chain(decades.flat_map(|decade| {
+                    [1, 2, 5].iter().filter_map(move |m| decade.checked_mul(*m))
+                }));
+        let step = SUB_DAILY_STEPS
+            .iter()
+            .copied()
+            .map(i128::from)
+            .chain(daily)
+            .find(|step| {
+                let first = begin.div_euclid(*step) + (begin.rem_euclid(*step) > 0) as i128;
+                let last = (end - 1).div_euclid(*step);
+                last - first < max_points as i128
+            });
+
+        let step = match step {
+            Some(step) => step,
+            None => return vec![self.0],
+        };
+        let mut value = begin.div_euclid(step) * step;
+        if value < begin {
+            value += step;
+        }
+        let mut ret = vec![];
+        while value < end {
+            ret.push(Duration::nanoseconds_i128(value));
+            value += step;
+        }
+        ret
+    }
+}
+
+impl ValueFormatter<Duration> for RangedTimeDuration {
+    fn format(value: &Duration) -> String {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::{Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap()
+    }
+
+    fn at(date: Date, hour: u8, minute: u8, offset: UtcOffset) -> OffsetDateTime {
+        PrimitiveDateTime::new(date, Time::from_hms(hour, minute, 0).unwrap()).assume_offset(offset)
+    }
+
+    #[test]
+    fn test_time_date_range() {
+        let coord: RangedTimeDate = (date(2023, 2, 17)..date(2025, 8, 3)).into();
+        assert_eq!(coord.map(&date(2023, 2, 17), (0, 100)), 0);
+        assert_eq!(coord.map(&date(2025, 8, 3), (0, 100)), 100);
+
+        // Quarterly, starting from January
+        let kps = coord.key_points(11);
+        assert_eq!(kps.len(), 10);
+        assert_eq!(kps[0], date(2023, 4, 1));
+        let labels: Vec<_> = kps.iter().map(|d| coord.format_ext(d)).collect();
+        assert_eq!(labels[..4], ["Apr\n2023", "Jul", "Oct", "Jan\n2024"]);
+
+        // Weekly, on Mondays
+        let coord: RangedTimeDate = (date(2023, 2, 17)..date(2023, 4, 17)).into();
+        let kps = coord.key_points(11);
+        assert!(!kps.is_empty() && kps.len() <= 11);
+        assert!(kps.iter().all(|d| d.weekday() == Weekday::Monday));
+
+        assert_eq!(coord.size(), 60);
+        assert_eq!(coord.index_of(&date(2023, 3, 1)), Some(12));
+        assert_eq!(coord.from_index(12), Some(date(2023, 3, 1)));
+    }
+
+    #[test]
+    fn test_offset_date_time_range() {
+        let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let start = at(date(2023, 2, 17), 2, 17, offset);
+        let coord: RangedOffsetDateTime = (start..start + Duration::hours(2)).into();
+
+        // Every 15 minutes, on the wall clock of the offset
+        let kps = coord.key_points(11);
+        assert_eq!(kps.len(), 8);
+        assert_eq!(kps[0], at(date(2023, 2, 17), 2, 30, offset));
+        let labels: Vec<_> = kps.iter().map(|p| coord.format_ext(p)).collect();
+        assert_eq!(labels[..2], ["02:30\nFeb 17", "02:45"]);
+
+        // The values in other offsets are labeled in the offset of the range
+        let utc = kps[1].to_offset(UtcOffset::UTC);
+        assert_eq!(coord.format_ext(&utc), "02:45");
+        assert_eq!(coord.map(&utc, (0, 120)), coord.map(&kps[1], (0, 120)));
+        assert_eq!(
+            coord.unmap(coord.map(&kps[1], (0, 120)), (0, 120)),
+            Some(kps[1])
+        );
+
+        let coord: RangedOffsetDateTime = (start..start + Duration::days(10)).into();
+        let kps = coord.key_points(11);
+        assert_eq!(kps.len(), 10);
+        assert!(kps.iter().all(|p| p.time() == Time::MIDNIGHT));
+
+        // Every 15 seconds, with more key points than the default
+        let two = at(date(2023, 2, 17), 2, 0, offset);
+        let coord: RangedOffsetDateTime = (two..two + Duration::minutes(10)).into();
+        let labels: Vec<_> = coord
+            .key_points(50)
+            .iter()
+            .map(|p| coord.format_ext(p))
+            .collect();
+        assert_eq!(labels.len(), 40);
+        assert_eq!(
+            labels[..5],
+            ["02:00\nFeb 17", "02:00:15", "02:00:30", "02:00:45", "02:01"]
+        );
+        assert_eq!(RangedOffsetDateTime::format(&start), "2023-02-17 02:17:00");
+    }
+
+    #[test]
+    fn test_time_duration_range() {
+        let coord: RangedTimeDuration = (Duration::ZERO..Duration::days(1)).into();
+        let kps = coord.key_points(50);
+        assert_eq!(kps.len(), 48);
+        assert_eq!(kps[1], Duration::minutes(30));
+
+        let coord: RangedTimeDuration = (Duration::days(-1000)..Duration::days(1000)).into();
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        assert!(kps.iter().all(|d| d.whole_days() % 200 == 0));
+
+        let coord: RangedTimeDuration =
+            (Duration::days(-1_000_000)..Duration::days(1_000_000)).into();
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+        assert!(kps.iter().all(|d| d.whole_days() % 200_000 == 0));
+        let coord: RangedTimeDuration = (Duration::MIN..Duration::MAX).into();
+        let kps = coord.key_points(10);
+        assert!(!kps.is_empty() && kps.len() <= 10);
+    }
+}
@@ -8,7 +8,7 @@
 */
 
 use crate::coord::ranged1d::{KeyPointHint, Ranged, ReversibleRanged};
-use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+use crate::coord::{CoordTranslate, NonFinitePolicy, ReverseCoordTranslate};
 
 use crate::style::ShapeStyle;
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
@@ -22,6 +22,7 @@ pub struct Cartesian2d<X: Ranged, Y: Ranged> {
     logic_y: Y,
     back_x: (i32, i32),
     back_y: (i32, i32),
+    non_finite_policy: NonFinitePolicy,
 }
 
 impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
@@ -38,9 +39,24 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
             logic_y: logic_y.into(),
             back_x: (actual.0.start, actual.0.end),
             back_y: (actual.1.start, actual.1.end),
+            non_finite_policy: NonFinitePolicy::default(),
         }
     }
 
+    /// Get the pixel range on the screen for this coordinate system, as passed to the constructor
+    pub(crate) fn pixel_range(&self) -> (Range<i32>, Range<i32>) {
+        (self.back_x.0..self.back_x.1, self.back_y.0..self.back_y.1)
+    }
+
+    /// Set how the values without a meaningful position (such as NaN) are handled, the
+    /// default policy is [NonFinitePolicy::Skip](../enum.NonFinitePolicy.html#variant.Skip).
+    /// Regardless of the policy, translating such a value places it on the edge of the axis.
+    ///
+    /// - `policy`: The policy to use
+    pub fn set_non_finite_policy(&mut self, policy: NonFinitePolicy) {
+        self.non_finite_policy = policy;
+    }
+
     /// Draw the mesh for the coordinate system
     pub fn draw_mesh<
         E,
@@ -99,6 +115,16 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Map a value on the X axis to the horizontal backend coordinate
+    pub fn map_x(&self, x: &X::ValueType) -> i32 {
+        map_clamped(&self.logic_x, x, self.back_x)
+    }
+
+    /// Map a value on the Y axis to the vertical backend coordinate
+    pub fn map_y(&self, y: &Y::ValueType) -> i32 {
+        map_clamped(&self.logic_y, y, self.back_y)
+    }
+
     /// Get the 1D coordinate spec for X axis
     pub fn x_spec(&self) -> &X {
         &self.logic_x
@@ -114,11 +140,24 @@ impl<X: Ranged, Y: Ranged> CoordTranslate for Cartesian2d<X, Y> {
     type From = (X::ValueType, Y::ValueType);
 
     fn translate(&self, from: &Self::From) -> BackendCoord {
-        (
-            self.logic_x.map(&from.0, self.back_x),
-            self.logic_y.map(&from.1, self.back_y),
-        )
+        (self.map_x(&from.0), self.map_y(&from.1))
+    }
+
+    fn is_finite_coord(&self, from: &Self::From) -> bool {
+        self.logic_x.is_finite_value(&from.0) && self.logic_y.is_finite_value(&from.1)
+    }
+
+    fn non_finite_policy(&self) -> NonFinitePolicy {
+        self.non_finite_policy
+    }
+}
+
+fn map_clamped<R: Ranged>(coord: &R, value: &R::ValueType, limit: (i32, i32)) -> i32 {
+    let pixel = coord.map(value, limit);
+    if coord.is_finite_value(value) {
+        return pixel;
     }
+    pixel.max(limit.0.min(limit.1)).min(limit.0.max(limit.1))
 }
 
 impl<X: ReversibleRanged, Y: ReversibleRanged> ReverseCoordTranslate for Cartesian2d<X, Y> {
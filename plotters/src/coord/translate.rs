@@ -1,6 +1,21 @@
 use plotters_backend::BackendCoord;
 use std::ops::Deref;
 
+/// Describes how a coordinate system handles the values without a meaningful position, such as
+/// NaN and the infinities of a floating point axis
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// Drawing an element with such a value fails with
+    /// [DrawingAreaErrorKind::NonFiniteValue](../drawing/enum.DrawingAreaErrorKind.html#variant.NonFiniteValue)
+    Error,
+    /// The value is placed on the edge of the axis, the infinities are placed on the
+    /// corresponding ends and NaN is placed on the start of the axis
+    ClampToEdge,
+    /// The element with such a value is skipped when the series is drawn
+    #[default]
+    Skip,
+}
+
 /// The trait that translates some customized object to the backend coordinate
 pub trait CoordTranslate {
     /// Specifies the object to be translated from
@@ -13,6 +28,17 @@ pub trait CoordTranslate {
     fn depth(&self, _from: &Self::From) -> i32 {
         0
     }
+
+    /// Check if the guest coordinate has a meaningful position in the coordinate system, see
+    /// [NonFinitePolicy](enum.NonFinitePolicy.html) for details
+    fn is_finite_coord(&self, _from: &Self::From) -> bool {
+        true
+    }
+
+    /// Get the policy of handling the guest coordinates without a meaningful position
+    fn non_finite_policy(&self) -> NonFinitePolicy {
+        NonFinitePolicy::ClampToEdge
+    }
 }
 
 impl<C, T> CoordTranslate for T
@@ -24,6 +50,18 @@ where
     fn translate(&self, from: &Self::From) -> BackendCoord {
         self.deref().translate(from)
     }
+
+    fn depth(&self, from: &Self::From) -> i32 {
+        self.deref().depth(from)
+    }
+
+    fn is_finite_coord(&self, from: &Self::From) -> bool {
+        self.deref().is_finite_coord(from)
+    }
+
+    fn non_finite_policy(&self) -> NonFinitePolicy {
+        self.deref().non_finite_policy()
+    }
 }
 
 /// The trait indicates that the coordinate system supports reverse transform
@@ -6,8 +6,20 @@ Such as, down-sampling, etc.
 mod data_range;
 pub use data_range::fitting_range;
 
+mod range_tracker;
+pub use range_tracker::RangeTracker;
+
 mod quartiles;
 pub use quartiles::Quartiles;
 
+mod summary;
+pub use summary::{trimmed_mean, weighted_quantile, Summary};
+
+#[cfg(feature = "chrono")]
+mod resample;
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+pub use resample::{resample, resample_weighted, TimeBucket};
+
 /// Handles the printing of floating-point numbers.
 pub mod float;
@@ -50,6 +50,12 @@ impl Quartiles {
         let lower = Quartiles::percentile_of_sorted(&s, 25_f64);
         let median = Quartiles::percentile_of_sorted(&s, 50_f64);
         let upper = Quartiles::percentile_of_sorted(&s, 75_f64);
+        Self::from_quartiles(lower, median, upper)
+    }
+
+    // Create the quartiles from the precomputed quartile values, the fences are 1.5 IQR away
+    // from the lower and upper quartiles
+    pub(crate) fn from_quartiles(lower: f64, median: f64, upper: f64) -> Self {
         let iqr = upper - lower;
         let lower_fence = lower - 1.5 * iqr;
         let upper_fence = upper + 1.5 * iqr;
@@ -0,0 +1,160 @@
+use std::ops::Range;
+
+/// Tracks the axis range of streaming data, which is used by the live charts that scale
+/// automatically as the new data arrives.
+///
+/// The tracker only keeps the extremes of the data, thus the range doesn't need to be recomputed
+/// from the whole history on every frame. When a value falls outside of the current range, the
+/// range grows to the new extremes plus a padding, so that the following values slightly beyond
+/// the old extremes don't change the range again. The chart only needs to be rebuilt when the
+/// range actually changes, otherwise the saved [ChartState](../chart/struct.ChartState.html) can
+/// be restored.
+///
+/// ```rust
+/// use plotters::coord::types::RangedCoordf64;
+/// use plotters::chart::ChartState;
+/// use plotters::data::RangeTracker;
+/// use plotters::prelude::*;
+///
+/// let mut buffer = vec![0; 640 * 480 * 3];
+/// let root = BitMapBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut tracker = RangeTracker::new().padding(0.2);
+/// let mut state: Option<ChartState<Cartesian2d<RangedCoordf64, RangedCoordf64>>> = None;
+/// let mut history = vec![];
+///
+/// for frame in 0..100 {
+///     let value = (frame as f64 / 10.0).sin() * frame as f64;
+///     history.push((frame as f64, value));
+///
+///     // Only rebuild the chart when the range changes
+///     let changed = tracker.push(value);
+///     let mut chart = match state.take() {
+///         Some(state) if !changed => state.restore(&root),
+///         _ => {
+///             root.fill(&WHITE).unwrap();
+///             let mut chart = ChartBuilder::on(&root)
+///                 .set_all_label_area_size(30)
+///                 .build_cartesian_2d(0.0..100.0, tracker.range())
+///                 .unwrap();
+///             chart.configure_mesh().draw().unwrap();
+///             chart
+///         }
+///     };
+///     chart.draw_series(LineSeries::new(history.iter().copied(), &RED)).unwrap();
+///     state = Some(chart.into_chart_state());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RangeTracker {
+    data: Option<(f64, f64)>,
+    range: Option<(f64, f64)>,
+    padding: f64,
+}
+
+impl Default for RangeTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RangeTracker {
+    /// Create an empty tracker, the range is padded by 10% of the data span on each side
+    pub fn new() -> Self {
+        Self {
+            data: None,
+            range: None,
+            padding: 0.1,
+        }
+    }
+
+    /// Set the padding of the range
+    ///
+    /// - `padding`: The padding on each side, relative to the span of the data. A larger padding
+    ///   makes the range change less often.
+    /// - **returns**: The updated tracker
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    /// Add a value to the tracker, the non-finite values are ignored
+    ///
+    /// - `value`: The new value
+    /// - **returns**: If the range has changed
+    pub fn push(&mut self, value: f64) -> bool {
+        if !value.is_finite() {
+            return false;
+        }
+        let (lo, hi) = match self.data {
+            Some((lo, hi)) => (lo.min(value), hi.max(value)),
+            None => (value, value),
+        };
+        self.data = Some((lo, hi));
+
+        match self.range {
+            Some((start, end)) if start <= value && value <= end => false,
+            _ => {
+                let span = if hi > lo { hi - lo } else { lo.abs().max(1.0) };
+                let pad = span * self.padding;
+                self.range = Some((lo - pad, hi + pad));
+                true
+            }
+        }
+    }
+
+    /// Add multiple values to the tracker
+    ///
+    /// - `values`: The new values
+    /// - **returns**: If the range has changed
+    pub fn extend<I: IntoIterator<Item = f64>>(&mut self, values: I) -> bool {
+        let mut changed = false;
+        for value in values {
+            changed |= self.push(value);
+        }
+        changed
+    }
+
+    /// Get the current range of the axis, which is `0.0..1.0` when there's no data yet
+    pub fn range(&self) -> Range<f64> {
+        self.range.map_or(0.0..1.0, |(start, end)| start..end)
+    }
+
+    /// Get the range of the values added so far, without the padding
+    pub fn data_range(&self) -> Option<Range<f64>> {
+        self.data.map(|(lo, hi)| lo..hi)
+    }
+
+    /// Forget all the values, for example, when the data older than a time window is dropped.
+    /// The range is rebuilt by the values added afterwards.
+    pub fn reset(&mut self) {
+        self.data = None;
+        self.range = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range_tracker() {
+        let mut tracker = RangeTracker::new();
+        assert_eq!(tracker.range(), 0.0..1.0);
+        assert!(tracker.push(5.0));
+        assert_eq!(tracker.range(), 4.5..5.5);
+
+        assert!(tracker.extend(vec![0.0, 10.0]));
+        assert_eq!(tracker.range(), -1.0..11.0);
+        assert_eq!(tracker.data_range(), Some(0.0..10.0));
+
+        // The values within the padding don't change the range
+        assert!(!tracker.extend(vec![10.5, -0.5, f64::NAN, f64::INFINITY]));
+        assert_eq!(tracker.range(), -1.0..11.0);
+        assert!(tracker.push(12.0));
+        assert_eq!(tracker.range(), -1.75..13.25);
+
+        tracker.reset();
+        assert_eq!(tracker.data_range(), None);
+        assert!(tracker.push(-3.0));
+    }
+}
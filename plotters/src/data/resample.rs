@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use chrono::Duration;
+
+use crate::coord::types::TimeValue;
+
+/// The aggregated values of the samples that fall into one time window.
+/// See [resample](fn.resample.html) for details.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeBucket<T> {
+    start: T,
+    open: (T, f64),
+    close: (T, f64),
+    high: f64,
+    low: f64,
+    sum: f64,
+    weighted_sum: f64,
+    total_weight: f64,
+    count: usize,
+}
+
+impl<T: TimeValue + Clone> TimeBucket<T> {
+    fn new(start: T, time: T, value: f64, weight: f64) -> Self {
+        Self {
+            start,
+            open: (time.clone(), value),
+            close: (time, value),
+            high: value,
+            low: value,
+            sum: value,
+            weighted_sum: value * weight,
+            total_weight: weight,
+            count: 1,
+        }
+    }
+
+    fn push(&mut self, time: T, value: f64, weight: f64) {
+        if time.subtract(&self.open.0) < Duration::zero() {
+            self.open = (time.clone(), value);
+        }
+        if time.subtract(&self.close.0) >= Duration::zero() {
+            self.close = (time, value);
+        }
+        self.high = self.high.max(value);
+        self.low = self.low.min(value);
+        self.sum += value;
+        self.weighted_sum += value * weight;
+        self.total_weight += weight;
+        self.count += 1;
+    }
+
+    /// Get the start time of the window
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// Get the value of the earliest sample in the window
+    pub fn open(&self) -> f64 {
+        self.open.1
+    }
+
+    /// Get the maximum value in the window
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+
+    /// Get the minimum value in the window
+    pub fn low(&self) -> f64 {
+        self.low
+    }
+
+    /// Get the value of the latest sample in the window
+    pub fn close(&self) -> f64 {
+        self.close.1
+    }
+
+    /// Get the sum of the values in the window
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Get the weighted mean of the values in the window. For the unweighted resampling,
+    /// this is the arithmetic mean.
+    pub fn mean(&self) -> f64 {
+        self.weighted_sum / self.total_weight
+    }
+
+    /// Get the number of samples in the window
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the `(start, open, high, low, close)` tuple of the window, which can be used
+    /// to build a [CandleStick](../element/struct.CandleStick.html) directly
+    pub fn ohlc(&self) -> (T, f64, f64, f64, f64) {
+        (
+            self.start.clone(),
+            self.open.1,
+            self.high,
+            self.low,
+            self.close.1,
+        )
+    }
+}
+
+/// Group a stream of time stamped values into fixed time windows and aggregate each window.
+///
+/// The windows are aligned to the midnight of the earliest sample, so a 5 minutes window
+/// always starts at a multiple of 5 minutes of a day. The samples don't need to be sorted,
+/// and the windows without any sample are omitted from the result.
+///
+/// ```rust
+/// use chrono::{Duration, NaiveDate};
+/// use plotters::data::resample;
+///
+/// let t0 = NaiveDate::from_ymd(2021, 1, 1).and_hms(9, 0, 0);
+/// let data = (0..10).map(|i| (t0 + Duration::minutes(i), i as f64));
+///
+/// let buckets = resample(data, Duration::minutes(5));
+/// assert_eq!(buckets.len(), 2);
+/// assert_eq!(buckets[1].ohlc(), (t0 + Duration::minutes(5), 5.0, 9.0, 5.0, 9.0));
+/// assert_eq!(buckets[1].mean(), 7.0);
+/// ```
+///
+/// - `data`: The `(time, value)` samples
+/// - `window`: The size of the window
+/// - **returns**: The aggregated windows sorted by time
+pub fn resample<T, I>(data: I, window: Duration) -> Vec<TimeBucket<T>>
+where
+    T: TimeValue + Clone,
+    I: IntoIterator<Item = (T, f64)>,
+{
+    resample_weighted(data.into_iter().map(|(t, v)| (t, v, 1.0)), window)
+}
+
+/// Group a stream of time stamped values into fixed time windows, and the mean of each window is
+/// weighted by the given weight of each sample, for example, the volume-weighted average price.
+/// See [resample](fn.resample.html) for details.
+///
+/// - `data`: The `(time, value, weight)` samples
+/// - `window`: The size of the window
+/// - **returns**: The aggregated windows sorted by time
+pub fn resample_weighted<T, I>(data: I, window: Duration) -> Vec<TimeBucket<T>>
+where
+    T: TimeValue + Clone,
+    I: IntoIterator<Item = (T, f64, f64)>,
+{
+    let window_ms = window.num_milliseconds().max(1);
+    let samples: Vec<_> = data.into_iter().collect();
+
+    let origin = match samples
+        .iter()
+        .min_by(|a, b| a.0.subtract(&b.0).cmp(&Duration::zero()))
+    {
+        Some((time, _, _)) => T::earliest_after_date(time.date_floor()),
+        None => return vec![],
+    };
+
+    let mut buckets: BTreeMap<i64, TimeBucket<T>> = BTreeMap::new();
+
+    for (time, value, weight) in samples {
+        let idx = time
+            .subtract(&origin)
+            .num_milliseconds()
+            .div_euclid(window_ms);
+        if let Some(bucket) = buckets.get_mut(&idx) {
+            bucket.push(time, value, weight);
+        } else {
+            let start = origin.add(&Duration::milliseconds(idx * window_ms));
+            buckets.insert(idx, TimeBucket::new(start, time, value, weight));
+        }
+    }
+
+    buckets.into_values().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_resample_unsorted() {
+        let t0 = NaiveDate::from_ymd(2021, 1, 1).and_hms(9, 3, 0);
+        let data = vec![
+            (t0 + Duration::minutes(1), 3.0, 1.0),
+            (t0, 1.0, 3.0),
+            (t0 + Duration::minutes(3), 2.0, 1.0),
+        ];
+
+        let buckets = resample_weighted(data, Duration::minutes(5));
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(*buckets[0].start(), t0 - Duration::minutes(3));
+        assert_eq!(buckets[0].open(), 1.0);
+        assert_eq!(buckets[0].close(), 3.0);
+        assert_eq!(buckets[0].mean(), 1.5);
+        assert_eq!(buckets[0].sum(), 4.0);
+        assert_eq!(buckets[0].count(), 2);
+        assert_eq!(*buckets[1].start(), t0 + Duration::minutes(2));
+    }
+
+    #[test]
+    fn test_resample_empty() {
+        let data: Vec<(NaiveDate, f64)> = vec![];
+        assert!(resample(data, Duration::days(1)).is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+use super::Quartiles;
+
+// Extract the `q` quantile of the values sorted by value, each of them is paired with a
+// positive weight. The weights are treated as frequencies: the value at index k covers the
+// positions from the total weight before it to that position plus its weight minus 1 in the
+// expanded sample, and the quantile is linearly interpolated between the adjacent values. With
+// equal weights, this is the same as the percentiles used by `Quartiles`, and a weight of `n` is
+// the same as repeating the value `n` times.
+fn quantile_of_sorted(s: &[(f64, f64)], q: f64) -> f64 {
+    let q = q.clamp(0.0, 1.0);
+    let last = s.len() - 1;
+    let span = s.iter().map(|(_, w)| w).sum::<f64>() - s[last].1.min(1.0);
+    if last == 0 || span <= 0.0 {
+        return s[last].0;
+    }
+
+    let target = q * span;
+    let (mut acc, mut prev_end) = (0.0, 0.0);
+    for (idx, &(v, w)) in s.iter().enumerate() {
+        let end = acc + (w - 1.0).max(0.0);
+        if target <= end {
+            if target >= acc || idx == 0 {
+                return v;
+            }
+            let d = (target - prev_end) / (acc - prev_end);
+            return s[idx - 1].0 + (v - s[idx - 1].0) * d;
+        }
+        prev_end = end;
+        acc += w;
+    }
+    s[last].0
+}
+
+fn sort_weighted<T, W, I>(data: I) -> Vec<(f64, f64)>
+where
+    T: Into<f64>,
+    W: Into<f64>,
+    I: IntoIterator<Item = (T, W)>,
+{
+    let mut s: Vec<(f64, f64)> = data
+        .into_iter()
+        .map(|(v, w)| (v.into(), w.into()))
+        .filter(|(v, w)| !v.is_nan() && w.is_finite() && *w > 0.0)
+        .collect();
+    s.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    s
+}
+
+/// Compute the weighted quantile of the data.
+///
+/// - `data`: The values paired with their weights, the values with non-positive or non-finite
+///   weights and NaN values are ignored
+/// - `q`: The quantile to compute, from 0 to 1
+/// - **returns**: The quantile, or `None` if there's no value with a positive weight. With equal
+///   weights, this is the same as the percentiles used by [Quartiles](struct.Quartiles.html).
+///
+/// ```rust
+/// use plotters::data::weighted_quantile;
+///
+/// let data = [(1.0, 1.0), (2.0, 1.0), (3.0, 2.0)];
+/// assert_eq!(weighted_quantile(data.iter().copied(), 0.5), Some(2.5));
+/// assert_eq!(weighted_quantile(data.iter().copied(), 1.0), Some(3.0));
+/// ```
+pub fn weighted_quantile<T, W, I>(data: I, q: f64) -> Option<f64>
+where
+    T: Into<f64>,
+    W: Into<f64>,
+    I: IntoIterator<Item = (T, W)>,
+{
+    let s = sort_weighted(data);
+    if s.is_empty() {
+        return None;
+    }
+    Some(quantile_of_sorted(&s, q))
+}
+
+/// Compute the trimmed mean of the data, which is the mean after removing the given proportion
+/// of the smallest and the largest values.
+///
+/// - `data`: The values, NaN values are ignored
+/// - `proportion`: The proportion to trim from each end, from 0 to 0.5
+/// - **returns**: The trimmed mean, or `None` if no value is left after trimming
+///
+/// ```rust
+/// use plotters::data::trimmed_mean;
+///
+/// assert_eq!(trimmed_mean(vec![1, 2, 3, 4, 100], 0.2), Some(3.0));
+/// ```
+pub fn trimmed_mean<T: Into<f64>, I: IntoIterator<Item = T>>(
+    data: I,
+    proportion: f64,
+) -> Option<f64> {
+    let s = sort_weighted(data.into_iter().map(|v| (v, 1.0)));
+    let trim = (s.len() as f64 * proportion.clamp(0.0, 0.5)).floor() as usize;
+    let kept = s.get(trim..s.len().saturating_sub(trim))?;
+    if kept.is_empty() {
+        return None;
+    }
+    Some(kept.iter().map(|(v, _)| v).sum::<f64>() / kept.len() as f64)
+}
+
+/// The summary statistics of a set of optionally weighted values, which is computed in one pass
+/// over the data. This is useful to build the boxplots and error bars for weighted data, such as
+/// survey data.
+///
+/// ```rust
+/// use plotters::data::Summary;
+///
+/// let summary = Summary::weighted(vec![(1.0, 1.0), (2.0, 1.0), (3.0, 2.0)]).unwrap();
+/// assert_eq!(summary.count(), 3);
+/// assert_eq!(summary.total_weight(), 4.0);
+/// assert_eq!(summary.mean(), 2.25);
+/// assert_eq!(summary.median(), 2.5);
+/// assert_eq!((summary.min(), summary.max()), (1.0, 3.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Summary {
+    sorted: Vec<(f64, f64)>,
+    total_weight: f64,
+    mean: f64,
+    variance: f64,
+}
+
+impl Summary {
+    /// Compute the summary of the values with equal weights
+    ///
+    /// - `data`: The values, NaN values are ignored
+    /// - **returns**: The summary, or `None` if there's no value
+    pub fn new<T: Into<f64>, I: IntoIterator<Item = T>>(data: I) -> Option<Self> {
+        Self::weighted(data.into_iter().map(|v| (v, 1.0)))
+    }
+
+    /// Compute the summary of the weighted values
+    ///
+    /// - `data`: The values paired with their weights, the values with non-positive or non-finite
+    ///   weights and NaN values are ignored
+    /// - **returns**: The summary, or `None` if there's no value with a positive weight
+    pub fn weighted<T, W, I>(data: I) -> Option<Self>
+    where
+        T: Into<f64>,
+        W: Into<f64>,
+        I: IntoIterator<Item = (T, W)>,
+    {
+        let mut sorted = vec![];
+        let (mut total_weight, mut mean, mut m2) = (0.0, 0.0, 0.0);
+
+        for (v, w) in data {
+            let (v, w): (f64, f64) = (v.into(), w.into());
+            if v.is_nan() || !w.is_finite() || w <= 0.0 {
+                continue;
+            }
+            // The weighted incremental algorithm by West (1979)
+            total_weight += w;
+            let delta = v - mean;
+            mean += delta * w / total_weight;
+            m2 += w * delta * (v - mean);
+            sorted.push((v, w));
+        }
+
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        Some(Self {
+            sorted,
+            total_weight,
+            mean,
+            variance: m2 / total_weight,
+        })
+    }
+
+    /// Get the number of the values
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// Get the sum of the weights
+    pub fn total_weight(&self) -> f64 {
+        self.total_weight
+    }
+
+    /// Get the minimum value
+    pub fn min(&self) -> f64 {
+        self.sorted[0].0
+    }
+
+    /// Get the maximum value
+    pub fn max(&self) -> f64 {
+        self.sorted[self.sorted.len() - 1].0
+    }
+
+    /// Get the weighted mean
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Get the weighted population variance
+    pub fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    /// Get the weighted population standard deviation
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+
+    /// Get the weighted quantile, see [weighted_quantile](fn.weighted_quantile.html) for details
+    ///
+    /// - `q`: The quantile to compute, from 0 to 1
+    /// - **returns**: The quantile
+    pub fn quantile(&self, q: f64) -> f64 {
+        quantile_of_sorted(&self.sorted, q)
+    }
+
+    /// Get the weighted median
+    pub fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+
+    /// Get the weighted quartiles, which can be used to draw a
+    /// [Boxplot](../element/struct.Boxplot.html)
+    pub fn quartiles(&self) -> Quartiles {
+        Quartiles::from_quartiles(self.quantile(0.25), self.median(), self.quantile(0.75))
+    }
+
+    /// Get the range of `k` standard deviations around the mean, which can be used to draw an
+    /// [ErrorBar](../element/struct.ErrorBar.html)
+    ///
+    /// - `k`: The number of standard deviations
+    /// - **returns**: The lower end, the mean and the upper end
+    pub fn mean_with_deviation(&self, k: f64) -> (f64, f64, f64) {
+        let d = self.std_dev() * k;
+        (self.mean - d, self.mean, self.mean + d)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_equal_weights_match_quartiles() {
+        let data = [7, 15, 36, 39, 40, 41];
+        let summary = Summary::new(data.iter().copied()).unwrap();
+        assert_eq!(summary.quartiles().values(), Quartiles::new(&data).values());
+        assert!((summary.mean() - 29.666666666666668).abs() < 1e-9);
+        assert!((summary.std_dev() - 13.486618882762606).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weighted_summary() {
+        // A weight of 2 is the same as repeating the value
+        let weighted = Summary::weighted(vec![(1, 2), (5, 1), (3, 1)]).unwrap();
+        let repeated = Summary::new(vec![1, 1, 3, 5]).unwrap();
+        assert!((weighted.mean() - repeated.mean()).abs() < 1e-9);
+        assert!((weighted.variance() - repeated.variance()).abs() < 1e-9);
+        assert_eq!(weighted.median(), repeated.median());
+        assert_eq!(weighted.quartiles().values(), repeated.quartiles().values());
+        assert_eq!(
+            weighted.quartiles().values(),
+            Quartiles::new(&[1, 1, 3, 5]).values()
+        );
+        assert_eq!(
+            weighted_quantile(vec![(0.0, 1.0), (10.0, 100.0)], 0.5),
+            Some(10.0)
+        );
+        assert_eq!(weighted.quantile(0.0), 1.0);
+        assert_eq!(weighted.quantile(1.0), 5.0);
+
+        assert!(Summary::weighted(vec![(1.0, 0.0), (f64::NAN, 1.0)]).is_none());
+        let summary = Summary::weighted(vec![(1.0, f64::NAN), (2.0, f64::INFINITY), (3.0, 1.0)]);
+        assert_eq!(summary.unwrap().mean(), 3.0);
+        assert_eq!(
+            weighted_quantile(vec![(1.0, f64::NAN), (2.0, 1.0)], 0.0),
+            Some(2.0)
+        );
+        assert_eq!(weighted_quantile(vec![(4.0, 1.0)], 0.3), Some(4.0));
+        assert_eq!(trimmed_mean(Vec::<f64>::new(), 0.1), None);
+        assert_eq!(trimmed_mean(vec![1.0, 2.0], 0.5), None);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
-use crate::coord::ranged1d::{KeyPointHint, Ranged};
+use crate::coord::ranged1d::{AsRangedCoord, KeyPointHint, Ranged};
 use crate::coord::{CoordTranslate, Shift};
 use crate::element::{CoordMapper, Drawable, PointCollection};
 use crate::style::text_anchor::{HPos, Pos, VPos};
@@ -15,8 +15,23 @@ use std::iter::{once, repeat};
 use std::ops::Range;
 use std::rc::Rc;
 
-/// The representation of the rectangle in backend canvas
-#[derive(Clone, Debug)]
+/// The representation of the rectangle in backend canvas.
+///
+/// The rectangle includes the upper-left corner but excludes the bottom-right corner, which is
+/// consistent with the pixel range of a [DrawingArea](struct.DrawingArea.html). A rectangle with
+/// zero width or height is empty.
+///
+/// ```rust
+/// use plotters::drawing::Rect;
+///
+/// let legend = Rect::from_size((10, 10), (100, 40));
+/// let annotation = Rect::new((90, 30), (150, 60));
+/// assert!(legend.intersects(&annotation));
+/// assert_eq!(legend.intersection(&annotation), Some(Rect::new((90, 30), (110, 50))));
+/// assert_eq!(legend.union(&annotation), Rect::new((10, 10), (150, 60)));
+/// assert_eq!(legend.inflate(5, 5), Rect::new((5, 5), (115, 55)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Rect {
     x0: i32,
     y0: i32,
@@ -25,6 +40,148 @@ pub struct Rect {
 }
 
 impl Rect {
+    /// Create a new rectangle from two corners, the corners are reordered if needed
+    ///
+    /// - `a`: One corner of the rectangle
+    /// - `b`: The opposite corner of the rectangle
+    /// - **returns**: The newly created rectangle
+    pub fn new(a: BackendCoord, b: BackendCoord) -> Self {
+        Self {
+            x0: a.0.min(b.0),
+            y0: a.1.min(b.1),
+            x1: a.0.max(b.0),
+            y1: a.1.max(b.1),
+        }
+    }
+
+    /// Create a new rectangle from the upper-left corner and the size
+    ///
+    /// - `upper_left`: The upper-left corner
+    /// - `size`: The width and height
+    /// - **returns**: The newly created rectangle
+    pub fn from_size(upper_left: BackendCoord, (w, h): (u32, u32)) -> Self {
+        Self::new(
+            upper_left,
+            (upper_left.0 + w as i32, upper_left.1 + h as i32),
+        )
+    }
+
+    /// Get the upper-left corner, which is inside the rectangle
+    pub fn upper_left(&self) -> BackendCoord {
+        (self.x0, self.y0)
+    }
+
+    /// Get the bottom-right corner, which is outside of the rectangle
+    pub fn bottom_right(&self) -> BackendCoord {
+        (self.x1, self.y1)
+    }
+
+    /// Get the width of the rectangle
+    pub fn width(&self) -> u32 {
+        (self.x1 - self.x0).max(0) as u32
+    }
+
+    /// Get the height of the rectangle
+    pub fn height(&self) -> u32 {
+        (self.y1 - self.y0).max(0) as u32
+    }
+
+    /// Get the width and height of the rectangle
+    pub fn size(&self) -> (u32, u32) {
+        (self.width(), self.height())
+    }
+
+    /// Get the center of the rectangle, rounded towards the upper-left corner
+    pub fn center(&self) -> BackendCoord {
+        ((self.x0 + self.x1) / 2, (self.y0 + self.y1) / 2)
+    }
+
+    /// Check if the rectangle has zero area
+    pub fn is_empty(&self) -> bool {
+        self.x0 >= self.x1 || self.y0 >= self.y1
+    }
+
+    /// Check if the point is inside the rectangle
+    ///
+    /// - `p`: The point to check
+    /// - **returns**: If the point is inside, the bottom and right edges are excluded
+    pub fn contains(&self, (x, y): BackendCoord) -> bool {
+        self.x0 <= x && x < self.x1 && self.y0 <= y && y < self.y1
+    }
+
+    /// Check if this rectangle overlaps with another one, the rectangles that only touch each
+    /// other don't overlap
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Compute the intersection of two rectangles
+    ///
+    /// - `other`: The other rectangle
+    /// - **returns**: The overlapping part, or `None` if the rectangles don't overlap
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let ret = Rect {
+            x0: self.x0.max(other.x0),
+            y0: self.y0.max(other.y0),
+            x1: self.x1.min(other.x1),
+            y1: self.y1.min(other.y1),
+        };
+        if ret.is_empty() {
+            None
+        } else {
+            Some(ret)
+        }
+    }
+
+    /// Compute the smallest rectangle that contains both rectangles
+    ///
+    /// - `other`: The other rectangle
+    /// - **returns**: The bounding rectangle
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            x0: self.x0.min(other.x0),
+            y0: self.y0.min(other.y0),
+            x1: self.x1.max(other.x1),
+            y1: self.y1.max(other.y1),
+        }
+    }
+
+    /// Grow the rectangle on each side, or shrink it with negative values. When shrunk, the
+    /// rectangle collapses to its center instead of being inverted.
+    ///
+    /// - `dx`: The amount added to the left and the right side
+    /// - `dy`: The amount added to the top and the bottom side
+    /// - **returns**: The inflated rectangle
+    pub fn inflate(&self, dx: i32, dy: i32) -> Rect {
+        let (cx, cy) = self.center();
+        Rect {
+            x0: (self.x0 - dx).min(cx),
+            y0: (self.y0 - dy).min(cy),
+            x1: (self.x1 + dx).max(cx),
+            y1: (self.y1 + dy).max(cy),
+        }
+    }
+
+    /// Compute the largest rectangle with the given aspect ratio which fits in this rectangle,
+    /// centered in this rectangle
+    ///
+    /// - `aspect`: The aspect ratio, which is width / height
+    /// - **returns**: The fitted rectangle
+    pub fn aspect_fit(&self, aspect: f64) -> Rect {
+        let (w, h) = (f64::from(self.width()), f64::from(self.height()));
+        if !aspect.is_finite() || aspect <= 0.0 {
+            return self.clone();
+        }
+        let (fw, fh) = if w > h * aspect {
+            ((h * aspect).round(), h)
+        } else {
+            (w, (w / aspect).round())
+        };
+        let x0 = self.x0 + ((w - fw) / 2.0) as i32;
+        let y0 = self.y0 + ((h - fh) / 2.0) as i32;
+        Rect::from_size((x0, y0), (fw as u32, fh as u32))
+    }
+
     /// Split the rectangle into a few smaller rectangles
     fn split<'a, BPI: IntoIterator<Item = &'a i32> + 'a>(
         &'a self,
@@ -134,6 +291,7 @@ impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, C
 
 /// The error description of any drawing area API
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     /// The error is due to drawing backend failure
     BackendError(DrawingErrorKind<E>),
@@ -143,6 +301,12 @@ pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     SharingError,
     /// The error caused by invalid layout
     LayoutError,
+    /// The element contains a value which can't be mapped to a meaningful position, such as NaN,
+    /// and the coordinate uses [NonFinitePolicy::Error](../coord/enum.NonFinitePolicy.html)
+    NonFiniteValue,
+    /// The error occurred when drawing the element at the given index of a batch, see
+    /// [DrawingArea::draw_iter](struct.DrawingArea.html#method.draw_iter)
+    ElementError(usize, Box<DrawingAreaErrorKind<E>>),
 }
 
 impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
@@ -153,6 +317,8 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
                 write!(fmt, "Multiple backend operation in progress")
             }
             DrawingAreaErrorKind::LayoutError => write!(fmt, "Bad layout"),
+            DrawingAreaErrorKind::NonFiniteValue => write!(fmt, "Non-finite value in the element"),
+            DrawingAreaErrorKind::ElementError(idx, e) => write!(fmt, "element #{}: {}", idx, e),
         }
     }
 }
@@ -271,8 +437,16 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         (self.rect.x0..self.rect.x1, self.rect.y0..self.rect.y1)
     }
 
+    /// Get the rectangle this area covers on the backend canvas
+    pub fn get_rect(&self) -> &Rect {
+        &self.rect
+    }
+
     /// Perform operation on the drawing backend
-    fn backend_ops<R, O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>>(
+    pub(crate) fn backend_ops<
+        R,
+        O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>,
+    >(
         &self,
         ops: O,
     ) -> Result<R, DrawingAreaError<DB>> {
@@ -326,6 +500,114 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
     }
 
+    /// Draw a batch of high-level elements, which is faster than calling
+    /// [draw](#method.draw) for each of them when there are many elements, such as a large
+    /// scatter plot. The backend is prepared once for the whole batch, and the buffer of the
+    /// mapped points is reused across the elements.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("draw_iter.svg", (300, 300)).into_drawing_area();
+    /// let area = root.apply_coord_spec(Cartesian2d::<
+    ///     plotters::coord::types::RangedCoordf64,
+    ///     plotters::coord::types::RangedCoordf64,
+    /// >::new(0.0..1.0, 0.0..1.0, (0..300, 0..300)));
+    ///
+    /// let points = (0..1000).map(|i| ((i % 37) as f64 / 37.0, (i % 41) as f64 / 41.0));
+    /// area.draw_iter(points.map(|p| Circle::new(p, 2, BLUE.filled())))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `elements`: The elements to draw
+    /// - **returns**: The result, the failure is reported as
+    ///   [ElementError](enum.DrawingAreaErrorKind.html#variant.ElementError) with the index of
+    ///   the failing element, and the remaining elements are not drawn
+    pub fn draw_iter<E, B, R, I>(&self, elements: I) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        I: IntoIterator<Item = R>,
+    {
+        self.draw_indexed_iter(elements.into_iter().enumerate())
+    }
+
+    // Draw a batch of the elements paired with their indices, which are reported on failure
+    pub(crate) fn draw_indexed_iter<E, B, R, I>(
+        &self,
+        elements: I,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        I: IntoIterator<Item = (usize, R)>,
+    {
+        let mut db = self
+            .backend
+            .try_borrow_mut()
+            .map_err(|_| DrawingAreaErrorKind::SharingError)?;
+        db.ensure_prepared()
+            .map_err(DrawingAreaErrorKind::BackendError)?;
+
+        let dim = self.dim_in_pixel();
+        let mut points = vec![];
+        for (idx, element) in elements {
+            let element = element.borrow();
+            points.extend(
+                element
+                    .point_iter()
+                    .into_iter()
+                    .map(|p| B::map(&self.coord, p.borrow(), &self.rect)),
+            );
+            element.draw(points.drain(..), &mut *db, dim).map_err(|e| {
+                DrawingAreaErrorKind::ElementError(
+                    idx,
+                    Box::new(DrawingAreaErrorKind::BackendError(e)),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Perform the drawing operations with the drawing clipped to this drawing area, thus the
+    /// elements partially outside of the area, such as the wide lines and the out-of-range data
+    /// points, don't spill into the neighboring areas.
+    ///
+    /// The clipping is done by the backend, see
+    /// [DrawingBackend::begin_clip_rect](../prelude/trait.DrawingBackend.html#method.begin_clip_rect).
+    /// On the backends without clipping support, the drawing is not clipped.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("with_clip.svg", (300, 300)).into_drawing_area();
+    /// let (left, _) = root.split_horizontally(150);
+    /// left.with_clip(|area| area.draw(&Circle::new((150, 150), 50, RED.filled())))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `draw`: The drawing operations, which take this drawing area
+    /// - **returns**: The result of the drawing operations
+    pub fn with_clip<R, F>(&self, draw: F) -> Result<R, DrawingAreaError<DB>>
+    where
+        F: FnOnce(&Self) -> Result<R, DrawingAreaError<DB>>,
+    {
+        let (upper_left, bottom_right) = (
+            (self.rect.x0, self.rect.y0),
+            (self.rect.x1 - 1, self.rect.y1 - 1),
+        );
+        let clipped = self.backend_ops(|b| b.begin_clip_rect(upper_left, bottom_right))?;
+        let result = draw(self);
+        if clipped {
+            self.backend_ops(|b| b.end_clip())?;
+        }
+        result
+    }
+
     /// Map coordinate to the backend coordinate
     pub fn map_coordinate(&self, coord: &CT::From) -> BackendCoord {
         self.coord.translate(coord)
@@ -477,6 +759,108 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .collect()
     }
 
+    /// Apply a 2D cartesian coordinate spec which maps the ranges to the whole drawing area,
+    /// with the Y axis pointing up, as the chart builder does
+    fn apply_cartesian_2d<X: AsRangedCoord, Y: AsRangedCoord>(
+        &self,
+        x_spec: X,
+        y_spec: Y,
+    ) -> DrawingArea<DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>> {
+        let mut pixel_range = self.get_pixel_range();
+        pixel_range.0.end -= 1;
+        pixel_range.1.end -= 1;
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        self.apply_coord_spec(Cartesian2d::new(x_spec, y_spec, pixel_range))
+    }
+
+    /// Split the drawing area evenly into rows and apply a coordinate spec to each row in one step,
+    /// which is handy for small multiples.
+    ///
+    /// Each row reserves a label area of the same width on its left side. The width is computed
+    /// once from the whole drawing area, thus the plotting areas of all the rows share exactly the
+    /// same horizontal pixel range and the strips line up with each other.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let root = SVGBackend::new("strips.svg", (400, 600)).into_drawing_area();
+    /// let specs = ["a", "b", "c"].iter().map(|_| (0.0..10.0, 0.0..1.0));
+    /// for (label_area, strip) in root.split_rows_with_coords(40, specs) {
+    ///     label_area.fill(&WHITE).unwrap();
+    ///     strip.draw(&Circle::new((5.0, 0.5), 3, RED.filled())).unwrap();
+    /// }
+    /// ```
+    ///
+    /// - `label_area_size`: The width of the label area on the left of each row
+    /// - `specs`: The `(x_spec, y_spec)` of each row, from the top to the bottom
+    /// - **returns**: The iterator of the `(label_area, plotting_area)` pairs of each row
+    pub fn split_rows_with_coords<S, X, Y, I>(
+        &self,
+        label_area_size: S,
+        specs: I,
+    ) -> impl Iterator<
+        Item = (
+            Self,
+            DrawingArea<DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
+        ),
+    >
+    where
+        S: SizeDesc,
+        X: AsRangedCoord,
+        Y: AsRangedCoord,
+        I: IntoIterator<Item = (X, Y)>,
+    {
+        let label_area_size = label_area_size.in_pixels(self).max(0);
+        let specs: Vec<_> = specs.into_iter().collect();
+        self.split_evenly((specs.len(), 1))
+            .into_iter()
+            .zip(specs)
+            .map(move |(strip, (x_spec, y_spec))| {
+                let (label_area, plotting_area) = strip.split_horizontally(label_area_size);
+                let plotting_area = plotting_area.apply_cartesian_2d(x_spec, y_spec);
+                (label_area, plotting_area)
+            })
+    }
+
+    /// Split the drawing area evenly into columns and apply a coordinate spec to each column in
+    /// one step. This is the column version of
+    /// [split_rows_with_coords](#method.split_rows_with_coords), the label area of the same height
+    /// is reserved at the bottom of each column, so the plotting areas share the same vertical
+    /// pixel range.
+    ///
+    /// - `label_area_size`: The height of the label area at the bottom of each column
+    /// - `specs`: The `(x_spec, y_spec)` of each column, from the left to the right
+    /// - **returns**: The iterator of the `(label_area, plotting_area)` pairs of each column
+    pub fn split_columns_with_coords<S, X, Y, I>(
+        &self,
+        label_area_size: S,
+        specs: I,
+    ) -> impl Iterator<
+        Item = (
+            Self,
+            DrawingArea<DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
+        ),
+    >
+    where
+        S: SizeDesc,
+        X: AsRangedCoord,
+        Y: AsRangedCoord,
+        I: IntoIterator<Item = (X, Y)>,
+    {
+        let label_area_size = label_area_size.in_pixels(self).max(0);
+        let specs: Vec<_> = specs.into_iter().collect();
+        self.split_evenly((1, specs.len()))
+            .into_iter()
+            .zip(specs)
+            .map(move |(strip, (x_spec, y_spec))| {
+                let height = strip.dim_in_pixel().1 as i32;
+                let (plotting_area, label_area) =
+                    strip.split_vertically((height - label_area_size).max(0));
+                let plotting_area = plotting_area.apply_cartesian_2d(x_spec, y_spec);
+                (label_area, plotting_area)
+            })
+    }
+
     /// Draw a title of the drawing area and return the remaining drawing area
     pub fn titled<'a, S: Into<TextStyle<'a>>>(
         &self,
@@ -521,6 +905,21 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
     ) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|b| b.draw_text(text, style, (pos.0 + self.rect.x0, pos.1 + self.rect.y0)))
     }
+
+    /// Draw a batch of texts on the drawing area, which allows the backend to share the font
+    /// setup among the texts
+    ///
+    /// - `texts`: The texts to draw, each with its anchor point and style
+    pub fn draw_texts(
+        &self,
+        texts: &[(&str, BackendCoord, &TextStyle)],
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let texts: Vec<_> = texts
+            .iter()
+            .map(|&(text, pos, style)| (text, (pos.0 + self.rect.x0, pos.1 + self.rect.y0), style))
+            .collect();
+        self.backend_ops(|b| b.draw_texts(&texts))
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
@@ -543,6 +942,59 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
 #[cfg(test)]
 mod drawing_area_tests {
     use crate::{create_mocked_drawing_area, prelude::*};
+
+    #[test]
+    fn test_draw_iter() {
+        use crate::element::{Drawable, PointCollection};
+        use plotters_backend::{BackendCoord, DrawingErrorKind};
+
+        // The element fails to draw when it's not in the drawing area
+        struct Marker((i32, i32));
+        impl<'a> PointCollection<'a, (i32, i32)> for &'a Marker {
+            type Point = &'a (i32, i32);
+            type IntoIter = std::iter::Once<&'a (i32, i32)>;
+            fn point_iter(self) -> Self::IntoIter {
+                std::iter::once(&self.0)
+            }
+        }
+        impl<DB: DrawingBackend> Drawable<DB> for Marker {
+            fn draw<I: Iterator<Item = BackendCoord>>(
+                &self,
+                mut pos: I,
+                backend: &mut DB,
+                _: (u32, u32),
+            ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+                let pos = pos.next().unwrap();
+                if pos.0 >= 100 {
+                    return Err(DrawingErrorKind::FontError(Box::new(std::fmt::Error)));
+                }
+                backend.draw_pixel(pos, BLACK.to_backend_color())
+            }
+        }
+
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_pixel_call, 12));
+        });
+        let area = drawing_area.apply_coord_spec(Cartesian2d::<
+            crate::coord::types::RangedCoordi32,
+            crate::coord::types::RangedCoordi32,
+        >::new(0..10, 0..10, (0..100, 0..100)));
+
+        area.draw_iter((0..10).map(|x| Marker((x, x)))).unwrap();
+        area.draw_iter(vec![
+            Marker((1, 1)),
+            Marker((2, 2)),
+            Marker((20, 0)),
+            Marker((3, 3)),
+        ])
+        .map_err(|e| match e {
+            DrawingAreaErrorKind::ElementError(2, e) => {
+                assert!(matches!(*e, DrawingAreaErrorKind::BackendError(_)))
+            }
+            _ => panic!("Unexpected error {}", e),
+        })
+        .unwrap_err();
+    }
     #[test]
     fn test_filling() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -776,6 +1228,31 @@ mod drawing_area_tests {
         assert_eq!(y_range, 0..200);
     }
 
+    #[test]
+    fn test_split_rows_with_coords() {
+        let drawing_area = create_mocked_drawing_area(1024, 768, |_m| {});
+        let strips: Vec<_> = drawing_area
+            .split_rows_with_coords(24, (0..3).map(|i| (0..10, 0..(i + 1) * 10)))
+            .collect();
+
+        assert_eq!(strips.len(), 3);
+        for (idx, (label_area, strip)) in strips.iter().enumerate() {
+            let y0 = 256 * idx as i32;
+            assert_eq!(label_area.get_pixel_range(), (0..24, y0..y0 + 256));
+            assert_eq!(strip.get_x_axis_pixel_range(), 24..1023);
+            assert_eq!(strip.get_y_axis_pixel_range(), y0..y0 + 255);
+            assert_eq!(strip.get_y_range(), 0..(idx as i32 + 1) * 10);
+            assert_eq!(strip.map_coordinate(&(0, 0)), (24, y0 + 255));
+        }
+
+        let (label_area, strip) = drawing_area
+            .split_columns_with_coords(30, vec![(0.0..1.0, 0.0..1.0)])
+            .next()
+            .unwrap();
+        assert_eq!(label_area.get_pixel_range(), (0..1024, 738..768));
+        assert_eq!(strip.get_y_axis_pixel_range(), 0..737);
+    }
+
     #[test]
     fn test_relative_size() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |_m| {});
@@ -856,4 +1333,23 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[test]
+    fn test_rect_operations() {
+        use crate::drawing::Rect;
+
+        let area = create_mocked_drawing_area(400, 300, |_| {});
+        let (top, _) = area.split_vertically(100);
+        assert_eq!(top.get_rect(), &Rect::new((400, 100), (0, 0)));
+
+        let rect = Rect::from_size((10, 20), (30, 40));
+        assert!(rect.contains((10, 20)));
+        assert!(!rect.contains((40, 20)));
+        assert_eq!(rect.intersection(&Rect::new((40, 0), (50, 100))), None);
+        assert_eq!(rect.inflate(-100, -100), Rect::new((25, 40), (25, 40)));
+        assert!(rect.inflate(-100, -100).is_empty());
+        assert_eq!(rect.aspect_fit(1.0), Rect::new((10, 25), (40, 55)));
+        assert_eq!(rect.aspect_fit(3.0), Rect::new((10, 35), (40, 45)));
+        assert_eq!(rect.aspect_fit(f64::NAN), rect);
+    }
 }
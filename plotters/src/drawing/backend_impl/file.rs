@@ -0,0 +1,325 @@
+use std::error::Error;
+use std::path::Path;
+
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, BitmapInterpolation,
+    DrawingBackend, DrawingErrorKind,
+};
+#[cfg(feature = "bitmap_encoder")]
+use plotters_bitmap::{BitMapBackend, BitMapBackendError};
+#[cfg(feature = "svg_backend")]
+use plotters_svg::SVGBackend;
+
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, IntoDrawingArea};
+
+/// The frame delay of the GIF files created by [save](fn.save.html), in milliseconds
+pub const SAVE_GIF_FRAME_DELAY: u32 = 100;
+
+/// The error type of [FileBackend](enum.FileBackend.html)
+#[derive(Debug)]
+pub enum FileBackendError {
+    /// The error reported by the bitmap backend
+    #[cfg(feature = "bitmap_encoder")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_encoder")))]
+    BitMap(BitMapBackendError),
+    /// The error reported by the SVG backend
+    #[cfg(feature = "svg_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "svg_backend")))]
+    Svg(std::io::Error),
+    /// The extension of the file isn't supported
+    UnsupportedFormat(String),
+}
+
+impl std::fmt::Display for FileBackendError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "bitmap_encoder")]
+            FileBackendError::BitMap(e) => write!(fmt, "{}", e),
+            #[cfg(feature = "svg_backend")]
+            FileBackendError::Svg(e) => write!(fmt, "{}", e),
+            FileBackendError::UnsupportedFormat(ext) => {
+                write!(fmt, "Unsupported image format: {:?}", ext)
+            }
+        }
+    }
+}
+
+impl Error for FileBackendError {}
+
+/// The backend which writes to a file, the actual backend is selected by the extension of the
+/// file. This is the backend used by [save](fn.save.html).
+pub enum FileBackend<'a> {
+    /// A PNG, JPEG or BMP image, or a GIF animation
+    #[cfg(feature = "bitmap_encoder")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_encoder")))]
+    BitMap(BitMapBackend<'a>),
+    /// A SVG image
+    #[cfg(feature = "svg_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "svg_backend")))]
+    Svg(SVGBackend<'a>),
+}
+
+impl<'a> FileBackend<'a> {
+    /// Create a backend for the file, based on the extension of the path. The supported
+    /// extensions are `svg`, `png`, `jpg`, `jpeg`, `bmp` and `gif`, depending on the enabled
+    /// features.
+    ///
+    /// - `path`: The path of the file
+    /// - `size`: The size of the image
+    /// - **returns**: The backend, or an error if the format isn't supported
+    pub fn new<T: AsRef<Path> + ?Sized>(
+        path: &'a T,
+        size: (u32, u32),
+    ) -> Result<Self, FileBackendError> {
+        let path = path.as_ref();
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            #[cfg(feature = "svg_backend")]
+            "svg" => Ok(FileBackend::Svg(SVGBackend::new(path, size))),
+            #[cfg(feature = "bitmap_gif")]
+            "gif" => BitMapBackend::gif(path, size, SAVE_GIF_FRAME_DELAY)
+                .map(FileBackend::BitMap)
+                .map_err(FileBackendError::BitMap),
+            #[cfg(feature = "bitmap_encoder")]
+            "png" | "jpg" | "jpeg" | "bmp" => {
+                Ok(FileBackend::BitMap(BitMapBackend::new(path, size)))
+            }
+            _ => Err(FileBackendError::UnsupportedFormat(ext)),
+        }
+    }
+}
+
+fn map_error<E, F: FnOnce(E) -> FileBackendError>(
+    error: DrawingErrorKind<E>,
+    wrap: F,
+) -> DrawingErrorKind<FileBackendError>
+where
+    E: Error + Send + Sync,
+{
+    match error {
+        DrawingErrorKind::DrawingError(e) => DrawingErrorKind::DrawingError(wrap(e)),
+        DrawingErrorKind::FontError(e) => DrawingErrorKind::FontError(e),
+    }
+}
+
+macro_rules! dispatch {
+    ($self:expr, $backend:ident => $call:expr) => {
+        match $self {
+            #[cfg(feature = "bitmap_encoder")]
+            FileBackend::BitMap($backend) => {
+                $call.map_err(|e| map_error(e, FileBackendError::BitMap))
+            }
+            #[cfg(feature = "svg_backend")]
+            FileBackend::Svg($backend) => $call.map_err(|e| map_error(e, FileBackendError::Svg)),
+        }
+    };
+}
+
+impl<'a> DrawingBackend for FileBackend<'a> {
+    type ErrorType = FileBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        match self {
+            #[cfg(feature = "bitmap_encoder")]
+            FileBackend::BitMap(b) => b.get_size(),
+            #[cfg(feature = "svg_backend")]
+            FileBackend::Svg(b) => b.get_size(),
+        }
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.ensure_prepared())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.present())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_line(from, to, style))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_rounded_rect(upper_left, bottom_right, radius, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_path(path, style))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.fill_polygon(vert, style))
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_text(text, style, pos))
+    }
+
+    fn draw_texts<TStyle: BackendTextStyle>(
+        &mut self,
+        texts: &[(&str, BackendCoord, &TStyle)],
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.draw_texts(texts))
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.estimate_text_size(text, style))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.blit_bitmap(pos, size, src))
+    }
+
+    fn blit_bitmap_scaled(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        src_size: (u32, u32),
+        src: &[u8],
+        interpolation: BitmapInterpolation,
+    ) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.blit_bitmap_scaled(upper_left, bottom_right, src_size, src, interpolation))
+    }
+
+    fn begin_clip_polygon(
+        &mut self,
+        vert: &[BackendCoord],
+    ) -> Result<bool, DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.begin_clip_polygon(vert))
+    }
+
+    fn begin_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.begin_clip_rect(upper_left, bottom_right))
+    }
+
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.end_clip())
+    }
+
+    fn add_metadata(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<bool, DrawingErrorKind<FileBackendError>> {
+        dispatch!(self, b => b.add_metadata(key, value))
+    }
+}
+
+/// Draw an image and save it to a file, the backend is selected by the extension of the file.
+///
+/// The drawing area is presented after the drawing function returns, so the image is always
+/// written and the errors of writing the file are reported. For a GIF animation, call `present`
+/// on the drawing area after each frame except the last one, the frame delay is
+/// [SAVE_GIF_FRAME_DELAY](constant.SAVE_GIF_FRAME_DELAY.html).
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// plotters::save("plotters-doc-data/save.svg", (640, 480), |root| {
+///     root.fill(&WHITE)?;
+///     let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+///     chart.draw_series(LineSeries::new((0..=10).map(|x| (x as f64 / 10.0, x as f64 / 10.0)), &RED))?;
+///     Ok(())
+/// })
+/// .unwrap();
+/// ```
+///
+/// - `path`: The path of the file. See [FileBackend::new](enum.FileBackend.html#method.new) for
+///   the supported extensions
+/// - `size`: The size of the image
+/// - `draw`: The function which draws the image on the root drawing area
+/// - **returns**: The result of the drawing
+pub fn save<T, Draw>(path: &T, size: (u32, u32), draw: Draw) -> Result<(), Box<dyn Error>>
+where
+    T: AsRef<Path> + ?Sized,
+    Draw: FnOnce(&DrawingArea<FileBackend, Shift>) -> Result<(), Box<dyn Error>>,
+{
+    let root = FileBackend::new(path, size)?.into_drawing_area();
+    draw(&root)?;
+    root.present()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_format() {
+        match FileBackend::new("image.txt", (100, 100)) {
+            Err(FileBackendError::UnsupportedFormat(ext)) => assert_eq!(ext, "txt"),
+            _ => panic!("the format should be unsupported"),
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use crate::coord::CoordTranslate;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, BitmapInterpolation,
+    DrawingBackend, DrawingErrorKind, LineCap, LineJoin,
+};
+
+use std::fmt::{Display, Formatter};
+
+/// The key of the metadata which [FingerprintBackend] embeds into the output
+pub const FINGERPRINT_METADATA_KEY: &str = "plotters-fingerprint";
+
+/// The information needed to tell if a chart is reproduced from the same data with the same
+/// code, which is computed by [FingerprintBackend].
+///
+/// The fingerprint is written to the metadata as `data=<hash> plotters=<version>`, followed by
+/// ` code=<version>` if the version of the code is given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// The hash of everything drawn, which is stable across runs and platforms
+    pub data_hash: u64,
+    /// The version of Plotters which draws the chart
+    pub plotters_version: &'static str,
+    /// The version of the code which draws the chart, for example a git commit hash
+    pub code_version: Option<String>,
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "data={:016x} plotters={}",
+            self.data_hash, self.plotters_version
+        )?;
+        if let Some(code_version) = &self.code_version {
+            write!(f, " code={}", code_version)?;
+        }
+        Ok(())
+    }
+}
+
+// The 64-bit FNV-1a hash, the standard library doesn't promise that its hashers are stable
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_u32(value.len() as u32);
+        self.write(value.as_bytes());
+    }
+
+    fn write_coord(&mut self, (x, y): BackendCoord) {
+        self.write_i32(x);
+        self.write_i32(y);
+    }
+
+    fn write_color(&mut self, color: BackendColor) {
+        self.write(&[color.rgb.0, color.rgb.1, color.rgb.2]);
+        self.write(&color.alpha.to_bits().to_le_bytes());
+    }
+
+    fn write_style<S: BackendStyle>(&mut self, style: &S) {
+        self.write_color(style.color());
+        self.write_u32(style.stroke_width());
+        // The default joins, caps and solid strokes are left out to keep the fingerprints stable
+        if style.line_join() != LineJoin::Miter || style.line_cap() != LineCap::Butt {
+            self.write_str(style.line_join().as_str());
+            self.write_str(style.line_cap().as_str());
+        }
+        if let Some(pattern) = style.dash_pattern() {
+            self.write(b"D");
+            self.write_u32(pattern.on);
+            self.write_u32(pattern.off);
+            self.write_u32(pattern.offset);
+        }
+    }
+
+    fn write_text<S: BackendTextStyle>(&mut self, text: &str, style: &S, pos: BackendCoord) {
+        self.write(b"T");
+        self.write_str(text);
+        self.write_coord(pos);
+        self.write_color(style.color());
+        self.write_str(style.family().as_str());
+        self.write_str(style.style().as_str());
+        self.write(&style.size().to_bits().to_le_bytes());
+    }
+}
+
+/**
+A backend wrapper which computes a [Fingerprint] of the chart, so that the output can be traced
+back to the data and the code that produced it.
+
+Every primitive sent to the wrapped backend, including the coordinates, the styles and the texts,
+is fed to a hash, thus the same data drawn by the same code always has the same fingerprint, and
+any visible change of the data changes it. When the drawing is presented, the fingerprint is
+embedded into the output with the
+[add_metadata](../../plotters_backend/trait.DrawingBackend.html#method.add_metadata) method of the
+wrapped backend, under the key [FINGERPRINT_METADATA_KEY], for example, the SVG backend writes a
+`<metadata>` element, while the backends without metadata support ignore it. Either way, the
+fingerprint is also available programmatically.
+
+```rust
+use plotters::drawing::FingerprintBackend;
+use plotters::prelude::*;
+
+let mut svg = String::new();
+{
+    let backend = SVGBackend::with_string(&mut svg, (300, 200));
+    let root = FingerprintBackend::new(backend)
+        .with_code_version("v1.2.0")
+        .into_drawing_area();
+    let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0..10, 0..10).unwrap();
+    chart
+        .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+        .unwrap();
+
+    let fingerprint = root.present_with_fingerprint().unwrap();
+    assert_eq!(fingerprint.code_version.as_deref(), Some("v1.2.0"));
+}
+assert!(svg.contains("<metadata data-key=\"plotters-fingerprint\">"));
+```
+*/
+pub struct FingerprintBackend<DB: DrawingBackend> {
+    inner: DB,
+    hasher: StableHasher,
+    code_version: Option<String>,
+}
+
+impl<DB: DrawingBackend> FingerprintBackend<DB> {
+    /// Wrap a backend
+    ///
+    /// - `inner`: The backend that actually draws
+    /// - **returns**: The wrapped backend
+    pub fn new(inner: DB) -> Self {
+        Self {
+            inner,
+            hasher: StableHasher(StableHasher::OFFSET_BASIS),
+            code_version: None,
+        }
+    }
+
+    /// Set the version of the code which draws the chart, which is recorded in the fingerprint
+    ///
+    /// - `version`: The version of the code, for example a git commit hash
+    /// - **returns**: The wrapped backend
+    pub fn with_code_version<S: Into<String>>(mut self, version: S) -> Self {
+        self.code_version = Some(version.into());
+        self
+    }
+
+    /// Get the fingerprint of the drawing since the last time it's presented
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint {
+            data_hash: self.hasher.0,
+            plotters_version: env!("CARGO_PKG_VERSION"),
+            code_version: self.code_version.clone(),
+        }
+    }
+
+    /// Get the wrapped backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for FingerprintBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let fingerprint = self.fingerprint().to_string();
+        self.inner
+            .add_metadata(FINGERPRINT_METADATA_KEY, &fingerprint)?;
+        self.hasher = StableHasher(StableHasher::OFFSET_BASIS);
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(b"P");
+        self.hasher.write_coord(point);
+        self.hasher.write_color(color);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(b"L");
+        self.hasher.write_coord(from);
+        self.hasher.write_coord(to);
+        self.hasher.write_style(style);
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(if fill { b"R" } else { b"r" });
+        self.hasher.write_coord(upper_left);
+        self.hasher.write_coord(bottom_right);
+        self.hasher.write_style(style);
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(if fill { b"Q" } else { b"q" });
+        self.hasher.write_coord(upper_left);
+        self.hasher.write_coord(bottom_right);
+        self.hasher.write_u32(radius);
+        self.hasher.write_style(style);
+        self.inner
+            .draw_rounded_rect(upper_left, bottom_right, radius, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.hasher.write(b"A");
+        self.hasher.write_u32(path.len() as u32);
+        path.iter().for_each(|p| self.hasher.write_coord(*p));
+        self.hasher.write_style(style);
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(if fill { b"C" } else { b"c" });
+        self.hasher.write_coord(center);
+        self.hasher.write_u32(radius);
+        self.hasher.write_style(style);
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.hasher.write(b"G");
+        self.hasher.write_u32(vert.len() as u32);
+        vert.iter().for_each(|p| self.hasher.write_coord(*p));
+        self.hasher.write_style(style);
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write_text(text, style, pos);
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn draw_texts<TStyle: BackendTextStyle>(
+        &mut self,
+        texts: &[(&str, BackendCoord, &TStyle)],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        for (text, pos, style) in texts {
+            self.hasher.write_text(text, *style, *pos);
+        }
+        self.inner.draw_texts(texts)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(b"B");
+        self.hasher.write_coord(pos);
+        self.hasher.write_u32(size.0);
+        self.hasher.write_u32(size.1);
+        self.hasher.write(src);
+        self.inner.blit_bitmap(pos, size, src)
+    }
+
+    fn blit_bitmap_scaled(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        src_size: (u32, u32),
+        src: &[u8],
+        interpolation: BitmapInterpolation,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(match interpolation {
+            BitmapInterpolation::Nearest => b"N",
+            BitmapInterpolation::Bilinear => b"I",
+        });
+        self.hasher.write_coord(upper_left);
+        self.hasher.write_coord(bottom_right);
+        self.hasher.write_u32(src_size.0);
+        self.hasher.write_u32(src_size.1);
+        self.hasher.write(src);
+        self.inner
+            .blit_bitmap_scaled(upper_left, bottom_right, src_size, src, interpolation)
+    }
+
+    fn begin_clip_polygon(
+        &mut self,
+        vert: &[BackendCoord],
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(b"K");
+        self.hasher.write_u32(vert.len() as u32);
+        vert.iter().for_each(|p| self.hasher.write_coord(*p));
+        self.inner.begin_clip_polygon(vert)
+    }
+
+    fn begin_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(b"C");
+        self.hasher.write_coord(upper_left);
+        self.hasher.write_coord(bottom_right);
+        self.inner.begin_clip_rect(upper_left, bottom_right)
+    }
+
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.hasher.write(b"k");
+        self.inner.end_clip()
+    }
+
+    fn add_metadata(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.inner.add_metadata(key, value)
+    }
+}
+
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<FingerprintBackend<DB>, CT> {
+    /// Present all the pending changes to the backend with the fingerprint embedded, and get the
+    /// fingerprint of the drawing since the last time it's presented
+    pub fn present_with_fingerprint(
+        &self,
+    ) -> Result<Fingerprint, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.backend_ops(|b| {
+            let fingerprint = b.fingerprint();
+            b.present()?;
+            Ok(fingerprint)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    fn draw_chart(data: &[(i32, i32)]) -> Fingerprint {
+        let area = FingerprintBackend::new(MockedBackend::new(200, 100))
+            .with_code_version("abc123")
+            .into_drawing_area();
+        let mut chart = ChartBuilder::on(&area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .draw_series(LineSeries::new(data.iter().copied(), &RED))
+            .unwrap();
+        area.present_with_fingerprint().unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let data = [(0, 1), (5, 3), (9, 9)];
+        let fingerprint = draw_chart(&data);
+        assert_eq!(fingerprint, draw_chart(&data));
+        assert_ne!(fingerprint.data_hash, draw_chart(&data[..2]).data_hash);
+        assert_eq!(
+            fingerprint.to_string(),
+            format!(
+                "data={:016x} plotters={} code=abc123",
+                fingerprint.data_hash,
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+
+        // Each frame has its own fingerprint
+        let area = FingerprintBackend::new(MockedBackend::new(100, 100)).into_drawing_area();
+        let empty = area.present_with_fingerprint().unwrap();
+        area.fill(&WHITE).unwrap();
+        assert_ne!(area.present_with_fingerprint().unwrap(), empty);
+        assert_eq!(area.present_with_fingerprint().unwrap(), empty);
+    }
+}
@@ -6,7 +6,9 @@ use plotters_backend::{
     BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
 };
 
+use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 pub fn check_color(left: BackendColor, right: RGBAColor) {
     assert_eq!(
@@ -294,3 +296,163 @@ pub fn create_mocked_drawing_area<F: FnOnce(&mut MockedBackend)>(
     setup(&mut backend);
     backend.into_drawing_area()
 }
+
+/// A line drawn by `draw_line`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnLine {
+    /// The color of the line
+    pub color: RGBAColor,
+    /// The stroke width
+    pub width: u32,
+    /// The start point
+    pub from: BackendCoord,
+    /// The end point
+    pub to: BackendCoord,
+}
+
+/// A rectangle drawn by `draw_rect`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnRect {
+    /// The color of the rectangle
+    pub color: RGBAColor,
+    /// The stroke width
+    pub width: u32,
+    /// If the rectangle is filled
+    pub fill: bool,
+    /// The upper left corner
+    pub upper_left: BackendCoord,
+    /// The bottom right corner
+    pub bottom_right: BackendCoord,
+}
+
+/// A path drawn by `draw_path`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnPath {
+    /// The color of the path
+    pub color: RGBAColor,
+    /// The stroke width
+    pub width: u32,
+    /// The points of the path
+    pub path: Vec<BackendCoord>,
+}
+
+/// A circle drawn by `draw_circle`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnCircle {
+    /// The color of the circle
+    pub color: RGBAColor,
+    /// The stroke width
+    pub width: u32,
+    /// If the circle is filled
+    pub fill: bool,
+    /// The center
+    pub center: BackendCoord,
+    /// The radius
+    pub radius: u32,
+}
+
+/// A text drawn by `draw_text`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnText {
+    /// The color of the text
+    pub color: RGBAColor,
+    /// The font family
+    pub family: String,
+    /// The font size
+    pub size: f64,
+    /// The position of the text
+    pub pos: BackendCoord,
+    /// The text
+    pub text: String,
+}
+
+/// A polygon filled by `fill_polygon`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawnPolygon {
+    /// The color of the polygon
+    pub color: RGBAColor,
+    /// The vertices of the polygon
+    pub vert: Vec<BackendCoord>,
+}
+
+/// The calls made on the mocked backend, in the order of drawing
+#[derive(Clone, Debug, Default)]
+pub struct DrawCalls {
+    /// The lines
+    pub lines: Vec<DrawnLine>,
+    /// The rectangles
+    pub rects: Vec<DrawnRect>,
+    /// The paths
+    pub paths: Vec<DrawnPath>,
+    /// The circles
+    pub circles: Vec<DrawnCircle>,
+    /// The texts
+    pub texts: Vec<DrawnText>,
+    /// The filled polygons
+    pub polygons: Vec<DrawnPolygon>,
+}
+
+/// Draw on a mocked drawing area and return the calls made on the backend, once the area is
+/// dropped
+pub fn record_draw_calls<F: FnOnce(&DrawingArea<MockedBackend, Shift>)>(
+    width: u32,
+    height: u32,
+    draw: F,
+) -> DrawCalls {
+    let calls = Rc::new(RefCell::new(DrawCalls::default()));
+    let area = create_mocked_drawing_area(width, height, |m| {
+        let c = calls.clone();
+        m.check_draw_line(move |color, width, from, to| {
+            c.borrow_mut().lines.push(DrawnLine {
+                color,
+                width,
+                from,
+                to,
+            })
+        });
+        let c = calls.clone();
+        m.check_draw_rect(move |color, width, fill, upper_left, bottom_right| {
+            c.borrow_mut().rects.push(DrawnRect {
+                color,
+                width,
+                fill,
+                upper_left,
+                bottom_right,
+            })
+        });
+        let c = calls.clone();
+        m.check_draw_path(move |color, width, path| {
+            c.borrow_mut().paths.push(DrawnPath { color, width, path })
+        });
+        let c = calls.clone();
+        m.check_draw_circle(move |color, width, fill, center, radius| {
+            c.borrow_mut().circles.push(DrawnCircle {
+                color,
+                width,
+                fill,
+                center,
+                radius,
+            })
+        });
+        let c = calls.clone();
+        m.check_draw_text(move |color, family, size, pos, text| {
+            c.borrow_mut().texts.push(DrawnText {
+                color,
+                family: family.to_string(),
+                size,
+                pos,
+                text: text.to_string(),
+            })
+        });
+        let c = calls.clone();
+        m.check_fill_polygon(move |color, vert| {
+            c.borrow_mut().polygons.push(DrawnPolygon { color, vert })
+        });
+    });
+
+    draw(&area);
+    drop(area);
+    Rc::try_unwrap(calls)
+        .expect("The mocked backend is still alive")
+        .into_inner()
+}
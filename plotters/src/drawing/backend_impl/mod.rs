@@ -1,7 +1,30 @@
 #[cfg(test)]
 mod mocked;
 #[cfg(test)]
-pub use mocked::{check_color, create_mocked_drawing_area, MockedBackend};
+pub use mocked::{
+    check_color, create_mocked_drawing_area, record_draw_calls, DrawCalls, DrawnCircle, DrawnLine,
+    DrawnPath, DrawnPolygon, DrawnRect, DrawnText, MockedBackend,
+};
+
+mod fingerprint;
+pub use fingerprint::{Fingerprint, FingerprintBackend, FINGERPRINT_METADATA_KEY};
+
+mod origin;
+pub use origin::{BackendOrigin, OriginBackend};
+
+mod stats;
+pub use stats::{RenderStats, StatsBackend};
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "svg_backend", feature = "bitmap_encoder")
+))]
+mod file;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "svg_backend", feature = "bitmap_encoder")
+))]
+pub use file::{save, FileBackend, FileBackendError, SAVE_GIF_FRAME_DELAY};
 
 /// This is the dummy backend placeholder for the backend that never fails
 #[derive(Debug)]
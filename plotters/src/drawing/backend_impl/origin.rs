@@ -0,0 +1,295 @@
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// The corner of the target where the pixel coordinate `(0, 0)` is, which determines the
+/// direction of the Y axis of the target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackendOrigin {
+    /// The origin is at the top-left corner and the Y axis points down, which is what Plotters
+    /// and most of the image formats use
+    TopLeft,
+    /// The origin is at the bottom-left corner and the Y axis points up, such as the OpenGL
+    /// textures and the PDF pages
+    BottomLeft,
+}
+
+/**
+A backend wrapper which changes the origin of the pixel coordinates of the wrapped backend.
+
+Plotters always lays out the drawing with the origin at the top-left corner. With the
+[`BackendOrigin::BottomLeft`] origin, every pixel is mapped to the mirrored row before it
+reaches the wrapped backend, so the rows of a bitmap buffer come out bottom to top, and it can be
+uploaded as an OpenGL texture without reversing the rows afterward.
+
+The geometric shapes are passed to the wrapped backend with the mapped coordinates. The texts and
+the bitmaps are rasterized into pixels by the wrapper, thus the glyphs are mirrored as well, just
+like the rest of the drawing.
+
+```rust
+use plotters::coord::Shift;
+use plotters::drawing::{BackendOrigin, OriginBackend};
+use plotters::prelude::*;
+
+fn draw<DB: DrawingBackend>(root: &DrawingArea<DB, Shift>) {
+    root.fill(&WHITE).unwrap();
+    root.draw(&Rectangle::new([(10, 10), (40, 20)], RED.filled()))
+        .unwrap();
+    root.draw(&Circle::new((30, 25), 8, BLUE.filled())).unwrap();
+}
+
+let mut top_down = vec![0; 60 * 40 * 3];
+draw(&BitMapBackend::with_buffer(&mut top_down, (60, 40)).into_drawing_area());
+
+let mut bottom_up = vec![0; 60 * 40 * 3];
+let backend = BitMapBackend::with_buffer(&mut bottom_up, (60, 40));
+draw(&OriginBackend::new(backend, BackendOrigin::BottomLeft).into_drawing_area());
+
+// The same image with the rows in the reversed order
+let reversed: Vec<u8> = top_down.chunks(60 * 3).rev().flatten().copied().collect();
+assert_eq!(bottom_up, reversed);
+```
+*/
+pub struct OriginBackend<DB: DrawingBackend> {
+    inner: DB,
+    origin: BackendOrigin,
+}
+
+impl<DB: DrawingBackend> OriginBackend<DB> {
+    /// Wrap a backend
+    ///
+    /// - `inner`: The backend that actually draws
+    /// - `origin`: The corner of the wrapped backend where its pixel coordinate `(0, 0)` is
+    /// - **returns**: The wrapped backend
+    pub fn new(inner: DB, origin: BackendOrigin) -> Self {
+        Self { inner, origin }
+    }
+
+    /// Get the origin of the wrapped backend
+    pub fn origin(&self) -> BackendOrigin {
+        self.origin
+    }
+
+    /// Get the wrapped backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    fn map_y(&self, y: i32) -> i32 {
+        match self.origin {
+            BackendOrigin::TopLeft => y,
+            BackendOrigin::BottomLeft => self.inner.get_size().1 as i32 - 1 - y,
+        }
+    }
+
+    fn map(&self, (x, y): BackendCoord) -> BackendCoord {
+        (x, self.map_y(y))
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for OriginBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let point = self.map(point);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = (self.map(from), self.map(to));
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        // The mirrored bottom edge becomes the upper edge. The backends fill the rows up to the
+        // bottom edge exclusively, while the outline is drawn on both edges.
+        let (y0, y1) = if fill && self.origin == BackendOrigin::BottomLeft {
+            (self.map_y(bottom_right.1 - 1), self.map_y(upper_left.1 - 1))
+        } else {
+            (self.map_y(upper_left.1), self.map_y(bottom_right.1))
+        };
+        self.inner.draw_rect(
+            (upper_left.0, y0.min(y1)),
+            (bottom_right.0, y0.max(y1)),
+            style,
+            fill,
+        )
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (y0, y1) = if fill && self.origin == BackendOrigin::BottomLeft {
+            (self.map_y(bottom_right.1 - 1), self.map_y(upper_left.1 - 1))
+        } else {
+            (self.map_y(upper_left.1), self.map_y(bottom_right.1))
+        };
+        self.inner.draw_rounded_rect(
+            (upper_left.0, y0.min(y1)),
+            (bottom_right.0, y0.max(y1)),
+            radius,
+            style,
+            fill,
+        )
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = path.into_iter().map(|p| self.map(p)).collect();
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = self.map(center);
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().map(|p| self.map(p)).collect();
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.origin == BackendOrigin::TopLeft || ih == 0 {
+            return self.inner.blit_bitmap(pos, (iw, ih), src);
+        }
+        // Blit the bitmap with its rows reversed at the mirrored position, whatever the pixel
+        // format of the wrapped backend is
+        let row_size = src.len() / ih as usize;
+        let flipped: Vec<u8> = src[..row_size * ih as usize]
+            .chunks(row_size.max(1))
+            .rev()
+            .flatten()
+            .copied()
+            .collect();
+        let top = self.map_y(pos.1 + ih as i32 - 1);
+        self.inner.blit_bitmap((pos.0, top), (iw, ih), &flipped)
+    }
+
+    fn begin_clip_polygon(
+        &mut self,
+        vert: &[BackendCoord],
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        let vert: Vec<_> = vert.iter().map(|p| self.map(*p)).collect();
+        self.inner.begin_clip_polygon(&vert)
+    }
+
+    fn begin_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        // The mirrored rectangle has its top and bottom edges swapped
+        let (y0, y1) = (self.map_y(upper_left.1), self.map_y(bottom_right.1));
+        self.inner
+            .begin_clip_rect((upper_left.0, y0.min(y1)), (bottom_right.0, y0.max(y1)))
+    }
+
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.end_clip()
+    }
+
+    fn add_metadata(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.inner.add_metadata(key, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bottom_left_origin() {
+        let mut backend = MockedBackend::new(100, 50);
+        backend
+            .check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!((upper_left, bottom_right), ((10, 30), (20, 40)));
+            })
+            .check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(0, 49), (99, 0)]);
+            })
+            .drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+
+        let area = OriginBackend::new(backend, BackendOrigin::BottomLeft).into_drawing_area();
+        area.draw(&Rectangle::new([(10, 10), (20, 20)], RED.filled()))
+            .unwrap();
+        area.draw(&PathElement::new(vec![(0, 0), (99, 49)], BLUE))
+            .unwrap();
+
+        // A rounded rect with zero radius is filled on the same rows as the plain rect
+        let mut backend = MockedBackend::new(10, 10);
+        backend
+            .check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!((upper_left, bottom_right), ((0, 5), (9, 8)));
+            })
+            .drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+
+        let mut origin = OriginBackend::new(backend, BackendOrigin::BottomLeft);
+        let style = RED.filled();
+        origin.draw_rect((0, 2), (9, 5), &style, true).unwrap();
+        origin
+            .draw_rounded_rect((0, 2), (9, 5), 0, &style, true)
+            .unwrap();
+    }
+}
@@ -0,0 +1,322 @@
+use crate::coord::CoordTranslate;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, BitmapInterpolation,
+    DrawingBackend, DrawingErrorKind,
+};
+
+use std::time::Duration;
+
+/// The statistics of the drawing operations, which is collected by [StatsBackend].
+///
+/// The counters are the numbers of the primitives that Plotters sends to the backend, for
+/// example, a path drawn by a backend without native path support is counted as one path, not the
+/// lines or pixels it's rasterized into.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// The number of the pixels drawn
+    pub pixels: u64,
+    /// The number of the lines drawn
+    pub lines: u64,
+    /// The number of the rectangles drawn
+    pub rects: u64,
+    /// The number of the paths drawn
+    pub paths: u64,
+    /// The number of the circles drawn
+    pub circles: u64,
+    /// The number of the polygons filled
+    pub polygons: u64,
+    /// The number of the texts drawn
+    pub texts: u64,
+    /// The number of the bitmaps blitted
+    pub bitmaps: u64,
+    /// The total number of the vertices of the paths and polygons
+    pub vertices: u64,
+    /// The number of the vertices of the largest path or polygon, which is the size of the
+    /// largest temporary vertex buffer the rasterizer may allocate
+    pub max_vertices: u64,
+    /// The time spent on the drawing operations in the backend
+    pub draw_time: Duration,
+    /// The time spent on presenting the result, for example, encoding and writing the file
+    pub present_time: Duration,
+}
+
+/// A backend wrapper which collects the [RenderStats] of the drawing operations, so that the
+/// performance of a chart can be tracked without an external profiler.
+///
+/// ```rust
+/// use plotters::drawing::StatsBackend;
+/// use plotters::prelude::*;
+///
+/// let root = StatsBackend::new(SVGBackend::new("render_stats.svg", (300, 200)))
+///     .into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0..10, 0..10).unwrap();
+/// chart
+///     .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+///     .unwrap();
+///
+/// let stats = root.present_with_stats().unwrap();
+/// assert_eq!(stats.paths, 1);
+/// assert_eq!(stats.max_vertices, 10);
+/// ```
+///
+/// The timing isn't available on WebAssembly, where the durations are always zero.
+pub struct StatsBackend<DB: DrawingBackend> {
+    inner: DB,
+    stats: RenderStats,
+}
+
+impl<DB: DrawingBackend> StatsBackend<DB> {
+    /// Wrap a backend
+    ///
+    /// - `inner`: The backend that actually draws
+    /// - **returns**: The wrapped backend
+    pub fn new(inner: DB) -> Self {
+        Self {
+            inner,
+            stats: RenderStats::default(),
+        }
+    }
+
+    /// Get the statistics collected so far
+    pub fn stats(&self) -> &RenderStats {
+        &self.stats
+    }
+
+    /// Get the statistics collected so far and start over
+    pub fn take_stats(&mut self) -> RenderStats {
+        std::mem::take(&mut self.stats)
+    }
+
+    /// Get the wrapped backend
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    fn timed<R, F: FnOnce(&mut DB) -> R>(inner: &mut DB, time: &mut Duration, f: F) -> R {
+        #[cfg(not(target_arch = "wasm32"))]
+        let start = std::time::Instant::now();
+        let result = f(inner);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            *time += start.elapsed();
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = time;
+        result
+    }
+
+    fn draw<R, F: FnOnce(&mut DB) -> R>(&mut self, f: F) -> R {
+        Self::timed(&mut self.inner, &mut self.stats.draw_time, f)
+    }
+
+    fn count_vertices(&mut self, n: u64) {
+        self.stats.vertices += n;
+        self.stats.max_vertices = self.stats.max_vertices.max(n);
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for StatsBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        Self::timed(&mut self.inner, &mut self.stats.present_time, |b| {
+            b.present()
+        })
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.pixels += 1;
+        self.draw(|b| b.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.lines += 1;
+        self.draw(|b| b.draw_line(from, to, style))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.rects += 1;
+        self.draw(|b| b.draw_rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.rects += 1;
+        self.draw(|b| b.draw_rounded_rect(upper_left, bottom_right, radius, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.paths += 1;
+        let path: Vec<_> = path.into_iter().collect();
+        self.count_vertices(path.len() as u64);
+        self.draw(|b| b.draw_path(path, style))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.circles += 1;
+        self.draw(|b| b.draw_circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.polygons += 1;
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.count_vertices(vert.len() as u64);
+        self.draw(|b| b.fill_polygon(vert, style))
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.texts += 1;
+        self.draw(|b| b.draw_text(text, style, pos))
+    }
+
+    fn draw_texts<TStyle: BackendTextStyle>(
+        &mut self,
+        texts: &[(&str, BackendCoord, &TStyle)],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.texts += texts.len() as u64;
+        self.draw(|b| b.draw_texts(texts))
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.bitmaps += 1;
+        self.draw(|b| b.blit_bitmap(pos, size, src))
+    }
+
+    fn blit_bitmap_scaled(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        src_size: (u32, u32),
+        src: &[u8],
+        interpolation: BitmapInterpolation,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.stats.bitmaps += 1;
+        self.draw(|b| b.blit_bitmap_scaled(upper_left, bottom_right, src_size, src, interpolation))
+    }
+
+    fn begin_clip_polygon(
+        &mut self,
+        vert: &[BackendCoord],
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.inner.begin_clip_polygon(vert)
+    }
+
+    fn begin_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.inner.begin_clip_rect(upper_left, bottom_right)
+    }
+
+    fn end_clip(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.end_clip()
+    }
+
+    fn add_metadata(
+        &mut self,
+        key: &str,
+        value: &str,
+    ) -> Result<bool, DrawingErrorKind<DB::ErrorType>> {
+        self.inner.add_metadata(key, value)
+    }
+}
+
+impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<StatsBackend<DB>, CT> {
+    /// Present all the pending changes to the backend, and get the statistics of the drawing
+    /// operations since the last time the statistics is taken
+    pub fn present_with_stats(&self) -> Result<RenderStats, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.backend_ops(|b| {
+            b.present()?;
+            Ok(b.take_stats())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_stats() {
+        let area = StatsBackend::new(MockedBackend::new(100, 100)).into_drawing_area();
+        area.fill(&WHITE).unwrap();
+        area.draw(&Circle::new((50, 50), 10, RED)).unwrap();
+        area.draw(&Polygon::new(vec![(0, 0), (10, 0), (10, 10)], BLUE))
+            .unwrap();
+        area.draw(&PathElement::new(vec![(0, 0), (10, 0)], BLUE))
+            .unwrap();
+
+        let stats = area.present_with_stats().unwrap();
+        assert_eq!(
+            (stats.rects, stats.circles, stats.polygons, stats.paths),
+            (1, 1, 1, 1)
+        );
+        assert_eq!((stats.vertices, stats.max_vertices), (5, 3));
+        assert_eq!(area.present_with_stats().unwrap().circles, 0);
+    }
+}
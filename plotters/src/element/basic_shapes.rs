@@ -1,6 +1,9 @@
-use super::{Drawable, PointCollection};
+use super::{Drawable, PointCollection, UnclampedCoordAndArea};
+use crate::drawing::Rect;
 use crate::style::{Color, ShapeStyle, SizeDesc};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{rasterizer, BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use std::ops::{Add, Deref, DerefMut};
 
 #[inline]
 fn to_i((x, y): (f32, f32)) -> (i32, i32) {
@@ -82,10 +85,159 @@ fn test_pixel_element() {
 #[deprecated(note = "Use new name PathElement instead")]
 pub type Path<Coord> = PathElement<Coord>;
 
+/// The arrowheads drawn along a [`PathElement`], which point in the direction of the path.
+///
+/// The heads are triangles drawn with the style of the path, either filled or open (only the two
+/// sides of the head are stroked). By default, a single filled head is drawn at the end of the
+/// path.
+///
+/// ```
+/// use plotters::prelude::*;
+/// let drawing_area = SVGBackend::new("arrowheads.svg", (300, 200)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&drawing_area)
+///     .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+///     .unwrap();
+/// chart
+///     .draw_series(std::iter::once(
+///         PathElement::new(vec![(1.0, 1.0), (5.0, 8.0), (9.0, 2.0)], BLUE)
+///             .arrowhead(Arrowhead::new(10).start(true).interval(60).open()),
+///     ))
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arrowhead {
+    size: u32,
+    width_ratio: f64,
+    filled: bool,
+    start: bool,
+    end: bool,
+    interval: Option<u32>,
+}
+
+impl Arrowhead {
+    /// Create a filled arrowhead at the end of the path
+    /// - `size`: The length of the head in pixels
+    /// - returns the created arrowhead
+    pub fn new(size: u32) -> Self {
+        Self {
+            size,
+            width_ratio: 0.5,
+            filled: true,
+            start: false,
+            end: true,
+            interval: None,
+        }
+    }
+
+    /// Draw the open heads, whose two sides are stroked with the style of the path
+    pub fn open(mut self) -> Self {
+        self.filled = false;
+        self
+    }
+
+    /// Set whether a head is drawn at the start of the path, pointing backward
+    pub fn start(mut self, value: bool) -> Self {
+        self.start = value;
+        self
+    }
+
+    /// Set whether a head is drawn at the end of the path
+    pub fn end(mut self, value: bool) -> Self {
+        self.end = value;
+        self
+    }
+
+    /// Draw the heads along the path as well, one every `interval` pixels of the path length
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = Some(interval).filter(|&v| v > 0);
+        self
+    }
+
+    /// Set the half width of the head as a ratio to its length, the default is 0.5
+    pub fn width_ratio(mut self, ratio: f64) -> Self {
+        self.width_ratio = ratio.max(0.0);
+        self
+    }
+
+    /// Compute the heads on the path in pixels, each as the tip and the two wings
+    fn heads(&self, path: &[BackendCoord], size: f64) -> Vec<[BackendCoord; 3]> {
+        let mut path: Vec<(f64, f64)> = path
+            .iter()
+            .map(|&(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+        path.dedup();
+        let mut tips = vec![];
+        if path.len() < 2 || size <= 0.0 {
+            return vec![];
+        }
+
+        if self.start {
+            tips.push((path[0], (path[0].0 - path[1].0, path[0].1 - path[1].1)));
+        }
+        if let Some(interval) = self.interval {
+            // The tips are placed every interval along the path, excluding the end point
+            let interval = f64::from(interval);
+            let mut next = interval;
+            let mut travelled = 0.0;
+            for seg in path.windows(2) {
+                let (dx, dy) = (seg[1].0 - seg[0].0, seg[1].1 - seg[0].1);
+                let len = dx.hypot(dy);
+                while next < travelled + len {
+                    let t = (next - travelled) / len;
+                    tips.push(((seg[0].0 + dx * t, seg[0].1 + dy * t), (dx, dy)));
+                    next += interval;
+                }
+                travelled += len;
+            }
+        }
+        if self.end {
+            let (a, b) = (path[path.len() - 2], path[path.len() - 1]);
+            tips.push((b, (b.0 - a.0, b.1 - a.1)));
+        }
+
+        tips.into_iter()
+            .map(|(tip, (dx, dy))| {
+                let len = dx.hypot(dy);
+                let (ux, uy) = (dx / len, dy / len);
+                let (bx, by) = (tip.0 - ux * size, tip.1 - uy * size);
+                let half = size * self.width_ratio;
+                [
+                    (bx + uy * half, by - ux * half),
+                    tip,
+                    (bx - uy * half, by + ux * half),
+                ]
+                .map(|(x, y)| (x.round() as i32, y.round() as i32))
+            })
+            .collect()
+    }
+
+    /// Draw the path in pixels along with the heads on it
+    fn draw_with_path<DB: DrawingBackend>(
+        &self,
+        path: Vec<BackendCoord>,
+        size: f64,
+        style: &ShapeStyle,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let heads = self.heads(&path, size);
+        backend.draw_path(path, style)?;
+        for head in heads {
+            if self.filled {
+                backend.fill_polygon(head.to_vec(), &style.color.to_backend_color())?;
+            } else {
+                backend.draw_path(head.to_vec(), style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// An element of a series of connected lines
 pub struct PathElement<Coord> {
     points: Vec<Coord>,
     style: ShapeStyle,
+    arrowhead: Option<Arrowhead>,
+    arrow_size_point: Option<Coord>,
 }
 impl<Coord> PathElement<Coord> {
     /// Create a new path
@@ -96,15 +248,43 @@ impl<Coord> PathElement<Coord> {
         Self {
             points: points.into(),
             style: style.into(),
+            arrowhead: None,
+            arrow_size_point: None,
         }
     }
+
+    /// Draw the arrowheads on the path, see [`Arrowhead`] for details
+    pub fn arrowhead(mut self, arrowhead: Arrowhead) -> Self {
+        self.arrowhead = Some(arrowhead);
+        self
+    }
+}
+
+impl<X, Y> PathElement<(X, Y)> {
+    /// Draw the arrowheads on the path with the length in the data units of the X axis, the size
+    /// of the arrowhead is ignored. The length is converted to pixels when the path is drawn, by
+    /// mapping the point `first_point + (size, 0)`, see [`DataSized`].
+    /// - `arrowhead`: The arrowheads to draw
+    /// - `size`: The length of the heads in the data units of the X axis
+    pub fn arrowhead_with_data_size<DX>(mut self, arrowhead: Arrowhead, size: DX) -> DataSized<Self>
+    where
+        X: Add<DX, Output = X> + Clone,
+        Y: Clone,
+    {
+        self.arrow_size_point = self
+            .points
+            .first()
+            .map(|(x, y)| (x.clone() + size, y.clone()));
+        self.arrowhead = Some(arrowhead);
+        DataSized(self)
+    }
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a PathElement<Coord> {
     type Point = &'a Coord;
-    type IntoIter = &'a [Coord];
-    fn point_iter(self) -> &'a [Coord] {
-        &self.points
+    type IntoIter = std::iter::Chain<std::slice::Iter<'a, Coord>, std::option::Iter<'a, Coord>>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.points.iter().chain(self.arrow_size_point.iter())
     }
 }
 
@@ -115,7 +295,20 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.draw_path(points, &self.style)
+        let arrowhead = match self.arrowhead {
+            Some(arrowhead) => arrowhead,
+            None => return backend.draw_path(points, &self.style),
+        };
+
+        let mut path: Vec<_> = points.collect();
+        let size = match self.arrow_size_point {
+            Some(_) => match (path.pop(), path.first()) {
+                (Some(p), Some(first)) => f64::from(p.0 - first.0).hypot(f64::from(p.1 - first.1)),
+                _ => 0.0,
+            },
+            None => f64::from(arrowhead.size),
+        };
+        arrowhead.draw_with_path(path, size, &self.style, backend)
     }
 }
 
@@ -141,6 +334,219 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_path_arrowheads() {
+    use crate::prelude::*;
+    let calls = crate::record_draw_calls(300, 300, |da| {
+        da.draw(&PathElement::new(vec![(10, 10), (50, 10)], BLUE).arrowhead(Arrowhead::new(10)))
+            .unwrap();
+        da.draw(
+            &PathElement::new(vec![(10, 10), (50, 10)], BLUE)
+                .arrowhead(Arrowhead::new(10).start(true).end(false).open()),
+        )
+        .unwrap();
+        // The heads every 30 pixels of the path
+        da.draw(
+            &PathElement::new(vec![(0, 0), (50, 0), (100, 0)], BLUE)
+                .arrowhead(Arrowhead::new(5).end(false).interval(30)),
+        )
+        .unwrap();
+
+        let chart = da.apply_coord_spec(Cartesian2d::<
+            crate::coord::types::RangedCoordf64,
+            crate::coord::types::RangedCoordf64,
+        >::new(0.0..30.0, 0.0..30.0, (0..300, 0..300)));
+        chart
+            .draw(
+                &PathElement::new(vec![(0.0, 10.0), (20.0, 10.0)], BLUE)
+                    .arrowhead_with_data_size(Arrowhead::new(1).open(), 2.0),
+            )
+            .unwrap();
+        // The size point is beyond the right edge, while the head keeps its length
+        chart
+            .draw(
+                &PathElement::new(vec![(29.0, 10.0), (29.0, 20.0)], BLUE)
+                    .arrowhead_with_data_size(Arrowhead::new(1).open(), 5.0),
+            )
+            .unwrap();
+    });
+
+    let paths: Vec<_> = calls.paths.into_iter().map(|p| p.path).collect();
+    let polygons: Vec<_> = calls.polygons.into_iter().map(|p| p.vert).collect();
+    assert_eq!(polygons[0], vec![(40, 5), (50, 10), (40, 15)]);
+    assert_eq!(paths[1], vec![(10, 10), (50, 10)]);
+    assert_eq!(paths[2], vec![(20, 15), (10, 10), (20, 5)]);
+    assert_eq!(
+        polygons[1..].iter().map(|head| head[1]).collect::<Vec<_>>(),
+        vec![(30, 0), (60, 0), (90, 0)]
+    );
+    // The head of 2 units on the X axis is 20 pixels long
+    assert_eq!(paths.len(), 8);
+    assert_eq!(paths[5][0].0, 180);
+    // The head of 5 units is 50 pixels long
+    assert_eq!(paths[7], vec![(315, 150), (290, 200), (265, 150)]);
+}
+
+/**
+An arrow for annotating a chart, which points from its tail to its tip.
+
+The tail is either given in the same coordinates as the tip, or as an offset in pixels from the
+tip, so that the arrow keeps its length regardless of the scale of the chart. By default a
+filled head of 10 pixels is drawn at the tip, see [`Arrowhead`] for the other heads.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("arrow.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+    .unwrap();
+chart.draw_series(std::iter::once(Circle::new((5.0, 5.0), 3, RED.filled()))).unwrap();
+// Point at the data point from 40 pixels to the upper right, leaving some room for the marker
+chart
+    .draw_series(std::iter::once(Arrow::pointing_at((5.0, 5.0), (40, -40), BLACK).gap(5)))
+    .unwrap();
+// A double-headed open arrow between two data points
+chart
+    .draw_series(std::iter::once(
+        Arrow::new((1.0, 1.0), (9.0, 1.0), BLUE).head(Arrowhead::new(8).start(true).open()),
+    ))
+    .unwrap();
+```
+*/
+pub struct Arrow<Coord> {
+    points: Vec<Coord>,
+    tail_offset: Option<BackendCoord>,
+    arrowhead: Arrowhead,
+    gap: u32,
+    style: ShapeStyle,
+}
+
+impl<Coord> Arrow<Coord> {
+    /// Create a straight arrow
+    /// - `from`: The tail of the arrow
+    /// - `to`: The tip of the arrow
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(from: Coord, to: Coord, style: S) -> Self {
+        Self::along(vec![from, to], style)
+    }
+
+    /// Create an arrow along a polyline, which points at the last point
+    /// - `points`: The points of the polyline, from the tail to the tip
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn along<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into(),
+            tail_offset: None,
+            arrowhead: Arrowhead::new(10),
+            gap: 0,
+            style: style.into(),
+        }
+    }
+
+    /// Create an arrow pointing at a point, whose tail is offset from the tip in pixels
+    /// - `tip`: The point the arrow points at
+    /// - `tail_offset`: The offset of the tail from the tip in pixels
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn pointing_at<S: Into<ShapeStyle>>(tip: Coord, tail_offset: (i32, i32), style: S) -> Self {
+        let mut ret = Self::along(vec![tip], style);
+        ret.tail_offset = Some(tail_offset);
+        ret
+    }
+
+    /// Set the heads of the arrow
+    pub fn head(mut self, arrowhead: Arrowhead) -> Self {
+        self.arrowhead = arrowhead;
+        self
+    }
+
+    /// Stop the arrow the given number of pixels before the point it points at, so that the
+    /// arrow doesn't cover the marker of the point
+    pub fn gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arrow<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut path: Vec<_> = points.collect();
+        if let (Some((dx, dy)), Some(&tip)) = (self.tail_offset, path.last()) {
+            path = vec![(tip.0 + dx, tip.1 + dy), tip];
+        }
+        path.dedup();
+        if path.len() < 2 {
+            return Ok(());
+        }
+
+        if self.gap > 0 {
+            let (a, b) = (path[path.len() - 2], path[path.len() - 1]);
+            let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+            let len = dx.hypot(dy);
+            let gap = f64::from(self.gap);
+            if gap >= len {
+                return Ok(());
+            }
+            let t = (len - gap) / len;
+            let tip = (
+                (f64::from(a.0) + dx * t).round() as i32,
+                (f64::from(a.1) + dy * t).round() as i32,
+            );
+            *path.last_mut().unwrap() = tip;
+        }
+
+        self.arrowhead
+            .draw_with_path(path, f64::from(self.arrowhead.size), &self.style, backend)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arrow_element() {
+    use crate::prelude::*;
+    let calls = crate::record_draw_calls(300, 300, |da| {
+        let chart = da.apply_coord_spec(Cartesian2d::<
+            crate::coord::types::RangedCoordf64,
+            crate::coord::types::RangedCoordf64,
+        >::new(0.0..30.0, 0.0..30.0, (0..300, 0..300)));
+        chart
+            .draw(&Arrow::new((1.0, 1.0), (5.0, 1.0), BLUE))
+            .unwrap();
+        // The tail is 30 pixels to the left of the tip, and the tip stops 10 pixels before the
+        // point
+        chart
+            .draw(&Arrow::pointing_at((10.0, 10.0), (-30, 0), BLUE).gap(10))
+            .unwrap();
+        chart
+            .draw(&Arrow::pointing_at((10.0, 10.0), (0, 5), BLUE).gap(10))
+            .unwrap();
+    });
+
+    let paths: Vec<_> = calls.paths.into_iter().map(|p| p.path).collect();
+    let polygons: Vec<_> = calls.polygons.into_iter().map(|p| p.vert).collect();
+    assert_eq!(paths.len(), 2);
+    assert_eq!(paths[0], vec![(10, 10), (50, 10)]);
+    assert_eq!(polygons[0][1], (50, 10));
+    assert_eq!(paths[1], vec![(70, 100), (90, 100)]);
+    assert_eq!(polygons[1], vec![(80, 95), (90, 100), (80, 105)]);
+}
+
 /// An element of a series of connected lines in dash style.
 ///
 /// It's similar to [`PathElement`] but has a dash style.
@@ -389,11 +795,107 @@ fn test_dotted_path_element() {
     .expect("Drawing Failure");
 }
 
+/// An element of a smooth curve through a series of points.
+///
+/// The curve is a Catmull-Rom spline through the points in the pixel coordinates, which is
+/// drawn as a cubic Bezier curve between each pair of neighboring points. Like other
+/// interpolating splines, the curve may overshoot the points where the data changes sharply.
+pub struct CurvedPathElement<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> CurvedPathElement<Coord> {
+    /// Create a new curve
+    /// - `points`: The points the curve goes through
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a CurvedPathElement<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for CurvedPathElement<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 3 {
+            return backend.draw_path(points, &self.style);
+        }
+
+        let p = |idx: usize| (f64::from(points[idx].0), f64::from(points[idx].1));
+        let last = points.len() - 1;
+        let mut path = vec![points[0]];
+        for idx in 0..last {
+            let (p0, p1, p2, p3) = (
+                p(idx.saturating_sub(1)),
+                p(idx),
+                p(idx + 1),
+                p((idx + 2).min(last)),
+            );
+            let ctrl0 = (p1.0 + (p2.0 - p0.0) / 6.0, p1.1 + (p2.1 - p0.1) / 6.0);
+            let ctrl1 = (p2.0 - (p3.0 - p1.0) / 6.0, p2.1 - (p3.1 - p1.1) / 6.0);
+            path.extend(
+                rasterizer::flatten_cubic_bezier(p1, ctrl0, ctrl1, p2)
+                    .into_iter()
+                    .skip(1),
+            );
+        }
+        backend.draw_path(path, &self.style)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_curved_path_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, s, path| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(s, 3);
+            // The curve goes through all the points smoothly
+            for p in [(100, 100), (150, 200), (200, 100), (250, 150)].iter() {
+                assert!(path.contains(p));
+            }
+            assert!(path.len() > 50);
+            assert!(path.windows(2).all(|w| w[0].0 <= w[1].0));
+            assert!(!path.contains(&(125, 150)));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.draw_count, 1);
+        });
+    });
+    da.draw(&CurvedPathElement::new(
+        vec![(100, 100), (150, 200), (200, 100), (250, 150)],
+        Color::stroke_width(&BLUE, 3),
+    ))
+    .expect("Drawing Failure");
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    fixed_size: (Option<u32>, Option<u32>),
+    centered: bool,
+    corner_radius: u32,
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -406,9 +908,30 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            fixed_size: (None, None),
+            centered: false,
+            corner_radius: 0,
         }
     }
 
+    /// Create a new rectangle centered at a point, with the size in pixels
+    /// - `center`: The center of the rectangle
+    /// - `size`: The width and height of the rectangle in pixels
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn from_center_and_size<S: Into<ShapeStyle>>(
+        center: Coord,
+        (width, height): (u32, u32),
+        style: S,
+    ) -> Self
+    where
+        Coord: Clone,
+    {
+        let mut ret = Self::new([center.clone(), center], style);
+        ret.set_fixed_size(Some(width), Some(height));
+        ret
+    }
+
     /// Set the margin of the rectangle
     /// - `t`: The top margin
     /// - `b`: The bottom margin
@@ -419,6 +942,33 @@ impl<Coord> Rectangle<Coord> {
         self
     }
 
+    /// Set the fixed size of the rectangle in pixels. In the fixed direction the rectangle is
+    /// centered in the area given by its points and the margin is ignored. The rounding error of
+    /// the centering goes to the right or the bottom side, thus a series of rectangles with the
+    /// same fixed size have the same pixel size regardless of how their points are rounded.
+    /// - `width`: The fixed width, or `None` if the width is given by the points
+    /// - `height`: The fixed height, or `None` if the height is given by the points
+    /// - returns a mut reference to the rectangle
+    pub fn set_fixed_size(&mut self, width: Option<u32>, height: Option<u32>) -> &mut Self {
+        self.fixed_size = (width, height);
+        self
+    }
+
+    /// Round the corners of the rectangle. The radius is limited to half of the shorter side of
+    /// the rectangle, thus a large radius makes a pill shape.
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut rect = Rectangle::new([(10, 10), (110, 40)], WHITE.filled());
+    /// rect.set_corner_radius(5);
+    /// ```
+    /// - `radius`: The radius of the corners in pixels, 0 for the square corners
+    /// - returns a mut reference to the rectangle
+    pub fn set_corner_radius(&mut self, radius: u32) -> &mut Self {
+        self.corner_radius = radius;
+        self
+    }
+
     /// Get the points of the rectangle
     /// - returns the element points
     pub fn get_points(&self) -> (&Coord, &Coord) {
@@ -434,6 +984,57 @@ impl<Coord> Rectangle<Coord> {
     }
 }
 
+impl<X, Y> Rectangle<(X, Y)> {
+    /// Create a new rectangle centered at a point, with the size in the data units. The size is
+    /// converted to pixels when the rectangle is drawn, by mapping the point `center + size`,
+    /// see [`DataSized`].
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// // A 2 by 0.5 box around the data point (5, 1.5)
+    /// let rect = Rectangle::from_center_and_data_size((5, 1.5), (2, 0.5), BLUE.filled());
+    /// ```
+    /// - `center`: The center of the rectangle
+    /// - `size`: The width and height of the rectangle in the data units
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn from_center_and_data_size<DX, DY, S: Into<ShapeStyle>>(
+        center: (X, Y),
+        (width, height): (DX, DY),
+        style: S,
+    ) -> DataSized<Self>
+    where
+        X: Add<DX, Output = X> + Clone,
+        Y: Add<DY, Output = Y> + Clone,
+    {
+        let corner = (center.0.clone() + width, center.1.clone() + height);
+        let mut ret = Self::new([center, corner], style);
+        ret.centered = true;
+        DataSized(ret)
+    }
+}
+
+impl<Coord> Rectangle<Coord> {
+    fn draw_box<DB: DrawingBackend>(
+        &self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.corner_radius > 0 {
+            backend.draw_rounded_rect(
+                upper_left,
+                bottom_right,
+                self.corner_radius,
+                &self.style,
+                self.style.filled,
+            )
+        } else {
+            backend.draw_rect(upper_left, bottom_right, &self.style, self.style.filled)
+        }
+    }
+}
+
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
     type Point = &'a Coord;
     type IntoIter = &'a [Coord];
@@ -450,13 +1051,29 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         match (points.next(), points.next()) {
+            (Some(center), Some(corner)) if self.centered => {
+                let (w, h) = ((corner.0 - center.0).abs(), (corner.1 - center.1).abs());
+                let a = (center.0 - w / 2, center.1 - h / 2);
+                let b = (a.0 + w, a.1 + h);
+                self.draw_box(a, b, backend)
+            }
             (Some(a), Some(b)) => {
                 let (mut a, mut b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
-                a.1 += self.margin.0 as i32;
-                b.1 -= self.margin.1 as i32;
-                a.0 += self.margin.2 as i32;
-                b.0 -= self.margin.3 as i32;
-                backend.draw_rect(a, b, &self.style, self.style.filled)
+                if let Some(height) = self.fixed_size.1 {
+                    a.1 += (b.1 - a.1 - height as i32) / 2;
+                    b.1 = a.1 + height as i32;
+                } else {
+                    a.1 += self.margin.0 as i32;
+                    b.1 -= self.margin.1 as i32;
+                }
+                if let Some(width) = self.fixed_size.0 {
+                    a.0 += (b.0 - a.0 - width as i32) / 2;
+                    b.0 = a.0 + width as i32;
+                } else {
+                    a.0 += self.margin.2 as i32;
+                    b.0 -= self.margin.3 as i32;
+                }
+                self.draw_box(a, b, backend)
             }
             _ => Ok(()),
         }
@@ -504,10 +1121,64 @@ fn test_rect_element() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_rect_fixed_size() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|_, _, _, u, d| {
+            assert_eq!([u, d], [(102, 100), (106, 120)]);
+        });
+        m.check_draw_rect(|_, _, _, u, d| {
+            assert_eq!([u, d], [(112, 100), (116, 120)]);
+        });
+    });
+    for &(x0, x1) in &[(100, 109), (109, 119)] {
+        let mut rect = Rectangle::new([(x0, 100), (x1, 120)], BLUE.filled());
+        rect.set_margin(0, 0, 1, 1).set_fixed_size(Some(4), None);
+        da.draw(&rect).expect("Drawing Failure");
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rounded_rect_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, p| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert!(p
+                .iter()
+                .all(|&(x, y)| (100..=140).contains(&x) && (100..=120).contains(&y)));
+            // The corners are cut off, while the middle of the sides are kept
+            assert!(!p.contains(&(100, 100)) && !p.contains(&(140, 120)));
+            assert!(p.contains(&(100, 105)) && p.contains(&(105, 100)));
+        });
+        m.check_draw_path(|_, s, p| {
+            assert_eq!(s, 2);
+            assert_eq!(p.first(), p.last());
+            // The radius is limited to half of the shorter side
+            assert!(p.contains(&(100, 110)) && p.contains(&(140, 110)));
+            assert!(p.contains(&(110, 100)) && p.contains(&(130, 100)));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 0);
+            assert_eq!(b.draw_count, 2);
+        });
+    });
+    let mut rect = Rectangle::new([(100, 100), (140, 120)], BLUE.filled());
+    rect.set_corner_radius(5);
+    da.draw(&rect).expect("Drawing Failure");
+    let mut rect = Rectangle::new([(100, 100), (140, 120)], Color::stroke_width(&BLUE, 2));
+    rect.set_corner_radius(50);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
 /// A circle element
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
     size: Size,
+    radius_point: Option<Coord>,
     style: ShapeStyle,
 }
 
@@ -521,16 +1192,44 @@ impl<Coord, Size: SizeDesc> Circle<Coord, Size> {
         Self {
             center: coord,
             size,
+            radius_point: None,
             style: style.into(),
         }
     }
 }
 
+impl<X, Y> Circle<(X, Y), u32> {
+    /// Create a new circle element with the radius in the data units of the X axis. The radius is
+    /// converted to pixels when the circle is drawn, by mapping the point `center + (radius, 0)`,
+    /// see [`DataSized`].
+    /// - `center` The center of the circle
+    /// - `radius` The radius of the circle in the data units of the X axis
+    /// - `style` The style of the circle
+    /// - Return: The newly created circle element
+    pub fn with_data_radius<DX, S: Into<ShapeStyle>>(
+        center: (X, Y),
+        radius: DX,
+        style: S,
+    ) -> DataSized<Self>
+    where
+        X: Add<DX, Output = X> + Clone,
+        Y: Clone,
+    {
+        let radius_point = (center.0.clone() + radius, center.1.clone());
+        DataSized(Self {
+            center,
+            size: 0,
+            radius_point: Some(radius_point),
+            style: style.into(),
+        })
+    }
+}
+
 impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Circle<Coord, Size> {
     type Point = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
-    fn point_iter(self) -> std::iter::Once<&'a Coord> {
-        std::iter::once(&self.center)
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center).chain(self.radius_point.iter())
     }
 }
 
@@ -542,7 +1241,10 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Circle<Coord, S
         ps: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
-            let size = self.size.in_pixels(&ps).max(0) as u32;
+            let size = match points.next() {
+                Some((rx, ry)) => f64::from(rx - x).hypot(f64::from(ry - y)).round() as u32,
+                None => self.size.in_pixels(&ps).max(0) as u32,
+            };
             return backend.draw_circle((x, y), size, &self.style, self.style.filled);
         }
         Ok(())
@@ -569,6 +1271,245 @@ fn test_circle_element() {
         .expect("Drawing Failure");
 }
 
+/// An ellipse element with axes parallel to the X and Y axis
+pub struct Ellipse<Coord> {
+    center: Coord,
+    radii: (u32, u32),
+    radius_point: Option<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> Ellipse<Coord> {
+    /// Create a new ellipse element
+    /// - `center` The center of the ellipse
+    /// - `radii` The horizontal and vertical radius in pixels
+    /// - `style` The style of the ellipse
+    /// - Return: The newly created ellipse element
+    pub fn new<S: Into<ShapeStyle>>(center: Coord, radii: (u32, u32), style: S) -> Self {
+        Self {
+            center,
+            radii,
+            radius_point: None,
+            style: style.into(),
+        }
+    }
+}
+
+impl<X, Y> Ellipse<(X, Y)> {
+    /// Create a new ellipse element with the radii in the data units. The radii are converted to
+    /// pixels when the ellipse is drawn, by mapping the point `center + radii`, see [`DataSized`].
+    /// - `center` The center of the ellipse
+    /// - `radii` The horizontal and vertical radius in the data units
+    /// - `style` The style of the ellipse
+    /// - Return: The newly created ellipse element
+    pub fn with_data_radii<DX, DY, S: Into<ShapeStyle>>(
+        center: (X, Y),
+        (rx, ry): (DX, DY),
+        style: S,
+    ) -> DataSized<Self>
+    where
+        X: Add<DX, Output = X> + Clone,
+        Y: Add<DY, Output = Y> + Clone,
+    {
+        let radius_point = (center.0.clone() + rx, center.1.clone() + ry);
+        DataSized(Self {
+            center,
+            radii: (0, 0),
+            radius_point: Some(radius_point),
+            style: style.into(),
+        })
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Ellipse<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center).chain(self.radius_point.iter())
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Ellipse<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+        let (rx, ry) = match points.next() {
+            Some((px, py)) => (f64::from((px - x).abs()), f64::from((py - y).abs())),
+            None => (f64::from(self.radii.0), f64::from(self.radii.1)),
+        };
+
+        // Use roughly one vertex for every 2 pixels of the perimeter
+        let n = ((rx.max(ry) * std::f64::consts::PI).round() as usize).max(16);
+        let mut vertices: Vec<_> = (0..n)
+            .map(|i| {
+                let t = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                to_i((
+                    (x as f64 + rx * t.cos()) as f32,
+                    (y as f64 + ry * t.sin()) as f32,
+                ))
+            })
+            .collect();
+
+        if self.style.filled {
+            backend.fill_polygon(vertices, &self.style.color.to_backend_color())
+        } else {
+            vertices.push(vertices[0]);
+            backend.draw_path(vertices, &self.style)
+        }
+    }
+}
+
+/**
+A shape with its size in the data units, such as the ones created by
+[`Rectangle::from_center_and_data_size()`], [`Circle::with_data_radius()`],
+[`Ellipse::with_data_radii()`] and [`PathElement::arrowhead_with_data_size()`].
+
+The last point of the shape is its size point, whose offset from the first point gives the size
+in pixels. The other points are truncated to the drawing area as usual, while the offset of the
+size point is taken before the truncation, thus the shape keeps its size near the edges of the
+drawing area. The shape itself is available through `Deref`, for example to round the corners
+of a rectangle.
+*/
+pub struct DataSized<E>(E);
+
+impl<E> Deref for DataSized<E> {
+    type Target = E;
+    fn deref(&self) -> &E {
+        &self.0
+    }
+}
+
+impl<E> DerefMut for DataSized<E> {
+    fn deref_mut(&mut self) -> &mut E {
+        &mut self.0
+    }
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord, UnclampedCoordAndArea> for &'a DataSized<E>
+where
+    &'a E: PointCollection<'a, Coord>,
+{
+    type Point = <&'a E as PointCollection<'a, Coord>>::Point;
+    type IntoIter = <&'a E as PointCollection<'a, Coord>>::IntoIter;
+    fn point_iter(self) -> Self::IntoIter {
+        self.0.point_iter()
+    }
+}
+
+impl<E: Drawable<DB>, DB: DrawingBackend> Drawable<DB, UnclampedCoordAndArea> for DataSized<E> {
+    fn draw<I: Iterator<Item = (BackendCoord, Rect)>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let mut truncated: Vec<_> = points.iter().map(|(p, rect)| rect.truncate(*p)).collect();
+        if points.len() > 1 {
+            let last = points.len() - 1;
+            let ((first, _), (size, _), anchor) = (&points[0], &points[last], truncated[0]);
+            truncated[last] = (
+                anchor.0.saturating_add(size.0.saturating_sub(first.0)),
+                anchor.1.saturating_add(size.1.saturating_sub(first.1)),
+            );
+        }
+        self.0.draw(truncated.into_iter(), backend, parent_dim)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_data_sized_elements() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|_, _, _, u, d| {
+            assert_eq!([u, d], [(145, 140), (155, 160)]);
+        });
+        m.check_draw_rect(|_, _, _, u, d| {
+            assert_eq!([u, d], [(140, 145), (160, 155)]);
+        });
+        m.check_draw_circle(|_, _, _, c, r| {
+            assert_eq!(c, (150, 150));
+            assert_eq!(r, 30);
+        });
+        m.check_fill_polygon(|_, p| {
+            assert!(p.contains(&(170, 150)));
+            assert!(p.contains(&(150, 160)));
+        });
+    });
+    let chart = da.apply_coord_spec(Cartesian2d::<
+        crate::coord::types::RangedCoordf64,
+        crate::coord::types::RangedCoordf64,
+    >::new(0.0..30.0, 0.0..30.0, (0..300, 0..300)));
+
+    chart
+        .draw(&Rectangle::from_center_and_size(
+            (15.0, 15.0),
+            (10, 20),
+            RED,
+        ))
+        .unwrap();
+    chart
+        .draw(&Rectangle::from_center_and_data_size(
+            (15.0, 15.0),
+            (2.0, 1.0),
+            RED,
+        ))
+        .unwrap();
+    chart
+        .draw(&Circle::with_data_radius((15.0, 15.0), 3.0, RED))
+        .unwrap();
+    chart
+        .draw(&Ellipse::with_data_radii(
+            (15.0, 15.0),
+            (2.0, 1.0),
+            RED.filled(),
+        ))
+        .unwrap();
+}
+
+#[cfg(test)]
+#[test]
+fn test_data_sized_elements_near_edge() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|_, _, _, u, d| {
+            assert_eq!([u, d], [(190, 140), (390, 160)]);
+        });
+        m.check_draw_circle(|_, _, _, c, r| {
+            assert_eq!(c, (290, 150));
+            assert_eq!(r, 300);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+    });
+    let chart = da.apply_coord_spec(Cartesian2d::<
+        crate::coord::types::RangedCoordf64,
+        crate::coord::types::RangedCoordf64,
+    >::new(0.0..30.0, 0.0..30.0, (0..300, 0..300)));
+
+    // The size points are far beyond the right edge, while the sizes are kept
+    chart
+        .draw(&Rectangle::from_center_and_data_size(
+            (29.0, 15.0),
+            (20.0, 2.0),
+            RED,
+        ))
+        .unwrap();
+    chart
+        .draw(&Circle::with_data_radius((29.0, 15.0), 30.0, RED))
+        .unwrap();
+}
+
 /// An element of a filled polygon
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
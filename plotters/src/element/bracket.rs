@@ -0,0 +1,215 @@
+/*!
+  The comparison bracket element, which marks the significance of the difference between two groups
+*/
+use crate::element::{Drawable, PointCollection};
+use crate::style::{
+    text_anchor::{HPos, Pos, VPos},
+    FontFamily, ShapeStyle, TextStyle, BLACK,
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The comparison bracket, which spans two X positions at a given Y position and shows a label
+/// centered above it, such as `p < 0.01`. This is commonly used to mark the significance of the
+/// difference between two bars of a scientific bar chart.
+///
+/// When multiple brackets overlap horizontally, use [stack](#method.stack) to raise the later
+/// brackets above the earlier ones.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = SVGBackend::new("comparison_bracket.svg", (400, 300)).into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0.0..4.0, 0.0..10.0).unwrap();
+///
+/// chart
+///     .draw_series(ComparisonBracket::stack(vec![
+///         ComparisonBracket::new((1.0, 8.0), (2.0, 8.0), "p < 0.01"),
+///         ComparisonBracket::new((2.0, 8.0), (3.0, 8.0), "n.s."),
+///         ComparisonBracket::new((1.0, 8.0), (3.0, 8.0), "p < 0.001"),
+///     ]))
+///     .unwrap();
+/// ```
+pub struct ComparisonBracket<'a, X, Y> {
+    points: [(X, Y); 2],
+    label: String,
+    style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    tick_size: u32,
+    level: u32,
+    level_spacing: u32,
+}
+
+impl<'a, X, Y> ComparisonBracket<'a, X, Y> {
+    /// Create a new comparison bracket.
+    ///
+    /// - `from`: The left end of the bracket in the data coordinate
+    /// - `to`: The right end of the bracket in the data coordinate, the bracket is drawn at the
+    ///   Y position of `from`
+    /// - `label`: The label shown above the bracket
+    /// - **returns**: The newly created bracket
+    pub fn new<L: Into<String>>(from: (X, Y), to: (X, Y), label: L) -> Self {
+        Self {
+            points: [from, to],
+            label: label.into(),
+            style: BLACK.into(),
+            label_style: TextStyle::from((FontFamily::SansSerif, 12)),
+            tick_size: 5,
+            level: 0,
+            level_spacing: 20,
+        }
+    }
+
+    /// Set the style of the bracket lines
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the label
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the length of the vertical ticks at both ends of the bracket in pixels, the default is 5
+    pub fn tick_size(mut self, size: u32) -> Self {
+        self.tick_size = size;
+        self
+    }
+
+    /// Set the stacking level of the bracket, the bracket is raised by `level` times the level
+    /// spacing. This is normally computed by [stack](#method.stack).
+    pub fn level(mut self, level: u32) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the vertical distance between two stacking levels in pixels, the default is 20
+    pub fn level_spacing(mut self, spacing: u32) -> Self {
+        self.level_spacing = spacing;
+        self
+    }
+}
+
+impl<'a, X: PartialOrd, Y> ComparisonBracket<'a, X, Y> {
+    fn span(&self) -> (&X, &X) {
+        let (a, b) = (&self.points[0].0, &self.points[1].0);
+        if a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Assign the stacking levels of the brackets, so that the overlapping brackets don't cross
+    /// each other. Each bracket is placed one level above all the previous brackets it overlaps
+    /// with, thus the brackets should be ordered from the innermost to the outermost.
+    ///
+    /// - `brackets`: The brackets to stack
+    /// - **returns**: The brackets with the stacking levels assigned
+    pub fn stack<I: IntoIterator<Item = Self>>(brackets: I) -> Vec<Self> {
+        let mut ret: Vec<Self> = vec![];
+        for bracket in brackets {
+            let (start, end) = bracket.span();
+            let level = ret
+                .iter()
+                .filter(|other| {
+                    let (other_start, other_end) = other.span();
+                    start <= other_end && other_start <= end
+                })
+                .map(|other| other.level + 1)
+                .max()
+                .unwrap_or(0);
+            ret.push(bracket.level(level));
+        }
+        ret
+    }
+}
+
+impl<'b, 'a, X: 'b, Y: 'b> PointCollection<'b, (X, Y)> for &'b ComparisonBracket<'a, X, Y> {
+    type Point = &'b (X, Y);
+    type IntoIter = &'b [(X, Y)];
+    fn point_iter(self) -> &'b [(X, Y)] {
+        &self.points
+    }
+}
+
+impl<'a, X, Y, DB: DrawingBackend> Drawable<DB> for ComparisonBracket<'a, X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, to) = match (points.next(), points.next()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Ok(()),
+        };
+
+        let y = from.1 - (self.level * self.level_spacing) as i32;
+        let tick = self.tick_size as i32;
+
+        backend.draw_path(
+            vec![(from.0, y + tick), (from.0, y), (to.0, y), (to.0, y + tick)],
+            &self.style,
+        )?;
+
+        if !self.label.is_empty() {
+            let style = self.label_style.pos(Pos::new(HPos::Center, VPos::Bottom));
+            backend.draw_text(&self.label, &style, ((from.0 + to.0) / 2, y - 2))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stacked_brackets() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(10, 105), (10, 100), (20, 100), (20, 105)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(20, 85), (20, 80), (30, 80), (30, 85)]);
+            });
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path, vec![(30, 65), (30, 60), (10, 60), (10, 65)]);
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "p < 0.01");
+                assert_eq!(pos, (15, 98));
+            });
+            m.check_draw_text(|_, _, _, pos, _| {
+                assert_eq!(pos, (25, 78));
+            });
+            m.check_draw_text(|_, _, _, pos, _| {
+                assert_eq!(pos, (20, 58));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 3);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+
+        let brackets = ComparisonBracket::stack(vec![
+            ComparisonBracket::new((10, 100), (20, 100), "p < 0.01"),
+            ComparisonBracket::new((20, 100), (30, 100), "n.s."),
+            ComparisonBracket::new((30, 100), (10, 100), "p < 0.001"),
+            ComparisonBracket::new((40, 100), (50, 100), "p < 0.05"),
+        ]);
+
+        assert_eq!(
+            brackets.iter().map(|b| b.level).collect::<Vec<_>>(),
+            vec![0, 1, 2, 0]
+        );
+
+        for bracket in brackets.iter().take(3) {
+            da.draw(bracket).unwrap();
+        }
+    }
+}
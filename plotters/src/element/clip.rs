@@ -0,0 +1,219 @@
+/*!
+  The element clipping, which clips an element or a series to an arbitrary polygon
+*/
+use super::{Drawable, PointCollection};
+use plotters_backend::rasterizer::PolygonClip;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+use std::borrow::Borrow;
+use std::rc::Rc;
+
+/// A polygon that the elements are clipped to, in the guest coordinate.
+///
+/// The clip path is cheap to clone, thus the same path, for example a country outline, can be
+/// shared by all the elements of a series.
+///
+/// On the backends with native clipping support, such as the SVG backend, the clipping is done
+/// by the backend, which defines the polygon once and references it from all the elements
+/// clipped to it. Otherwise the element is rasterized and only the pixels inside the polygon are
+/// drawn.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = SVGBackend::new("clip_path.svg", (300, 300)).into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0..10, 0..10).unwrap();
+///
+/// let outline = ClipPath::new(vec![(5, 0), (10, 5), (5, 10), (0, 5)]);
+/// chart
+///     .draw_series(outline.clip_series(
+///         (0..10).map(|x| Rectangle::new([(x, 0), (x + 1, 10)], Palette99::pick(x as usize).filled())),
+///     ))
+///     .unwrap();
+/// ```
+pub struct ClipPath<Coord> {
+    points: Rc<Vec<Coord>>,
+}
+
+impl<Coord> Clone for ClipPath<Coord> {
+    fn clone(&self) -> Self {
+        Self {
+            points: self.points.clone(),
+        }
+    }
+}
+
+impl<Coord: Clone> ClipPath<Coord> {
+    /// Create a new clip path
+    ///
+    /// - `points`: The vertices of the polygon, the polygon is implicitly closed
+    /// - **returns**: The newly created clip path
+    pub fn new<P: Into<Vec<Coord>>>(points: P) -> Self {
+        Self {
+            points: Rc::new(points.into()),
+        }
+    }
+
+    /// Clip an element to this path
+    ///
+    /// - `element`: The element to clip
+    /// - **returns**: The clipped element
+    pub fn clip<E>(&self, element: E) -> Clipped<E, Coord>
+    where
+        for<'a> &'a E: PointCollection<'a, Coord>,
+    {
+        Clipped {
+            points: element
+                .point_iter()
+                .into_iter()
+                .map(|p| p.borrow().clone())
+                .collect(),
+            element,
+            clip: self.clone(),
+        }
+    }
+
+    /// Clip all the elements of a series to this path
+    ///
+    /// - `series`: The series to clip
+    /// - **returns**: The series of the clipped elements
+    pub fn clip_series<S: IntoIterator>(
+        &self,
+        series: S,
+    ) -> impl Iterator<Item = Clipped<S::Item, Coord>>
+    where
+        for<'a> &'a S::Item: PointCollection<'a, Coord>,
+    {
+        let clip = self.clone();
+        series.into_iter().map(move |element| clip.clip(element))
+    }
+}
+
+/// An element clipped to a [ClipPath](struct.ClipPath.html)
+pub struct Clipped<E, Coord> {
+    points: Vec<Coord>,
+    element: E,
+    clip: ClipPath<Coord>,
+}
+
+impl<'a, E, Coord: 'a> PointCollection<'a, Coord> for &'a Clipped<E, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Chain<std::slice::Iter<'a, Coord>, std::slice::Iter<'a, Coord>>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.points.iter().chain(self.clip.points.iter())
+    }
+}
+
+impl<E, Coord, DB> Drawable<DB> for Clipped<E, Coord>
+where
+    DB: DrawingBackend,
+    E: Drawable<DB> + for<'b> Drawable<ClippedBackend<'b, DB>>,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.collect();
+        let clip = points.split_off(self.points.len().min(points.len()));
+
+        if backend.begin_clip_polygon(&clip)? {
+            self.element.draw(points.into_iter(), backend, parent_dim)?;
+            return backend.end_clip();
+        }
+
+        let clip = PolygonClip::new(&clip, backend.get_size());
+        let mut clipped = ClippedBackend {
+            inner: backend,
+            clip,
+        };
+        self.element
+            .draw(points.into_iter(), &mut clipped, parent_dim)
+    }
+}
+
+/// The backend adapter used to draw a clipped element on the backends without native clipping
+/// support, which only passes the pixels inside the clipping polygon to the underlying backend
+pub struct ClippedBackend<'a, DB: DrawingBackend> {
+    inner: &'a mut DB,
+    clip: PolygonClip,
+}
+
+impl<'a, DB: DrawingBackend> DrawingBackend for ClippedBackend<'a, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.clip.contains(point) {
+            self.inner.draw_pixel(point, color)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<DB::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_clipped_rectangle() {
+        let da = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_pixel(|_, (x, y)| {
+                assert!(x >= 10 && y >= 10 && x + y < 60);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 0);
+                assert!(b.num_draw_pixel_call > 0);
+            });
+        });
+
+        let clip = ClipPath::new(vec![(0, 0), (60, 0), (0, 60)]);
+        for rect in clip.clip_series(vec![Rectangle::new([(10, 10), (50, 50)], RED.filled())]) {
+            da.draw(&rect).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_clipped_series_shares_clip_path() {
+        let mut content = String::new();
+        {
+            let da = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let clip = ClipPath::new(vec![(0, 0), (60, 0), (0, 60)]);
+            let rects = (0..5).map(|x| Rectangle::new([(x * 10, 0), (x * 10 + 5, 50)], RED));
+            for rect in clip.clip_series(rects) {
+                da.draw(&rect).unwrap();
+            }
+        }
+        assert_eq!(content.matches("<clipPath").count(), 1);
+        assert_eq!(content.matches("clip-path=\"url(#plotters-clip-0)\"").count(), 5);
+    }
+}
@@ -0,0 +1,313 @@
+/*!
+  The colorbar element, which shows how the values are mapped to the colors
+*/
+#[cfg(feature = "colormaps")]
+use std::ops::Range;
+
+use crate::data::float::pretty_print_float;
+use crate::element::{Drawable, PointCollection};
+#[cfg(feature = "colormaps")]
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{
+    text_anchor::{HPos, Pos, VPos},
+    Color, FontFamily, RGBAColor, ShapeStyle, TextStyle, BLACK,
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The vertical colorbar element.
+///
+/// The colorbar can either show a continuous gradient with evenly spaced value labels, or a set of
+/// discrete classes, which is useful for classified data such as choropleth maps. In discrete mode
+/// each class takes the same height of the bar, the class boundaries are labeled on the right side
+/// of the bar and the optional per-class counts are labeled on the left side.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = SVGBackend::new("colorbar_discrete.svg", (100, 300)).into_drawing_area();
+/// root.fill(&WHITE).unwrap();
+/// root.draw(
+///     &ColorBar::discrete((30, 20), (20, 260), &[0.0, 10.0, 50.0, 100.0], &[GREEN, YELLOW, RED])
+///         .counts(&[12, 30, 4]),
+/// )
+/// .unwrap();
+/// ```
+pub struct ColorBar<'a, Coord> {
+    pos: Coord,
+    size: (u32, u32),
+    colors: Vec<RGBAColor>,
+    gradient: Option<Box<dyn Fn(f64) -> RGBAColor + 'a>>,
+    boundaries: Vec<f64>,
+    counts: Vec<usize>,
+    discrete: bool,
+    label_count: usize,
+    label_style: TextStyle<'a>,
+    border_style: ShapeStyle,
+    formatter: Box<dyn Fn(f64) -> String + 'a>,
+}
+
+impl<'a, Coord> ColorBar<'a, Coord> {
+    fn with_colors(
+        pos: Coord,
+        size: (u32, u32),
+        colors: Vec<RGBAColor>,
+        boundaries: Vec<f64>,
+        discrete: bool,
+    ) -> Self {
+        Self {
+            pos,
+            size,
+            colors,
+            gradient: None,
+            boundaries,
+            counts: vec![],
+            discrete,
+            label_count: 5,
+            label_style: TextStyle::from((FontFamily::SansSerif, 12)),
+            border_style: BLACK.into(),
+            formatter: Box::new(|v| pretty_print_float(v, true)),
+        }
+    }
+
+    /// Create a colorbar showing a continuous gradient of the colormap.
+    ///
+    /// - `pos`: The upper-left corner of the bar
+    /// - `size`: The size of the bar in pixels, labels are drawn outside of it
+    /// - `colormap`: The colormap to show
+    /// - `range`: The value range mapped to the colormap
+    /// - **returns**: The newly created colorbar
+    #[cfg(feature = "colormaps")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+    pub fn continuous<C: Color, CM: ColorMap<C, f64>>(
+        pos: Coord,
+        size: (u32, u32),
+        colormap: &'a CM,
+        range: Range<f64>,
+    ) -> Self {
+        let gradient = move |h| colormap.get_color(h).to_rgba();
+        let mut ret = Self::with_colors(pos, size, vec![], vec![range.start, range.end], false);
+        ret.colors = Self::sample_gradient(&gradient, size.1);
+        ret.gradient = Some(Box::new(gradient));
+        ret
+    }
+
+    // Sample the gradient at the center of each pixel row of the bar
+    #[cfg(feature = "colormaps")]
+    fn sample_gradient<G: Fn(f64) -> RGBAColor + ?Sized>(
+        gradient: &G,
+        height: u32,
+    ) -> Vec<RGBAColor> {
+        let steps = height.max(1);
+        (0..steps)
+            .map(|i| gradient((f64::from(i) + 0.5) / f64::from(steps)))
+            .collect()
+    }
+
+    /// Create a colorbar showing a set of discrete classes.
+    ///
+    /// - `pos`: The upper-left corner of the bar
+    /// - `size`: The size of the bar in pixels, labels are drawn outside of it
+    /// - `boundaries`: The boundaries of the classes in ascending order, which should have one
+    ///   more element than `colors`
+    /// - `colors`: The color of each class, from the lowest class to the highest one
+    /// - **returns**: The newly created colorbar
+    pub fn discrete<C: Color>(
+        pos: Coord,
+        size: (u32, u32),
+        boundaries: &[f64],
+        colors: &[C],
+    ) -> Self {
+        let n = colors.len().min(boundaries.len().saturating_sub(1));
+        Self::with_colors(
+            pos,
+            size,
+            colors[..n].iter().map(Color::to_rgba).collect(),
+            boundaries[..(n + 1).min(boundaries.len())].to_vec(),
+            true,
+        )
+    }
+
+    /// Create a discrete colorbar by quantizing the colormap. Each class uses the color at the
+    /// middle of the class, with the colormap spanning from the first to the last boundary.
+    ///
+    /// - `pos`: The upper-left corner of the bar
+    /// - `size`: The size of the bar in pixels, labels are drawn outside of it
+    /// - `colormap`: The colormap to quantize
+    /// - `boundaries`: The boundaries of the classes in ascending order
+    /// - **returns**: The newly created colorbar
+    #[cfg(feature = "colormaps")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+    pub fn quantized<C: Color, CM: ColorMap<C, f64>>(
+        pos: Coord,
+        size: (u32, u32),
+        colormap: &CM,
+        boundaries: &[f64],
+    ) -> Self {
+        let (min, max) = match (boundaries.first(), boundaries.last()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => (0.0, 1.0),
+        };
+        let colors: Vec<_> = boundaries
+            .windows(2)
+            .map(|w| colormap.get_color_normalized((w[0] + w[1]) / 2.0, min, max))
+            .collect();
+        Self::discrete(pos, size, boundaries, &colors)
+    }
+
+    /// Set the number of samples in each class, which are labeled on the left side of the bar.
+    /// This only applies to the discrete colorbar.
+    pub fn counts(mut self, counts: &[usize]) -> Self {
+        self.counts = counts.to_vec();
+        self
+    }
+
+    /// Set the number of value labels of a continuous colorbar, the default is 5
+    pub fn label_count(mut self, count: usize) -> Self {
+        self.label_count = count;
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Set the style of the border of the bar and the class boundary ticks
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /// Set the function used to format the value labels
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, formatter: F) -> Self {
+        self.formatter = Box::new(formatter);
+        self
+    }
+
+    pub(crate) fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    // Move the bar and change its size, used when the bar is attached to a chart. The gradient
+    // of a continuous colorbar is resampled for the new height.
+    pub(crate) fn relocate(&mut self, pos: Coord, size: (u32, u32)) {
+        self.pos = pos;
+        self.size = size;
+        #[cfg(feature = "colormaps")]
+        if let Some(gradient) = self.gradient.as_ref() {
+            self.colors = Self::sample_gradient(gradient.as_ref(), size.1);
+        }
+    }
+}
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a ColorBar<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for ColorBar<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        let (x1, y1) = (x0 + w, y0 + h);
+        let n = self.colors.len() as i32;
+
+        if n == 0 {
+            return Ok(());
+        }
+
+        // The pixel position of the i-th edge counting from the bottom of the bar
+        let edge = |i: i32| y1 - i * h / n;
+
+        for (i, color) in self.colors.iter().enumerate() {
+            let i = i as i32;
+            backend.draw_rect(
+                (x0, edge(i + 1)),
+                (x1, edge(i)),
+                &color.to_backend_color(),
+                true,
+            )?;
+        }
+        backend.draw_rect((x0, y0), (x1, y1), &self.border_style, false)?;
+
+        let value_style = self.label_style.pos(Pos::new(HPos::Left, VPos::Center));
+        let tick = 4;
+
+        if self.discrete {
+            for (i, value) in self.boundaries.iter().enumerate() {
+                let y = edge(i as i32);
+                backend.draw_line((x1, y), (x1 + tick, y), &self.border_style)?;
+                backend.draw_text(&(self.formatter)(*value), &value_style, (x1 + tick * 2, y))?;
+            }
+
+            let count_style = self.label_style.pos(Pos::new(HPos::Right, VPos::Center));
+            for (i, count) in self.counts.iter().take(n as usize).enumerate() {
+                let i = i as i32;
+                let y = (edge(i) + edge(i + 1)) / 2;
+                backend.draw_text(&count.to_string(), &count_style, (x0 - tick * 2, y))?;
+            }
+        } else if self.label_count > 1 && self.boundaries.len() == 2 {
+            let (min, max) = (self.boundaries[0], self.boundaries[1]);
+            for i in 0..self.label_count {
+                let t = i as f64 / (self.label_count - 1) as f64;
+                let y = y1 - (t * f64::from(h)).round() as i32;
+                let value = min + (max - min) * t;
+                backend.draw_line((x1, y), (x1 + tick, y), &self.border_style)?;
+                backend.draw_text(&(self.formatter)(value), &value_style, (x1 + tick * 2, y))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_discrete_colorbar() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "0");
+                assert_eq!(pos, (68, 160));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "10");
+                assert_eq!(pos, (68, 110));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "100");
+                assert_eq!(pos, (68, 60));
+            });
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(text, "7");
+                assert_eq!(pos, (42, 135));
+            });
+            m.drop_check(|b| {
+                // Two classes, the border, two boundary labels, one count label
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+
+        da.draw(
+            &ColorBar::discrete((50, 60), (10, 100), &[0.0, 10.0, 100.0], &[GREEN, RED])
+                .counts(&[7]),
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,200 @@
+/*!
+  The covariance ellipse element, which shows the confidence region of 2D Gaussian data
+*/
+use crate::element::{Drawable, PointCollection};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+// The number of vertices on the perimeter of the ellipse
+const VERTEX_COUNT: usize = 100;
+
+/// The ellipse of constant Mahalanobis distance of a 2D Gaussian distribution, which is the
+/// error ellipse commonly drawn around the estimates in state estimation and statistics plots.
+///
+/// The ellipse is computed in the data coordinates and then mapped to the pixels vertex by vertex,
+/// thus it is correct on the charts whose axes have different scales.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = SVGBackend::new("covariance_ellipse.svg", (400, 300)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_cartesian_2d(-5.0..5.0, -1.0..1.0).unwrap();
+///
+/// let (mean, cov) = ((0.0, 0.0), [[4.0, 0.3], [0.3, 0.05]]);
+/// chart
+///     .draw_series((1..=3).map(|n| CovarianceEllipse::new(mean, cov, n as f64, BLUE.mix(0.3))))
+///     .unwrap();
+/// chart
+///     .draw_series(std::iter::once(CovarianceEllipse::with_confidence(mean, cov, 0.95, RED)))
+///     .unwrap();
+/// ```
+pub struct CovarianceEllipse {
+    vertices: Vec<(f64, f64)>,
+    style: ShapeStyle,
+}
+
+impl CovarianceEllipse {
+    /// Create the n-sigma ellipse of the distribution
+    ///
+    /// - `mean`: The mean of the distribution
+    /// - `cov`: The covariance matrix of the distribution, which should be symmetric and positive
+    ///   semi-definite. The negative eigenvalues are treated as zeros.
+    /// - `n_sigma`: The Mahalanobis distance of the ellipse, in standard deviations
+    /// - `style`: The style of the ellipse
+    /// - **returns**: The newly created ellipse
+    pub fn new<S: Into<ShapeStyle>>(
+        mean: (f64, f64),
+        cov: [[f64; 2]; 2],
+        n_sigma: f64,
+        style: S,
+    ) -> Self {
+        let (a, b, d) = (cov[0][0], (cov[0][1] + cov[1][0]) / 2.0, cov[1][1]);
+        let center = (a + d) / 2.0;
+        let delta = ((a - d) / 2.0).hypot(b);
+        let (major, minor) = (center + delta, center - delta);
+        let angle = 0.5 * (2.0 * b).atan2(a - d);
+        Self::from_axes(
+            mean,
+            (
+                n_sigma * major.max(0.0).sqrt(),
+                n_sigma * minor.max(0.0).sqrt(),
+            ),
+            angle,
+            style,
+        )
+    }
+
+    /// Create the ellipse of the distribution which contains the given probability mass
+    ///
+    /// - `mean`: The mean of the distribution
+    /// - `cov`: The covariance matrix of the distribution
+    /// - `confidence`: The probability inside the ellipse, such as 0.95
+    /// - `style`: The style of the ellipse
+    /// - **returns**: The newly created ellipse
+    pub fn with_confidence<S: Into<ShapeStyle>>(
+        mean: (f64, f64),
+        cov: [[f64; 2]; 2],
+        confidence: f64,
+        style: S,
+    ) -> Self {
+        // The squared Mahalanobis distance follows the chi-squared distribution with 2 degrees of
+        // freedom, whose quantile has a closed form
+        let n_sigma = (-2.0 * (1.0 - confidence.clamp(0.0, 1.0)).ln()).sqrt();
+        Self::new(mean, cov, n_sigma, style)
+    }
+
+    /// Create the ellipse from the precomputed axes
+    ///
+    /// - `center`: The center of the ellipse
+    /// - `semi_axes`: The lengths of the semi-major and semi-minor axes in the data units
+    /// - `angle`: The angle from the X axis to the major axis in radians, counterclockwise in
+    ///   the data coordinates
+    /// - `style`: The style of the ellipse
+    /// - **returns**: The newly created ellipse
+    pub fn from_axes<S: Into<ShapeStyle>>(
+        center: (f64, f64),
+        semi_axes: (f64, f64),
+        angle: f64,
+        style: S,
+    ) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let vertices = (0..VERTEX_COUNT)
+            .map(|i| {
+                let t = 2.0 * std::f64::consts::PI * i as f64 / VERTEX_COUNT as f64;
+                let (u, v) = (semi_axes.0 * t.cos(), semi_axes.1 * t.sin());
+                (center.0 + u * cos - v * sin, center.1 + u * sin + v * cos)
+            })
+            .collect();
+        Self {
+            vertices,
+            style: style.into(),
+        }
+    }
+
+    /// Get the vertices of the ellipse in the data coordinates
+    pub fn vertices(&self) -> &[(f64, f64)] {
+        &self.vertices
+    }
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a CovarianceEllipse {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.vertices
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for CovarianceEllipse {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut vertices: Vec<_> = points.collect();
+        if vertices.is_empty() {
+            return Ok(());
+        }
+        if self.style.filled {
+            backend.fill_polygon(vertices, &self.style.color.to_backend_color())
+        } else {
+            vertices.push(vertices[0]);
+            backend.draw_path(vertices, &self.style)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_covariance_ellipse_axes() {
+        // A diagonal covariance gives the axis-aligned ellipse
+        let ellipse = CovarianceEllipse::new((1.0, 2.0), [[4.0, 0.0], [0.0, 1.0]], 2.0, RED);
+        let v = ellipse.vertices();
+        assert!((v[0].0 - 5.0).abs() < 1e-9 && (v[0].1 - 2.0).abs() < 1e-9);
+        let top = v[VERTEX_COUNT / 4];
+        assert!((top.0 - 1.0).abs() < 1e-9 && (top.1 - 4.0).abs() < 1e-9);
+
+        // The major axis of a positively correlated distribution points to the first quadrant
+        let ellipse = CovarianceEllipse::new((0.0, 0.0), [[1.0, 0.9], [0.9, 1.0]], 1.0, RED);
+        let v = ellipse.vertices()[0];
+        assert!((v.0 - v.1).abs() < 1e-9 && (v.0.hypot(v.1) - 1.9f64.sqrt()).abs() < 1e-9);
+
+        // About 86.5% of the mass is within 2 sigma
+        let a =
+            CovarianceEllipse::with_confidence((0.0, 0.0), [[1.0, 0.0], [0.0, 1.0]], 0.8647, RED);
+        assert!((a.vertices()[0].0 - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_covariance_ellipse_scaling() {
+        let da = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), VERTEX_COUNT + 1);
+                let xs = path.iter().map(|p| p.0);
+                let ys = path.iter().map(|p| p.1);
+                let width = xs.clone().max().unwrap() - xs.min().unwrap();
+                let height = ys.clone().max().unwrap() - ys.min().unwrap();
+                // A unit circle in the data coordinates is stretched by the axes
+                assert!((width - 20).abs() <= 1 && (height - 100).abs() <= 1);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let chart = da.apply_coord_spec(Cartesian2d::<
+            crate::coord::types::RangedCoordf64,
+            crate::coord::types::RangedCoordf64,
+        >::new(-10.0..10.0, -2.0..2.0, (0..200, 0..200)));
+        chart
+            .draw(&CovarianceEllipse::new(
+                (0.0, 0.0),
+                [[1.0, 0.0], [0.0, 1.0]],
+                1.0,
+                RED,
+            ))
+            .unwrap();
+    }
+}
@@ -5,7 +5,7 @@
 use image::{DynamicImage, GenericImageView};
 
 use super::{Drawable, PointCollection};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, BitmapInterpolation, DrawingBackend, DrawingErrorKind};
 
 use plotters_bitmap::bitmap_pixel::{PixelFormat, RGBPixel};
 
@@ -60,10 +60,16 @@ impl<'a> Buffer<'a> {
 }
 
 /// The element that contains a bitmap on it
+///
+/// By default the bitmap is blitted without scaling with its upper-left corner at `pos`.
+/// Use [BitMapElement::stretch_to] to fit the bitmap into a rectangle instead, for example a
+/// rectangle in the data coordinate of a chart.
 pub struct BitMapElement<'a, Coord, P: PixelFormat = RGBPixel> {
     image: Buffer<'a>,
     size: (u32, u32),
     pos: Coord,
+    bottom_right: Option<Coord>,
+    interpolation: BitmapInterpolation,
     phantom: PhantomData<P>,
 }
 
@@ -78,6 +84,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(vec![0; (size.0 * size.1) as usize * P::PIXEL_SIZE]),
             size,
             pos,
+            bottom_right: None,
+            interpolation: BitmapInterpolation::Nearest,
             phantom: PhantomData,
         }
     }
@@ -99,6 +107,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Owned(buf),
             size,
             pos,
+            bottom_right: None,
+            interpolation: BitmapInterpolation::Nearest,
             phantom: PhantomData,
         })
     }
@@ -119,6 +129,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::BorrowedMut(buf),
             size,
             pos,
+            bottom_right: None,
+            interpolation: BitmapInterpolation::Nearest,
             phantom: PhantomData,
         })
     }
@@ -140,6 +152,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(buf),
             size,
             pos,
+            bottom_right: None,
+            interpolation: BitmapInterpolation::Nearest,
             phantom: PhantomData,
         })
     }
@@ -152,6 +166,8 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
             image: Buffer::Borrowed(self.image.borrow()),
             size: self.size,
             pos,
+            bottom_right: None,
+            interpolation: self.interpolation,
             phantom: PhantomData,
         }
     }
@@ -163,6 +179,38 @@ impl<'a, Coord, P: PixelFormat> BitMapElement<'a, Coord, P> {
         self.pos = pos;
     }
 
+    /// Stretch the bitmap so that it fills the rectangle between the position of the element
+    /// and the given bottom-right corner, rather than blitting it with its original size.
+    ///
+    /// - `bottom_right`: The bottom-right corner of the target rectangle
+    /// - **returns**: The element that draws the scaled bitmap
+    pub fn stretch_to(mut self, bottom_right: Coord) -> Self {
+        self.bottom_right = Some(bottom_right);
+        self
+    }
+
+    /// Set the interpolation method used when the bitmap is scaled, the default is
+    /// [BitmapInterpolation::Nearest].
+    ///
+    /// - `interpolation`: The interpolation method
+    /// - **returns**: The updated element
+    pub fn interpolation(mut self, interpolation: BitmapInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub(crate) fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    pub(crate) fn buffer(&self) -> &[u8] {
+        self.image.as_ref()
+    }
+
+    pub(crate) fn get_interpolation(&self) -> BitmapInterpolation {
+        self.interpolation
+    }
+
     /// Make the bitmap element as a bitmap backend, so that we can use
     /// plotters drawing functionality on the bitmap element
     pub fn as_bitmap_backend(&mut self) -> BitMapBackend<P> {
@@ -182,6 +230,8 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBPixe
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            bottom_right: None,
+            interpolation: BitmapInterpolation::Nearest,
             phantom: PhantomData,
         }
     }
@@ -199,6 +249,8 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
             pos,
             image: Buffer::Owned(rgb_image),
             size: (w, h),
+            bottom_right: None,
+            interpolation: BitmapInterpolation::Nearest,
             phantom: PhantomData,
         }
     }
@@ -206,9 +258,9 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
 
 impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord> {
     type Point = &'a Coord;
-    type IntoIter = std::iter::Once<&'a Coord>;
+    type IntoIter = std::iter::Chain<std::iter::Once<&'a Coord>, std::option::Iter<'a, Coord>>;
     fn point_iter(self) -> Self::IntoIter {
-        std::iter::once(&self.pos)
+        std::iter::once(&self.pos).chain(self.bottom_right.iter())
     }
 }
 
@@ -221,6 +273,15 @@ impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
             // TODO: convert the pixel format when needed
+            if let Some(bottom_right) = points.next() {
+                return backend.blit_bitmap_scaled(
+                    (x, y),
+                    bottom_right,
+                    self.size,
+                    self.image.as_ref(),
+                    self.interpolation,
+                );
+            }
             return backend.blit_bitmap((x, y), self.size, self.image.as_ref());
         }
         Ok(())
@@ -0,0 +1,206 @@
+/*!
+  The infinite line element, which is a straight line across the whole plotting area
+*/
+use crate::drawing::Rect;
+use crate::element::{Drawable, PointCollection, UnclampedCoordAndArea};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+// The points on the line at the distances of the powers of two, in multiples of the direction
+// vector, from the anchor point in both directions are mapped to find the direction of the line in
+// pixels. The line is drawn through the farthest pair that is mapped precisely, thus it's accurate
+// however the axes are scaled.
+const MAX_EXPONENT: i32 = 40;
+
+// The points mapped further than this are considered saturated by the coordinate mapping
+const MAX_PIXEL: i32 = 1 << 24;
+
+/// A straight line which extends across the whole plotting area, such as the `y = x` reference
+/// line, a regression line or an asymptote, similar to `axline` in matplotlib.
+///
+/// The line is defined in the data coordinates, either by a point and a slope, or by two points,
+/// and it's clipped to the plotting area when it's drawn, thus the intersections with the axis
+/// ranges don't need to be computed manually. The line is straight in pixels, which means it's
+/// only a straight line of the data on the linear axes.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let root = SVGBackend::new("infinite_line.svg", (300, 300)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0.0..10.0, 0.0..5.0).unwrap();
+///
+/// chart
+///     .draw_series(std::iter::once(InfiniteLine::with_slope((0.0, 0.0), 1.0, &RED)))
+///     .unwrap();
+/// chart
+///     .draw_series(std::iter::once(InfiniteLine::through((2.0, 0.0), (2.0, 1.0), &BLUE)))
+///     .unwrap();
+/// ```
+pub struct InfiniteLine {
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+}
+
+impl InfiniteLine {
+    /// Create a line through a point with the given slope
+    ///
+    /// - `point`: A point on the line
+    /// - `slope`: The change of Y per unit change of X
+    /// - `style`: The style of the line
+    /// - **returns**: The newly created line
+    pub fn with_slope<S: Into<ShapeStyle>>(point: (f64, f64), slope: f64, style: S) -> Self {
+        Self::new(point, (1.0, slope), style)
+    }
+
+    /// Create a line through two points, the line is vertical if the points have the same X
+    ///
+    /// - `from`: A point on the line
+    /// - `to`: Another point on the line, which should be different from `from`
+    /// - `style`: The style of the line
+    /// - **returns**: The newly created line
+    pub fn through<S: Into<ShapeStyle>>(from: (f64, f64), to: (f64, f64), style: S) -> Self {
+        Self::new(from, (to.0 - from.0, to.1 - from.1), style)
+    }
+
+    fn new<S: Into<ShapeStyle>>(anchor: (f64, f64), (dx, dy): (f64, f64), style: S) -> Self {
+        // The points are ordered along the line
+        let point = |t: f64| (anchor.0 + dx * t, anchor.1 + dy * t);
+        let scales = (-MAX_EXPONENT..=MAX_EXPONENT).map(|e| 2f64.powi(e));
+        let points = scales
+            .clone()
+            .rev()
+            .map(|t| point(-t))
+            .chain(std::iter::once(anchor))
+            .chain(scales.map(point))
+            .collect();
+        Self {
+            points,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a> PointCollection<'a, (f64, f64), UnclampedCoordAndArea> for &'a InfiniteLine {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+// Clip the line through `a` and `b` to the rectangle, returns the visible segment
+fn clip_line(
+    a: BackendCoord,
+    b: BackendCoord,
+    area: &Rect,
+) -> Option<(BackendCoord, BackendCoord)> {
+    let (x0, y0) = area.upper_left();
+    let (x1, y1) = area.bottom_right();
+    let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+    let (mut t0, mut t1) = (f64::NEG_INFINITY, f64::INFINITY);
+    // The bottom-right corner of the area is exclusive
+    for &(start, delta, low, high) in &[(a.0, dx, x0, x1 - 1), (a.1, dy, y0, y1 - 1)] {
+        if delta == 0.0 {
+            if start < low || start > high {
+                return None;
+            }
+            continue;
+        }
+        let (ta, tb) = (
+            f64::from(low - start) / delta,
+            f64::from(high - start) / delta,
+        );
+        t0 = t0.max(ta.min(tb));
+        t1 = t1.min(ta.max(tb));
+    }
+    if t0 > t1 || t0.is_infinite() || t1.is_infinite() {
+        return None;
+    }
+    let at = |t: f64| (a.0 + (dx * t).round() as i32, a.1 + (dy * t).round() as i32);
+    Some((at(t0), at(t1)))
+}
+
+impl<DB: DrawingBackend> Drawable<DB, UnclampedCoordAndArea> for InfiniteLine {
+    fn draw<I: Iterator<Item = (BackendCoord, Rect)>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut area = None;
+        let points: Vec<_> = points
+            .filter_map(|(p, rect)| {
+                area = Some(rect);
+                let range = -MAX_PIXEL..MAX_PIXEL;
+                if range.contains(&p.0) && range.contains(&p.1) {
+                    Some(p)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let area = match area {
+            Some(area) => area,
+            None => return Ok(()),
+        };
+
+        // The points are ordered along the line, so the first and the last ones are the farthest
+        let best = points.first().copied().zip(points.last().copied());
+        match best {
+            Some((a, b)) if a != b => match clip_line(a, b, &area) {
+                Some((from, to)) => backend.draw_path(vec![from, to], &self.style),
+                None => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn draw_line(
+        range: (std::ops::Range<f64>, std::ops::Range<f64>),
+        line: InfiniteLine,
+    ) -> Vec<Vec<(i32, i32)>> {
+        let calls = record_draw_calls(101, 101, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(range.0, range.1)
+                .unwrap();
+            chart.draw_series(std::iter::once(line)).unwrap();
+        });
+        calls.paths.into_iter().map(|p| p.path).collect()
+    }
+
+    #[test]
+    fn test_infinite_line() {
+        // y = x from the bottom-left corner to the top-right corner
+        let paths = draw_line(
+            (0.0..1.0, 0.0..1.0),
+            InfiniteLine::with_slope((0.5, 0.5), 1.0, RED),
+        );
+        assert_eq!(paths, vec![vec![(0, 100), (100, 0)]]);
+
+        // The anchor point far away from the plotting area
+        let paths = draw_line(
+            (0.0..1.0, 0.0..1e6),
+            InfiniteLine::with_slope((-1e3, 5e5), 0.0, RED),
+        );
+        assert_eq!(paths, vec![vec![(0, 50), (100, 50)]]);
+
+        // A vertical line
+        let paths = draw_line(
+            (0.0..10.0, 0.0..10.0),
+            InfiniteLine::through((2.0, 0.0), (2.0, 1.0), RED),
+        );
+        assert_eq!(paths, vec![vec![(20, 100), (20, 0)]]);
+
+        // Outside of the plotting area
+        let paths = draw_line(
+            (0.0..10.0, 0.0..10.0),
+            InfiniteLine::with_slope((0.0, 20.0), 0.5, RED),
+        );
+        assert!(paths.is_empty());
+    }
+}
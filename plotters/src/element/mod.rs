@@ -195,6 +195,21 @@ mod image;
 #[cfg(feature = "bitmap_backend")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
 pub use self::image::BitMapElement;
+#[cfg(feature = "bitmap_backend")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
+pub use plotters_backend::BitmapInterpolation;
+
+mod colorbar;
+pub use colorbar::ColorBar;
+
+mod bracket;
+pub use bracket::ComparisonBracket;
+
+mod covariance;
+pub use covariance::CovarianceEllipse;
+
+mod clip;
+pub use clip::{ClipPath, Clipped, ClippedBackend};
 
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
@@ -202,6 +217,9 @@ pub use dynelem::{DynElement, IntoDynElement};
 mod pie;
 pub use pie::Pie;
 
+mod infinite_line;
+pub use infinite_line::InfiniteLine;
+
 use crate::coord::CoordTranslate;
 use crate::drawing::Rect;
 
@@ -256,6 +274,72 @@ pub trait Drawable<DB: DrawingBackend, CM: CoordMapper = BackendCoordOnly> {
 }
 
 /// Useful to translate from guest coordinates to backend coordinates
+///
+/// The coordinate mapper decides what an element receives in its `Drawable::draw` method for each
+/// of its key points. Plotters provides [BackendCoordOnly] for 2D elements and [BackendCoordAndZ]
+/// for the 3D elements that need the depth of the points. The trait is open, so an element family
+/// can define its own mapper to carry whatever per-point information it needs, as long as the
+/// information can be derived from the coordinate spec and the drawing area.
+///
+/// An element opts in to a mapper by implementing `PointCollection<'_, Coord, Mapper>` and
+/// `Drawable<DB, Mapper>`, after that it can be drawn with `DrawingArea::draw` and
+/// `ChartContext::draw_series` just like any built-in element.
+///
+/// ```rust
+/// use plotters::coord::CoordTranslate;
+/// use plotters::drawing::Rect;
+/// use plotters::element::{CoordMapper, Drawable, PointCollection};
+/// use plotters::prelude::*;
+/// use plotters_backend::{BackendCoord, DrawingErrorKind};
+///
+/// // Maps a point to its backend coordinate, its depth and if it's inside the drawing area
+/// struct CoordDepthAndVisibility;
+///
+/// impl CoordMapper for CoordDepthAndVisibility {
+///     type Output = (BackendCoord, i32, bool);
+///     fn map<CT: CoordTranslate>(coord_trans: &CT, from: &CT::From, rect: &Rect) -> Self::Output {
+///         let coord = coord_trans.translate(from);
+///         let truncated = rect.truncate(coord);
+///         (truncated, coord_trans.depth(from), truncated == coord)
+///     }
+/// }
+///
+/// // A dot which gets darker when it's further away, and isn't drawn when it's out of the chart
+/// struct DepthShadedDot((f64, f64, f64));
+///
+/// impl<'a> PointCollection<'a, (f64, f64, f64), CoordDepthAndVisibility> for &'a DepthShadedDot {
+///     type Point = &'a (f64, f64, f64);
+///     type IntoIter = std::iter::Once<&'a (f64, f64, f64)>;
+///     fn point_iter(self) -> Self::IntoIter {
+///         std::iter::once(&self.0)
+///     }
+/// }
+///
+/// impl<DB: DrawingBackend> Drawable<DB, CoordDepthAndVisibility> for DepthShadedDot {
+///     fn draw<I: Iterator<Item = (BackendCoord, i32, bool)>>(
+///         &self,
+///         mut points: I,
+///         backend: &mut DB,
+///         _: (u32, u32),
+///     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+///         match points.next() {
+///             Some((coord, depth, true)) => {
+///                 let shade = (depth.max(0).min(255)) as u8;
+///                 backend.draw_circle(coord, 3, &RGBColor(shade, shade, 255), true)
+///             }
+///             _ => Ok(()),
+///         }
+///     }
+/// }
+///
+/// let root = SVGBackend::new("custom_mapper.svg", (300, 300)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+///     .unwrap();
+/// chart
+///     .draw_series((0..10).map(|i| DepthShadedDot((i as f64 / 10.0, 0.5, 0.5))))
+///     .unwrap();
+/// ```
 pub trait CoordMapper {
     /// Specifies the output data from the translation
     type Output;
@@ -292,3 +376,36 @@ impl CoordMapper for BackendCoordAndZ {
         (coord, z)
     }
 }
+
+/// Used for the 2d elements which are sized by the drawing area rather than their coordinates,
+/// such as [SpanSeries](../series/struct.SpanSeries.html). Each point is mapped to its truncated
+/// backend coordinate along with the rectangle of the drawing area.
+pub struct BackendCoordAndArea;
+
+impl CoordMapper for BackendCoordAndArea {
+    type Output = (BackendCoord, Rect);
+    fn map<CT: CoordTranslate>(
+        coord_trans: &CT,
+        from: &CT::From,
+        rect: &Rect,
+    ) -> (BackendCoord, Rect) {
+        (rect.truncate(coord_trans.translate(from)), rect.clone())
+    }
+}
+
+/// Used for the 2d elements which extend beyond their coordinates to the edges of the drawing
+/// area, such as [InfiniteLine](struct.InfiniteLine.html). Unlike [BackendCoordAndArea], the
+/// backend coordinates aren't truncated, thus the points outside of the drawing area keep their
+/// positions.
+pub struct UnclampedCoordAndArea;
+
+impl CoordMapper for UnclampedCoordAndArea {
+    type Output = (BackendCoord, Rect);
+    fn map<CT: CoordTranslate>(
+        coord_trans: &CT,
+        from: &CT::From,
+        rect: &Rect,
+    ) -> (BackendCoord, Rect) {
+        (coord_trans.translate(from), rect.clone())
+    }
+}
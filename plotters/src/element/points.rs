@@ -129,6 +129,60 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for TriangleMarker<
     }
 }
 
+/**
+A square marker for visualizing data series.
+
+See [`EmptyElement`] for more information and examples.
+*/
+pub struct SquareMarker<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> SquareMarker<Coord, Size> {
+    /**
+    Creates a square marker.
+
+    See [`EmptyElement`] for more information and examples.
+    */
+    pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: Size, style: T) -> Self {
+        Self {
+            center: coord,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a SquareMarker<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for SquareMarker<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let size = self.size.in_pixels(&ps);
+            backend.draw_rect(
+                (x - size, y - size),
+                (x + size, y + size),
+                &self.style,
+                self.style.filled,
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Cross<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
@@ -141,6 +195,12 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for TriangleMarker<Coord,
     }
 }
 
+impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for SquareMarker<Coord, Size> {
+    fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+        Self::new(pos, size, style)
+    }
+}
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Circle<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
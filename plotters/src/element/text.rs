@@ -1,7 +1,8 @@
 use std::borrow::Borrow;
 
 use super::{Drawable, PointCollection};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontDesc, FontResult, LayoutBox, ShapeStyle, TextStyle, BASELINE_RATIO};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// A single line text element. This can be owned or borrowed string, dependents on
@@ -49,13 +50,147 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
     }
 }
 
+/// A single line text element made of several spans, each of them with its own style.
+/// This allows a label to mix bold, italic or colored segments, for example to emphasize
+/// a value in a caption. The spans are placed one after another and share the same baseline.
+///
+/// The coordinate is the upper left corner of the element, so the anchors of the span styles
+/// are ignored.
+///
+/// ```
+/// use plotters::prelude::*;
+/// let drawing_area = SVGBackend::new("rich_text.svg", (300, 100)).into_drawing_area();
+/// drawing_area.fill(&WHITE).unwrap();
+/// let caption = RichText::new(
+///     vec![
+///         ("Peak load: ", ("sans-serif", 15).into_font().color(&BLACK)),
+///         ("93%", ("sans-serif", 20, FontStyle::Bold).into_font().color(&RED)),
+///     ],
+///     (10, 10),
+/// );
+/// drawing_area.draw(&caption).unwrap();
+/// ```
+pub struct RichText<'a, Coord, T: Borrow<str>> {
+    spans: Vec<(T, TextStyle<'a>)>,
+    coord: Coord,
+}
+
+impl<'a, Coord, T: Borrow<str>> RichText<'a, Coord, T> {
+    /// Create a new rich text element
+    /// - `spans`: The text and the style of each span
+    /// - `pos`: The upper left corner of the element
+    /// - Return the newly created rich text element
+    pub fn new<S: Into<TextStyle<'a>>, I: IntoIterator<Item = (T, S)>>(
+        spans: I,
+        pos: Coord,
+    ) -> Self {
+        Self {
+            spans: spans
+                .into_iter()
+                .map(|(text, style)| (text, style.into()))
+                .collect(),
+            coord: pos,
+        }
+    }
+
+    /// Push a new span at the end of the text
+    /// `text`: The text of the span
+    /// `style`: The style of the span
+    pub fn push_span<S: Into<TextStyle<'a>>>(&mut self, text: T, style: S) {
+        self.spans.push((text, style.into()));
+    }
+
+    /// Estimate the rich text element's dimension
+    pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        let mut width = 0;
+        let mut height = 0;
+        for ((x, y), (text, style)) in self.layout_spans((0, 0))?.into_iter().zip(&self.spans) {
+            let (w, h) = style.font.box_size(text.borrow())?;
+            width = width.max(x + w as i32);
+            height = height.max(y + h as i32);
+        }
+        Ok((width, height))
+    }
+
+    fn layout_spans(&self, (x0, y0): BackendCoord) -> FontResult<Vec<BackendCoord>> {
+        let mut sizes = Vec::with_capacity(self.spans.len());
+        for (text, style) in &self.spans {
+            sizes.push(style.font.box_size(text.borrow())?);
+        }
+        let max_height = sizes.iter().map(|&(_, h)| h).max().unwrap_or(0);
+
+        let mut x = x0;
+        Ok(sizes
+            .into_iter()
+            .map(|(w, h)| {
+                let y = y0 + (f64::from(max_height - h) * BASELINE_RATIO).round() as i32;
+                x += w as i32;
+                (x - w as i32, y)
+            })
+            .collect())
+    }
+}
+
+impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
+    for &'a RichText<'b, Coord, T>
+{
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for RichText<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(a) = points.next() {
+            let layout = self
+                .layout_spans(a)
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+            for (pos, (text, style)) in layout.into_iter().zip(&self.spans) {
+                let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+                backend.draw_text(text.borrow(), &style, pos)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// An multi-line text element. The `Text` element allows only single line text
 /// and the `MultiLineText` supports drawing multiple lines
+///
+/// The text can be wrapped at a maximum width with [`MultiLineText::from_str`] and
+/// [`MultiLineText::from_string`], and optionally drawn in a box, which makes it handy for
+/// annotations and long captions.
+///
+/// ```
+/// use plotters::prelude::*;
+/// let drawing_area = SVGBackend::new("multi_line_text.svg", (300, 200)).into_drawing_area();
+/// drawing_area.fill(&WHITE).unwrap();
+/// let mut note = MultiLineText::<_, &str>::from_str(
+///     "The peak was caused by a configuration change, which was reverted the next day.",
+///     (20, 20),
+///     ("sans-serif", 15),
+///     200,
+/// );
+/// note.set_background(WHITE.mix(0.8).filled())
+///     .set_border(BLACK)
+///     .set_padding(5);
+/// drawing_area.draw(&note).unwrap();
+/// ```
 pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
     lines: Vec<T>,
     coord: Coord,
     style: TextStyle<'a>,
     line_height: f64,
+    background: Option<ShapeStyle>,
+    border: Option<ShapeStyle>,
+    padding: u32,
 }
 
 impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
@@ -70,6 +205,9 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
             coord: pos,
             style: style.into(),
             line_height: 1.25,
+            background: None,
+            border: None,
+            padding: 0,
         }
     }
 
@@ -79,13 +217,31 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
         self
     }
 
+    /// Fill the box around the text with the given style
+    pub fn set_background<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.background = Some(style.into());
+        self
+    }
+
+    /// Draw the border of the box around the text with the given style
+    pub fn set_border<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.border = Some(style.into());
+        self
+    }
+
+    /// Set the space between the text and the box around it in pixels
+    pub fn set_padding(&mut self, padding: u32) -> &mut Self {
+        self.padding = padding;
+        self
+    }
+
     /// Push a new line into the given multi-line text
     /// `line`: The line to be pushed
     pub fn push_line<L: Into<T>>(&mut self, line: L) {
         self.lines.push(line.into());
     }
 
-    /// Estimate the multi-line text element's dimension
+    /// Estimate the multi-line text element's dimension, including the padding
     pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
         let (mut mx, mut my) = (0, 0);
 
@@ -95,7 +251,8 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
             my = my.max(y + dy as i32);
         }
 
-        Ok((mx, my))
+        let padding = self.padding as i32;
+        Ok((mx + padding, my + padding))
     }
 
     /// Move the location to the specified location
@@ -104,6 +261,7 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
     }
 
     fn layout_lines(&self, (x0, y0): BackendCoord) -> impl Iterator<Item = BackendCoord> {
+        let (x0, y0) = (x0 + self.padding as i32, y0 + self.padding as i32);
         let font_height = self.style.font.get_size();
         let actual_line_height = font_height * self.line_height;
         (0..self.lines.len() as u32).map(move |idx| {
@@ -115,49 +273,64 @@ impl<'a, Coord, T: Borrow<str>> MultiLineText<'a, Coord, T> {
     }
 }
 
-// Rewrite of the layout function for multiline-text. It crashes when UTF-8 is used
-// instead of ASCII. Solution taken from:
-// https://stackoverflow.com/questions/68122526/splitting-a-utf-8-string-into-chunks
-// and modified for our purposes.
+// Split the text into lines at the newlines, and break the lines wider than max_width between the
+// words. The words wider than max_width are broken between the characters, which works for UTF-8
+// as well, see https://stackoverflow.com/questions/68122526/splitting-a-utf-8-string-into-chunks
 fn layout_multiline_text<'a, F: FnMut(&'a str)>(
     text: &'a str,
     max_width: u32,
     font: FontDesc<'a>,
     mut func: F,
 ) {
+    let width = |text: &str| font.box_size(text).map_or(0, |(w, _)| w);
     for line in text.lines() {
-        if max_width == 0 || line.is_empty() {
-            func(line);
-        } else {
-            let mut indices = line.char_indices().map(|(idx, _)| idx).peekable();
-
-            let it = std::iter::from_fn(|| {
-                let start_idx = match indices.next() {
-                    Some(idx) => idx,
-                    None => return None,
-                };
-
-                // iterate over indices
-                for idx in indices.by_ref() {
-                    let substring = &line[start_idx..idx];
-                    let width = font.box_size(substring).unwrap_or((0, 0)).0 as i32;
-                    if width > max_width as i32 {
-                        break;
-                    }
-                }
+        let mut words = vec![];
+        let mut word_start = None;
+        for (idx, c) in line.char_indices() {
+            if !c.is_whitespace() {
+                word_start = word_start.or(Some(idx));
+            } else if let Some(start) = word_start.take() {
+                words.push((start, idx));
+            }
+        }
+        if let Some(start) = word_start {
+            words.push((start, line.len()));
+        }
 
-                let end_idx = match indices.peek() {
-                    Some(idx) => *idx,
-                    None => line.bytes().len(),
-                };
+        if max_width == 0 || words.is_empty() {
+            func(line);
+            continue;
+        }
 
-                Some(&line[start_idx..end_idx])
-            });
+        // The pending line is line[start..end], which is empty before the first word
+        let (mut start, mut end) = (0, 0);
+        for (word_start, word_end) in words {
+            if start < end && width(&line[start..word_end]) <= max_width {
+                end = word_end;
+                continue;
+            }
+            if start < end {
+                func(&line[start..end]);
+            }
 
-            for chunk in it {
-                func(chunk);
+            let mut chunk_start = word_start;
+            let mut prev = word_start;
+            let boundaries = line[word_start..word_end]
+                .char_indices()
+                .skip(1)
+                .map(|(idx, _)| word_start + idx)
+                .chain(std::iter::once(word_end));
+            for idx in boundaries {
+                if prev > chunk_start && width(&line[chunk_start..idx]) > max_width {
+                    func(&line[chunk_start..prev]);
+                    chunk_start = prev;
+                }
+                prev = idx;
             }
+            start = chunk_start;
+            end = word_end;
         }
+        func(&line[start..end]);
     }
 }
 
@@ -170,10 +343,22 @@ fn test_multi_layout() {
 
     let font = FontDesc::new(FontFamily::SansSerif, 20 as f64, FontStyle::Bold);
 
-    layout_multiline_text("öäabcde", 40, font, |txt| {
-        println!("Got: {}", txt);
-        assert!(txt == "öäabc" || txt == "de");
+    let mut chunks = vec![];
+    layout_multiline_text("öäabcde", 40, font.clone(), |txt| chunks.push(txt));
+    assert_eq!(chunks, ["öäa", "bcd", "e"]);
+    for txt in chunks {
+        assert!(font.box_size(txt).unwrap().0 <= 40);
+    }
+
+    let mut lines = vec![];
+    layout_multiline_text("one two three four\n\nfive", 100, font.clone(), |txt| {
+        lines.push(txt)
     });
+    assert_eq!(lines, ["one two", "three four", "", "five"]);
+    for txt in lines {
+        assert!(!txt.starts_with(' ') && !txt.ends_with(' '));
+        assert!(font.box_size(txt).unwrap().0 <= 100);
+    }
 
     let font = FontDesc::new(FontFamily::SansSerif, 20 as f64, FontStyle::Bold);
     layout_multiline_text("öä", 100, font, |txt| {
@@ -184,6 +369,78 @@ fn test_multi_layout() {
     });
 }
 
+#[cfg(all(feature = "ttf", target_os = "linux"))]
+#[test]
+fn test_multi_line_text_box() {
+    use crate::prelude::*;
+
+    let mut text = MultiLineText::<_, &str>::from_str(
+        "lorem ipsum dolor sit amet",
+        (10, 10),
+        ("sans-serif", 20),
+        100,
+    );
+    text.set_background(WHITE.filled())
+        .set_border(BLACK)
+        .set_padding(5);
+    let (w, h) = text.estimate_dimension().unwrap();
+    let calls = crate::record_draw_calls(300, 300, |da| {
+        da.draw(&text).unwrap();
+    });
+
+    let rects: Vec<_> = calls
+        .rects
+        .iter()
+        .map(|r| (r.color, r.fill, r.upper_left, r.bottom_right))
+        .collect();
+    assert_eq!(
+        rects,
+        [
+            (WHITE.to_rgba(), true, (10, 10), (10 + w, 10 + h)),
+            (BLACK.to_rgba(), false, (10, 10), (10 + w, 10 + h))
+        ]
+    );
+    let texts = &calls.texts;
+    assert!(texts.len() > 1);
+    assert_eq!(texts[0].pos, (15, 15));
+    assert!(texts.iter().all(|t| !t.text.contains("lorem ipsum dolor")));
+}
+
+#[cfg(all(feature = "ttf", target_os = "linux"))]
+#[test]
+fn test_rich_text() {
+    use crate::prelude::*;
+
+    let small = TextStyle::from(("sans-serif", 10)).pos(Pos::new(HPos::Center, VPos::Center));
+    let large = ("sans-serif", 30).into_font().color(&RED);
+    let text = RichText::new(
+        vec![("value: ", small.clone()), ("42", large.clone())],
+        (10, 10),
+    );
+    let calls = crate::record_draw_calls(300, 300, |da| {
+        da.draw(&text).unwrap();
+    });
+
+    let (w0, h0) = small.font.box_size("value: ").unwrap();
+    let (_, h1) = large.font.box_size("42").unwrap();
+    let texts = &calls.texts;
+    assert_eq!(texts.len(), 2);
+    assert_eq!((texts[0].size, texts[0].text.as_str()), (10.0, "value: "));
+    assert_eq!(
+        (
+            texts[1].color,
+            texts[1].size,
+            texts[1].pos,
+            texts[1].text.as_str()
+        ),
+        (RED.to_rgba(), 30.0, (10 + w0 as i32, 10), "42")
+    );
+    let baseline0 = texts[0].pos.1 as f64 + h0 as f64 * BASELINE_RATIO;
+    let baseline1 = texts[1].pos.1 as f64 + h1 as f64 * BASELINE_RATIO;
+    assert!((baseline0 - baseline1).abs() <= 1.0);
+    assert!(texts[0].pos.1 > 10);
+}
+
 impl<'a, T: Borrow<str>> MultiLineText<'a, BackendCoord, T> {
     /// Compute the line layout
     pub fn compute_line_layout(&self) -> FontResult<Vec<LayoutBox>> {
@@ -265,6 +522,18 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
+            if self.background.is_some() || self.border.is_some() {
+                let (w, h) = self
+                    .estimate_dimension()
+                    .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+                let b = (a.0 + w, a.1 + h);
+                if let Some(style) = &self.background {
+                    backend.draw_rect(a, b, style, true)?;
+                }
+                if let Some(style) = &self.border {
+                    backend.draw_rect(a, b, style, false)?;
+                }
+            }
             for (point, text) in self.layout_lines(a).zip(self.lines.iter()) {
                 backend.draw_text(text.borrow(), &self.style, point)?;
             }
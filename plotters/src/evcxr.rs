@@ -5,11 +5,21 @@ use plotters_svg::SVGBackend;
 use std::fs::File;
 use std::io::Write;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::drawing::FileBackend;
+#[cfg(not(target_arch = "wasm32"))]
+use once_cell::sync::Lazy;
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+
 #[cfg(feature = "evcxr_bitmap")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "evcxr_bitmap")))]
 use plotters_bitmap::BitMapBackend;
 
 /// The wrapper for the generated SVG
+#[derive(Clone)]
 pub struct SVGWrapper(String, String);
 
 impl SVGWrapper {
@@ -90,3 +100,227 @@ pub fn evcxr_bitmap_figure<
     }
     SVGWrapper(buffer, "".to_string())
 }
+
+/// The environment variable that gives the default figure size of
+/// [evcxr_figure_with](fn.evcxr_figure_with.html), in the form of `WIDTHxHEIGHT`, for example
+/// `800x600`
+pub const EVCXR_FIGURE_SIZE_VAR: &str = "PLOTTERS_EVCXR_FIGURE_SIZE";
+
+/// The default figure size when there's no size hint
+pub const EVCXR_DEFAULT_FIGURE_SIZE: (u32, u32) = (640, 480);
+
+/// The output format of [evcxr_figure_with](fn.evcxr_figure_with.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvcxrFormat {
+    /// Always output the SVG
+    Svg,
+    /// Always output a bitmap, which is embedded as a base64 encoded PNG image
+    #[cfg(feature = "evcxr_bitmap")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "evcxr_bitmap")))]
+    Bitmap,
+    /// Output the SVG, unless the SVG is larger than the given number of bytes, in which case the
+    /// figure is drawn again as a bitmap. Figures with many elements, such as scatter plots of
+    /// large datasets, are much smaller and faster to render in the notebook as bitmaps.
+    #[cfg(feature = "evcxr_bitmap")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "evcxr_bitmap")))]
+    Auto(usize),
+}
+
+/// The configuration of [evcxr_figure_with](fn.evcxr_figure_with.html)
+#[derive(Clone, Debug)]
+pub struct EvcxrConfig {
+    size: (u32, u32),
+    format: EvcxrFormat,
+    cache_key: Option<String>,
+    style: String,
+}
+
+impl Default for EvcxrConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EvcxrConfig {
+    /// Create the default configuration. The figure size is taken from the
+    /// [EVCXR_FIGURE_SIZE_VAR](constant.EVCXR_FIGURE_SIZE_VAR.html) environment variable if it's
+    /// set, and the figure is output as SVG.
+    pub fn new() -> Self {
+        Self {
+            size: size_hint().unwrap_or(EVCXR_DEFAULT_FIGURE_SIZE),
+            format: EvcxrFormat::Svg,
+            cache_key: None,
+            style: "".to_string(),
+        }
+    }
+
+    /// Set the size of the figure, which overrides the size hint of the environment
+    pub fn size(mut self, size: (u32, u32)) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the output format of the figure
+    pub fn format(mut self, format: EvcxrFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Cache the figure with the key, for example, the name of the notebook cell. The last figure
+    /// of each key can be displayed again with [evcxr_cached_figure](fn.evcxr_cached_figure.html)
+    /// without drawing it.
+    pub fn cache_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.cache_key = Some(key.into());
+        self
+    }
+
+    /// Set the CSS style of the figure
+    pub fn style<S: Into<String>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Get the size of the figure
+    pub fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+}
+
+fn size_hint() -> Option<(u32, u32)> {
+    let value = std::env::var(EVCXR_FIGURE_SIZE_VAR).ok()?;
+    let mut parts = value.trim().splitn(2, &['x', 'X', ','][..]);
+    let w = parts.next()?.trim().parse().ok()?;
+    let h = parts.next()?.trim().parse().ok()?;
+    Some((w, h))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+static FIGURE_CACHE: Lazy<Mutex<HashMap<String, SVGWrapper>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[cfg(not(target_arch = "wasm32"))]
+type EvcxrDrawingArea<'a> = DrawingArea<FileBackend<'a>, Shift>;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn draw_svg<Draw>(size: (u32, u32), draw: &Draw) -> String
+where
+    Draw: Fn(EvcxrDrawingArea) -> Result<(), Box<dyn std::error::Error>>,
+{
+    let mut buffer = "".to_string();
+    {
+        let root = FileBackend::Svg(SVGBackend::with_string(&mut buffer, size)).into_drawing_area();
+        draw(root).expect("Drawing failure");
+    }
+    buffer
+}
+
+#[cfg(feature = "evcxr_bitmap")]
+fn draw_bitmap<Draw>(size: (u32, u32), draw: &Draw) -> String
+where
+    Draw: Fn(EvcxrDrawingArea) -> Result<(), Box<dyn std::error::Error>>,
+{
+    let mut buf = vec![0; (size.0 as usize) * (size.1 as usize) * 3];
+    {
+        let root =
+            FileBackend::BitMap(BitMapBackend::with_buffer(&mut buf, size)).into_drawing_area();
+        draw(root).expect("Drawing failure");
+    }
+    let mut buffer = "".to_string();
+    {
+        let mut svg_root = SVGBackend::with_string(&mut buffer, size);
+        svg_root
+            .blit_bitmap((0, 0), size, &buf)
+            .expect("Failure converting to SVG");
+    }
+    buffer
+}
+
+/// Draw an evcxr figure with the configuration, which selects the size, the output format and
+/// the caching of the figure. The drawing function may be called more than once, for example,
+/// when the [EvcxrFormat::Auto](enum.EvcxrFormat.html#variant.Auto) format falls back to a
+/// bitmap, thus it should be free of side effects.
+///
+/// The figure cache is shared by all the threads, so the figures can be drawn in parallel.
+///
+/// ```rust,no_run
+/// use plotters::evcxr::{evcxr_figure_with, EvcxrConfig};
+/// use plotters::prelude::*;
+///
+/// let figure = evcxr_figure_with(EvcxrConfig::new().cache_key("cell-1"), |root| {
+///     root.fill(&WHITE)?;
+///     let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+///     chart.draw_series(LineSeries::new((0..=10).map(|x| (x as f64 / 10.0, x as f64 / 10.0)), &RED))?;
+///     Ok(())
+/// });
+/// figure.evcxr_display();
+/// ```
+///
+/// - `config`: The configuration of the figure
+/// - `draw`: The function which draws the figure on the root drawing area
+/// - **returns**: The figure that can be displayed in the notebook
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(doc_cfg, doc(cfg(not(target_arch = "wasm32"))))]
+pub fn evcxr_figure_with<Draw>(config: EvcxrConfig, draw: Draw) -> SVGWrapper
+where
+    Draw: Fn(EvcxrDrawingArea) -> Result<(), Box<dyn std::error::Error>>,
+{
+    let buffer = match config.format {
+        EvcxrFormat::Svg => draw_svg(config.size, &draw),
+        #[cfg(feature = "evcxr_bitmap")]
+        EvcxrFormat::Bitmap => draw_bitmap(config.size, &draw),
+        #[cfg(feature = "evcxr_bitmap")]
+        EvcxrFormat::Auto(max_svg_size) => {
+            let svg = draw_svg(config.size, &draw);
+            if svg.len() > max_svg_size {
+                draw_bitmap(config.size, &draw)
+            } else {
+                svg
+            }
+        }
+    };
+
+    let figure = SVGWrapper(buffer, config.style);
+
+    if let Some(key) = config.cache_key {
+        if let Ok(mut cache) = FIGURE_CACHE.lock() {
+            cache.insert(key, figure.clone());
+        }
+    }
+
+    figure
+}
+
+/// Get the last figure drawn by [evcxr_figure_with](fn.evcxr_figure_with.html) with the cache
+/// key, see [EvcxrConfig::cache_key](struct.EvcxrConfig.html#method.cache_key).
+///
+/// - `key`: The cache key of the figure
+/// - **returns**: The cached figure, or `None` if there's no figure cached with the key
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(doc_cfg, doc(cfg(not(target_arch = "wasm32"))))]
+pub fn evcxr_cached_figure(key: &str) -> Option<SVGWrapper> {
+    FIGURE_CACHE.lock().ok()?.get(key).cloned()
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_evcxr_figure_with() {
+        let config = EvcxrConfig::new()
+            .size((100, 50))
+            .cache_key("test_evcxr_figure_with");
+        let figure = evcxr_figure_with(config, |root| {
+            root.fill(&WHITE)?;
+            Ok(())
+        });
+
+        assert!(figure.0.contains("width=\"100\" height=\"50\""));
+        assert_eq!(
+            evcxr_cached_figure("test_evcxr_figure_with").map(|f| f.0),
+            Some(figure.0)
+        );
+        assert!(evcxr_cached_figure("no_such_figure").is_none());
+    }
+}
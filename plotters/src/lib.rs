@@ -672,6 +672,7 @@ The following list is a complete list of features that can be opted in or out.
 | bitmap\_encoder  | Allow `BitMapBackend` to save the result to bitmap files | image, rusttype, font-kit | Yes |
 | svg\_backend     | Enable `SVGBackend` Support | None | Yes |
 | bitmap\_gif| Opt-in GIF animation Rendering support for `BitMapBackend`, implies `bitmap` enabled | gif | Yes |
+| png\_encoder\_mini | Allow `BitMapBackend` to save PNG files with a small built-in encoder, without `bitmap_encoder` | None | No |
 
 - Font manipulation features
 
@@ -713,6 +714,7 @@ pub fn register_font(
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | datetime | Enable the date and time coordinate support | chrono | Yes |
+| time | Enable the date and time coordinate support for the types of the `time` crate | time | No |
 
 - Element, series and util functions
 
@@ -731,6 +733,7 @@ pub fn register_font(
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | deprecated\_items | This feature allows use of deprecated items which is going to be removed in the future | None | Yes |
+| compat | Exposes the 0.2 API names as deprecated aliases, see [compat](coord/compat/index.html) | None | No |
 | debug | Enable the code used for debugging | None | No |
 
 
@@ -801,26 +804,43 @@ pub mod style;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "evcxr")))]
 pub mod evcxr;
 
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "svg_backend", feature = "bitmap_encoder")
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(any(feature = "svg_backend", feature = "bitmap_encoder")))
+)]
+pub use crate::drawing::save;
+
 #[cfg(test)]
-pub use crate::drawing::{check_color, create_mocked_drawing_area};
+pub use crate::drawing::{check_color, create_mocked_drawing_area, record_draw_calls};
 
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
     // Chart related types
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        CaptionPosition, ChartBuilder, ChartContext, LabelAreaConfig, LabelAreaPosition,
+        LabelOverlap, LegendCollector, SeriesLabelPosition, SizeLegend, TickDirection,
+        TickLabelOverlap,
+    };
 
     // Coordinates
     pub use crate::coord::{
         cartesian::Cartesian2d,
         combinators::{
-            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BrokenAxis, BuildNestedCoord,
+            GroupBy, IntoBrokenAxis, IntoLinspace, IntoLogRange, IntoPadded, IntoPartialAxis,
+            IntoPowerScale, Linspace, LogCoord, LogScalable, NestedRange, NestedValue, Padded,
+            PowerCoord, RangedFn, ToGroupByRange,
         },
         ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
-        CoordTranslate,
+        CoordTranslate, NonFinitePolicy,
     };
 
+    pub use crate::coord::types::RangedStdDuration;
+
     #[allow(deprecated)]
     pub use crate::coord::combinators::LogRange;
 
@@ -830,6 +850,14 @@ pub mod prelude {
         IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
     };
 
+    #[cfg(feature = "time")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+    pub use crate::coord::types::{RangedOffsetDateTime, RangedTimeDate, RangedTimeDuration};
+
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+    pub use crate::coord::combinators::{CalendarPeriod, IntoCalendarFiltered, ToGroupByPeriod};
+
     // Re-export the backend for backward compatibility
     pub use plotters_backend::DrawingBackend;
 
@@ -839,18 +867,28 @@ pub mod prelude {
     #[cfg(feature = "area_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
     pub use crate::series::AreaSeries;
+    pub use crate::series::Downsampled;
+    #[cfg(feature = "histogram")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
+    pub use crate::series::GroupedBarSeries;
+    #[cfg(feature = "colormaps")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+    pub use crate::series::HeatmapSeries;
     #[cfg(feature = "histogram")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
     pub use crate::series::Histogram;
     #[cfg(feature = "point_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
     pub use crate::series::PointSeries;
+    pub use crate::series::SpanSeries;
     #[cfg(feature = "surface_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
     pub use crate::series::SurfaceSeries;
     #[cfg(feature = "line_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
-    pub use crate::series::{DashedLineSeries, DottedLineSeries, LineSeries};
+    pub use crate::series::{
+        CurvedLineSeries, DashedLineSeries, DottedLineSeries, EnvelopeSeries, LineSeries, Marker,
+    };
 
     // Styles
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
@@ -864,15 +902,17 @@ pub mod prelude {
     pub use crate::style::colors::colormaps::*;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBAColor,
-        RGBColor, ShapeStyle, TextStyle,
+        AsRelative, Color, DashPattern, DynPalette, FontDesc, FontFamily, FontStyle, FontTransform,
+        HSLColor, IntoFont, IntoTextStyle, LineCap, LineJoin, Palette, Palette100, Palette99,
+        Palette9999, PaletteColor, RGBAColor, RGBColor, ScriptText, ShapeStyle, TextStyle,
     };
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pie, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Arrow, Arrowhead, Circle, ClipPath, ColorBar, ComparisonBracket, CovarianceEllipse, Cross,
+        Cubiod, CurvedPathElement, DynElement, Ellipse, EmptyElement, InfiniteLine, IntoDynElement,
+        MultiLineText, PathElement, Pie, Pixel, Polygon, Rectangle, RichText, SquareMarker, Text,
+        TriangleMarker,
     };
 
     #[cfg(feature = "boxplot")]
@@ -925,12 +965,12 @@ pub mod backend {
     #[cfg(feature = "bitmap_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
     pub use plotters_bitmap::{
-        bitmap_pixel::{BGRXPixel, PixelFormat, RGBPixel},
+        bitmap_pixel::{BGRXPixel, PixelFormat, RGBAPixel, RGBPixel},
         BitMapBackend,
     };
     #[cfg(feature = "svg_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "svg_backend")))]
-    pub use plotters_svg::SVGBackend;
+    pub use plotters_svg::{SVGBackend, SVGUnit};
 }
 
 #[cfg(test)]
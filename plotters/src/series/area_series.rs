@@ -1,4 +1,5 @@
 use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::series::gap::{split_at_gaps, MaybeMissing};
 use crate::style::colors::TRANSPARENT;
 use crate::style::ShapeStyle;
 use plotters_backend::DrawingBackend;
@@ -27,13 +28,29 @@ chart_context.draw_series(AreaSeries::new(x_values.map(|x| (x, 2. - 0.1 * x * x)
 The result is a chart with three line series; one of them has a highlighted blue border:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@b6703f7/apidoc/area_series.svg)
+
+The fill and the border are styled independently, thus a translucent area can still have a
+crisp opaque boundary. Both are drawn by the same series, which gets a single legend entry:
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("area_series_border.svg", (300, 200)).into_drawing_area();
+let mut chart_context = ChartBuilder::on(&drawing_area).build_cartesian_2d(0.0..4.0, 0.0..3.0).unwrap();
+chart_context
+    .draw_series(AreaSeries::new((0..=40).map(|x| (x as f64 / 10.0, 1.5 + (x as f64 / 5.0).sin())), 0.0, TRANSPARENT)
+        .fill(RED.mix(0.2))
+        .border(RED.stroke_width(2)))
+    .unwrap()
+    .label("Signal")
+    .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], RED.mix(0.2).filled()));
+```
 */
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     area_style: ShapeStyle,
     border_style: ShapeStyle,
     baseline: Y,
-    data: Vec<(X, Y)>,
-    state: u32,
+    segments: Vec<Vec<(X, Y)>>,
+    state: usize,
     _p: std::marker::PhantomData<DB>,
 }
 
@@ -47,11 +64,55 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         iter: I,
         baseline: Y,
         area_style: S,
+    ) -> Self {
+        Self::from_segments(vec![iter.into_iter().collect()], baseline, area_style)
+    }
+
+    /**
+    Creates an area series which leaves a gap at the missing data points, instead of filling the
+    area between the data points around them.
+
+    The data points are either `Option`s, where `None` is missing, or the coordinate tuples,
+    where a NaN float value is missing. The area and its border are split into separate parts at
+    the missing data points.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("area_series_gaps.svg", (300, 200)).into_drawing_area();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0..6, 0.0..3.0)
+        .unwrap();
+    let samples = [Some(1.0), Some(1.5), None, Some(2.5), Some(2.0), Some(1.0)];
+    chart_context
+        .draw_series(
+            AreaSeries::with_gaps(
+                samples.iter().enumerate().map(|(x, y)| y.map(|y| (x as i32, y))),
+                0.0,
+                BLUE.mix(0.2),
+            )
+            .border_style(BLUE),
+        )
+        .unwrap();
+    ```
+    */
+    pub fn with_gaps<P, I, S>(iter: I, baseline: Y, area_style: S) -> Self
+    where
+        P: MaybeMissing<(X, Y)>,
+        I: IntoIterator<Item = P>,
+        S: Into<ShapeStyle>,
+    {
+        Self::from_segments(split_at_gaps(iter), baseline, area_style)
+    }
+
+    fn from_segments<S: Into<ShapeStyle>>(
+        segments: Vec<Vec<(X, Y)>>,
+        baseline: Y,
+        area_style: S,
     ) -> Self {
         Self {
             area_style: area_style.into(),
             baseline,
-            data: iter.into_iter().collect(),
+            segments,
             state: 0,
             border_style: (&TRANSPARENT).into(),
             _p: std::marker::PhantomData,
@@ -67,30 +128,112 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         self.border_style = style.into();
         self
     }
+
+    /**
+    Sets the fill style of the area, which replaces the style passed to [`AreaSeries::new`].
+
+    See [`AreaSeries`] for more information and examples.
+    */
+    pub fn fill<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.area_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the boundary line, the same as [`AreaSeries::border_style`].
+
+    See [`AreaSeries`] for more information and examples.
+    */
+    pub fn border<S: Into<ShapeStyle>>(self, style: S) -> Self {
+        self.border_style(style)
+    }
 }
 
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
     type Item = DynElement<'static, DB, (X, Y)>;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.state == 0 {
-            let mut data: Vec<_> = self.data.clone();
+        // Each segment is drawn as the filled area followed by its border
+        let segment = self.segments.get_mut(self.state / 2)?;
+        self.state += 1;
+
+        if self.state % 2 == 1 {
+            let mut data: Vec<_> = segment.clone();
 
             if !data.is_empty() {
                 data.push((data[data.len() - 1].0.clone(), self.baseline.clone()));
                 data.push((data[0].0.clone(), self.baseline.clone()));
             }
 
-            self.state = 1;
-
             Some(Polygon::new(data, self.area_style).into_dyn())
-        } else if self.state == 1 {
-            let data: Vec<_> = self.data.clone();
-
-            self.state = 2;
+        } else {
+            let data = std::mem::take(segment);
 
             Some(PathElement::new(data, self.border_style).into_dyn())
-        } else {
-            None
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_area_series_fill_and_border() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.mix(0.2).to_rgba());
+                assert_eq!(path.len(), 5);
+            });
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 2);
+                assert_eq!(path.len(), 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        chart
+            .draw_series(
+                AreaSeries::new(vec![(0, 5), (5, 8), (10, 5)], 0, BLUE)
+                    .fill(RED.mix(0.2))
+                    .border(RED.stroke_width(2)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_area_series_with_gaps() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+            chart
+                .draw_series(
+                    AreaSeries::with_gaps(
+                        vec![
+                            Some((0, 5)),
+                            Some((2, 8)),
+                            None,
+                            Some((5, 8)),
+                            Some((10, 5)),
+                        ],
+                        0,
+                        BLUE.mix(0.2),
+                    )
+                    .border(BLUE),
+                )
+                .unwrap();
+        });
+
+        // Each part of the area is closed down to the baseline separately
+        let polygons: Vec<_> = calls.polygons.iter().map(|p| p.vert.len()).collect();
+        assert_eq!(polygons, [4, 4]);
+        assert_eq!(calls.paths.len(), 2);
+    }
+}
@@ -0,0 +1,125 @@
+/**
+Reduces a huge number of data points to the data points which make a visible difference, thus
+the time of drawing a series of millions of data points depends on the size of the chart rather
+than the number of the data points.
+
+The data points are split into buckets of consecutive data points, and only the first, the last,
+the minimum and the maximum data points of each bucket are kept, in the original order. As long
+as each bucket covers no more than a pixel column, the downsampled line looks the same as the
+line of all the data points, including the spikes. The data points are expected to be sorted by
+X and roughly evenly spaced, which is the case for the usual time series. The number of the data
+points which fit into the chart is about four times the width of the plotting area.
+
+```rust
+use plotters::prelude::*;
+
+let root = SVGBackend::new("downsampled.svg", (400, 300)).into_drawing_area();
+let mut chart = ChartBuilder::on(&root)
+    .build_cartesian_2d(0.0..1000.0, -1.5..1.5)
+    .unwrap();
+
+let data = (0..1_000_000).map(|x| x as f64 / 1000.0).map(|x| (x, (x * 10.0).sin()));
+let target_points = 4 * chart.plotting_area().dim_in_pixel().0 as usize;
+chart
+    .draw_series(LineSeries::new(Downsampled::new(data, target_points), &RED))
+    .unwrap();
+```
+*/
+pub struct Downsampled<X, Y> {
+    points: std::vec::IntoIter<(X, Y)>,
+}
+
+impl<X, Y: PartialOrd> Downsampled<X, Y> {
+    /**
+    Downsamples the data points.
+
+    - `data`: The data points, sorted by X
+    - `target_points`: The maximum number of the data points after downsampling, the data
+      points are kept as they are if there are no more than that. A target less than 4 is
+      treated as 4, since a bucket always keeps its first, last, minimum and maximum data
+      points
+    - **returns**: The iterator of the downsampled data points
+    */
+    pub fn new<I: IntoIterator<Item = (X, Y)>>(data: I, target_points: usize) -> Self {
+        let data: Vec<_> = data.into_iter().collect();
+        // Each bucket keeps up to 4 data points
+        let buckets = (target_points / 4).max(1);
+        if data.len() <= target_points.max(4) {
+            return Self {
+                points: data.into_iter(),
+            };
+        }
+
+        let mut keep = vec![false; data.len()];
+        // The bounds are computed in u64, since the product may overflow usize on 32-bit targets
+        let bound = |bucket: usize| (bucket as u64 * data.len() as u64 / buckets as u64) as usize;
+        for bucket in 0..buckets {
+            let (start, end) = (bound(bucket), bound(bucket + 1));
+            if start == end {
+                continue;
+            }
+            let (mut min, mut max) = (start, start);
+            for idx in start..end {
+                if data[idx].1 < data[min].1 {
+                    min = idx;
+                }
+                if data[idx].1 > data[max].1 {
+                    max = idx;
+                }
+            }
+            for idx in [start, min, max, end - 1].iter() {
+                keep[*idx] = true;
+            }
+        }
+
+        let points: Vec<_> = data
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(point, keep)| if keep { Some(point) } else { None })
+            .collect();
+        Self {
+            points: points.into_iter(),
+        }
+    }
+}
+
+impl<X, Y> Iterator for Downsampled<X, Y> {
+    type Item = (X, Y);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.points.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_downsampled() {
+        // A sawtooth with a single spike
+        let data = (0..1000).map(|x| (x, if x == 567 { 100 } else { x % 10 }));
+        let points: Vec<_> = Downsampled::new(data, 40).collect();
+
+        assert!(points.len() <= 40);
+        assert_eq!(points[0], (0, 0));
+        assert_eq!(points[points.len() - 1], (999, 9));
+        assert!(points.contains(&(567, 100)));
+        assert!(points.windows(2).all(|w| w[0].0 < w[1].0));
+        // Each bucket of 100 data points keeps its first point as the minimum, its first 9 as the
+        // maximum and its last point, except the spike replaces the maximum of its bucket
+        assert_eq!(points.iter().filter(|(_, y)| *y == 0).count(), 10);
+        assert_eq!(points.iter().filter(|(_, y)| *y == 9).count(), 19);
+        assert_eq!(points.len(), 30);
+
+        let points: Vec<_> = Downsampled::new(vec![(0, 1), (1, 2), (2, 3)], 2).collect();
+        assert_eq!(points, [(0, 1), (1, 2), (2, 3)]);
+
+        // The target is at least 4 data points
+        let points: Vec<_> = Downsampled::new((0..100).map(|x| (x, x % 7)), 1).collect();
+        assert_eq!(points, [(0, 0), (6, 6), (99, 1)]);
+    }
+}
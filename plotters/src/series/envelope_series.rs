@@ -0,0 +1,145 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+An envelope series, which draws a large number of points as one vertical line per pixel column,
+from the minimum to the maximum of the points in that column, like the waveform display of an
+audio editor.
+
+The rendering cost depends on the width of the plotting area rather than the number of points,
+but no extreme value is visually lost, thus the peaks of the data are still visible. The
+consecutive columns are connected, so the series looks like a line series where the data is
+sparse.
+
+The points should be sorted by the X coordinate, the points in the same pixel column are grouped
+only if they are adjacent in the data.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("envelope_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..1.0, -1.5..1.5)
+    .unwrap();
+chart_context
+    .draw_series(EnvelopeSeries::new(
+        (0..100_000).map(|i| {
+            let x = i as f64 / 100_000.0;
+            (x, (x * 2000.0).sin() * (x * 10.0).cos())
+        }),
+        BLUE,
+    ))
+    .unwrap();
+```
+*/
+pub struct EnvelopeSeries<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> EnvelopeSeries<Coord> {
+    /// Create a new envelope series
+    /// - `points`: The iterator of the points, sorted by the X coordinate
+    /// - `style`: The shape style of the vertical lines
+    /// - returns the created series
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(points: I, style: S) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<Coord> IntoIterator for EnvelopeSeries<Coord> {
+    type Item = Envelope<Coord>;
+    type IntoIter = std::iter::Once<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(Envelope {
+            points: self.points,
+            style: self.style,
+        })
+    }
+}
+
+/// The element drawn by [`EnvelopeSeries`], which reduces the points to the min-max line of each
+/// pixel column after they are mapped to the backend coordinate
+pub struct Envelope<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Envelope<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Envelope<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        // The current column, as (x, min y, max y, last y)
+        let mut column: Option<(i32, i32, i32, i32)> = None;
+        for (x, y) in points {
+            match column.as_mut() {
+                Some((cx, min, max, last)) if *cx == x => {
+                    *min = (*min).min(y);
+                    *max = (*max).max(y);
+                    *last = y;
+                }
+                _ => {
+                    if let Some((cx, min, max, last)) = column {
+                        backend.draw_line((cx, min), (cx, max), &self.style)?;
+                        backend.draw_line((cx, last), (x, y), &self.style)?;
+                    }
+                    column = Some((x, y, y, y));
+                }
+            }
+        }
+        if let Some((cx, min, max, _)) = column {
+            backend.draw_line((cx, min), (cx, max), &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_envelope_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLUE.to_rgba());
+                // Every column covers both peaks
+                if from.0 == to.0 {
+                    assert_eq!((from.1, to.1), (0, 99));
+                }
+            });
+            m.drop_check(|b| {
+                // One vertical line for each of the 100 columns, and 99 connections
+                assert_eq!(b.num_draw_line_call, 199);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+
+        chart
+            .draw_series(EnvelopeSeries::new(
+                // 1000 points in each column
+                (0..100_000).map(|i| ((i / 1000) as f64 / 99.0, (i % 7) as f64 / 6.0)),
+                BLUE,
+            ))
+            .unwrap();
+    }
+}
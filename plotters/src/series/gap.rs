@@ -0,0 +1,111 @@
+/// The value of a coordinate which may be missing, such as a NaN float. The series created with
+/// `with_gaps`, for example [`LineSeries::with_gaps`](struct.LineSeries.html#method.with_gaps),
+/// leave a gap at the data points with a missing value.
+pub trait MissingValue {
+    /// Check if the value is missing, the value is never missing by default
+    fn is_missing(&self) -> bool {
+        false
+    }
+}
+
+impl MissingValue for f32 {
+    fn is_missing(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl MissingValue for f64 {
+    fn is_missing(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+macro_rules! impl_never_missing {
+    ($($t:ty),*) => {
+        $(impl MissingValue for $t {})*
+    };
+}
+
+impl_never_missing!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A data point which may be missing, which is taken by the series created with `with_gaps`.
+///
+/// The missing data points are either `None`, or the tuples containing a
+/// [MissingValue](trait.MissingValue.html) that is missing, such as `(1.0, f64::NAN)`.
+pub trait MaybeMissing<Coord> {
+    /// Get the data point, or `None` if the data point is missing
+    fn into_present(self) -> Option<Coord>;
+}
+
+impl<Coord> MaybeMissing<Coord> for Option<Coord> {
+    fn into_present(self) -> Option<Coord> {
+        self
+    }
+}
+
+impl<X: MissingValue, Y: MissingValue> MaybeMissing<(X, Y)> for (X, Y) {
+    fn into_present(self) -> Option<(X, Y)> {
+        if self.0.is_missing() || self.1.is_missing() {
+            return None;
+        }
+        Some(self)
+    }
+}
+
+impl<X: MissingValue, Y: MissingValue, Z: MissingValue> MaybeMissing<(X, Y, Z)> for (X, Y, Z) {
+    fn into_present(self) -> Option<(X, Y, Z)> {
+        if self.0.is_missing() || self.1.is_missing() || self.2.is_missing() {
+            return None;
+        }
+        Some(self)
+    }
+}
+
+/// Split the data points into the runs of the present data points, the empty runs are dropped
+#[cfg(any(feature = "line_series", feature = "area_series"))]
+pub(crate) fn split_at_gaps<Coord, P, I>(iter: I) -> Vec<Vec<Coord>>
+where
+    P: MaybeMissing<Coord>,
+    I: IntoIterator<Item = P>,
+{
+    let mut segments = vec![];
+    let mut current = vec![];
+    for point in iter {
+        match point.into_present() {
+            Some(point) => current.push(point),
+            None if !current.is_empty() => segments.push(std::mem::take(&mut current)),
+            None => {}
+        }
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+#[cfg(all(test, feature = "line_series"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_at_gaps() {
+        let data = vec![
+            (0.0, f64::NAN),
+            (1.0, 1.0),
+            (2.0, 2.0),
+            (f64::NAN, 3.0),
+            (4.0, f64::NAN),
+            (5.0, 5.0),
+        ];
+        assert_eq!(
+            split_at_gaps(data),
+            [vec![(1.0, 1.0), (2.0, 2.0)], vec![(5.0, 5.0)]]
+        );
+        assert_eq!(
+            split_at_gaps(vec![Some(1), None, Some(2), Some(3), None]),
+            [vec![1], vec![2, 3]]
+        );
+        assert!(split_at_gaps::<(i32, i32), _, _>(vec![None, None]).is_empty());
+        assert_eq!(split_at_gaps(vec![(1, 2)]), [vec![(1, 2)]]);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+use plotters_backend::DrawingBackend;
+
+struct BarGroupMember<A> {
+    name: String,
+    style: ShapeStyle,
+    values: Vec<(usize, A)>,
+}
+
+/**
+Presents multiple series over the same categories as grouped bars, which are drawn side by side
+within the segment of each category.
+
+The bars of a category share the width of the segment, the offsets and the widths of the bars
+are computed from the number of the series, thus no manual offset math is needed. Each series
+gets its own legend entry, which is shown by [`ChartContext::configure_series_labels()`].
+
+```rust
+use plotters::prelude::*;
+
+let root = SVGBackend::new("grouped_bar_series.svg", (400, 300)).into_drawing_area();
+let mut chart = ChartBuilder::on(&root)
+    .build_cartesian_2d((0..3).into_segmented(), 0..10)
+    .unwrap();
+
+GroupedBarSeries::vertical(&chart)
+    .margin(10)
+    .gap(2)
+    .series("2023", RED.filled(), vec![(0, 3), (1, 5), (2, 7)])
+    .series("2024", BLUE.filled(), vec![(0, 4), (1, 6), (2, 9)])
+    .draw(&mut chart)
+    .unwrap();
+chart.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct GroupedBarSeries<BR: DiscreteRanged, A> {
+    br: BR,
+    pixel_limit: (i32, i32),
+    margin: u32,
+    gap: u32,
+    members: Vec<BarGroupMember<A>>,
+}
+
+impl<BR, A> GroupedBarSeries<BR, A>
+where
+    BR: DiscreteRanged + Clone,
+    A: Default,
+{
+    /**
+    Creates a grouped bar series with the vertical bars, the categories are on the X axis.
+
+    - `parent`: The chart which the bars are drawn on
+    - **returns**: The newly created series, without any bars
+    */
+    pub fn vertical<ACoord, DB: DrawingBackend>(
+        parent: &ChartContext<DB, Cartesian2d<BR, ACoord>>,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        let coord = parent.as_coord_spec();
+        let pixel_range = coord.get_x_axis_pixel_range();
+        Self {
+            br: coord.x_spec().clone(),
+            pixel_limit: (pixel_range.start, pixel_range.end),
+            margin: 5,
+            gap: 0,
+            members: vec![],
+        }
+    }
+
+    /**
+    Sets the margin on both sides of each group of bars, in backend pixels.
+
+    - `value`: The margin
+    */
+    pub fn margin(mut self, value: u32) -> Self {
+        self.margin = value;
+        self
+    }
+
+    /**
+    Sets the gap between the adjacent bars in a group, in backend pixels.
+
+    - `value`: The gap
+    */
+    pub fn gap(mut self, value: u32) -> Self {
+        self.gap = value;
+        self
+    }
+
+    /**
+    Appends a series, which is drawn right to the previously appended series in each group.
+
+    - `name`: The name of the series shown in the legend, the series without a name has no
+      legend entry
+    - `style`: The style of the bars
+    - `data`: The categories and the values of the bars, the categories out of the range are
+      ignored
+    */
+    pub fn series<N, S, TB, I>(mut self, name: N, style: S, data: I) -> Self
+    where
+        N: Into<String>,
+        S: Into<ShapeStyle>,
+        TB: Into<BR::ValueType>,
+        I: IntoIterator<Item = (TB, A)>,
+    {
+        let br = &self.br;
+        let values = data
+            .into_iter()
+            .filter_map(|(x, y)| br.index_of(&x.into()).map(|idx| (idx, y)))
+            .collect();
+        self.members.push(BarGroupMember {
+            name: name.into(),
+            style: style.into(),
+            values,
+        });
+        self
+    }
+
+    // The left and the right margins of the n-th bar in the segment of the category
+    fn bar_margins(&self, idx: usize, nth: usize, count: usize) -> Option<(u32, u32)> {
+        let (start, end) = (self.br.from_index(idx)?, self.br.from_index(idx + 1)?);
+        let (a, b) = (
+            self.br.map(&start, self.pixel_limit),
+            self.br.map(&end, self.pixel_limit),
+        );
+        let segment = (b - a).abs();
+        let (margin, gap) = (self.margin as i32, self.gap as i32);
+        let pitch = f64::from((segment - 2 * margin).max(0) + gap) / count as f64;
+        let left = margin + (pitch * nth as f64).round() as i32;
+        let right = (margin + (pitch * (nth + 1) as f64).round() as i32 - gap).max(left + 1);
+        Some((left.max(0) as u32, (segment - right).max(0) as u32))
+    }
+
+    /**
+    Draws the bars on the chart, and appends a legend entry for each named series.
+
+    - `chart`: The chart which the bars are drawn on
+    */
+    pub fn draw<'a, DB, ACoord>(
+        mut self,
+        chart: &mut ChartContext<'a, DB, Cartesian2d<BR, ACoord>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend + 'a,
+        ACoord: Ranged<ValueType = A>,
+    {
+        let count = self.members.len();
+        for (nth, member) in std::mem::take(&mut self.members).into_iter().enumerate() {
+            let style = member.style;
+            let bars = member.values.into_iter().filter_map(|(idx, value)| {
+                let (left, right) = self.bar_margins(idx, nth, count)?;
+                let (x, nx) = (self.br.from_index(idx)?, self.br.from_index(idx + 1)?);
+                let mut rect = Rectangle::new([(x, value), (nx, A::default())], style);
+                rect.set_margin(0, 0, left, right);
+                Some(rect)
+            });
+            let anno = chart.draw_series(bars)?;
+            if !member.name.is_empty() {
+                anno.label(member.name)
+                    .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], style));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_grouped_bar_series() {
+        let calls = record_draw_calls(200, 100, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d((0..1).into_segmented(), 0..10)
+                .expect("Create chart");
+            GroupedBarSeries::vertical(&chart)
+                .margin(10)
+                .gap(4)
+                .series("A", RED.filled(), vec![(0, 5), (1, 10)])
+                .series("B", BLUE.filled(), vec![(0, 2), (5, 3)])
+                .series("", GREEN.filled(), vec![(1, 1)])
+                .draw(&mut chart)
+                .expect("Drawing error");
+
+            let labels: Vec<_> = chart.series_anno.iter().map(|a| a.get_label()).collect();
+            assert_eq!(labels, ["A", "B", ""]);
+            assert!(chart.series_anno[2].get_draw_func().is_none());
+        });
+
+        // The categories are about 100 pixels wide, thus each bar gets about
+        // (100 - 2 * 10 + 4) / 3 = 28 pixels including the gap
+        let bars = &calls.rects;
+        let spans: Vec<_> = bars
+            .iter()
+            .map(|r| (r.upper_left.0, r.bottom_right.0))
+            .collect();
+        assert_eq!(spans, [(10, 34), (109, 133), (38, 61), (165, 189)]);
+        assert_eq!(bars[0].color, RED.to_rgba());
+        assert_eq!(bars[2].color, BLUE.to_rgba());
+    }
+}
@@ -0,0 +1,218 @@
+use std::ops::Range;
+
+use crate::element::Rectangle;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, RGBAColor, ShapeStyle};
+
+/**
+Presents a 2D grid of values as a heatmap, each cell is filled with the color which the colormap
+assigns to the value of the cell.
+
+The values are normalized to the range of the colormap automatically, from the minimum to the
+maximum of the finite values in the grid, unless the range is set by
+[`HeatmapSeries::value_range()`]. The cells of the non-finite values, such as `NaN`, are left
+blank.
+
+```rust
+use plotters::prelude::*;
+
+let root = SVGBackend::new("heatmap_series.svg", (300, 300)).into_drawing_area();
+let mut chart = ChartBuilder::on(&root)
+    .build_cartesian_2d(0..3, 0..2)
+    .unwrap();
+
+let values = [[0.0, 1.0, 2.0], [3.0, 4.0, f64::NAN]];
+chart
+    .draw_series(
+        HeatmapSeries::new(0..=3, 0..=2, &values, ViridisRGBA).outline(BLACK.stroke_width(1)),
+    )
+    .unwrap();
+```
+*/
+pub struct HeatmapSeries<'a, X, Y> {
+    x_edges: Vec<X>,
+    y_edges: Vec<Y>,
+    cells: Vec<(usize, usize, f64)>,
+    colormap: Box<dyn Fn(f64) -> RGBAColor + 'a>,
+    value_range: Range<f64>,
+    outline: Option<ShapeStyle>,
+    idx: usize,
+}
+
+impl<'a, X: Clone, Y: Clone> HeatmapSeries<'a, X, Y> {
+    /**
+    Creates a heatmap series.
+
+    - `x_edges`: The boundaries of the columns on the X axis, the n-th column of the grid is
+      between the n-th and the (n+1)-th boundaries
+    - `y_edges`: The boundaries of the rows on the Y axis, the n-th row of the grid is between
+      the n-th and the (n+1)-th boundaries
+    - `values`: The rows of the grid, the cells without the boundaries are ignored
+    - `colormap`: The colormap which assigns the colors to the values
+    - **returns**: The newly created series
+    */
+    pub fn new<XI, YI, R, V, C, M>(x_edges: XI, y_edges: YI, values: &[R], colormap: M) -> Self
+    where
+        XI: IntoIterator<Item = X>,
+        YI: IntoIterator<Item = Y>,
+        R: AsRef<[V]>,
+        V: Into<f64> + Copy,
+        C: Color,
+        M: ColorMap<C, f64> + 'a,
+    {
+        let x_edges: Vec<_> = x_edges.into_iter().collect();
+        let y_edges: Vec<_> = y_edges.into_iter().collect();
+        let (columns, rows) = (
+            x_edges.len().saturating_sub(1),
+            y_edges.len().saturating_sub(1),
+        );
+
+        let cells: Vec<_> = values
+            .iter()
+            .take(rows)
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.as_ref()
+                    .iter()
+                    .take(columns)
+                    .enumerate()
+                    .map(move |(x, value)| (x, y, (*value).into()))
+            })
+            .filter(|(_, _, value)| value.is_finite())
+            .collect();
+
+        let value_range = cells
+            .iter()
+            .fold(f64::INFINITY..f64::NEG_INFINITY, |range, (_, _, value)| {
+                range.start.min(*value)..range.end.max(*value)
+            });
+
+        Self {
+            x_edges,
+            y_edges,
+            cells,
+            colormap: Box::new(move |h| colormap.get_color(h).to_rgba()),
+            value_range,
+            outline: None,
+            idx: 0,
+        }
+    }
+
+    /**
+    Sets the range of the values which is mapped to the colormap, the values out of the range
+    get the colors at the ends of the colormap. This is useful to share the same scale among
+    multiple heatmaps.
+
+    - `range`: The range of the values
+    */
+    pub fn value_range(mut self, range: Range<f64>) -> Self {
+        self.value_range = range;
+        self
+    }
+
+    /**
+    Draws the outline of each cell, which is drawn after all the cells are filled.
+
+    - `style`: The style of the outline
+    */
+    pub fn outline<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.outline = Some(style.into());
+        self
+    }
+
+    // Maps the value to the position in the colormap, the position is in the middle of the
+    // colormap if all the values are the same
+    fn normalize(&self, value: f64) -> f64 {
+        let Range { start, end } = self.value_range;
+        if end > start {
+            ((value - start) / (end - start)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    }
+}
+
+impl<'a, X: Clone, Y: Clone> Iterator for HeatmapSeries<'a, X, Y> {
+    type Item = Rectangle<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = self.cells.len();
+        let (nth, style) = if self.idx < count {
+            let value = self.cells[self.idx].2;
+            (self.idx, (self.colormap)(self.normalize(value)).filled())
+        } else {
+            (self.idx - count, self.outline?)
+        };
+        let &(x, y, _) = self.cells.get(nth)?;
+        self.idx += 1;
+        Some(Rectangle::new(
+            [
+                (self.x_edges[x].clone(), self.y_edges[y].clone()),
+                (self.x_edges[x + 1].clone(), self.y_edges[y + 1].clone()),
+            ],
+            style,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    // Draws the series on a mocked chart, returns the drawn rectangles
+    fn draw_heatmap(series: HeatmapSeries<i32, i32>) -> Vec<DrawnRect> {
+        record_draw_calls(300, 100, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..3, 0..1)
+                .expect("Create chart");
+            chart.draw_series(series).expect("Drawing error");
+        })
+        .rects
+    }
+
+    #[test]
+    fn test_heatmap_series() {
+        let values = [vec![1, 3, 9], vec![5]];
+        let rects =
+            draw_heatmap(HeatmapSeries::new(0..=2, 0..=1, &values, BlackWhite).outline(RED));
+
+        // Only the cells within the boundaries are drawn, normalized from 1 to 3, and the
+        // outlines are drawn after the cells
+        let colors: Vec<_> = rects.iter().map(|r| (r.color, r.fill)).collect();
+        assert_eq!(
+            colors,
+            [
+                (BlackWhite.get_color(0.0).to_rgba(), true),
+                (BlackWhite.get_color(1.0).to_rgba(), true),
+                (RED.to_rgba(), false),
+                (RED.to_rgba(), false),
+            ]
+        );
+        assert_eq!(
+            (rects[0].upper_left, rects[0].bottom_right),
+            (rects[2].upper_left, rects[2].bottom_right)
+        );
+        assert_eq!(rects[1].upper_left.0, rects[0].bottom_right.0);
+    }
+
+    #[test]
+    fn test_heatmap_value_range() {
+        let values = [[0.0, f64::NAN, 10.0]];
+        let rects = draw_heatmap(
+            HeatmapSeries::new(0..=3, 0..=1, &values, BlackWhite).value_range(0.0..5.0),
+        );
+        let colors: Vec<_> = rects.iter().map(|r| r.color).collect();
+        assert_eq!(
+            colors,
+            [
+                BlackWhite.get_color(0.0).to_rgba(),
+                BlackWhite.get_color(1.0).to_rgba()
+            ]
+        );
+
+        let uniform = [[2.0, 2.0]];
+        let rects = draw_heatmap(HeatmapSeries::new(0..=2, 0..=1, &uniform, BlackWhite));
+        assert!(rects
+            .iter()
+            .all(|r| r.color == BlackWhite.get_color(0.5).to_rgba()));
+    }
+}
@@ -1,11 +1,11 @@
-use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
+use std::collections::{btree_map::IntoIter as BTreeMapIter, BTreeMap};
 use std::marker::PhantomData;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Range};
 
 use crate::chart::ChartContext;
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{DiscreteRanged, Ranged};
-use crate::element::Rectangle;
+use crate::element::{PathElement, Rectangle};
 use crate::style::{Color, ShapeStyle, GREEN};
 use plotters_backend::DrawingBackend;
 
@@ -55,6 +55,11 @@ replaced by `1..4`:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@a617d37/apidoc/histogram_not_segmented.svg)
 
+The discrete range may also be descending, such as `(10..1).into_segmented()`, in which case the
+first value of the range is placed at the start of the axis. This is handy for ranked bar charts:
+with [`Histogram::horizontal()`], the bar of rank 1 is drawn on the top without reversing the data
+or the labels.
+
 [`Histogram::style()`] sets the style of the bars. Here is a histogram without `.filled()`:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@a617d37/apidoc/histogram_hollow.svg)
@@ -85,8 +90,12 @@ where
 {
     style: Box<dyn Fn(&BR::ValueType, &A) -> ShapeStyle + 'a>,
     margin: u32,
-    iter: HashMapIter<usize, A>,
+    snap_to_pixels: bool,
+    pixel_limit: (i32, i32),
+    iter: BTreeMapIter<usize, A>,
     baseline: Box<dyn Fn(&BR::ValueType) -> A + 'a>,
+    value_range: Option<Range<A>>,
+    clip: Option<Box<dyn Fn(A) -> A + 'a>>,
     br: BR,
     _p: PhantomData<Tag>,
 }
@@ -97,12 +106,16 @@ where
     A: AddAssign<A> + Default + 'a,
     Tag: HistogramType,
 {
-    fn empty(br: &BR) -> Self {
+    fn empty(br: &BR, pixel_range: Range<i32>, value_range: Range<A>) -> Self {
         Self {
             style: Box::new(|_, _| GREEN.filled()),
             margin: 5,
-            iter: HashMap::new().into_iter(),
+            snap_to_pixels: false,
+            pixel_limit: (pixel_range.start, pixel_range.end),
+            iter: BTreeMap::new().into_iter(),
             baseline: Box::new(|_| A::default()),
+            value_range: Some(value_range),
+            clip: None,
             br: br.clone(),
             _p: PhantomData,
         }
@@ -158,6 +171,52 @@ where
         self
     }
 
+    /**
+    Clips the bars to the range of the value axis.
+
+    When the value axis doesn't start at zero, the bars starting from the default baseline are
+    silently cut by the edge of the plotting area. With this option the values and the baselines
+    of the bars are clamped to the axis range, thus the bars explicitly start from the edge of the
+    axis. Combine this with [`crate::chart::MeshStyle::y_axis_truncated()`] (or
+    [`crate::chart::MeshStyle::x_axis_truncated()`] for a horizontal histogram) to mark the
+    truncated axis explicitly.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("histogram_truncated.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(20)
+        .build_cartesian_2d((1..3).into_segmented(), 90..100)
+        .unwrap();
+    chart.configure_mesh().y_axis_truncated().draw().unwrap();
+    chart
+        .draw_series(Histogram::vertical(&chart).clip_to_axis().data(vec![(1, 95), (2, 98), (3, 92)]))
+        .unwrap();
+    ```
+    */
+    pub fn clip_to_axis(mut self) -> Self
+    where
+        A: PartialOrd + Clone,
+    {
+        if let Some(Range { start, end }) = self.value_range.take() {
+            let (min, max) = if start <= end {
+                (start, end)
+            } else {
+                (end, start)
+            };
+            self.clip = Some(Box::new(move |value| {
+                if value < min {
+                    min.clone()
+                } else if value > max {
+                    max.clone()
+                } else {
+                    value
+                }
+            }));
+        }
+        self
+    }
+
     /**
     Sets the margin for each bar, in backend pixels.
 
@@ -168,16 +227,34 @@ where
         self
     }
 
+    /**
+    Snaps the bars to the device pixels.
+
+    The bars are positioned independently, so the rounding of their edges makes the bars of a
+    dense histogram differ in width by one pixel, which shows up as a moiré pattern. When the
+    snapping is enabled, all the bars have the same width in pixels, which is the narrowest bar
+    width without snapping, and the rounding error is distributed evenly to the gaps between
+    the bars.
+    */
+    pub fn snap_to_pixels(mut self, value: bool) -> Self {
+        self.snap_to_pixels = value;
+        self
+    }
+
     /**
     Specifies the input data for the histogram through an appropriate data iterator.
 
+    The items are `(category, value)` pairs for both the vertical and the horizontal histograms,
+    the values of the same category are summed up, and the bars are drawn in the order of the
+    categories along the discrete axis.
+
     See [`Histogram`] for more information and examples.
     */
     pub fn data<TB: Into<BR::ValueType>, I: IntoIterator<Item = (TB, A)>>(
         mut self,
         iter: I,
     ) -> Self {
-        let mut buffer = HashMap::<usize, A>::new();
+        let mut buffer = BTreeMap::<usize, A>::new();
         for (x, y) in iter.into_iter() {
             if let Some(x) = self.br.index_of(&x.into()) {
                 *buffer.entry(x).or_default() += y;
@@ -188,6 +265,89 @@ where
     }
 }
 
+impl<'a, BR, A, Tag> Histogram<'a, BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+    Tag: HistogramType,
+{
+    /// Get the snapped size of the bars along the discrete axis in pixels, if snapping is enabled
+    fn snapped_bar_size(&self) -> Option<u32> {
+        if !self.snap_to_pixels {
+            return None;
+        }
+        let n = self.br.size();
+        let first = self.br.from_index(0)?;
+        let last = self.br.from_index(n.checked_sub(1).filter(|&n| n > 0)?)?;
+        let len =
+            (self.br.map(&last, self.pixel_limit) - self.br.map(&first, self.pixel_limit)).abs();
+        let pitch = len as u32 / (n - 1) as u32;
+        Some(pitch.saturating_sub(2 * self.margin).max(1))
+    }
+
+    /// Clip the value and the baseline of a bar, if clipping is enabled
+    fn clip_bar(&self, value: A, base: A) -> (A, A) {
+        match self.clip.as_ref() {
+            Some(clip) => (clip(value), clip(base)),
+            None => (value, base),
+        }
+    }
+
+    /// Compute the step profile of the bars as (position, value) pairs, together with the style
+    /// of the first bar
+    fn outline_points(mut self) -> (Vec<(BR::ValueType, A)>, Option<ShapeStyle>)
+    where
+        A: Clone,
+    {
+        let bars: Vec<_> = std::mem::replace(&mut self.iter, BTreeMap::new().into_iter())
+            .filter(|(idx, _)| self.br.from_index(idx + 1).is_some())
+            .collect();
+
+        let mut style = None;
+        let mut points = vec![];
+        // The index and the baseline of the previous bar
+        let mut prev: Option<(usize, A)> = None;
+        for (idx, value) in bars {
+            // The position is needed twice, but the value type isn't required to be cloneable
+            let (start, pos, next) = match (
+                self.br.from_index(idx),
+                self.br.from_index(idx),
+                self.br.from_index(idx + 1),
+            ) {
+                (Some(start), Some(pos), Some(next)) => (start, pos, next),
+                _ => continue,
+            };
+            let base = (self.baseline)(&pos);
+            style.get_or_insert_with(|| (self.style)(&pos, &value));
+            let (value, base) = self.clip_bar(value, base);
+
+            match prev.take() {
+                // The profile steps directly to the adjacent bar
+                Some((prev_idx, _)) if prev_idx + 1 == idx => {}
+                // Otherwise it drops to the baseline of the previous bar and rises from the
+                // baseline of this bar
+                prev_bar => {
+                    if let Some((prev_idx, prev_base)) = prev_bar {
+                        if let Some(prev_end) = self.br.from_index(prev_idx + 1) {
+                            points.push((prev_end, prev_base));
+                        }
+                    }
+                    points.push((start, base.clone()));
+                }
+            }
+            points.push((pos, value.clone()));
+            points.push((next, value));
+            prev = Some((idx, base));
+        }
+        if let Some((prev_idx, prev_base)) = prev {
+            if let Some(prev_end) = self.br.from_index(prev_idx + 1) {
+                points.push((prev_end, prev_base));
+            }
+        }
+        (points, style)
+    }
+}
+
 impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged + Clone,
@@ -204,9 +364,13 @@ where
     where
         ACoord: Ranged<ValueType = A>,
     {
-        let dp = parent.as_coord_spec().x_spec();
+        let coord = parent.as_coord_spec();
 
-        Self::empty(dp)
+        Self::empty(
+            coord.x_spec(),
+            coord.get_x_axis_pixel_range(),
+            coord.get_y_range(),
+        )
     }
 }
 
@@ -216,7 +380,42 @@ where
     A: AddAssign<A> + Default + 'a,
 {
     /**
-    Creates a horizontal histogram.
+    Creates a horizontal histogram, the categories are on the Y axis and the bars grow along the
+    X axis.
+
+    The builder methods work the same as the vertical histogram: [`Histogram::baseline()`] and
+    [`Histogram::baseline_func()`] set the X position where the bars start, and the closure of
+    [`Histogram::style_func()`] takes the category and the value of each bar. A segmented Y axis
+    centers the bars and the labels on the categories, and the value axis can be formatted
+    freely.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("histogram_bar.svg", (400, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(40)
+        .build_cartesian_2d(0.0..100.0, (0..3).into_segmented())
+        .unwrap();
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .x_label_formatter(&|x| format!("{:.0}%", x))
+        .y_label_formatter(&|y| match y {
+            SegmentValue::CenterOf(v) => ["Rust", "C++", "Go", "Zig"][*v as usize].to_string(),
+            _ => String::new(),
+        })
+        .draw()
+        .unwrap();
+    chart
+        .draw_series(
+            Histogram::horizontal(&chart)
+                .margin(8)
+                .baseline(10.0)
+                .style_func(|_, share| if *share > 50.0 { RED.filled() } else { BLUE.filled() })
+                .data(vec![(0, 87.0), (1, 45.0), (2, 30.5), (3, 12.0)]),
+        )
+        .unwrap();
+    ```
 
     See [`Histogram`] for more information and examples.
     */
@@ -226,8 +425,68 @@ where
     where
         ACoord: Ranged<ValueType = A>,
     {
-        let dp = parent.as_coord_spec().y_spec();
-        Self::empty(dp)
+        let coord = parent.as_coord_spec();
+        Self::empty(
+            coord.y_spec(),
+            coord.get_y_axis_pixel_range(),
+            coord.get_x_range(),
+        )
+    }
+}
+
+impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default + Clone,
+{
+    /**
+    Draws only the outline of the histogram: the step profile along the tops of the bars, without
+    the fills and the internal vertical lines. This is the usual way to overlay multiple
+    distributions without occluding each other.
+
+    The outline uses the style of the first bar, the margins of the bars are ignored.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("histogram_outline.svg", (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d((0..9).into_segmented(), 0..10)
+        .unwrap();
+    for (data, color) in [([1, 3, 7, 9, 6, 2, 1, 0, 0], RED), ([0, 1, 2, 4, 8, 9, 5, 2, 1], BLUE)].iter() {
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(color.stroke_width(2))
+                    .data(data.iter().enumerate().map(|(x, &y)| (x as i32, y)))
+                    .outline(),
+            )
+            .unwrap();
+    }
+    ```
+    */
+    pub fn outline(self) -> std::option::IntoIter<PathElement<(BR::ValueType, A)>> {
+        let (points, style) = self.outline_points();
+        style
+            .map(|style| PathElement::new(points, style))
+            .into_iter()
+    }
+}
+
+impl<'a, BR, A> Histogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default + Clone,
+{
+    /**
+    Draws only the outline of the histogram, see [`Histogram::outline()`] of the vertical
+    histogram for details.
+    */
+    pub fn outline(self) -> std::option::IntoIter<PathElement<(A, BR::ValueType)>> {
+        let (points, style) = self.outline_points();
+        let points: Vec<_> = points.into_iter().map(|(y, x)| (x, y)).collect();
+        style
+            .map(|style| PathElement::new(points, style))
+            .into_iter()
     }
 }
 
@@ -246,8 +505,10 @@ where
             {
                 let base = (self.baseline)(&x);
                 let style = (self.style)(&x, &y);
+                let (y, base) = self.clip_bar(y, base);
                 let mut rect = Rectangle::new([(x, y), (nx, base)], style);
-                rect.set_margin(0, 0, self.margin, self.margin);
+                rect.set_margin(0, 0, self.margin, self.margin)
+                    .set_fixed_size(self.snapped_bar_size(), None);
                 return Some(rect);
             }
         }
@@ -270,11 +531,171 @@ where
             {
                 let base = (self.baseline)(&y);
                 let style = (self.style)(&y, &x);
+                let (x, base) = self.clip_bar(x, base);
                 let mut rect = Rectangle::new([(x, y), (base, ny)], style);
-                rect.set_margin(self.margin, self.margin, 0, 0);
+                rect.set_margin(self.margin, self.margin, 0, 0)
+                    .set_fixed_size(None, self.snapped_bar_size());
                 return Some(rect);
             }
         }
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_histogram_snap_to_pixels() {
+        let da = crate::create_mocked_drawing_area(101, 100, |m| {
+            m.check_draw_rect(|_, _, _, u, d| {
+                assert_eq!(d.0 - u.0, 2);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 7);
+            });
+        });
+        let mut chart = ChartBuilder::on(&da)
+            .build_cartesian_2d((0..7).into_segmented(), 0..10)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .margin(5)
+                    .snap_to_pixels(true)
+                    .data((0..7).map(|x| (x, 1))),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_histogram_clip_to_axis() {
+        let calls = crate::record_draw_calls(100, 100, |da| {
+            let mut chart = ChartBuilder::on(da)
+                .set_left_and_bottom_label_area_size(20)
+                .build_cartesian_2d((0..1).into_segmented(), 90..100)
+                .unwrap();
+            chart
+                .configure_mesh()
+                .disable_mesh()
+                .y_axis_truncated()
+                .draw()
+                .unwrap();
+            chart
+                .draw_series(
+                    Histogram::vertical(&chart)
+                        .clip_to_axis()
+                        .data(vec![(0, 95), (1, 100)]),
+                )
+                .unwrap();
+        });
+
+        // The bars start from the bottom of the plotting area
+        assert_eq!(calls.rects.len(), 2);
+        assert!(calls
+            .rects
+            .iter()
+            .all(|r| r.bottom_right.1 == 79 && r.upper_left.1 < r.bottom_right.1));
+        // The squiggle crosses the left edge of the plotting area near the bottom
+        assert!(calls
+            .paths
+            .iter()
+            .any(|p| p.path == [(19, 77), (15, 73), (23, 65), (19, 61)]));
+    }
+
+    #[test]
+    fn test_histogram_outline() {
+        let da = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, _, path| {
+                // The profile drops to the baseline around the empty bin
+                assert_eq!(path.len(), 10);
+                let ys: Vec<_> = path.iter().map(|p| p.1).collect();
+                assert_eq!(ys[0], ys[5]);
+                assert_eq!(ys[0], ys[9]);
+                assert!(ys[3] < ys[1] && ys[1] < ys[0]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 0);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&da)
+            .build_cartesian_2d((0..4).into_segmented(), 0..10)
+            .unwrap();
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .data(vec![(0, 2), (1, 5), (3, 1)])
+                    .outline(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_histogram_descending_range() {
+        let calls = crate::record_draw_calls(100, 100, |da| {
+            // Rank 1 is the first value of the range, thus it is the bar on the top
+            let mut chart = ChartBuilder::on(da)
+                .build_cartesian_2d(0..10, (3..1).into_segmented())
+                .unwrap();
+            chart
+                .draw_series(Histogram::horizontal(&chart).margin(0).data(vec![
+                    (1, 9),
+                    (2, 5),
+                    (3, 2),
+                ]))
+                .unwrap();
+        });
+
+        assert_eq!(calls.rects.len(), 3);
+        let mut bars: Vec<_> = calls
+            .rects
+            .iter()
+            .map(|r| (r.upper_left, r.bottom_right))
+            .collect();
+        bars.sort_by_key(|(u, _)| u.1);
+        let widths: Vec<_> = bars.iter().map(|(u, d)| d.0 - u.0).collect();
+        assert!(widths[0] > widths[1] && widths[1] > widths[2]);
+        assert!(bars.windows(2).all(|w| w[0].1 .1 <= w[1].0 .1));
+    }
+
+    #[test]
+    fn test_histogram_horizontal_segmented() {
+        let calls = crate::record_draw_calls(200, 130, |da| {
+            let mut chart = ChartBuilder::on(da)
+                .build_cartesian_2d(0.0..100.0, (0..2).into_segmented())
+                .unwrap();
+            chart
+                .draw_series(
+                    Histogram::horizontal(&chart)
+                        .margin(5)
+                        .baseline(50.0)
+                        .style_func(|y, _| match y {
+                            SegmentValue::Exact(1) => RED.filled(),
+                            _ => BLUE.filled(),
+                        })
+                        .data(vec![(2, 25.0), (0, 100.0), (1, 75.0), (0, -25.0)]),
+                )
+                .unwrap();
+        });
+
+        // The bars are drawn in the order of the categories, from the bottom to the top, and
+        // start from the baseline
+        let bars = &calls.rects;
+        let colors: Vec<_> = bars.iter().map(|r| r.color).collect();
+        assert_eq!(colors, [BLUE.to_rgba(), RED.to_rgba(), BLUE.to_rgba()]);
+        let spans: Vec<_> = bars
+            .iter()
+            .map(|r| (r.upper_left.0, r.bottom_right.0))
+            .collect();
+        assert_eq!(spans, [(99, 149), (99, 149), (49, 99)]);
+        assert!(bars
+            .windows(2)
+            .all(|w| w[1].bottom_right.1 < w[0].upper_left.1));
+        assert!(bars
+            .iter()
+            .all(|r| r.bottom_right.1 - r.upper_left.1 < 130 / 3 - 8));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::element::{
-    Circle, DashedPathElement, DottedPathElement, DynElement, IntoDynElement, PathElement,
+    Arrowhead, Circle, Cross, CurvedPathElement, DashedPathElement, DottedPathElement, DynElement,
+    IntoDynElement, PathElement, SquareMarker, TriangleMarker,
 };
+use crate::series::gap::{split_at_gaps, MaybeMissing};
 use crate::style::{ShapeStyle, SizeDesc};
 use plotters_backend::{BackendCoord, DrawingBackend};
 use std::marker::PhantomData;
@@ -33,29 +35,68 @@ The result is a chart with three line series; two of them have their data points
 */
 pub struct LineSeries<DB: DrawingBackend, Coord> {
     style: ShapeStyle,
-    data: Vec<Coord>,
-    point_idx: usize,
+    segments: Vec<Vec<Coord>>,
+    point_idx: (usize, usize),
+    path_idx: usize,
     point_size: u32,
+    marker: Marker,
+    arrowhead: Option<Arrowhead>,
     phantom: PhantomData<DB>,
 }
 
+/// The shape of the markers drawn on the data points of a [`LineSeries`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Marker {
+    /// A circle
+    Circle,
+    /// A cross
+    Cross,
+    /// A triangle pointing up
+    Triangle,
+    /// A square
+    Square,
+}
+
+impl Marker {
+    fn make_element<DB: DrawingBackend, Coord: Clone + 'static>(
+        self,
+        pos: Coord,
+        size: u32,
+        style: ShapeStyle,
+    ) -> DynElement<'static, DB, Coord> {
+        match self {
+            Marker::Circle => Circle::new(pos, size, style).into_dyn(),
+            Marker::Cross => Cross::new(pos, size, style).into_dyn(),
+            Marker::Triangle => TriangleMarker::new(pos, size, style).into_dyn(),
+            Marker::Square => SquareMarker::new(pos, size, style).into_dyn(),
+        }
+    }
+}
+
 impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for LineSeries<DB, Coord> {
     type Item = DynElement<'static, DB, Coord>;
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.data.is_empty() {
-            if self.point_size > 0 && self.point_idx < self.data.len() {
-                let idx = self.point_idx;
-                self.point_idx += 1;
-                return Some(
-                    Circle::new(self.data[idx].clone(), self.point_size, self.style).into_dyn(),
-                );
+        if self.point_size > 0 {
+            while let Some(segment) = self.segments.get(self.point_idx.0) {
+                let (segment_idx, idx) = self.point_idx;
+                if idx < segment.len() {
+                    self.point_idx.1 += 1;
+                    return Some(self.marker.make_element(
+                        segment[idx].clone(),
+                        self.point_size,
+                        self.style,
+                    ));
+                }
+                self.point_idx = (segment_idx + 1, 0);
             }
-            let mut data = vec![];
-            std::mem::swap(&mut self.data, &mut data);
-            Some(PathElement::new(data, self.style).into_dyn())
-        } else {
-            None
         }
+        let data = std::mem::take(self.segments.get_mut(self.path_idx)?);
+        self.path_idx += 1;
+        let mut path = PathElement::new(data, self.style);
+        if let Some(arrowhead) = self.arrowhead {
+            path = path.arrowhead(arrowhead);
+        }
+        Some(path.into_dyn())
     }
 }
 
@@ -66,11 +107,51 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
     See [`LineSeries`] for more information and examples.
     */
     pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        let data: Vec<_> = iter.into_iter().collect();
+        Self::from_segments(if data.is_empty() { vec![] } else { vec![data] }, style)
+    }
+
+    /**
+    Creates a line series which leaves a gap at the missing data points, instead of connecting
+    the data points around them.
+
+    The data points are either `Option`s, where `None` is missing, or the coordinate tuples,
+    where a NaN float value is missing. The line is split into separate segments at the missing
+    data points, and the markers are only drawn on the present data points.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("line_series_gaps.svg", (300, 200)).into_drawing_area();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..6.0, 0.0..3.0)
+        .unwrap();
+    let samples = [1.0, 1.5, f64::NAN, 2.5, 2.0, f64::NAN, 1.0];
+    chart_context
+        .draw_series(LineSeries::with_gaps(
+            samples.iter().enumerate().map(|(x, y)| (x as f64, *y)),
+            RED,
+        ))
+        .unwrap();
+    ```
+    */
+    pub fn with_gaps<P, I, S>(iter: I, style: S) -> Self
+    where
+        P: MaybeMissing<Coord>,
+        I: IntoIterator<Item = P>,
+        S: Into<ShapeStyle>,
+    {
+        Self::from_segments(split_at_gaps(iter), style)
+    }
+
+    fn from_segments<S: Into<ShapeStyle>>(segments: Vec<Vec<Coord>>, style: S) -> Self {
         Self {
             style: style.into(),
-            data: iter.into_iter().collect(),
+            segments,
             point_size: 0,
-            point_idx: 0,
+            point_idx: (0, 0),
+            path_idx: 0,
+            marker: Marker::Circle,
+            arrowhead: None,
             phantom: PhantomData,
         }
     }
@@ -84,6 +165,62 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
         self.point_size = size;
         self
     }
+
+    /**
+    Draws a marker of the given shape on each data point, in addition to the line.
+
+    The markers use the style of the line, thus they are filled if the style is filled. Since the
+    markers belong to the same series, the series has a single legend entry.
+
+    - `marker`: The shape of the markers
+    - `size`: The size of the markers, in pixels
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("line_series_markers.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+        .unwrap();
+    chart_context
+        .draw_series(
+            LineSeries::new((0..5).map(|x| (x as f64, 0.5 * x as f64)), BLUE.filled())
+                .with_markers(Marker::Square, 3),
+        )
+        .unwrap()
+        .label("With square markers")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+    ```
+    */
+    pub fn with_markers(mut self, marker: Marker, size: u32) -> Self {
+        self.marker = marker;
+        self.point_size = size;
+        self
+    }
+
+    /**
+    Draws arrowheads on the line, which show the direction of the data, such as a trajectory.
+
+    See [`Arrowhead`] for the options.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("line_series_arrowhead.svg", (300, 200)).into_drawing_area();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(-1.5..1.5, -1.5..1.5)
+        .unwrap();
+    chart_context
+        .draw_series(
+            LineSeries::new((0..50).map(|t| (t as f64 / 8.0).sin_cos()), RED)
+                .with_arrowhead(Arrowhead::new(8).interval(80)),
+        )
+        .unwrap();
+    ```
+    */
+    pub fn with_arrowhead(mut self, arrowhead: Arrowhead) -> Self {
+        self.arrowhead = Some(arrowhead);
+        self
+    }
 }
 
 /// A dashed line series, map an iterable object to the dashed line element. Can be used to draw simple dashed and dotted lines.
@@ -99,11 +236,7 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
 ///         data_series,
 ///         5, /* size = length of dash */
 ///         10, /* spacing */
-///         ShapeStyle {
-///             color: BLACK.mix(1.0),
-///             filled: false,
-///             stroke_width: 1,
-///         },
+///         BLACK.stroke_width(1),
 ///     ))
 ///     .unwrap();
 /// ```
@@ -115,11 +248,7 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
 ///         data_series,
 ///         1, /* size = length of dash */
 ///         4, /* spacing, best to keep this at least 1 larger than size */
-///         ShapeStyle {
-///             color: BLACK.mix(1.0),
-///             filled: false,
-///             stroke_width: 1,
-///         },
+///         BLACK.stroke_width(1),
 ///     ))
 ///     .unwrap();
 /// ```
@@ -209,6 +338,52 @@ impl<I: Iterator + Clone, Size: SizeDesc, Marker: 'static> IntoIterator
     }
 }
 
+/**
+A smooth line series, which draws a curve through the data points instead of straight lines.
+
+The curve is interpolated in the pixel coordinates, see [`CurvedPathElement`] for details.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("curved_line_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .build_cartesian_2d(0.0..6.0, -1.2..1.2)
+    .unwrap();
+let data = (0..=6).map(|x| (x as f64, (x as f64).sin()));
+chart.draw_series(CurvedLineSeries::new(data.clone(), &BLUE)).unwrap();
+chart.draw_series(LineSeries::new(data, RED.mix(0.5))).unwrap();
+```
+*/
+pub struct CurvedLineSeries<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+}
+
+impl<Coord> CurvedLineSeries<Coord> {
+    /// Create a new smooth line series
+    /// - `iter`: The data points, in the order the curve goes through
+    /// - `style`: The style of the curve
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        Self {
+            points: iter.into_iter().collect(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<Coord> IntoIterator for CurvedLineSeries<Coord> {
+    type Item = CurvedPathElement<Coord>;
+    type IntoIter = std::iter::Once<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(CurvedPathElement::new(self.points, self.style))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -255,4 +430,82 @@ mod test {
             .draw_series(DottedLineSeries::new((0..=50).map(|x| (x, 0)), 5, 5, mk_f))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_curved_line_series() {
+        let drawing_area = create_mocked_drawing_area(201, 201, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.first(), Some(&(0, 200)));
+                assert_eq!(path.last(), Some(&(200, 200)));
+                assert!(path.contains(&(100, 0)));
+                assert!(path.len() > 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+        chart
+            .draw_series(CurvedLineSeries::new(vec![(0, 0), (5, 10), (10, 0)], RED))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_markers() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path.len(), 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+
+        chart
+            .draw_series(
+                LineSeries::new(vec![(1, 1), (2, 2), (3, 3)], BLUE).with_markers(Marker::Square, 2),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_line_series_with_gaps() {
+        let calls = record_draw_calls(200, 200, |drawing_area| {
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+                .unwrap();
+            chart
+                .draw_series(
+                    LineSeries::with_gaps(
+                        vec![
+                            (1.0, f64::NAN),
+                            (2.0, 2.0),
+                            (3.0, 3.0),
+                            (4.0, f64::NAN),
+                            (f64::NAN, 5.0),
+                            (6.0, 6.0),
+                            (7.0, 7.0),
+                        ],
+                        BLUE,
+                    )
+                    .with_markers(Marker::Square, 2),
+                )
+                .unwrap();
+        });
+
+        // The line is split at the missing points, and the markers are only on the present ones
+        let paths: Vec<_> = calls.paths.iter().map(|p| p.path.len()).collect();
+        assert_eq!(paths, [2, 2]);
+        assert_eq!(calls.rects.len(), 4);
+    }
 }
@@ -12,27 +12,48 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+mod downsampled;
+#[cfg(feature = "line_series")]
+mod envelope_series;
+mod gap;
+#[cfg(feature = "histogram")]
+mod grouped_bar_series;
+#[cfg(feature = "colormaps")]
+mod heatmap_series;
 #[cfg(feature = "histogram")]
 mod histogram;
 #[cfg(feature = "line_series")]
 mod line_series;
 #[cfg(feature = "point_series")]
 mod point_series;
+mod span_series;
 #[cfg(feature = "surface_series")]
 mod surface;
 
 #[cfg(feature = "area_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
 pub use area_series::AreaSeries;
+pub use downsampled::Downsampled;
+#[cfg(feature = "line_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
+pub use envelope_series::{Envelope, EnvelopeSeries};
+pub use gap::{MaybeMissing, MissingValue};
+#[cfg(feature = "histogram")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
+pub use grouped_bar_series::GroupedBarSeries;
+#[cfg(feature = "colormaps")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+pub use heatmap_series::HeatmapSeries;
 #[cfg(feature = "histogram")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
 pub use histogram::Histogram;
 #[cfg(feature = "line_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
-pub use line_series::{DashedLineSeries, DottedLineSeries, LineSeries};
+pub use line_series::{CurvedLineSeries, DashedLineSeries, DottedLineSeries, LineSeries, Marker};
 #[cfg(feature = "point_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
 pub use point_series::PointSeries;
+pub use span_series::{Span, SpanSeries};
 #[cfg(feature = "surface_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
 pub use surface::SurfaceSeries;
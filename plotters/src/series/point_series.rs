@@ -1,4 +1,5 @@
 use crate::element::PointElement;
+use crate::series::gap::MaybeMissing;
 use crate::style::{ShapeStyle, SizeDesc};
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
@@ -39,6 +40,36 @@ where
     }
 }
 
+impl<'a, Coord, E, Size: SizeDesc + Clone> PointSeries<'a, Coord, Vec<Coord>, E, Size>
+where
+    E: PointElement<Coord, Size>,
+{
+    /// Create a new point series which skips the missing data points, which are either `None`
+    /// or the coordinate tuples with a NaN float value. This is the point series counterpart of
+    /// [LineSeries::with_gaps](struct.LineSeries.html#method.with_gaps)
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    /// let drawing_area = SVGBackend::new("point_series_gaps.svg", (300, 200)).into_drawing_area();
+    /// let mut chart_context = ChartBuilder::on(&drawing_area)
+    ///     .build_cartesian_2d(0.0..3.0, 0.0..3.0)
+    ///     .unwrap();
+    /// let data = vec![(0.0, 1.0), (1.0, f64::NAN), (2.0, 2.0)];
+    /// chart_context
+    ///     .draw_series(PointSeries::<_, _, Circle<_, _>, _>::with_gaps(data, 3, RED))
+    ///     .unwrap();
+    /// ```
+    pub fn with_gaps<P, I, S>(iter: I, size: Size, style: S) -> Self
+    where
+        P: MaybeMissing<Coord>,
+        I: IntoIterator<Item = P>,
+        S: Into<ShapeStyle>,
+    {
+        let data = iter.into_iter().filter_map(P::into_present).collect();
+        Self::new(data, size, style)
+    }
+}
+
 impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
     PointSeries<'a, Coord, I, E, Size>
 {
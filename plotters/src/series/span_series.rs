@@ -0,0 +1,213 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::Rect;
+use crate::element::{Drawable, PointCollection, UnclampedCoordAndArea};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use std::ops::Range;
+
+/**
+A span series, which highlights intervals on one axis across the whole extent of the other axis,
+for example, the alarm periods on the time axis.
+
+Only the bounds of the intervals are in the data coordinates. The extent on the other axis is
+resolved against the plotting area when the span is drawn, thus the spans always cover the full
+height (or width) of the plot, no matter what the range of the other axis is. The intervals
+outside of the plot are truncated to the plotting area.
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("span_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_context = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..10.0, -1.5..1.5)
+    .unwrap();
+chart_context
+    .draw_series(SpanSeries::vertical(&chart_context, vec![1.0..2.5, 6.0..7.0], RED.mix(0.2).filled()))
+    .unwrap()
+    .label("Alarm");
+chart_context
+    .draw_series(LineSeries::new((0..=100).map(|x| (x as f64 / 10.0, (x as f64 / 10.0).sin())), BLUE))
+    .unwrap();
+```
+*/
+pub struct SpanSeries<Coord> {
+    spans: Vec<Span<Coord>>,
+}
+
+impl<X: Clone, Y: Clone> SpanSeries<(X, Y)> {
+    /// Create a series of vertical spans, which are intervals on the X axis covering the full
+    /// height of the plot
+    ///
+    /// - `chart`: The chart the series is drawn on, only used to pick a placeholder Y value
+    /// - `spans`: The intervals on the X axis
+    /// - `style`: The style of the spans, an unfilled style draws the outline only
+    /// - **returns**: The newly created series
+    pub fn vertical<DB, XR, YR, I, S>(
+        chart: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        spans: I,
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        I: IntoIterator<Item = Range<X>>,
+        S: Into<ShapeStyle>,
+    {
+        let y = chart.y_range().start;
+        let style = style.into();
+        Self {
+            spans: spans
+                .into_iter()
+                .map(|r| Span {
+                    points: [(r.start, y.clone()), (r.end, y.clone())],
+                    vertical: true,
+                    style,
+                })
+                .collect(),
+        }
+    }
+
+    /// Create a series of horizontal spans, which are intervals on the Y axis covering the full
+    /// width of the plot
+    ///
+    /// - `chart`: The chart the series is drawn on, only used to pick a placeholder X value
+    /// - `spans`: The intervals on the Y axis
+    /// - `style`: The style of the spans, an unfilled style draws the outline only
+    /// - **returns**: The newly created series
+    pub fn horizontal<DB, XR, YR, I, S>(
+        chart: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        spans: I,
+        style: S,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        I: IntoIterator<Item = Range<Y>>,
+        S: Into<ShapeStyle>,
+    {
+        let x = chart.x_range().start;
+        let style = style.into();
+        Self {
+            spans: spans
+                .into_iter()
+                .map(|r| Span {
+                    points: [(x.clone(), r.start), (x.clone(), r.end)],
+                    vertical: false,
+                    style,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<Coord> IntoIterator for SpanSeries<Coord> {
+    type Item = Span<Coord>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.spans.into_iter()
+    }
+}
+
+/// The element drawn by [`SpanSeries`], a rectangle between two bounds on one axis which is
+/// stretched to the edges of the plotting area on the other axis
+pub struct Span<Coord> {
+    points: [Coord; 2],
+    vertical: bool,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord, UnclampedCoordAndArea> for &'a Span<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB, UnclampedCoordAndArea> for Span<Coord> {
+    fn draw<I: Iterator<Item = (BackendCoord, Rect)>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, area) = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        let b = match points.next() {
+            Some((b, _)) => b,
+            None => return Ok(()),
+        };
+        // The bottom-right corner of the area is exclusive, while the one of draw_rect isn't
+        let (x0, y0) = area.upper_left();
+        let (x1, y1) = area.bottom_right();
+        let (x1, y1) = (x1 - 1, y1 - 1);
+        // The bounds aren't truncated by the mapping, so a span entirely outside of the plot
+        // isn't squashed onto its edge
+        let (upper_left, bottom_right) = if self.vertical {
+            ((a.0.min(b.0).max(x0), y0), (a.0.max(b.0).min(x1), y1))
+        } else {
+            ((x0, a.1.min(b.1).max(y0)), (x1, a.1.max(b.1).min(y1)))
+        };
+        if upper_left.0 > bottom_right.0 || upper_left.1 > bottom_right.1 {
+            // The span is entirely outside of the plot
+            return Ok(());
+        }
+        backend.draw_rect(upper_left, bottom_right, &self.style, self.style.filled)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_span_series() {
+        let calls = record_draw_calls(100, 100, |drawing_area| {
+            // The Y range doesn't contain any data, the spans still cover the full height
+            let mut chart = ChartBuilder::on(drawing_area)
+                .build_cartesian_2d(0..10, 1000..2000)
+                .unwrap();
+            chart
+                .draw_series(SpanSeries::vertical(
+                    &chart,
+                    vec![2..4, 8..20, 15..20, -5..-1, -5..1],
+                    RED.filled(),
+                ))
+                .unwrap();
+            chart
+                .draw_series(SpanSeries::horizontal(
+                    &chart,
+                    vec![1500..1750, 2500..3000],
+                    RED.filled(),
+                ))
+                .unwrap();
+        });
+
+        assert_eq!(calls.rects.len(), 4);
+        assert!(calls
+            .rects
+            .iter()
+            .all(|r| r.color == RED.to_rgba() && r.fill));
+        let rects: Vec<_> = calls
+            .rects
+            .iter()
+            .map(|r| (r.upper_left, r.bottom_right))
+            .collect();
+        assert_eq!(rects[0], ((19, 0), (39, 99)));
+        // The part outside of the plot is truncated
+        assert_eq!(rects[1], ((79, 0), (99, 99)));
+        // The spans entirely outside of the plot are skipped, instead of drawn on its edge
+        assert_eq!(rects[2], ((0, 0), (9, 99)));
+        assert_eq!(rects[3].0 .0, 0);
+        assert_eq!(rects[3].1 .0, 99);
+        assert!((rects[3].0 .1 - 25).abs() <= 1 && (rects[3].1 .1 - 50).abs() <= 1);
+    }
+}
@@ -96,6 +96,20 @@ impl<P: Palette> PaletteColor<P> {
     pub fn pick(idx: usize) -> PaletteColor<P> {
         PaletteColor(idx % P::COLORS.len(), PhantomData)
     }
+
+    /// Pick a color from the palette, or `None` if the index exceeds the palette
+    pub fn try_pick(idx: usize) -> Option<PaletteColor<P>> {
+        if idx < P::COLORS.len() {
+            Some(PaletteColor(idx, PhantomData))
+        } else {
+            None
+        }
+    }
+
+    /// Get the index of the color in the palette
+    pub fn index(&self) -> usize {
+        self.0
+    }
 }
 
 impl<P: Palette> Color for PaletteColor<P> {
@@ -1,4 +1,7 @@
-use crate::style::{HSLColor, RGBAColor, RGBColor};
+use crate::style::{Color, HSLColor, RGBAColor, RGBColor};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 /// Converts scalar values to colors.
 pub trait ColorMap<ColorType: crate::prelude::Color, FloatType = f32>
@@ -368,3 +371,110 @@ def_linear_colormap! {
     BROWN,
     ORANGE
 }
+
+type SharedColorMap = Arc<dyn ColorMap<RGBAColor, f64> + Send + Sync>;
+
+// Converts the colors of a colormap to RGBA, so that the colormaps of any color type can be
+// stored in the registry
+struct RGBAAdapter<C, M>(M, std::marker::PhantomData<fn() -> C>);
+
+impl<C: Color, M: ColorMap<C, f64>> ColorMap<RGBAColor, f64> for RGBAAdapter<C, M> {
+    fn get_color_normalized(&self, h: f64, min: f64, max: f64) -> RGBAColor {
+        self.0.get_color_normalized(h, min, max).to_rgba()
+    }
+}
+
+static COLORMAPS: Lazy<RwLock<HashMap<String, SharedColorMap>>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    let mut add = |name: &str, colormap: SharedColorMap| {
+        map.insert(name.to_string(), colormap);
+    };
+    add("viridis", Arc::new(ViridisRGBA));
+    add(
+        "black_white",
+        Arc::new(RGBAAdapter(BlackWhite, Default::default())),
+    );
+    add(
+        "mandelbrot",
+        Arc::new(RGBAAdapter(MandelbrotHSL, Default::default())),
+    );
+    add(
+        "vulcano",
+        Arc::new(RGBAAdapter(VulcanoHSL, Default::default())),
+    );
+    add("bone", Arc::new(RGBAAdapter(Bone, Default::default())));
+    add("copper", Arc::new(RGBAAdapter(Copper, Default::default())));
+    RwLock::new(map)
+});
+
+/// A colormap looked up by its name at runtime, for example, from a configuration file.
+///
+/// The built-in colormaps are registered as `viridis`, `black_white`, `mandelbrot`, `vulcano`,
+/// `bone` and `copper`. Other crates can [register](#method.register) their own colormaps, which
+/// are then available to the whole application. The names are case-insensitive.
+///
+/// ```
+/// use plotters::prelude::*;
+///
+/// def_linear_colormap! {
+///     Cividis,
+///     RGBColor,
+///     "A colormap for the people with color vision deficiency.",
+///     (  0,  34,  78),
+///     (124, 123, 120),
+///     (254, 232,  56)
+/// }
+///
+/// DynColorMap::register("cividis", Cividis);
+///
+/// let colormap = DynColorMap::by_name("Cividis").unwrap();
+/// assert_eq!(colormap.get_color(0.0), RGBAColor(0, 34, 78, 1.0));
+/// assert!(DynColorMap::names().contains(&"viridis".to_string()));
+/// assert!(DynColorMap::by_name("unknown").is_none());
+/// ```
+#[derive(Clone)]
+pub struct DynColorMap(SharedColorMap);
+
+impl DynColorMap {
+    /// Register a colormap, which replaces the existing one with the same name
+    ///
+    /// - `name`: The name of the colormap
+    /// - `colormap`: The colormap, its colors are converted to RGBA when they are looked up
+    pub fn register<C, M>(name: &str, colormap: M)
+    where
+        C: Color + 'static,
+        M: ColorMap<C, f64> + Send + Sync + 'static,
+    {
+        let colormap = Arc::new(RGBAAdapter(colormap, std::marker::PhantomData));
+        COLORMAPS
+            .write()
+            .unwrap()
+            .insert(name.to_lowercase(), colormap);
+    }
+
+    /// Look up a registered colormap
+    ///
+    /// - `name`: The name of the colormap
+    /// - **returns**: The colormap, or `None` if there's no colormap with this name
+    pub fn by_name(name: &str) -> Option<Self> {
+        COLORMAPS
+            .read()
+            .unwrap()
+            .get(&name.to_lowercase())
+            .cloned()
+            .map(DynColorMap)
+    }
+
+    /// Get the names of all the registered colormaps, in alphabetical order
+    pub fn names() -> Vec<String> {
+        let mut names: Vec<_> = COLORMAPS.read().unwrap().keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+impl ColorMap<RGBAColor, f64> for DynColorMap {
+    fn get_color_normalized(&self, h: f64, min: f64, max: f64) -> RGBAColor {
+        self.0.get_color_normalized(h, min, max)
+    }
+}
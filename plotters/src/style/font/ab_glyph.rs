@@ -65,6 +65,7 @@ pub fn register_font(
 
 #[derive(Clone)]
 pub struct FontDataInternal {
+    family: String,
     font_ref: FontRef<'static>,
 }
 
@@ -89,6 +90,7 @@ impl FontData for FontDataInternal {
     type ErrorType = FontError;
     fn new(family: FontFamily<'_>, style: FontStyle) -> Result<Self, Self::ErrorType> {
         Ok(Self {
+            family: family.as_str().to_owned(),
             font_ref: FONTS
                 .read()
                 .unwrap()
@@ -98,6 +100,14 @@ impl FontData for FontDataInternal {
                 .clone(),
         })
     }
+    fn family_name(&self) -> Option<String> {
+        Some(self.family.clone())
+    }
+    fn available_families() -> Vec<String> {
+        let mut families: Vec<_> = FONTS.read().unwrap().keys().cloned().collect();
+        families.sort();
+        families
+    }
     // TODO: ngl, it makes no sense that this uses the same error type as `new`
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
         let pixel_per_em = size / 1.24;
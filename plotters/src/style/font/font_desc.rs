@@ -2,6 +2,7 @@ use super::{FontData, FontDataInternal};
 use crate::style::text_anchor::Pos;
 use crate::style::{Color, TextStyle};
 
+use std::borrow::Cow;
 use std::convert::From;
 
 pub use plotters_backend::{FontFamily, FontStyle, FontTransform};
@@ -12,6 +13,57 @@ pub type FontError = <FontDataInternal as FontData>::ErrorType;
 /// The type we used to represent a result of any font operations
 pub type FontResult<T> = Result<T, FontError>;
 
+/// The error returned when the requested font isn't available, see
+/// [FontDesc::resolve](struct.FontDesc.html#method.resolve) for details
+#[derive(Clone, Debug)]
+pub struct MissingFont {
+    family: String,
+    style: String,
+    available: Vec<String>,
+}
+
+impl MissingFont {
+    fn new(family: FontFamily, style: FontStyle) -> Self {
+        Self {
+            family: family.as_str().to_owned(),
+            style: style.as_str().to_owned(),
+            available: FontDataInternal::available_families(),
+        }
+    }
+
+    /// Get the name of the requested font family
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// Get the name of the requested font style
+    pub fn style(&self) -> &str {
+        &self.style
+    }
+
+    /// Get the names of the font families that are available. The list is empty when the font
+    /// implementation isn't able to enumerate the fonts.
+    pub fn available_families(&self) -> &[String] {
+        &self.available
+    }
+}
+
+impl std::fmt::Display for MissingFont {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "No such font: {} {}", self.family, self.style)?;
+        if !self.available.is_empty() {
+            write!(
+                fmt,
+                ", available font families: {}",
+                self.available.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MissingFont {}
+
 /// Describes a font
 #[derive(Clone)]
 pub struct FontDesc<'a> {
@@ -20,6 +72,7 @@ pub struct FontDesc<'a> {
     data: FontResult<FontDataInternal>,
     transform: FontTransform,
     style: FontStyle,
+    fallback: Vec<FontFamily<'a>>,
 }
 
 impl<'a> FontDesc<'a> {
@@ -36,6 +89,7 @@ impl<'a> FontDesc<'a> {
             data: FontDataInternal::new(family, style),
             transform: FontTransform::None,
             style,
+            fallback: vec![],
         }
     }
 
@@ -50,6 +104,7 @@ impl<'a> FontDesc<'a> {
             data: self.data.clone(),
             transform: self.transform.clone(),
             style: self.style,
+            fallback: self.fallback.clone(),
         }
     }
 
@@ -64,6 +119,7 @@ impl<'a> FontDesc<'a> {
             data: self.data.clone(),
             transform: self.transform.clone(),
             style,
+            fallback: self.fallback.clone(),
         }
     }
 
@@ -78,9 +134,104 @@ impl<'a> FontDesc<'a> {
             data: self.data.clone(),
             transform: trans,
             style: self.style,
+            fallback: self.fallback.clone(),
         }
     }
 
+    /// Check which font is actually used for this font description.
+    ///
+    /// The font lookup may silently substitute a default font when the requested family isn't
+    /// installed, and the error of a failed lookup only surfaces when the text is drawn. This
+    /// function reports both cases upfront. A generic family, such as `FontFamily::SansSerif`,
+    /// is resolved to whatever font the system provides for it.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// match ("no-such-font", 20).into_font().resolve() {
+    ///     Ok(family) => println!("Using {}", family),
+    ///     Err(e) => println!("Available fonts: {:?}", e.available_families()),
+    /// }
+    /// ```
+    ///
+    /// - **returns**: The name of the family which is actually selected, or the error listing the
+    ///   available families. When the font implementation doesn't load any font data, for example
+    ///   on the web, the requested family name is returned. If the font has a fallback chain, see
+    ///   [with_fallback](#method.with_fallback), the first available family of the chain is
+    ///   returned.
+    pub fn resolve(&self) -> Result<String, MissingFont> {
+        let result = self.resolve_family();
+        if result.is_err() {
+            if let Some(font) = self.fallback_fonts().find(|font| font.validate().is_ok()) {
+                return font.resolve_family();
+            }
+        }
+        result
+    }
+
+    // Resolve the family of this font, regardless of the fallback chain
+    fn resolve_family(&self) -> Result<String, MissingFont> {
+        let font = self
+            .data
+            .as_ref()
+            .map_err(|_| MissingFont::new(self.family, self.style))?;
+        match (font.family_name(), self.family) {
+            (Some(selected), FontFamily::Name(name)) if !selected.eq_ignore_ascii_case(name) => {
+                Err(MissingFont::new(self.family, self.style))
+            }
+            (Some(selected), _) => Ok(selected),
+            (None, family) => Ok(family.as_str().to_owned()),
+        }
+    }
+
+    /// Check if the requested font is available.
+    /// See [resolve](#method.resolve) for details.
+    pub fn validate(&self) -> Result<(), MissingFont> {
+        self.resolve().map(|_| ())
+    }
+
+    /// Use the first available font family of the fallback chain if the font isn't available.
+    ///
+    /// The chain is resolved when the text is measured or drawn, thus the font description
+    /// still reports the requested family.
+    ///
+    /// - `fallback`: The font families to try in order
+    /// - **returns** The font description with the fallback chain, which draws with this font
+    ///   if none of the families is available
+    pub fn with_fallback(&self, fallback: &[FontFamily<'a>]) -> Self {
+        Self {
+            fallback: fallback.to_vec(),
+            ..self.clone()
+        }
+    }
+
+    /// Get the fallback chain of the font
+    pub(crate) fn get_fallback(&self) -> &[FontFamily<'a>] {
+        &self.fallback
+    }
+
+    // The fonts of the fallback chain, this font excluded
+    fn fallback_fonts(&self) -> impl Iterator<Item = FontDesc<'a>> + '_ {
+        self.fallback.iter().map(move |family| {
+            FontDesc::new(*family, self.size, self.style).transform(self.get_transform())
+        })
+    }
+
+    // The font which is actually used for drawing, the first available one of the fallback chain
+    fn selected(&self) -> Cow<'_, FontDesc<'a>> {
+        if self.fallback.is_empty() || self.resolve_family().is_ok() {
+            return Cow::Borrowed(self);
+        }
+        self.fallback_fonts()
+            .find(|font| font.validate().is_ok())
+            .map_or(Cow::Borrowed(self), Cow::Owned)
+    }
+
+    /// Get the family which is actually used for drawing, see [with_fallback](#method.with_fallback)
+    pub(crate) fn get_selected_family(&self) -> FontFamily<'a> {
+        self.selected().family
+    }
+
     /// Get the font transformation description
     pub fn get_transform(&self) -> FontTransform {
         self.transform.clone()
@@ -114,6 +265,7 @@ impl<'a> FontDesc<'a> {
             font: self.clone(),
             color: color.to_backend_color(),
             pos: Pos::default(),
+            halo: None,
         }
     }
 
@@ -142,7 +294,8 @@ impl<'a> FontDesc<'a> {
     /// For a TTF type, zero point of the layout box is the left most baseline char of the string
     /// Thus the upper bound of the box is most likely be negative
     pub fn layout_box(&self, text: &str) -> FontResult<((i32, i32), (i32, i32))> {
-        match &self.data {
+        let selected = self.selected();
+        match &selected.data {
             Ok(ref font) => font.estimate_layout(self.size, text),
             Err(e) => Err(e.clone()),
         }
@@ -164,7 +317,8 @@ impl<'a> FontDesc<'a> {
         (x, y): (i32, i32),
         draw: DrawFunc,
     ) -> FontResult<Result<(), E>> {
-        match &self.data {
+        let selected = self.selected();
+        match &selected.data {
             Ok(ref font) => font.draw((x, y), self.size, text, draw),
             Err(e) => Err(e.clone()),
         }
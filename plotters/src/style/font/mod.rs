@@ -61,10 +61,22 @@ pub use font_desc::*;
 /// Represents a box where a text label can be fit
 pub type LayoutBox = ((i32, i32), (i32, i32));
 
+/// The ratio between the ascent of the font and the height of its layout box, which is where the
+/// font rasterizer puts the baseline
+pub(crate) const BASELINE_RATIO: f64 = 0.76;
+
 pub trait FontData: Clone {
     type ErrorType: Sized + std::error::Error + Clone;
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, Self::ErrorType>;
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType>;
+    /// The family name of the font that is actually selected, if the implementation knows it
+    fn family_name(&self) -> Option<String> {
+        None
+    }
+    /// The font families available to the implementation, if it is able to enumerate them
+    fn available_families() -> Vec<String> {
+        vec![]
+    }
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
         _pos: (i32, i32),
@@ -204,6 +204,17 @@ impl FontData for FontDataInternal {
         Ok(FontDataInternal(load_font_data(family, style)?))
     }
 
+    fn family_name(&self) -> Option<String> {
+        Some(self.0.family_name())
+    }
+
+    fn available_families() -> Vec<String> {
+        let mut families = FONT_SOURCE.with(|source| source.all_families().unwrap_or_default());
+        families.sort();
+        families.dedup();
+        families
+    }
+
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
         let font = &self.0;
         let pixel_per_em = size / 1.24;
@@ -317,4 +328,24 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_resolve_font() {
+        use crate::style::FontDesc;
+
+        let font = FontDesc::new(FontFamily::SansSerif, 12.0, FontStyle::Normal);
+        let family = font.resolve().unwrap();
+        assert!(!family.is_empty());
+
+        let missing = FontDesc::new("no-such-font-family".into(), 12.0, FontStyle::Normal);
+        let error = missing.resolve().unwrap_err();
+        assert_eq!(error.family(), "no-such-font-family");
+        assert!(error.available_families().contains(&family));
+
+        let fallback =
+            missing.with_fallback(&["no-such-font-either".into(), FontFamily::SansSerif]);
+        assert_eq!(fallback.get_family().as_str(), "no-such-font-family");
+        assert_eq!(fallback.get_selected_family().as_str(), "sans-serif");
+        assert_eq!(fallback.resolve().unwrap(), family);
+    }
 }
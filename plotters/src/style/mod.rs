@@ -5,6 +5,7 @@ mod color;
 pub mod colors;
 mod font;
 mod palette;
+mod script;
 mod shape;
 mod size;
 mod text;
@@ -20,11 +21,15 @@ pub use colors::full_palette;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "ab_glyph"))]
 pub use font::register_font;
+pub(crate) use font::BASELINE_RATIO;
 pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
+    MissingFont,
 };
 
-pub use shape::ShapeStyle;
+pub use script::{Script, ScriptText};
+pub use shape::{DashPattern, LineCap, LineJoin, ShapeStyle};
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
+pub(crate) use text::OwnedTextStyle;
 pub use text::{IntoTextStyle, TextStyle};
@@ -1,16 +1,158 @@
-use super::color::PaletteColor;
+use super::color::{PaletteColor, RGBColor};
+use std::iter::Cycle;
+use std::marker::PhantomData;
+use std::ops::Range;
 
 /// Represents a color palette
 pub trait Palette {
     /// Array of colors
     const COLORS: &'static [(u8, u8, u8)];
-    /// Returns a color from the palette
+    /// Returns a color from the palette, the index wraps around when it exceeds the palette
     fn pick(idx: usize) -> PaletteColor<Self>
     where
         Self: Sized,
     {
         PaletteColor::<Self>::pick(idx)
     }
+
+    /// Returns a color from the palette, or `None` if the index exceeds the palette
+    fn try_pick(idx: usize) -> Option<PaletteColor<Self>>
+    where
+        Self: Sized,
+    {
+        PaletteColor::<Self>::try_pick(idx)
+    }
+
+    /// Returns the number of distinct colors in the palette
+    fn len() -> usize
+    where
+        Self: Sized,
+    {
+        Self::COLORS.len()
+    }
+
+    /// Returns an iterator over all the colors in the palette, in order
+    fn iter() -> PaletteIter<Self>
+    where
+        Self: Sized,
+    {
+        PaletteIter {
+            range: 0..Self::COLORS.len(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns an endless iterator repeating the colors of the palette
+    fn cycle() -> Cycle<PaletteIter<Self>>
+    where
+        Self: Sized,
+    {
+        Self::iter().cycle()
+    }
+}
+
+/// The iterator over the colors of a palette, see [Palette::iter](trait.Palette.html#method.iter)
+pub struct PaletteIter<P: Palette> {
+    range: Range<usize>,
+    phantom: PhantomData<P>,
+}
+
+impl<P: Palette> Clone for PaletteIter<P> {
+    fn clone(&self) -> Self {
+        Self {
+            range: self.range.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P: Palette> Iterator for PaletteIter<P> {
+    type Item = PaletteColor<P>;
+    fn next(&mut self) -> Option<PaletteColor<P>> {
+        self.range.next().map(PaletteColor::pick)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<P: Palette> DoubleEndedIterator for PaletteIter<P> {
+    fn next_back(&mut self) -> Option<PaletteColor<P>> {
+        self.range.next_back().map(PaletteColor::pick)
+    }
+}
+
+impl<P: Palette> ExactSizeIterator for PaletteIter<P> {}
+
+/// A palette selected at runtime, which refers to the colors of any palette.
+///
+/// Unlike the [Palette](trait.Palette.html) types, the palette can be stored and passed around as
+/// a value, thus the code assigning the colors to the series can switch the palette based on the
+/// number of series.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let series_count = 12;
+/// let palette = if series_count <= Palette9999::len() {
+///     DynPalette::of::<Palette9999>()
+/// } else {
+///     DynPalette::of::<Palette99>()
+/// };
+/// assert!(palette.len() >= series_count);
+/// let colors: Vec<RGBColor> = palette.iter().take(series_count).collect();
+/// assert_eq!(colors[0], palette.pick(0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DynPalette<'a> {
+    colors: &'a [(u8, u8, u8)],
+}
+
+impl<'a> DynPalette<'a> {
+    /// Create a palette from a list of colors
+    pub fn new(colors: &'a [(u8, u8, u8)]) -> Self {
+        Self { colors }
+    }
+
+    /// Refer to the colors of a predefined palette
+    pub fn of<P: Palette>() -> DynPalette<'static> {
+        DynPalette { colors: P::COLORS }
+    }
+
+    /// Returns the number of distinct colors in the palette
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns true if the palette has no color
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns a color from the palette, the index wraps around when it exceeds the palette
+    ///
+    /// Panics if the palette is empty
+    pub fn pick(&self, idx: usize) -> RGBColor {
+        let (r, g, b) = self.colors[idx % self.colors.len()];
+        RGBColor(r, g, b)
+    }
+
+    /// Returns a color from the palette, or `None` if the index exceeds the palette
+    pub fn try_pick(&self, idx: usize) -> Option<RGBColor> {
+        self.colors.get(idx).map(|&(r, g, b)| RGBColor(r, g, b))
+    }
+
+    /// Returns an iterator over all the colors in the palette, in order
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = RGBColor> + ExactSizeIterator + Clone + 'a {
+        self.colors.iter().map(|&(r, g, b)| RGBColor(r, g, b))
+    }
+
+    /// Returns an endless iterator repeating the colors of the palette
+    pub fn cycle(&self) -> impl Iterator<Item = RGBColor> + Clone + 'a {
+        self.iter().cycle()
+    }
 }
 
 /// The palette of 99% accessibility
@@ -64,3 +206,28 @@ impl Palette for Palette100 {
     const COLORS: &'static [(u8, u8, u8)] =
         &[(255, 225, 25), (0, 130, 200), (128, 128, 128), (0, 0, 0)];
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_palette_iteration() {
+        assert_eq!(Palette100::len(), 4);
+        assert_eq!(Palette99::iter().len(), Palette99::len());
+        assert_eq!(Palette99::iter().next_back().map(|c| c.index()), Some(20));
+        assert_eq!(Palette100::try_pick(3).map(|c| c.index()), Some(3));
+        assert!(Palette100::try_pick(4).is_none());
+        assert_eq!(Palette100::cycle().nth(5).map(|c| c.index()), Some(1));
+
+        let palette = DynPalette::of::<Palette9999>();
+        assert_eq!(palette.len(), Palette9999::len());
+        assert!(palette
+            .iter()
+            .zip(Palette9999::iter())
+            .all(|(a, b)| a.to_backend_color().rgb == b.to_backend_color().rgb));
+        assert_eq!(palette.try_pick(9), None);
+        assert_eq!(palette.pick(9), palette.pick(0));
+    }
+}
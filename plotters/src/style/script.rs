@@ -0,0 +1,360 @@
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::font::{FontTransform, BASELINE_RATIO};
+use super::text::{text_anchor, TextStyle};
+use plotters_backend::BackendCoord;
+
+/// The size of the superscripts and subscripts relative to the normal text
+const SCRIPT_SCALE: f64 = 0.7;
+/// How much the baseline of a superscript is raised, relative to the height of the normal text
+const SUPERSCRIPT_RISE: f64 = 0.35;
+/// How much the baseline of a subscript is lowered, relative to the height of the normal text
+const SUBSCRIPT_DROP: f64 = 0.2;
+
+/// The vertical placement of a run of text in a [`ScriptText`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Script {
+    /// The run is drawn on the baseline with the normal size
+    Normal,
+    /// The run is drawn smaller and above the baseline, such as the exponent of `10⁴`
+    Superscript,
+    /// The run is drawn smaller and below the baseline, such as the index of `CO₂`
+    Subscript,
+}
+
+/**
+A line of text made of runs which are either normal text, superscripts or subscripts. This is
+the minimal rich text model for math-style labels, such as `10⁴` or `CO₂`, which can be
+returned by the label formatter set with
+[`MeshStyle::x_label_script_formatter()`](crate::chart::MeshStyle::x_label_script_formatter).
+
+The text is either built run by run, or parsed from a TeX-like markup, where `^` starts a
+superscript and `_` starts a subscript, which is a single character or a group in braces.
+A literal `^`, `_` or `\` is escaped with a backslash, as is a `}` in a group.
+
+```
+use plotters::prelude::*;
+let text = ScriptText::new().normal("10").superscript("-4");
+assert_eq!(text, ScriptText::parse("10^{-4}"));
+assert_eq!(ScriptText::parse("CO_2").to_string(), "CO_{2}");
+```
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptText {
+    runs: Vec<(String, Script)>,
+}
+
+impl ScriptText {
+    /// Create an empty script text
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the script text from a TeX-like markup, for example `10^{4}` or `CO_2`.
+    /// A `^` or `_` which isn't followed by any character is kept as it is, so is a backslash
+    /// which doesn't escape anything.
+    /// - `text`: The markup to parse
+    /// - **returns**: The parsed script text
+    pub fn parse(text: &str) -> Self {
+        let mut ret = Self::new();
+        let mut normal = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            let script = match c {
+                '\\' => {
+                    normal.push(unescape(&mut chars, NORMAL_ESCAPED));
+                    continue;
+                }
+                '^' => Script::Superscript,
+                '_' => Script::Subscript,
+                _ => {
+                    normal.push(c);
+                    continue;
+                }
+            };
+            let run: String = match chars.next() {
+                Some('{') => {
+                    let mut run = String::new();
+                    while let Some(c) = chars.next() {
+                        match c {
+                            '}' => break,
+                            '\\' => run.push(unescape(&mut chars, GROUP_ESCAPED)),
+                            c => run.push(c),
+                        }
+                    }
+                    run
+                }
+                Some(c) => c.to_string(),
+                None => {
+                    normal.push(c);
+                    break;
+                }
+            };
+            ret = ret.normal(std::mem::take(&mut normal)).push(run, script);
+        }
+        ret.normal(normal)
+    }
+
+    fn push(mut self, text: String, script: Script) -> Self {
+        if !text.is_empty() {
+            match self.runs.last_mut() {
+                Some((last, last_script)) if *last_script == script => last.push_str(&text),
+                _ => self.runs.push((text, script)),
+            }
+        }
+        self
+    }
+
+    /// Append a run of normal text
+    /// - `text`: The text to append
+    pub fn normal<T: Into<String>>(self, text: T) -> Self {
+        self.push(text.into(), Script::Normal)
+    }
+
+    /// Append a superscript
+    /// - `text`: The text to append
+    pub fn superscript<T: Into<String>>(self, text: T) -> Self {
+        self.push(text.into(), Script::Superscript)
+    }
+
+    /// Append a subscript
+    /// - `text`: The text to append
+    pub fn subscript<T: Into<String>>(self, text: T) -> Self {
+        self.push(text.into(), Script::Subscript)
+    }
+
+    /// Format the number in the mantissa-exponent form, such as `2.5×10⁶`, with at most four
+    /// significant digits. The mantissa is omitted for the powers of ten, and the exponent is
+    /// omitted when it's zero.
+    /// - `value`: The number to format
+    /// - **returns**: The formatted number
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    /// assert_eq!(ScriptText::scientific(2500000.0).to_string(), "2.5×10^{6}");
+    /// assert_eq!(ScriptText::scientific(0.001).to_string(), "10^{-3}");
+    /// assert_eq!(ScriptText::scientific(-7.0).to_string(), "-7");
+    /// ```
+    pub fn scientific(value: f64) -> Self {
+        if value == 0.0 || !value.is_finite() {
+            return Self::new().normal(value.to_string());
+        }
+        let formatted = format!("{:.3e}", value);
+        let (mantissa, exponent) = formatted.split_at(formatted.find('e').unwrap_or(0));
+        let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+        let exponent = &exponent[1..];
+        match (mantissa, exponent) {
+            (mantissa, "0") => Self::new().normal(mantissa),
+            ("1", exponent) => Self::new().normal("10").superscript(exponent),
+            ("-1", exponent) => Self::new().normal("-10").superscript(exponent),
+            (mantissa, exponent) => Self::new()
+                .normal(format!("{}×10", mantissa))
+                .superscript(exponent),
+        }
+    }
+
+    /// Iterate over the runs of the text
+    pub fn runs(&self) -> impl Iterator<Item = (&str, Script)> {
+        self.runs
+            .iter()
+            .map(|(text, script)| (text.as_str(), *script))
+    }
+
+    /// Get the text which is drawn as a single run in the given style, which is the case when
+    /// the text is a single line without any script, or the font is transformed, since the runs
+    /// are only laid out horizontally. Otherwise `None` is returned.
+    pub(crate) fn plain_text(&self, style: &TextStyle) -> Option<Cow<'_, str>> {
+        match &self.runs[..] {
+            [] => Some(Cow::Borrowed("")),
+            [(text, Script::Normal)] if !text.contains('\n') => Some(Cow::Borrowed(text)),
+            _ if !matches!(style.font.get_transform(), FontTransform::None) => Some(Cow::Owned(
+                self.runs
+                    .iter()
+                    .map(|(text, _)| text.replace('\n', " "))
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Lay out the runs in the given style, see [`ScriptText::plain_text()`] for the text which
+    /// is laid out as a single run. The lines separated by `\n` are stacked and centered.
+    /// - `style`: The style of the normal text
+    /// - `measure`: The function which estimates the size of a text in a style
+    /// - **returns**: The size of the whole text, and the runs with their style and the offset
+    ///   of their upper left corner
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn layout<'s, 'b, E, F: FnMut(&str, &TextStyle) -> Result<(u32, u32), E>>(
+        &'s self,
+        style: &TextStyle<'b>,
+        mut measure: F,
+    ) -> Result<((u32, u32), Vec<(Cow<'s, str>, TextStyle<'b>, BackendCoord)>), E> {
+        let style = style.pos(text_anchor::Pos::default());
+        if let Some(text) = self.plain_text(&style) {
+            let size = measure(&text, &style)?;
+            return Ok((size, vec![(text, style, (0, 0))]));
+        }
+
+        let mut lines = vec![vec![]];
+        for (text, script) in &self.runs {
+            for (idx, part) in text.split('\n').enumerate() {
+                if idx > 0 {
+                    lines.push(vec![]);
+                }
+                if !part.is_empty() {
+                    lines.last_mut().unwrap().push((part, *script));
+                }
+            }
+        }
+
+        let mut laid_out = Vec::with_capacity(lines.len());
+        for line in &lines {
+            laid_out.push(layout_line(line, &style, &mut measure)?);
+        }
+
+        let width = laid_out.iter().map(|(w, ..)| *w).max().unwrap_or(0);
+        let mut runs = Vec::with_capacity(self.runs.len());
+        let mut y = 0;
+        for (w, h, line_runs) in laid_out {
+            let x = (width - w) as i32 / 2;
+            runs.extend(
+                line_runs
+                    .into_iter()
+                    .map(|(text, style, (dx, dy))| (text, style, (x + dx, y + dy))),
+            );
+            y += h as i32;
+        }
+        Ok(((width, y as u32), runs))
+    }
+}
+
+/// Lay out a single line of runs horizontally
+/// - `line`: The runs of the line
+/// - `style`: The style of the normal text
+/// - `measure`: The function which estimates the size of a text in a style
+/// - **returns**: The width and the height of the line, and the runs with their style and the
+///   offset of their upper left corner from the upper left corner of the line
+#[allow(clippy::type_complexity)]
+fn layout_line<'s, 'b, E, F: FnMut(&str, &TextStyle) -> Result<(u32, u32), E>>(
+    line: &[(&'s str, Script)],
+    style: &TextStyle<'b>,
+    measure: &mut F,
+) -> Result<(u32, u32, Vec<(Cow<'s, str>, TextStyle<'b>, BackendCoord)>), E> {
+    let script_style = TextStyle {
+        font: style.font.resize(style.font.get_size() * SCRIPT_SCALE),
+        ..style.clone()
+    };
+    let (_, height) = measure("", style)?;
+    let height = f64::from(height);
+    let baseline = height * BASELINE_RATIO;
+
+    let mut runs = Vec::with_capacity(line.len());
+    let mut x = 0;
+    let (mut top, mut bottom) = (0.0f64, height);
+    for &(text, script) in line {
+        let run_style = match script {
+            Script::Normal => style,
+            _ => &script_style,
+        };
+        let (w, h) = measure(text, run_style)?;
+        let h = f64::from(h);
+        let y = match script {
+            Script::Normal => 0.0,
+            Script::Superscript => baseline - height * SUPERSCRIPT_RISE - h * BASELINE_RATIO,
+            Script::Subscript => baseline + height * SUBSCRIPT_DROP - h * BASELINE_RATIO,
+        };
+        top = top.min(y);
+        bottom = bottom.max(y + h);
+        runs.push((Cow::Borrowed(text), run_style.clone(), (x, y)));
+        x += w as i32;
+    }
+
+    let runs = runs
+        .into_iter()
+        .map(|(text, style, (x, y))| (text, style, (x, (y - top).round() as i32)))
+        .collect();
+    Ok((x as u32, (bottom - top).round() as u32, runs))
+}
+
+/// The characters escaped by a backslash in the normal text of the markup
+const NORMAL_ESCAPED: &[char] = &['\\', '^', '_'];
+/// The characters escaped by a backslash in a group of the markup
+const GROUP_ESCAPED: &[char] = &['\\', '}'];
+
+// Take the character escaped by the backslash just read, the backslash itself is the result if it
+// doesn't escape anything
+fn unescape(chars: &mut Peekable<Chars>, escaped: &[char]) -> char {
+    match chars.peek() {
+        Some(c) if escaped.contains(c) => chars.next().unwrap(),
+        _ => '\\',
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter, text: &str, escaped: &[char]) -> fmt::Result {
+    for c in text.chars() {
+        if escaped.contains(&c) {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for ScriptText {
+    /// Format the text with the same markup accepted by [`ScriptText::parse()`]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (text, script) in &self.runs {
+            match script {
+                Script::Normal => write_escaped(f, text, NORMAL_ESCAPED)?,
+                Script::Superscript => {
+                    f.write_str("^{")?;
+                    write_escaped(f, text, GROUP_ESCAPED)?;
+                    f.write_char('}')?;
+                }
+                Script::Subscript => {
+                    f.write_str("_{")?;
+                    write_escaped(f, text, GROUP_ESCAPED)?;
+                    f.write_char('}')?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<String> for ScriptText {
+    fn from(text: String) -> Self {
+        Self::new().normal(text)
+    }
+}
+
+impl<'a> From<&'a str> for ScriptText {
+    fn from(text: &'a str) -> Self {
+        Self::new().normal(text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_script_text_round_trip() {
+        let text = ScriptText::new()
+            .normal("a^b_c\\d")
+            .superscript("x}\\")
+            .normal("\\")
+            .subscript("{i}");
+        assert_eq!(text.to_string(), "a\\^b\\_c\\\\d^{x\\}\\\\}\\\\_{{i\\}}");
+        assert_eq!(ScriptText::parse(&text.to_string()), text);
+
+        // The backslash which doesn't escape anything is kept
+        assert_eq!(
+            ScriptText::parse("C:\\dir_1"),
+            ScriptText::new().normal("C:\\dir").subscript("1")
+        );
+    }
+}
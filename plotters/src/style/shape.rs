@@ -1,7 +1,13 @@
 use super::color::{Color, RGBAColor};
 use plotters_backend::{BackendColor, BackendStyle};
+pub use plotters_backend::{DashPattern, LineCap, LineJoin};
 
 /// Style for any shape
+///
+/// The style is made from a color, such as `BLUE.stroke_width(2)`, or with
+/// [`ShapeStyle::new()`], which replaces the struct literal `ShapeStyle { color, filled,
+/// stroke_width }`. The stroke details are set with the builder methods, such as
+/// [`ShapeStyle::line_join()`].
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ShapeStyle {
     /// Specification of the color.
@@ -10,9 +16,41 @@ pub struct ShapeStyle {
     pub filled: bool,
     /// Stroke width.
     pub stroke_width: u32,
+    /// How the segments of a thick stroke are joined, see [`ShapeStyle::line_join()`].
+    line_join: LineJoin,
+    /// How the ends of a thick stroke are drawn, see [`ShapeStyle::line_cap()`].
+    line_cap: LineCap,
+    /// The dash pattern of the stroke, the stroke is solid if there's none, see
+    /// [`ShapeStyle::dash_pattern()`].
+    dash_pattern: Option<DashPattern>,
 }
 
 impl ShapeStyle {
+    /**
+    Creates a style with the solid stroke, the mitered joins and the butt caps.
+
+    - `color`: The color of the style
+    - `filled`: Whether the style is filled with color
+    - `stroke_width`: The stroke width
+    - **returns**: The newly created style
+
+    ```
+    use plotters::prelude::*;
+    let style = ShapeStyle::new(BLUE.mix(0.6), false, 2);
+    assert_eq!(style, BLUE.mix(0.6).stroke_width(2));
+    ```
+    */
+    pub fn new<C: Color>(color: C, filled: bool, stroke_width: u32) -> Self {
+        Self {
+            color: color.to_rgba(),
+            filled,
+            stroke_width,
+            line_join: LineJoin::Miter,
+            line_cap: LineCap::Butt,
+            dash_pattern: None,
+        }
+    }
+
     /**
     Returns a filled style with the same color and stroke width.
 
@@ -20,11 +58,7 @@ impl ShapeStyle {
 
     ```
     use plotters::prelude::*;
-    let original_style = ShapeStyle {
-        color: BLUE.mix(0.6),
-        filled: false,
-        stroke_width: 2,
-    };
+    let original_style = BLUE.mix(0.6).stroke_width(2);
     let filled_style = original_style.filled();
     let drawing_area = SVGBackend::new("shape_style_filled.svg", (400, 200)).into_drawing_area();
     drawing_area.fill(&WHITE).unwrap();
@@ -40,7 +74,7 @@ impl ShapeStyle {
         Self {
             color: self.color.to_rgba(),
             filled: true,
-            stroke_width: self.stroke_width,
+            ..*self
         }
     }
 
@@ -51,11 +85,7 @@ impl ShapeStyle {
 
     ```
     use plotters::prelude::*;
-    let original_style = ShapeStyle {
-        color: BLUE.mix(0.6),
-        filled: false,
-        stroke_width: 2,
-    };
+    let original_style = BLUE.mix(0.6).stroke_width(2);
     let new_style = original_style.stroke_width(5);
     let drawing_area = SVGBackend::new("shape_style_stroke_width.svg", (400, 200)).into_drawing_area();
     drawing_area.fill(&WHITE).unwrap();
@@ -70,19 +100,99 @@ impl ShapeStyle {
     pub fn stroke_width(&self, width: u32) -> Self {
         Self {
             color: self.color.to_rgba(),
-            filled: self.filled,
             stroke_width: width,
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the same color and stroke width, joining the segments of the stroke
+    as specified. This only makes a difference for strokes thicker than 1 pixel.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("shape_style_line_join.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let style = BLUE.stroke_width(10);
+    for (idx, join) in [LineJoin::Miter, LineJoin::Round, LineJoin::Bevel].iter().enumerate() {
+        let x = 50 + idx as i32 * 120;
+        drawing_area
+            .draw(&PathElement::new(
+                vec![(x, 150), (x + 50, 50), (x + 100, 150)],
+                style.line_join(*join),
+            ))
+            .unwrap();
+    }
+    ```
+    */
+    pub fn line_join(&self, join: LineJoin) -> Self {
+        Self {
+            color: self.color.to_rgba(),
+            line_join: join,
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the same color and stroke width, drawing the ends of the stroke as
+    specified. This only makes a difference for strokes thicker than 1 pixel.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("shape_style_line_cap.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let style = BLUE.stroke_width(20);
+    for (idx, cap) in [LineCap::Butt, LineCap::Round, LineCap::Square].iter().enumerate() {
+        let y = 50 + idx as i32 * 50;
+        drawing_area
+            .draw(&PathElement::new(vec![(50, y), (350, y)], style.line_cap(*cap)))
+            .unwrap();
+    }
+    ```
+    */
+    pub fn line_cap(&self, cap: LineCap) -> Self {
+        Self {
+            color: self.color.to_rgba(),
+            line_cap: cap,
+            ..*self
+        }
+    }
+
+    /**
+    Returns a new style with the same color and stroke width, drawing the stroke with the given
+    dash pattern. Lines, paths and the outlines of rectangles can be dashed.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("shape_style_dash_pattern.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let style = BLUE.stroke_width(2).dash_pattern(DashPattern::new(10, 5));
+    drawing_area
+        .draw(&PathElement::new(vec![(50, 150), (200, 50), (350, 150)], style))
+        .unwrap();
+    drawing_area
+        .draw(&Rectangle::new([(20, 20), (380, 180)], style))
+        .unwrap();
+    ```
+    */
+    pub fn dash_pattern(&self, pattern: DashPattern) -> Self {
+        Self {
+            color: self.color.to_rgba(),
+            dash_pattern: Some(pattern),
+            ..*self
         }
     }
 }
 
 impl<T: Color> From<T> for ShapeStyle {
     fn from(f: T) -> Self {
-        ShapeStyle {
-            color: f.to_rgba(),
-            filled: false,
-            stroke_width: 1,
-        }
+        ShapeStyle::new(f, false, 1)
     }
 }
 
@@ -95,4 +205,16 @@ impl BackendStyle for ShapeStyle {
     fn stroke_width(&self) -> u32 {
         self.stroke_width
     }
+    /// Returns how the segments of a thick stroke are joined.
+    fn line_join(&self) -> LineJoin {
+        self.line_join
+    }
+    /// Returns how the ends of a thick stroke are drawn.
+    fn line_cap(&self) -> LineCap {
+        self.line_cap
+    }
+    /// Returns the dash pattern of the stroke.
+    fn dash_pattern(&self) -> Option<DashPattern> {
+        self.dash_pattern
+    }
 }
@@ -14,6 +14,9 @@ pub struct TextStyle<'a> {
     pub color: BackendColor,
     /// The anchor point position
     pub pos: text_anchor::Pos,
+    /// The halo around the glyphs, as the color and the width in pixels, see
+    /// [`TextStyle::halo()`]
+    pub(crate) halo: Option<(BackendColor, u32)>,
 }
 
 /// Trait for values that can be converted into `TextStyle` values
@@ -150,6 +153,7 @@ impl<'a> TextStyle<'a> {
             font: self.font.clone(),
             color: color.to_backend_color(),
             pos: self.pos,
+            halo: self.halo,
         }
     }
 
@@ -168,6 +172,7 @@ impl<'a> TextStyle<'a> {
             font: self.font.clone().transform(trans),
             color: self.color,
             pos: self.pos,
+            halo: self.halo,
         }
     }
 
@@ -192,6 +197,110 @@ impl<'a> TextStyle<'a> {
             font: self.font.clone(),
             color: self.color,
             pos,
+            halo: self.halo,
+        }
+    }
+
+    /// Sets the halo of the text, which is an outline in a contrasting color drawn around the
+    /// glyphs, so that the labels placed over the data remain readable.
+    ///
+    /// - `color`: The color of the halo, usually the background color
+    /// - `width`: The width of the halo in pixels
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("sans-serif", 20).into_font()).halo(&WHITE, 2);
+    /// ```
+    pub fn halo<C: Color>(&self, color: &C, width: u32) -> Self {
+        Self {
+            font: self.font.clone(),
+            color: self.color,
+            pos: self.pos,
+            halo: Some((color.to_backend_color(), width)),
+        }
+    }
+
+    /// Sets the fallback font families, which are used in order when the font isn't available.
+    ///
+    /// - `fallback`: The fallback font families
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("Fira Sans", 20).into_font())
+    ///     .with_fallback(&["Helvetica".into(), FontFamily::SansSerif]);
+    /// ```
+    ///
+    /// See [FontDesc::resolve](struct.FontDesc.html#method.resolve) for how the availability
+    /// is checked.
+    pub fn with_fallback(&self, fallback: &[FontFamily<'a>]) -> Self {
+        Self {
+            font: self.font.with_fallback(fallback),
+            color: self.color,
+            pos: self.pos,
+            halo: self.halo,
+        }
+    }
+}
+
+/// A text style which owns the names of its font families, thus it's kept by the chart context
+/// without borrowing from the builder. It doesn't hold the loaded font, which is looked up again
+/// when the style is used, so that the chart state stays `Send`.
+#[derive(Clone)]
+pub(crate) struct OwnedTextStyle {
+    // The font family followed by its fallback chain, the name is owned for a named family
+    families: Vec<(Option<String>, FontFamily<'static>)>,
+    size: f64,
+    style: FontStyle,
+    transform: FontTransform,
+    color: BackendColor,
+    pos: text_anchor::Pos,
+    halo: Option<(BackendColor, u32)>,
+}
+
+impl OwnedTextStyle {
+    pub(crate) fn new(style: &TextStyle) -> Self {
+        let families = std::iter::once(&style.font.get_family())
+            .chain(style.font.get_fallback())
+            .map(|family| match family {
+                FontFamily::Name(name) => (Some((*name).to_owned()), FontFamily::SansSerif),
+                FontFamily::Serif => (None, FontFamily::Serif),
+                FontFamily::SansSerif => (None, FontFamily::SansSerif),
+                FontFamily::Monospace => (None, FontFamily::Monospace),
+            })
+            .collect();
+        Self {
+            families,
+            size: style.font.get_size(),
+            style: style.font.get_style(),
+            transform: style.font.get_transform(),
+            color: style.color,
+            pos: style.pos,
+            halo: style.halo,
+        }
+    }
+
+    /// Get the text style borrowing the family names
+    pub(crate) fn get(&self) -> TextStyle<'_> {
+        let families: Vec<_> = self
+            .families
+            .iter()
+            .map(|(name, generic)| match name.as_ref() {
+                Some(name) => FontFamily::Name(name),
+                None => *generic,
+            })
+            .collect();
+        let font = FontDesc::new(families[0], self.size, self.style)
+            .transform(self.transform.clone())
+            .with_fallback(&families[1..]);
+        TextStyle {
+            font,
+            color: self.color,
+            pos: self.pos,
+            halo: self.halo,
         }
     }
 }
@@ -277,6 +386,7 @@ impl<'a, T: Into<FontDesc<'a>>> From<T> for TextStyle<'a> {
             font: font.into(),
             color: BLACK.to_backend_color(),
             pos: text_anchor::Pos::default(),
+            halo: None,
         }
     }
 }
@@ -309,7 +419,11 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
     }
 
     fn family(&self) -> FontFamily {
-        self.font.get_family()
+        self.font.get_selected_family()
+    }
+
+    fn halo(&self) -> Option<(BackendColor, u32)> {
+        self.halo
     }
 
     fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(